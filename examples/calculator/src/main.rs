@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use pogui::gui::*;
 use pogui::native_gui::NativeGui;
+use pogui::vtree::{Element, VTree};
 
 
 #[derive(Clone, Copy, PartialEq)]
@@ -16,16 +17,16 @@ struct Calc {
     state:   f64,
     action:  Action,
     input:   String,
-    display: Node,
+    display: VTree,
 }
 
 impl Calc {
     fn new(display: Node, g: &mut Gui) -> Calc {
-        let this = Calc {
+        let mut this = Calc {
             state:  0.0,
             action: Action::None,
             input:  String::new(),
-            display,
+            display: VTree::new(display),
         };
         this.update_display(g);
         this
@@ -37,7 +38,7 @@ impl Calc {
     }
 
     fn action(&mut self, a: Action, g: &mut Gui) {
-        if self.input.len() > 0 {
+        if !self.input.is_empty() {
             let input = self.input.parse::<f64>().unwrap();
             self.input.clear();
 
@@ -53,15 +54,15 @@ impl Calc {
         self.update_display(g)
     }
 
-    fn update_display(&self, g: &mut Gui) {
+    fn update_display(&mut self, g: &mut Gui) {
         let text =
-            if self.input.len() == 0 {
-                g.create_text(&self.state.to_string())
+            if self.input.is_empty() {
+                Element::text(&self.state.to_string())
             }
             else {
-                g.create_text(&self.input)
+                Element::text(&self.input)
             };
-        g.set_children(self.display, [text]);
+        self.display.update(g, vec![text]);
     }
 }
 
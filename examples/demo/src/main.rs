@@ -3,12 +3,13 @@ use std::rc::Rc;
 
 use pogui::gui::*;
 use pogui::native_gui::NativeGui;
+use pogui::signal::Signal;
 
 
 
 fn mk_node<C: IntoIterator<Item=Node>>(kind: NodeKind, children: C, style: &[(&str, &str)], gui: &mut Gui) -> Node {
     let node = gui.create_node(kind);
-    gui.set_children(node, children.into_iter());
+    gui.set_children(node, children);
     gui.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
     node
 }
@@ -82,9 +83,11 @@ pub fn main() {
         let bw = mk_button(g, "ffffff", mk_button_handler(&active, &hidden));
 
 
-        let state = Rc::new(Cell::new(1));
+        let count = Signal::new(1);
 
-        let the_span = span([text(&state.get().to_string(), g)], &[], g);
+        let count_text = text("", g);
+        g.bind_text(count_text, &count.map(|count| count.to_string()));
+        let the_span = span([count_text], &[], g);
 
         let nodes =
             [
@@ -132,11 +135,7 @@ pub fn main() {
                     text(" ", g),
                     button([text("increment", g)], &[
                         ("background_color", "ffffdd"),
-                    ], { let state = state.clone(); move |gui, _e| {
-                        state.set(state.get() + 1);
-                        let new_text = text(&state.get().to_string(), gui);
-                        gui.set_children(the_span, [new_text]);
-                    }}, g),
+                    ], move |_, _e| count.update(|count| *count += 1), g),
                     text(" ", g),
                     div([
                         div([text("hi", g)], &[], g),
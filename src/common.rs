@@ -108,6 +108,7 @@ pub enum Layout {
 }
 
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Cursor {
     Default,
     Pointer,
@@ -1,51 +1,92 @@
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
+use std::rc::Rc;
 
+#[cfg(windows)]
 use crate::win::*;
 use crate::text::*;
 
 
 pub struct CtxData {
+    #[cfg(windows)]
     pub dw_factory: IDWriteFactory2,
+    #[cfg(windows)]
     pub dw_system_fonts:    IDWriteFontCollection,
+    #[cfg(windows)]
     pub dw_system_fallback: IDWriteFontFallback,
 
     pub fonts: RefCell<Fonts>,
+    pub text_backend: Cell<TextBackend>,
 }
 
 
-#[derive(Clone, Copy)]
-pub struct Ctx (pub &'static CtxData);
+/// the fonts & text backend state `Gui`s use.
+/// cheap to clone. `Gui`s can share a context or each have their own.
+/// freed, when the last clone is dropped.
+#[derive(Clone)]
+pub struct Ctx (Rc<CtxData>);
 
 impl Ctx {
+    #[cfg(windows)]
     pub fn new() -> Ctx {unsafe {
         let dw_factory: IDWriteFactory2 = DWriteCreateFactory(DWRITE_FACTORY_TYPE_SHARED).unwrap();
 
         let mut dw_system_fonts = None;
         dw_factory.GetSystemFontCollection(&mut dw_system_fonts, false).unwrap();
 
-        let ctx = Ctx(Box::leak(Box::new(CtxData {
+        let ctx = Ctx(Rc::new(CtxData {
             dw_factory: dw_factory.clone(),
             dw_system_fonts:    dw_system_fonts.unwrap(),
             dw_system_fallback: dw_factory.GetSystemFontFallback().unwrap(),
 
-            fonts: RefCell::new(Fonts::new()),
-        })));
+            fonts: RefCell::new(Fonts::new(dw_factory.clone())),
+            text_backend: Cell::new(TextBackend::default()),
+        }));
 
         // TODO: how to set up default font?
-        ctx.fonts.borrow_mut().query("Tahoma", ctx).unwrap();
+        ctx.fonts.borrow_mut().query("Tahoma", &ctx).unwrap();
 
         ctx
     }}
 
+    #[cfg(not(windows))]
+    pub fn new() -> Ctx {
+        let ctx = Ctx(Rc::new(CtxData {
+            fonts: RefCell::new(Fonts::new()),
+            text_backend: Cell::new(TextBackend::default()),
+        }));
+
+        // TODO: how to set up default font?
+        let defaults = ["DejaVu Sans", "Noto Sans", "Liberation Sans", "Helvetica", "Arial"];
+        if !defaults.iter().any(|name| ctx.font_query(name).is_some()) {
+            ctx.fonts.borrow_mut().add_empty_family("");
+        }
+
+        // windows uses DirectWrite's system fallback instead.
+        // TODO: per script & locale.
+        let fallback = [
+            "Noto Sans CJK SC", "Noto Sans CJK JP", "Droid Sans Fallback", "WenQuanYi Micro Hei",
+            "PingFang SC", "Hiragino Sans", "Apple SD Gothic Neo",
+            "Noto Sans Symbols", "Noto Sans Symbols2", "Apple Symbols",
+            "DejaVu Sans", "FreeSans", "Arial Unicode MS",
+        ];
+        let fallback: Vec<FontFamilyId> = fallback.iter().filter_map(|name| ctx.font_query(name)).collect();
+        ctx.fonts.borrow_mut().set_global_fallback(&fallback);
+
+        ctx
+    }
+
 
     #[inline]
-    pub fn font_query(self, family_name: &str) -> Option<FontFamilyId> {
+    pub fn font_query(&self, family_name: &str) -> Option<FontFamilyId> {
         self.fonts.borrow_mut().query(family_name, self)
     }
 }
 
+impl Default for Ctx {
+    #[inline] fn default() -> Ctx { Ctx::new() }
+}
+
 impl core::ops::Deref for Ctx {
     type Target = CtxData;
     #[inline] fn deref(&self) -> &Self::Target { &self.0 }
 }
-
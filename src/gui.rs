@@ -1,17 +1,31 @@
+use core::any::Any;
 use core::cell::*;
 use core::num::NonZeroU32;
 use std::rc::Rc;
+use std::path::Path;
 
-use crate::win::*;
 use crate::common::*;
-use crate::ctx::Ctx;
 use crate::node::*;
+use crate::paint::{Painter, Rect};
+use crate::paint::image::Image;
+use crate::paint::software::render_frame;
+use crate::signal::Signal;
+use crate::selector::{Selector, SelectorError};
+use crate::mutation::*;
+use crate::iter::*;
+use crate::timer::*;
+use crate::text::{FontFamilyId, FontFallbackId};
 
 
 pub struct Gui {
     ctx: Ctx,
 
     pub(crate) nodes: Vec<NodeWrapper>,
+    first_free: Option<u32>,
+    // generation of slots past the end, so compaction doesn't revive old handles.
+    next_gen:   NonZeroU32,
+    num_live:   usize,
+    peak_live:  usize,
     root: Node,
 
     hover:  Option<Node>,
@@ -20,12 +34,35 @@ pub struct Gui {
     passive_focus: Option<(Node, usize)>,
 
     window_size: [f32; 2],
+
+    // bound signal changes, applied in the next layout.
+    pending_bindings: Rc<RefCell<Vec<BindingUpdate>>>,
+
+    observers: Vec<MutationObserver>,
+    next_observer_id: u32,
+
+    timers: Timers,
 }
 
+type BindingUpdate = Box<dyn FnOnce(&mut Gui)>;
+
 pub(crate) struct NodeWrapper {
     data: RefCell<NodeData>,
     gen:  NonZeroU32,
     used: bool,
+    next_free: Option<u32>,
+}
+
+
+/// node allocation statistics, see `Gui::node_stats`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct NodeStats {
+    /// nodes, that have been created & not destroyed.
+    pub live: usize,
+    /// slots of destroyed nodes, reused by the next created ones.
+    pub free: usize,
+    /// the most live nodes at once.
+    pub peak: usize,
 }
 
 
@@ -38,7 +75,7 @@ pub enum NodeKind {
 }
 
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Node {
     index: u32,
     gen:   NonZeroU32,
@@ -47,26 +84,26 @@ pub struct Node {
 impl Node {
     #[inline]
     pub(crate) fn get(self, nodes: &[NodeWrapper]) -> &NodeWrapper {
-        let result = &nodes[self.index as usize];
-        assert_eq!(self.gen, result.gen);
-        assert!(result.used);
+        let result = nodes.get(self.index as usize).expect("stale node handle");
+        assert!(result.gen == self.gen && result.used, "stale node handle");
         debug_assert_eq!(result.data.borrow().this, self);
         result
     }
 
     #[inline]
-    pub(crate) fn borrow(self, gui: &Gui) -> Ref<NodeData> {
+    pub(crate) fn borrow(self, gui: &Gui) -> Ref<'_, NodeData> {
         self.get(&gui.nodes).data.borrow()
     }
 
     #[inline]
-    pub(crate) fn borrow_mut(self, gui: &Gui) -> RefMut<NodeData> {
+    pub(crate) fn borrow_mut(self, gui: &Gui) -> RefMut<'_, NodeData> {
         self.get(&gui.nodes).data.borrow_mut()
     }
 }
 
 
 pub use crate::common::Cursor;
+pub use crate::ctx::Ctx;
 
 
 pub struct Event {
@@ -98,6 +135,11 @@ pub trait IGui {
 
     fn swap_nodes(&mut self, a: Node, b: Node);
 
+    /// a detached deep copy of `node`.
+    /// copies kinds, styles, texts, ids, classes & click handlers.
+    /// user data & signal bindings aren't copied.
+    fn clone_subtree(&mut self, node: Node) -> Node;
+
     fn get_parent(&self, node: Node) -> Option<Node>;
     fn get_first_child(&self, node: Node) -> Option<Node>;
     fn get_last_child(&self, node: Node) -> Option<Node>;
@@ -109,11 +151,38 @@ pub trait IGui {
     fn next_node_post_order(&self, node: Node) -> Option<Node>;
     fn prev_node_post_order(&self, node: Node) -> Option<Node>;
 
+    fn children(&self, node: Node) -> Children<'_>;
+    fn children_rev(&self, node: Node) -> ChildrenRev<'_>;
+    /// the descendants of `node` in pre-order, without `node`.
+    fn descendants(&self, node: Node) -> Descendants<'_>;
+    /// the parent, its parent, ..., up to the root.
+    fn ancestors(&self, node: Node) -> Ancestors<'_>;
+    /// enters & leaves `node` & its descendants, in pre-order.
+    fn traverse(&self, node: Node) -> Traverse<'_>;
+
+    /// the first descendant of `root` in pre-order, that matches `selector`.
+    /// errors, if the selector is invalid, see `Selector::parse`.
+    fn query_selector(&self, root: Node, selector: &str) -> Result<Option<Node>, SelectorError>;
+    /// all descendants of `root`, that match `selector`, in pre-order.
+    fn query_selector_all(&self, root: Node, selector: &str) -> Result<Vec<Node>, SelectorError>;
+
     fn set_style(&mut self, node: Node, style: Style);
     fn set_text(&mut self, node: Node, text: String);
 
     fn set_on_click<H: EventHandler>(&mut self, node: Node, handler: H);
 
+    fn set_id(&mut self, node: Node, id: Option<String>);
+    fn get_id(&self, node: Node) -> Option<String>;
+
+    fn add_class(&mut self, node: Node, class: &str);
+    fn remove_class(&mut self, node: Node, class: &str);
+    fn has_class(&self, node: Node, class: &str) -> bool;
+
+    /// replaces the node's user data, of any type.
+    fn set_user_data<T: Any>(&mut self, node: Node, data: T);
+    /// `None`, if there's none, or it's not a `T`.
+    fn user_data<T: Any>(&self, node: Node) -> Option<Rc<T>>;
+
     fn on_key_down(&mut self, vk: u32);
     fn on_key_up(&mut self, vk: u32);
     fn on_char(&mut self, cp: char, shift_down: bool);
@@ -125,10 +194,19 @@ pub trait IGui {
 
     fn set_window_size(&mut self, w: f32, h: f32);
 
-    fn paint(&mut self, rt: &ID2D1RenderTarget);
+    fn paint(&mut self, painter: &mut dyn Painter);
 
     fn get_cursor(&self) -> Cursor;
 
+    fn get_hover(&self)  -> Option<Node>;
+    fn get_active(&self) -> Option<Node>;
+    fn get_focus(&self)  -> Option<Node>;
+
+    /// the node's box in window coordinates, as of the last layout.
+    /// only divs & buttons have boxes.
+    fn get_rect(&self, node: Node) -> Rect;
+    fn get_scroll_pos(&self, node: Node) -> [f32; 2];
+
     fn root(&self) -> Node;
 }
 
@@ -143,12 +221,20 @@ impl Gui {
         let mut gui = Gui {
             ctx,
             nodes: vec![],
+            first_free: None,
+            next_gen:   NonZeroU32::new(1).unwrap(),
+            num_live:   0,
+            peak_live:  0,
             root: fake_root,
             hover:  None,
             active: None,
             focus:  None,
             passive_focus: None,
             window_size: [0.0; 2],
+            pending_bindings: Default::default(),
+            observers: vec![],
+            next_observer_id: 0,
+            timers: Timers::default(),
         };
         gui.root = gui.alloc_node(NodeKind::Div);
         // TEMP: invariant: all nodes in the tree have a parent.
@@ -159,26 +245,183 @@ impl Gui {
 }
 
 impl Gui {
-    pub(crate) fn alloc_node(&mut self, kind: NodeKind) -> Node {
-        println!("create {:?}", kind);
-        for (i, n) in self.nodes.iter_mut().enumerate() {
+    /// keeps the text of the text `node` in sync with `signal`.
+    /// changes are applied in the next layout.
+    /// the binding ends, when the node is destroyed.
+    pub fn bind_text(&mut self, node: Node, signal: &Signal<String>) {
+        self.set_text(node, signal.get());
+        self.bind(node, signal, |gui, node, value| gui.set_text(node, value));
+    }
+
+    /// keeps the style property `name` of `node` in sync with `signal`.
+    /// see `bind_text`.
+    pub fn bind_style(&mut self, node: Node, name: &str, signal: &Signal<String>) {
+        let name = name.to_string();
+        self.set_style_property(node, name.clone(), signal.get());
+        self.bind(node, signal, move |gui, node, value| {
+            gui.set_style_property(node, name.clone(), value);
+        });
+    }
+
+    fn set_style_property(&mut self, node: Node, name: String, value: String) {
+        let mut d = node.borrow_mut(self);
+        if d.style.get(&name) == Some(&value) {
+            return;
+        }
+        let old_value = d.style.clone();
+        let mut style = old_value.clone();
+        style.insert(name, value);
+        d.set_style(style);
+        drop(d);
+        self.record_mutation(MutationRecord::Style { target: node, old_value });
+    }
+
+    fn bind<F: Fn(&mut Gui, Node, String) + 'static>(&mut self, node: Node, signal: &Signal<String>, apply: F) {
+        // the node owns the signal, the signal only refers to the node.
+        let token: Rc<dyn Any> = Rc::new(signal.clone());
+        let alive = Rc::downgrade(&token);
+        node.borrow_mut(self).bindings.push(token);
+
+        let apply = Rc::new(apply);
+        let queue = Rc::downgrade(&self.pending_bindings);
+        signal.subscribe(move |value| {
+            let Some(queue) = queue.upgrade() else { return false };
+            if alive.strong_count() == 0 {
+                return false;
+            }
+
+            let apply = apply.clone();
+            let value = value.clone();
+            queue.borrow_mut().push(Box::new(move |gui: &mut Gui| {
+                // destroyed since.
+                if gui.is_alive(node) {
+                    apply(gui, node, value);
+                }
+            }));
+            true
+        });
+    }
+
+    /// whether `node` hasn't been destroyed.
+    pub fn is_alive(&self, node: Node) -> bool {
+        self.nodes.get(node.index as usize)
+            .is_some_and(|n| n.used && n.gen == node.gen)
+    }
+
+    pub fn node_stats(&self) -> NodeStats {
+        NodeStats {
+            live: self.num_live,
+            free: self.nodes.len() - self.num_live,
+            peak: self.peak_live,
+        }
+    }
+
+    /// releases the memory of free slots at the end of the node storage.
+    /// nodes never move, so handles stay valid.
+    /// that's also the limit: free slots before the last live node are kept,
+    /// only reused by the next created nodes.
+    /// the free list is rebuilt in order, so low slots are reused first.
+    pub fn compact_nodes(&mut self) {
+        while let Some(n) = self.nodes.last() {
+            if n.used {
+                break;
+            }
+            self.next_gen = self.next_gen.max(n.gen);
+            self.nodes.pop();
+        }
+        self.nodes.shrink_to_fit();
+
+        self.first_free = None;
+        for (i, n) in self.nodes.iter_mut().enumerate().rev() {
             if !n.used {
-                println!("reuse");
-                let node = Node { index: i as u32, gen: n.gen };
-                let mut d = n.data.borrow_mut();
-                d.kind = kind;
-                d.this = node;
-                n.used = true;
-                return node;
+                n.next_free = self.first_free;
+                self.first_free = Some(i as u32);
             }
         }
+    }
+
+    /// the context, to create `Gui`s that share its fonts.
+    #[inline]
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    /// a font family by name, registered or installed.
+    pub fn query_font(&self, name: &str) -> Option<FontFamilyId> {
+        self.ctx.font_query(name)
+    }
+
+    /// registers the faces of a font file (`.ttf`, `.otf`, `.ttc`, `.otc`).
+    /// returns the family of the file's first face.
+    pub fn add_font_file<P: AsRef<Path>>(&mut self, path: P) -> Option<FontFamilyId> {
+        self.ctx.fonts.borrow_mut().add_file(path.as_ref())
+    }
+
+    /// registers the faces of a font file in memory.
+    /// returns the family of the first face.
+    pub fn add_font_data(&mut self, data: &[u8]) -> Option<FontFamilyId> {
+        self.ctx.fonts.borrow_mut().add_data(data)
+    }
+
+    /// registers the font files in `dir` & its subdirectories.
+    /// returns the number of faces added.
+    pub fn add_font_dir<P: AsRef<Path>>(&mut self, dir: P) -> usize {
+        self.ctx.fonts.borrow_mut().add_dir(dir.as_ref())
+    }
+
+    /// the family of text without a `font_family` style.
+    pub fn set_default_font(&mut self, font: FontFamilyId) {
+        self.ctx.fonts.borrow_mut().set_default_family(font);
+    }
+
+    /// a fallback chain for `TextFormat::fallback`,
+    /// for chars the format's family has no glyphs for.
+    pub fn add_font_fallback(&mut self, families: &[FontFamilyId]) -> FontFallbackId {
+        self.ctx.fonts.borrow_mut().add_fallback(families)
+    }
+
+    /// the families tried for all text, after the format's fallback chain.
+    pub fn set_global_font_fallback(&mut self, families: &[FontFamilyId]) {
+        self.ctx.fonts.borrow_mut().set_global_fallback(families);
+    }
+}
+
+impl Gui {
+    /// renders a frame of `size` offscreen, with the software renderer.
+    /// doesn't need a window or GPU.
+    pub fn screenshot(&mut self, size: [u32; 2]) -> Image {
+        let window_size = self.window_size;
+        let pixmap = render_frame(self, size[0], size[1]);
+        self.set_window_size(window_size[0], window_size[1]);
+        Image::new(pixmap)
+    }
+}
+
+impl Gui {
+    pub(crate) fn alloc_node(&mut self, kind: NodeKind) -> Node {
+        self.num_live += 1;
+        self.peak_live = self.peak_live.max(self.num_live);
+
+        if let Some(index) = self.first_free {
+            let n = &mut self.nodes[index as usize];
+            debug_assert!(!n.used);
+            self.first_free = n.next_free.take();
+
+            let node = Node { index, gen: n.gen };
+            let mut d = n.data.borrow_mut();
+            d.kind = kind;
+            d.this = node;
+            n.used = true;
+            return node;
+        }
 
-        let gen = NonZeroU32::new(1).unwrap();
+        let gen = self.next_gen;
         let node = Node { index: self.nodes.len() as u32, gen };
         self.nodes.push(NodeWrapper {
             data: RefCell::new(NodeData::new(kind, node)),
             gen,
             used: true,
+            next_free: None,
         });
         node
     }
@@ -205,15 +448,69 @@ impl Gui {
         assert_eq!(n.gen, node.gen);
         assert!(n.used);
 
-        let mut d = n.data.borrow_mut();
-        println!("destroy {:?}", d.kind);
-        *d = NodeData::new(NodeKind::Div, Node { index: u32::MAX, gen: NonZeroU32::new(u32::MAX).unwrap() });
+        *n.data.get_mut() = NodeData::new(NodeKind::Div, Node { index: u32::MAX, gen: NonZeroU32::new(u32::MAX).unwrap() });
         n.gen = NonZeroU32::new(n.gen.get() + 1).unwrap();
         n.used = false;
+        n.next_free = self.first_free;
+        self.first_free = Some(node.index);
+        self.num_live -= 1;
     }
 
+    fn unlink_child(&mut self, parent: Node, child: Node) {
+        let mut p = parent.borrow_mut(self);
+        let mut c = child.borrow_mut(self);
+        assert_eq!(c.parent, Some(parent)); // TEMP
+
+        let record = MutationRecord::ChildList {
+            target:  parent,
+            added:   vec![],
+            removed: vec![child],
+            prev_sibling: c.prev_sibling,
+            next_sibling: c.next_sibling,
+        };
+
+        if let Some(prev) = c.prev_sibling {
+            let mut prev = prev.borrow_mut(self);
+            assert_eq!(prev.next_sibling, Some(child));
+            prev.next_sibling = c.next_sibling;
+        }
+        else {
+            assert_eq!(p.first_child, Some(child));
+            p.first_child = c.next_sibling;
+        }
+
+        if let Some(next) = c.next_sibling {
+            let mut next = next.borrow_mut(self);
+            assert_eq!(next.prev_sibling, Some(child));
+            next.prev_sibling = c.prev_sibling;
+        }
+        else {
+            assert_eq!(p.last_child, Some(child));
+            p.last_child = c.prev_sibling;
+        }
+
+        c.parent = None;
+        c.next_sibling = None;
+        c.prev_sibling = None;
+
+        drop((p, c));
+        self.record_mutation(record);
+    }
+
+    /// removes `node` from its old parent.
+    /// moves within `new_parent` keep hover, active & focus.
+    fn detach_for_insert(&mut self, new_parent: Node, node: Node) {
+        if let Some(old_parent) = self.get_parent(node) {
+            if old_parent == new_parent {
+                self.unlink_child(old_parent, node);
+            }
+            else {
+                self.remove_child(old_parent, node, true);
+            }
+        }
+    }
 
-    fn check_tree(&self) -> bool {
+    pub(crate) fn check_tree(&self) -> bool {
         // check hover/active refs are valid.
         if let Some(hover)  = self.hover  { let h = hover.borrow(self);  assert_ne!(h.parent, None); }
         if let Some(active) = self.active { let a = active.borrow(self); assert_ne!(a.parent, None); }
@@ -281,7 +578,7 @@ impl Gui {
         }
 
         let root_index = self.root.index as usize;
-        assert!(visited[root_index] == false);
+        assert!(!visited[root_index]);
         visited[root_index] = true;
 
         for (i, n) in self.nodes.iter().enumerate() {
@@ -295,11 +592,16 @@ impl Gui {
     }
 
     fn next_pre_order<P: Fn(&NodeData) -> bool>(&self, node: Node, p: P) -> Option<Node> {
+        self.next_pre_order_in(self.root, node, p)
+    }
+
+    /// like `next_pre_order`, but only visits the descendants of `scope`.
+    pub(crate) fn next_pre_order_in<P: Fn(&NodeData) -> bool>(&self, scope: Node, node: Node, p: P) -> Option<Node> {
         let mut at = node;
         loop {
             let d = at.borrow(self);
             let first_child = d.first_child;
-            let mut parent = d.parent.unwrap();
+            let mut parent = d.parent;
             let mut next   = d.next_sibling;
             drop(d);
 
@@ -307,19 +609,19 @@ impl Gui {
                 at = first_child;
             }
             else {
-                if at == self.root {
+                if at == scope {
                     return None;
                 }
 
                 // go up, until we can go right.
                 while next.is_none() {
-                    at = parent;
-                    if at == self.root {
+                    at = parent.unwrap();
+                    if at == scope {
                         return None;
                     }
 
                     let d = at.borrow(self);
-                    parent = d.parent.unwrap();
+                    parent = d.parent;
                     next   = d.next_sibling;
                 }
                 at = next.unwrap();
@@ -420,6 +722,25 @@ impl Gui {
         }
     }
 
+    /// styles & lays out the tree for the current window size.
+    pub(crate) fn layout(&mut self) {
+        let updates = core::mem::take(&mut *self.pending_bindings.borrow_mut());
+        for update in updates {
+            update(self);
+        }
+        self.flush_mutations();
+
+        let [w, h] = self.window_size;
+
+        let mut root = self.root.borrow_mut(self);
+        root.style(self, &Style::new());
+        root.render_children(&self.ctx, self);
+        root.layout(self, LayoutBox::tight([(w/2.0).ceil(), h]));
+        drop(root);
+
+        self.clamp_scroll_offsets();
+    }
+
     fn clamp_scroll_offsets(&mut self) {
         for node in self.nodes.iter() {
             if !node.used {
@@ -436,6 +757,224 @@ impl Gui {
     }
 }
 
+impl Gui {
+    /// calls `callback` with the changes to `root` & its descendants:
+    /// added & removed children, styles & texts.
+    /// the records are batched & delivered in the next layout, or `flush_mutations`.
+    /// the observer is dropped, once `root` is destroyed & its records are delivered.
+    pub fn observe_mutations<F: MutationCallback>(&mut self, root: Node, callback: F) -> MutationObserverId {
+        let id = MutationObserverId(self.next_observer_id);
+        self.next_observer_id += 1;
+        self.observers.push(MutationObserver { id, root, callback: Rc::new(callback), records: vec![] });
+        id
+    }
+
+    /// drops pending records.
+    pub fn disconnect_mutations(&mut self, id: MutationObserverId) {
+        self.observers.retain(|observer| observer.id != id);
+    }
+
+    /// delivers pending records, until there are none.
+    /// callbacks may modify the tree, their changes are delivered too,
+    /// for up to 100 passes. the rest is left for the next flush,
+    /// so callbacks, that keep mutating what they observe, don't hang.
+    pub fn flush_mutations(&mut self) {
+        for _ in 0..100 {
+            let pending: Vec<_> = self.observers.iter_mut()
+                .filter(|observer| !observer.records.is_empty())
+                .map(|observer| (observer.callback.clone(), core::mem::take(&mut observer.records)))
+                .collect();
+            if pending.is_empty() {
+                break;
+            }
+
+            for (callback, records) in pending {
+                callback(self, &records);
+            }
+        }
+
+        let mut observers = core::mem::take(&mut self.observers);
+        observers.retain(|observer| self.is_alive(observer.root) || !observer.records.is_empty());
+        observers.append(&mut self.observers);
+        self.observers = observers;
+    }
+
+    pub(crate) fn record_mutation(&mut self, record: MutationRecord) {
+        if self.observers.is_empty() {
+            return;
+        }
+
+        let target = record.target();
+        let mut observers = core::mem::take(&mut self.observers);
+        // observers of destroyed roots go away, once their records are delivered.
+        observers.retain(|observer| self.is_alive(observer.root) || !observer.records.is_empty());
+        for observer in &mut observers {
+            if self.is_alive(observer.root) && self.is_in_subtree(target, observer.root) {
+                observer.records.push(record.clone());
+            }
+        }
+        self.observers = observers;
+    }
+
+    fn is_in_subtree(&self, node: Node, root: Node) -> bool {
+        let mut at = node;
+        loop {
+            if at == root {
+                return true;
+            }
+            if at == self.root {
+                return false;
+            }
+            match at.borrow(self).parent {
+                Some(parent) => at = parent,
+                None => return false,
+            }
+        }
+    }
+
+    fn record_added(&mut self, parent: Node, child: Node) {
+        if self.observers.is_empty() {
+            return;
+        }
+        let c = child.borrow(self);
+        let record = MutationRecord::ChildList {
+            target:  parent,
+            added:   vec![child],
+            removed: vec![],
+            prev_sibling: c.prev_sibling,
+            next_sibling: c.next_sibling,
+        };
+        drop(c);
+        self.record_mutation(record);
+    }
+}
+
+impl Gui {
+    /// calls `callback` once, `delay` seconds from now.
+    /// timers run in `advance_time`, which `NativeGui`'s message loop calls.
+    pub fn set_timeout<F: FnOnce(&mut Gui) + 'static>(&mut self, delay: f64, callback: F) -> TimerId {
+        let mut callback = Some(callback);
+        self.add_timer(delay, None, Box::new(move |gui| {
+            if let Some(callback) = callback.take() {
+                callback(gui);
+            }
+        }))
+    }
+
+    /// calls `callback` every `interval` seconds, until it's cleared.
+    /// the interval is at least a millisecond.
+    /// the native loops coalesce ticks, that are late, into one call.
+    pub fn set_interval<F: FnMut(&mut Gui) + 'static>(&mut self, interval: f64, callback: F) -> TimerId {
+        let interval = interval.max(0.001);
+        self.add_timer(interval, Some(interval), Box::new(callback))
+    }
+
+    fn add_timer(&mut self, delay: f64, interval: Option<f64>, callback: TimerCallback) -> TimerId {
+        let id = TimerId(self.timers.alloc_id());
+        let due = self.timers.now + delay.max(0.0);
+        self.timers.timers.push(Timer { id, due, interval, callback: Some(callback) });
+        id
+    }
+
+    /// cancels a timeout or interval.
+    /// does nothing, if it has already run.
+    pub fn clear_timer(&mut self, id: TimerId) {
+        self.timers.timers.retain(|timer| timer.id != id);
+    }
+
+    /// calls `callback` with the current time, before the next frame is painted.
+    /// request again from the callback to animate.
+    pub fn request_animation_frame<F: FnOnce(&mut Gui, f64) + 'static>(&mut self, callback: F) -> AnimationFrameId {
+        let id = AnimationFrameId(self.timers.alloc_id());
+        self.timers.frames.push((id, Box::new(callback)));
+        id
+    }
+
+    pub fn cancel_animation_frame(&mut self, id: AnimationFrameId) {
+        self.timers.frames.retain(|(frame, _)| *frame != id);
+    }
+
+    /// the gui's clock, in seconds.
+    /// starts at zero & only moves in `advance_time`.
+    #[inline]
+    pub fn time(&self) -> f64 {
+        self.timers.now
+    }
+
+    /// the seconds until the next timer is due, if there are any.
+    pub fn time_to_next_timer(&self) -> Option<f64> {
+        self.timers.timers.iter()
+            .map(|timer| (timer.due - self.timers.now).max(0.0))
+            .min_by(f64::total_cmp)
+    }
+
+    #[inline]
+    pub fn has_animation_frames(&self) -> bool {
+        !self.timers.frames.is_empty()
+    }
+
+    /// moves the clock `dt` seconds forward.
+    /// runs the timers, that became due, in order, then the animation frames.
+    /// during a timer's callback, `time` is its due time.
+    /// timers & frames created by the callbacks run in the next call.
+    /// returns whether any callbacks ran.
+    pub fn advance_time(&mut self, dt: f64) -> bool {
+        let timers = self.advance_timers(dt, false);
+        let frames = self.run_animation_frames();
+        timers || frames
+    }
+
+    /// `advance_time` without the animation frames,
+    /// which the native loops pace separately.
+    /// with `coalesce`, intervals run once, even if several ticks are due.
+    /// the native loops use it, as their time has actually passed.
+    pub(crate) fn advance_timers(&mut self, dt: f64, coalesce: bool) -> bool {
+        let end = self.timers.now + dt.max(0.0);
+        let mut ran = false;
+
+        // so timeouts, that re-arm themselves, don't run forever.
+        let first_new = self.timers.next_id;
+        while let Some(index) = self.timers.next_due(end, first_new) {
+            let timer = &mut self.timers.timers[index];
+            let id = timer.id;
+            let mut callback = timer.callback.take().unwrap();
+            self.timers.now = self.timers.now.max(timer.due);
+
+            callback(self);
+            ran = true;
+
+            // may have been cleared by the callback.
+            let Some(index) = self.timers.timers.iter().position(|timer| timer.id == id) else { continue };
+            let timer = &mut self.timers.timers[index];
+            match timer.interval {
+                Some(interval) => {
+                    timer.due += interval;
+                    // skip the late ticks, the next one is after `end`.
+                    if coalesce && timer.due <= end {
+                        timer.due += ((end - timer.due) / interval).floor() * interval + interval;
+                        if timer.due <= end {
+                            timer.due += interval;
+                        }
+                    }
+                    timer.callback = Some(callback);
+                }
+                None => { self.timers.timers.remove(index); }
+            }
+        }
+        self.timers.now = end;
+        ran
+    }
+
+    pub(crate) fn run_animation_frames(&mut self) -> bool {
+        let frames = core::mem::take(&mut self.timers.frames);
+        let ran = !frames.is_empty();
+        for (_, callback) in frames {
+            callback(self, self.timers.now);
+        }
+        ran
+    }
+}
+
 impl IGui for Gui {
     fn create_node(&mut self, kind: NodeKind) -> Node {
         self.alloc_node(kind)
@@ -454,10 +993,7 @@ impl IGui for Gui {
 
 
     fn prepend_child(&mut self, parent: Node, new_child: Node) {
-        // remove from old parent.
-        if let Some(old_parent) = self.get_parent(new_child) {
-            self.remove_child(old_parent, new_child, true);
-        }
+        self.detach_for_insert(parent, new_child);
 
         let mut p = parent.borrow_mut(self);
         let mut n = new_child.borrow_mut(self);
@@ -478,14 +1014,12 @@ impl IGui for Gui {
         p.first_child = Some(new_child);
 
         drop((p, n));
+        self.record_added(parent, new_child);
         debug_assert!(self.check_tree());
     }
 
     fn append_child(&mut self, parent: Node, new_child: Node) {
-        // remove from old parent.
-        if let Some(old_parent) = self.get_parent(new_child) {
-            self.remove_child(old_parent, new_child, true);
-        }
+        self.detach_for_insert(parent, new_child);
 
         let mut p = parent.borrow_mut(self);
         let mut n = new_child.borrow_mut(self);
@@ -506,6 +1040,7 @@ impl IGui for Gui {
         p.last_child = Some(new_child);
 
         drop((p, n));
+        self.record_added(parent, new_child);
         debug_assert!(self.check_tree());
     }
 
@@ -518,10 +1053,7 @@ impl IGui for Gui {
             return;
         }
 
-        // remove from old parent.
-        if let Some(old_parent) = self.get_parent(new_child) {
-            self.remove_child(old_parent, new_child, true);
-        }
+        self.detach_for_insert(parent, new_child);
 
         let mut p = parent.borrow_mut(self);
         let mut r = ref_child.borrow_mut(self);
@@ -546,6 +1078,7 @@ impl IGui for Gui {
         r.prev_sibling = Some(new_child);
 
         drop((p, r, n));
+        self.record_added(parent, new_child);
         debug_assert!(self.check_tree());
     }
 
@@ -558,10 +1091,7 @@ impl IGui for Gui {
             return;
         }
 
-        // remove from old parent.
-        if let Some(old_parent) = self.get_parent(new_child) {
-            self.remove_child(old_parent, new_child, true);
-        }
+        self.detach_for_insert(parent, new_child);
 
         let mut p = parent.borrow_mut(self);
         let mut r = ref_child.borrow_mut(self);
@@ -586,6 +1116,7 @@ impl IGui for Gui {
         r.next_sibling = Some(new_child);
 
         drop((p, r, n));
+        self.record_added(parent, new_child);
         debug_assert!(self.check_tree());
     }
 
@@ -600,37 +1131,12 @@ impl IGui for Gui {
         if self.active == Some(child) { self.active = None; }
         if self.focus  == Some(child) { self.focus  = None; }
 
-        let mut p = parent.borrow_mut(self);
         let mut c = child.borrow_mut(self);
-        assert_eq!(c.parent, Some(parent)); // TEMP
-
-        if let Some(prev) = c.prev_sibling {
-            let mut prev = prev.borrow_mut(self);
-            assert_eq!(prev.next_sibling, Some(child));
-            prev.next_sibling = c.next_sibling;
-        }
-        else {
-            assert_eq!(p.first_child, Some(child));
-            p.first_child = c.next_sibling;
-        }
-
-        if let Some(next) = c.next_sibling {
-            let mut next = next.borrow_mut(self);
-            assert_eq!(next.prev_sibling, Some(child));
-            next.prev_sibling = c.prev_sibling;
-        }
-        else {
-            assert_eq!(p.last_child, Some(child));
-            p.last_child = c.prev_sibling;
-        }
-
         c.hover  = false;
         c.active = false;
-        c.parent = None;
-        c.next_sibling = None;
-        c.prev_sibling = None;
+        drop(c);
 
-        drop((c, p));
+        self.unlink_child(parent, child);
 
         if !keep_alive {
             self.free_node(child)
@@ -654,6 +1160,33 @@ impl IGui for Gui {
         self.remove_node(node, false);
     }
 
+    fn clone_subtree(&mut self, node: Node) -> Node {
+        let d = node.borrow(self);
+        let kind        = d.kind;
+        let style       = d.style.clone();
+        let text        = d.text.clone();
+        let id          = d.id.clone();
+        let classes     = d.classes.clone();
+        let on_click    = d.on_click.clone();
+        let first_child = d.first_child;
+        drop(d);
+
+        let copy = self.alloc_node(kind);
+        let mut c = copy.borrow_mut(self);
+        c.style    = style;
+        c.text     = text;
+        c.id       = id;
+        c.classes  = classes;
+        c.on_click = on_click;
+        drop(c);
+
+        let mut children = vec![];
+        NodeData::visit_children(self, first_child, |child| children.push(child));
+        let children = children.into_iter().map(|child| self.clone_subtree(child)).collect();
+        NodeData::set_children(self, copy, children);
+        copy
+    }
+
     fn swap_nodes(&mut self, a: Node, b: Node) {
         if a == b {
             return;
@@ -722,15 +1255,54 @@ impl IGui for Gui {
         self.prev_post_order(node, |_| true)
     }
 
+    fn children(&self, node: Node) -> Children<'_> {
+        Children { gui: self, next: self.get_first_child(node) }
+    }
+
+    fn children_rev(&self, node: Node) -> ChildrenRev<'_> {
+        ChildrenRev { gui: self, next: self.get_last_child(node) }
+    }
+
+    fn descendants(&self, node: Node) -> Descendants<'_> {
+        Descendants { gui: self, root: node, at: Some(node) }
+    }
+
+    fn ancestors(&self, node: Node) -> Ancestors<'_> {
+        Ancestors { gui: self, at: Some(node) }
+    }
+
+    fn traverse(&self, node: Node) -> Traverse<'_> {
+        Traverse { gui: self, root: node, next: Some(Edge::Enter(node)) }
+    }
+
+    fn query_selector(&self, root: Node, selector: &str) -> Result<Option<Node>, SelectorError> {
+        let selector = Selector::parse(selector)?;
+        Ok(self.descendants(root).find(|node| selector.matches(self, *node)))
+    }
+
+    fn query_selector_all(&self, root: Node, selector: &str) -> Result<Vec<Node>, SelectorError> {
+        let selector = Selector::parse(selector)?;
+        Ok(self.descendants(root).filter(|node| selector.matches(self, *node)).collect())
+    }
+
 
     fn set_style(&mut self, node: Node, style: Style) {
         let mut d = node.get(&self.nodes).data.borrow_mut();
+        if d.style == style {
+            return;
+        }
+        let old_value = core::mem::take(&mut d.style);
         d.set_style(style);
+        drop(d);
+        self.record_mutation(MutationRecord::Style { target: node, old_value });
     }
 
     fn set_text(&mut self, node: Node, text: String) {
         let mut d = node.get(&self.nodes).data.borrow_mut();
+        let old_value = core::mem::take(&mut d.text);
         d.set_text(text);
+        drop(d);
+        self.record_mutation(MutationRecord::Text { target: node, old_value });
     }
 
     fn set_on_click<H: EventHandler>(&mut self, node: Node, handler: H) {
@@ -738,6 +1310,38 @@ impl IGui for Gui {
         d.set_on_click(Rc::new(handler));
     }
 
+    fn set_id(&mut self, node: Node, id: Option<String>) {
+        node.borrow_mut(self).id = id;
+    }
+
+    fn get_id(&self, node: Node) -> Option<String> {
+        node.borrow(self).id.clone()
+    }
+
+    fn add_class(&mut self, node: Node, class: &str) {
+        let mut d = node.borrow_mut(self);
+        if !d.classes.iter().any(|c| c == class) {
+            d.classes.push(class.into());
+        }
+    }
+
+    fn remove_class(&mut self, node: Node, class: &str) {
+        node.borrow_mut(self).classes.retain(|c| c != class);
+    }
+
+    fn has_class(&self, node: Node, class: &str) -> bool {
+        node.borrow(self).classes.iter().any(|c| c == class)
+    }
+
+    fn set_user_data<T: Any>(&mut self, node: Node, data: T) {
+        node.borrow_mut(self).user_data = Some(Rc::new(data));
+    }
+
+    fn user_data<T: Any>(&self, node: Node) -> Option<Rc<T>> {
+        let data = node.borrow(self).user_data.clone()?;
+        data.downcast().ok()
+    }
+
     fn on_key_down(&mut self, vk: u32) {
         let _ = vk;
     }
@@ -784,14 +1388,7 @@ impl IGui for Gui {
 
     fn on_mouse_move(&mut self, x: f32, y: f32) {
         // TEMP
-        {
-            let [w, h] = self.window_size;
-            let mut root = self.root.borrow_mut(self);
-            root.style(self, &Style::new());
-            root.render_children(self.ctx, self);
-            root.layout(self, LayoutBox::tight([(w/2.0).ceil(), h]));
-        }
-        self.clamp_scroll_offsets();
+        self.layout();
 
         let old_hover = self.hover;
         let new_hover = {
@@ -913,18 +1510,11 @@ impl IGui for Gui {
         self.window_size = new_size;
     }
 
-    fn paint(&mut self, rt: &ID2D1RenderTarget) {
-        let [w, h] = self.window_size;
-
+    fn paint(&mut self, painter: &mut dyn Painter) {
         // TEMP
+        self.layout();
         let mut root = self.root.borrow_mut(self);
-        root.style(self, &Style::new());
-        root.render_children(self.ctx, self);
-        root.layout(self, LayoutBox::tight([(w/2.0).ceil(), h]));
-        drop(root);
-        self.clamp_scroll_offsets();
-        let mut root = self.root.borrow_mut(self);
-        root.paint(self, rt);
+        root.paint(self, painter);
     }
 
     fn get_cursor(&self) -> Cursor {
@@ -933,6 +1523,29 @@ impl IGui for Gui {
         .unwrap_or(Cursor::Default)
     }
 
+    #[inline]
+    fn get_hover(&self) -> Option<Node> {
+        self.hover
+    }
+    #[inline]
+    fn get_active(&self) -> Option<Node> {
+        self.active
+    }
+    #[inline]
+    fn get_focus(&self) -> Option<Node> {
+        self.focus
+    }
+
+    fn get_rect(&self, node: Node) -> Rect {
+        let pos = NodeData::window_pos(self, node);
+        Rect::from_pos_size(pos, node.borrow(self).size)
+    }
+
+    #[inline]
+    fn get_scroll_pos(&self, node: Node) -> [f32; 2] {
+        node.borrow(self).scroll_pos
+    }
+
     fn root(&self) -> Node {
         self.root
     }
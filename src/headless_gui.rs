@@ -0,0 +1,67 @@
+use crate::ctx::Ctx;
+use crate::gui::{Gui, IGui, Node};
+use crate::paint::record::{DrawCommand, RecordingPainter};
+
+
+/// a `Gui` without a window, for tests.
+///
+/// input is simulated with the `IGui` event functions,
+/// state is queried with `get_hover`, `get_rect`, `get_cursor`, etc.
+pub struct HeadlessGui {
+    gui: Gui,
+}
+
+impl HeadlessGui {
+    pub fn new(width: f32, height: f32) -> HeadlessGui {
+        Self::new_with_ctx(Ctx::new(), width, height)
+    }
+
+    pub fn new_with_ctx(ctx: Ctx, width: f32, height: f32) -> HeadlessGui {
+        let mut gui = Gui::new(ctx);
+        gui.set_window_size(width, height);
+        HeadlessGui { gui }
+    }
+
+    #[inline]
+    pub fn with_gui<R, F: FnOnce(&mut Gui) -> R>(&mut self, f: F) -> R {
+        f(&mut self.gui)
+    }
+
+    /// lays out the tree, like `NativeGui` does before painting.
+    /// the event functions only lay out on mouse moves.
+    pub fn update(&mut self) {
+        self.gui.layout();
+    }
+
+    /// paints a frame into a display list.
+    pub fn record(&mut self) -> Vec<DrawCommand> {
+        let mut painter = RecordingPainter::new();
+        self.gui.paint(&mut painter);
+        painter.commands
+    }
+
+    /// a mouse move, press & release at `(x, y)`.
+    pub fn click(&mut self, x: f32, y: f32) {
+        self.gui.on_mouse_move(x, y);
+        self.gui.on_mouse_down(x, y);
+        self.gui.on_mouse_up();
+    }
+
+    /// moves the mouse to the center of `node`'s box.
+    pub fn hover(&mut self, node: Node) {
+        self.update();
+        let rect = self.gui.get_rect(node);
+        self.gui.on_mouse_move(
+            (rect.min[0] + rect.max[0]) / 2.0,
+            (rect.min[1] + rect.max[1]) / 2.0);
+    }
+}
+
+impl core::ops::Deref for HeadlessGui {
+    type Target = Gui;
+    #[inline] fn deref(&self) -> &Self::Target { &self.gui }
+}
+
+impl core::ops::DerefMut for HeadlessGui {
+    #[inline] fn deref_mut(&mut self) -> &mut Self::Target { &mut self.gui }
+}
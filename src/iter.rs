@@ -0,0 +1,123 @@
+use crate::gui::*;
+
+
+// the iterators only store nodes & borrow the tree in `next`,
+// so they don't conflict with borrows of the visited data.
+
+
+/// see `IGui::children`.
+pub struct Children<'a> {
+    pub(crate) gui:  &'a Gui,
+    pub(crate) next: Option<Node>,
+}
+
+impl<'a> Iterator for Children<'a> {
+    type Item = Node;
+
+    fn next(&mut self) -> Option<Node> {
+        let at = self.next?;
+        self.next = self.gui.get_next_sibling(at);
+        Some(at)
+    }
+}
+
+
+/// see `IGui::children_rev`.
+pub struct ChildrenRev<'a> {
+    pub(crate) gui:  &'a Gui,
+    pub(crate) next: Option<Node>,
+}
+
+impl<'a> Iterator for ChildrenRev<'a> {
+    type Item = Node;
+
+    fn next(&mut self) -> Option<Node> {
+        let at = self.next?;
+        self.next = self.gui.get_prev_sibling(at);
+        Some(at)
+    }
+}
+
+
+/// see `IGui::descendants`.
+pub struct Descendants<'a> {
+    pub(crate) gui:  &'a Gui,
+    pub(crate) root: Node,
+    pub(crate) at:   Option<Node>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = Node;
+
+    fn next(&mut self) -> Option<Node> {
+        self.at = self.gui.next_pre_order_in(self.root, self.at?, |_| true);
+        self.at
+    }
+}
+
+
+/// see `IGui::ancestors`.
+pub struct Ancestors<'a> {
+    pub(crate) gui: &'a Gui,
+    pub(crate) at:  Option<Node>,
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = Node;
+
+    fn next(&mut self) -> Option<Node> {
+        let at = self.at?;
+        // the root's parent isn't a real node.
+        if at == self.gui.root() {
+            self.at = None;
+            return None;
+        }
+        self.at = self.gui.get_parent(at);
+        self.at
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Edge {
+    /// before the node's children.
+    Enter(Node),
+    /// after the node's children.
+    Leave(Node),
+}
+
+/// see `IGui::traverse`.
+pub struct Traverse<'a> {
+    pub(crate) gui:  &'a Gui,
+    pub(crate) root: Node,
+    pub(crate) next: Option<Edge>,
+}
+
+impl<'a> Iterator for Traverse<'a> {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Edge> {
+        let edge = self.next?;
+        self.next = match edge {
+            Edge::Enter(node) => {
+                match self.gui.get_first_child(node) {
+                    Some(child) => Some(Edge::Enter(child)),
+                    None        => Some(Edge::Leave(node)),
+                }
+            }
+
+            Edge::Leave(node) => {
+                if node == self.root {
+                    None
+                }
+                else if let Some(next) = self.gui.get_next_sibling(node) {
+                    Some(Edge::Enter(next))
+                }
+                else {
+                    self.gui.get_parent(node).map(Edge::Leave)
+                }
+            }
+        };
+        Some(edge)
+    }
+}
@@ -1,9 +1,20 @@
+#[cfg(windows)]
 pub mod win;
 pub mod unicode;
 mod common;
 mod ctx;
 pub mod text;
+pub mod paint;
 pub mod gui;
 pub mod native_gui;
+pub mod headless_gui;
+pub mod vtree;
+pub mod signal;
+pub mod selector;
+pub mod markup;
+pub mod mutation;
+pub mod iter;
+pub mod message;
+pub mod timer;
 mod node;
 
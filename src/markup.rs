@@ -0,0 +1,356 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::common::Style;
+use crate::gui::*;
+
+
+/// the nodes `parse_markup` created.
+pub struct Markup {
+    /// the top level nodes, not attached to a parent.
+    pub roots: Vec<Node>,
+    /// the nodes with an `id` attribute.
+    pub ids: HashMap<String, Node>,
+}
+
+impl Markup {
+    #[inline]
+    pub fn get(&self, id: &str) -> Option<Node> {
+        self.ids.get(id).copied()
+    }
+}
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MarkupError {
+    /// 1 based.
+    pub line:    u32,
+    /// 1 based, in chars.
+    pub column:  u32,
+    pub message: String,
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+
+/// builds nodes from XML-like markup:
+///
+/// ```text
+/// <div id="toolbar" class="dark" style="height: 30; background_color: 202020">
+///     <button id="save">save &amp; quit</button>
+///     <span style="text_color: 40b040">unsaved</span>
+///     <div/>
+/// </div>
+/// ```
+///
+/// elements are `div`, `span` & `button`, text becomes text nodes.
+/// whitespace is collapsed, like in HTML.
+/// on errors, the created nodes are destroyed.
+pub fn parse_markup(gui: &mut Gui, source: &str) -> Result<Markup, MarkupError> {
+    let mut parser = Parser {
+        source,
+        at: 0,
+        markup: Markup { roots: vec![], ids: HashMap::new() },
+        stack:  vec![],
+    };
+
+    match parser.parse(gui) {
+        Ok(()) => Ok(parser.markup),
+
+        Err((offset, message)) => {
+            for node in parser.markup.roots {
+                gui.destroy_node(node);
+            }
+
+            let before = &source[..offset];
+            let line_begin = before.rfind('\n').map_or(0, |i| i + 1);
+            Err(MarkupError {
+                line:   before.matches('\n').count() as u32 + 1,
+                column: before[line_begin..].chars().count() as u32 + 1,
+                message,
+            })
+        }
+    }
+}
+
+
+struct Parser<'a> {
+    source: &'a str,
+    at:     usize,
+    markup: Markup,
+    stack:  Vec<OpenElement<'a>>,
+}
+
+struct OpenElement<'a> {
+    node: Node,
+    name: &'a str,
+    at:   usize,
+}
+
+type Error = (usize, String);
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.source[self.at..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.at += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), Error> {
+        if !self.rest().starts_with(token) {
+            return Err((self.at, format!("expected `{}`", token)));
+        }
+        self.at += token.len();
+        Ok(())
+    }
+
+    fn name(&mut self) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-')).unwrap_or(rest.len());
+        if len == 0 {
+            return Err((self.at, "expected a name".into()));
+        }
+        self.at += len;
+        Ok(&rest[..len])
+    }
+
+    fn append(&mut self, gui: &mut Gui, node: Node) {
+        match self.stack.last() {
+            Some(parent) => gui.append_child(parent.node, node),
+            None         => self.markup.roots.push(node),
+        }
+    }
+
+    fn parse(&mut self, gui: &mut Gui) -> Result<(), Error> {
+        while self.at < self.source.len() {
+            let rest = self.rest();
+
+            if rest.starts_with("<!--") {
+                let Some(end) = rest.find("-->") else {
+                    return Err((self.at, "unterminated comment".into()));
+                };
+                self.at += end + 3;
+            }
+            else if rest.starts_with("</") {
+                self.at += 2;
+                let name_at = self.at;
+                let name = self.name()?;
+                self.skip_whitespace();
+                self.expect(">")?;
+
+                match self.stack.pop() {
+                    Some(open) if open.name == name => (),
+                    Some(open) => return Err((name_at, format!("expected `</{}>`", open.name))),
+                    None       => return Err((name_at, format!("unexpected `</{}>`", name))),
+                }
+            }
+            else if rest.starts_with('<') {
+                self.at += 1;
+                self.element(gui)?;
+            }
+            else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                let text = decode_entities(&rest[..len]).map_err(|i| (self.at + i, "invalid entity".into()))?;
+                self.at += len;
+
+                let text = collapse_whitespace(&text);
+                if !text.trim().is_empty() {
+                    let node = gui.create_text(&text);
+                    self.append(gui, node);
+                }
+            }
+        }
+
+        if let Some(open) = self.stack.last() {
+            return Err((open.at, format!("unclosed `<{}>`", open.name)));
+        }
+        Ok(())
+    }
+
+    /// after the `<`.
+    fn element(&mut self, gui: &mut Gui) -> Result<(), Error> {
+        let at = self.at - 1;
+        let name = self.name()?;
+        let kind = match name {
+            "div"    => NodeKind::Div,
+            "span"   => NodeKind::Span,
+            "button" => NodeKind::Button,
+            _ => return Err((at + 1, format!("unknown element `{}`", name))),
+        };
+        // attached right away, so errors clean it up.
+        let node = gui.create_node(kind);
+        self.append(gui, node);
+
+        let self_closing = loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.at += 2;
+                break true;
+            }
+            if self.rest().starts_with('>') {
+                self.at += 1;
+                break false;
+            }
+
+            let name_at = self.at;
+            let attribute = self.name().map_err(|(at, _)| (at, "expected an attribute or `>`".into()))?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let value_at = self.at + 1;
+            let raw = self.quoted()?;
+            let decode = |raw: &str| decode_entities(raw).map_err(|i| (value_at + i, "invalid entity".to_string()));
+
+            match attribute {
+                "id" => {
+                    let value = decode(raw)?;
+                    if self.markup.ids.insert(value.clone(), node).is_some() {
+                        return Err((value_at, format!("duplicate id `{}`", value)));
+                    }
+                    gui.set_id(node, Some(value));
+                }
+
+                "class" => {
+                    for class in decode(raw)?.split_whitespace() {
+                        gui.add_class(node, class);
+                    }
+                }
+
+                "style" => {
+                    let style = parse_style(raw).map_err(|(i, message)| (value_at + i, message))?;
+                    gui.set_style(node, style);
+                }
+
+                _ => return Err((name_at, format!("unknown attribute `{}`", attribute))),
+            }
+        };
+
+        if !self_closing {
+            self.stack.push(OpenElement { node, name, at });
+        }
+        Ok(())
+    }
+
+    /// the raw value, entities aren't decoded.
+    fn quoted(&mut self) -> Result<&'a str, Error> {
+        let quote = match self.rest().chars().next() {
+            Some(c @ ('"' | '\'')) => c,
+            _ => return Err((self.at, "expected a quoted value".into())),
+        };
+        self.at += 1;
+
+        let Some(len) = self.rest().find(quote) else {
+            return Err((self.at - 1, "unterminated value".into()));
+        };
+        let value = &self.rest()[..len];
+        self.at += len + 1;
+        Ok(value)
+    }
+}
+
+
+/// `name: value; ...`, from the raw attribute value.
+/// the names & values are decoded separately,
+/// so error offsets are in the raw value.
+fn parse_style(raw: &str) -> Result<Style, Error> {
+    let mut style = Style::new();
+    let mut offset = 0;
+    while offset < raw.len() {
+        let rest = &raw[offset..];
+        let end = declaration_end(rest);
+        let declaration = &rest[..end];
+        let begin = offset + declaration.len() - declaration.trim_start().len();
+        let at = offset;
+        offset += end + 1;
+        if declaration.trim().is_empty() {
+            continue;
+        }
+
+        let expected = || (begin, "expected `name: value`".to_string());
+        let Some(colon) = declaration.find(':') else { return Err(expected()) };
+        let decode = |begin: usize, end: usize| {
+            decode_entities(&raw[at + begin .. at + end]).map_err(|i| (at + begin + i, "invalid entity".to_string()))
+        };
+        let name  = decode(0, colon)?;
+        let value = decode(colon + 1, declaration.len())?;
+        let (name, value) = (name.trim(), value.trim());
+        if name.is_empty() || value.is_empty() {
+            return Err(expected());
+        }
+        style.insert(name.into(), value.into());
+    }
+    Ok(style)
+}
+
+/// the `;` of entities doesn't end the declaration.
+fn declaration_end(rest: &str) -> usize {
+    let mut in_entity = false;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '&' => in_entity = true,
+            ';' if in_entity => in_entity = false,
+            ';' => return i,
+            _ => (),
+        }
+    }
+    rest.len()
+}
+
+/// returns the offset of the invalid entity.
+fn decode_entities(text: &str) -> Result<String, usize> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        let offset = text.len() - rest.len() + amp;
+
+        let Some(semi) = rest[amp..].find(';') else { return Err(offset) };
+        let entity = &rest[amp + 1 .. amp + semi];
+        let c = match entity {
+            "lt"   => Some('<'),
+            "gt"   => Some('>'),
+            "amp"  => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                }
+                else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse().ok().and_then(char::from_u32)
+                }
+                else { None }
+            }
+        };
+        result.push(c.ok_or(offset)?);
+        rest = &rest[amp + semi + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut was_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !was_space {
+                result.push(' ');
+            }
+            was_space = true;
+        }
+        else {
+            result.push(c);
+            was_space = false;
+        }
+    }
+    result
+}
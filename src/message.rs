@@ -0,0 +1,122 @@
+use core::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::gui::Gui;
+
+
+/// a handle, that other threads use to run code on a `Gui`'s thread.
+/// cheap to clone.
+///
+/// see `NativeGui::message_sender`.
+#[derive(Clone)]
+pub struct MessageSender {
+    shared: Arc<Shared>,
+}
+
+/// the `Gui` thread's end of the queue.
+/// the native guis dispatch it between window messages.
+pub struct MessageQueue {
+    shared:   Arc<Shared>,
+    handlers: HashMap<TypeId, Handler>,
+}
+
+type Handler = Box<dyn Fn(&mut Gui, Box<dyn Any>)>;
+
+struct Shared {
+    queue: Mutex<Queue>,
+    // wakes the `Gui` thread's message loop.
+    wake:  Box<dyn Fn() + Send + Sync>,
+}
+
+// `closed` is behind the same lock as `messages`,
+// so no message is queued after the queue was dropped.
+#[derive(Default)]
+struct Queue {
+    messages: Vec<Message>,
+    closed:   bool,
+}
+
+enum Message {
+    Call(Box<dyn FnOnce(&mut Gui) + Send>),
+    Typed(TypeId, Box<dyn Any + Send>),
+}
+
+
+impl MessageSender {
+    /// runs `f` on the `Gui`'s thread.
+    /// returns `false`, if the queue has been dropped.
+    pub fn post<F: FnOnce(&mut Gui) + Send + 'static>(&self, f: F) -> bool {
+        self.push(Message::Call(Box::new(f)))
+    }
+
+    /// passes `message` to the handler for `T`, see `MessageQueue::on_message`.
+    /// messages without a handler are dropped.
+    /// returns `false`, if the queue has been dropped.
+    pub fn send<T: Any + Send>(&self, message: T) -> bool {
+        self.push(Message::Typed(TypeId::of::<T>(), Box::new(message)))
+    }
+
+    fn push(&self, message: Message) -> bool {
+        let mut queue = self.shared.queue.lock().unwrap();
+        if queue.closed {
+            return false;
+        }
+        queue.messages.push(message);
+        // under the lock, so the loop isn't woken after the drop.
+        (self.shared.wake)();
+        true
+    }
+}
+
+impl MessageQueue {
+    /// `wake` is called from the sending thread, after a message was queued.
+    pub fn new<W: Fn() + Send + Sync + 'static>(wake: W) -> MessageQueue {
+        MessageQueue {
+            shared: Arc::new(Shared {
+                queue: Mutex::new(Queue::default()),
+                wake:  Box::new(wake),
+            }),
+            handlers: HashMap::new(),
+        }
+    }
+
+    pub fn sender(&self) -> MessageSender {
+        MessageSender { shared: self.shared.clone() }
+    }
+
+    /// handles the messages of type `T`, replaces the previous handler.
+    pub fn on_message<T: Any, F: Fn(&mut Gui, T) + 'static>(&mut self, handler: F) {
+        self.handlers.insert(TypeId::of::<T>(), Box::new(move |gui, message| {
+            handler(gui, *message.downcast::<T>().unwrap());
+        }));
+    }
+
+    /// runs the queued messages, in order.
+    /// returns whether there were any.
+    pub fn dispatch(&mut self, gui: &mut Gui) -> bool {
+        let messages = core::mem::take(&mut self.shared.queue.lock().unwrap().messages);
+        let any = !messages.is_empty();
+
+        for message in messages {
+            match message {
+                Message::Call(f) => f(gui),
+
+                Message::Typed(type_id, message) => {
+                    if let Some(handler) = self.handlers.get(&type_id) {
+                        handler(gui, message);
+                    }
+                }
+            }
+        }
+        any
+    }
+}
+
+impl Drop for MessageQueue {
+    fn drop(&mut self) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        queue.closed = true;
+        queue.messages.clear();
+    }
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use crate::common::Style;
+use crate::gui::*;
+
+
+/// a change to the tree, see `Gui::observe_mutations`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MutationRecord {
+    /// children of `target` were added or removed.
+    /// the siblings are the ones around the added node after,
+    /// or around the removed node before the change.
+    ChildList {
+        target:  Node,
+        added:   Vec<Node>,
+        removed: Vec<Node>,
+        prev_sibling: Option<Node>,
+        next_sibling: Option<Node>,
+    },
+    Style { target: Node, old_value: Style },
+    Text  { target: Node, old_value: String },
+}
+
+impl MutationRecord {
+    #[inline]
+    pub fn target(&self) -> Node {
+        match self {
+            MutationRecord::ChildList { target, .. } => *target,
+            MutationRecord::Style     { target, .. } => *target,
+            MutationRecord::Text      { target, .. } => *target,
+        }
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct MutationObserverId (pub u32);
+
+pub trait MutationCallback: Fn(&mut Gui, &[MutationRecord]) + 'static {}
+
+impl<T: Fn(&mut Gui, &[MutationRecord]) + 'static> MutationCallback for T {}
+
+
+pub(crate) struct MutationObserver {
+    pub id:       MutationObserverId,
+    pub root:     Node,
+    pub callback: Rc<dyn MutationCallback>,
+    pub records:  Vec<MutationRecord>,
+}
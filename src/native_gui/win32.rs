@@ -4,16 +4,22 @@ use crate::win::*;
 use crate::ctx::Ctx;
 use crate::common::Cursor;
 use crate::gui::{Gui, IGui};
+use crate::message::{MessageQueue, MessageSender};
+use crate::timer::LoopClock;
+use crate::paint::d2d::D2dPainter;
+use crate::paint::image::Image;
 
 
-// TODO, safety: destroy window on drop.
 pub struct NativeGui {
+    window: HWND,
     data: Box<RefCell<NativeGuiData>>,
 }
 
 struct NativeGuiData {
     gui: Gui,
 
+    messages: MessageQueue,
+
     cursor_default: HCURSOR,
     cursor_pointer: HCURSOR,
     cursor_text:    HCURSOR,
@@ -26,13 +32,20 @@ struct NativeGuiData {
 }
 
 impl NativeGui {
-    pub fn new() -> NativeGui {unsafe {
+    // opens a window, not a default value.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> NativeGui {
+        Self::new_with_ctx(Ctx::new())
+    }
+
+    pub fn new_with_ctx(ctx: Ctx) -> NativeGui {unsafe {
         const WINDOW_CLASS_NAME: &HSTRING = w!("window_class");
 
         let instance = GetModuleHandleW(None).unwrap();
 
-        // set up window class
-        {
+        // set up window class, once per process.
+        static REGISTER_CLASS: std::sync::Once = std::sync::Once::new();
+        REGISTER_CLASS.call_once(|| {
             let wc = WNDCLASSW {
                 hInstance: instance,
                 lpszClassName: WINDOW_CLASS_NAME.into(),
@@ -44,7 +57,7 @@ impl NativeGui {
 
             let atom = RegisterClassW(&wc);
             assert!(atom != 0);
-        }
+        });
 
         // create window.
         let window = CreateWindowExW(
@@ -85,12 +98,17 @@ impl NativeGui {
             }).unwrap();
 
 
-        // TEMP
-        let ctx = Ctx::new();
         let gui = Gui::new(ctx);
 
+        // other threads wake the loop with a `WM_APP`.
+        let raw_window = window.0;
+        let messages = MessageQueue::new(move || {
+            let _ = PostMessageW(HWND(raw_window), WM_APP, WPARAM(0), LPARAM(0));
+        });
+
         let data = Box::new(RefCell::new(NativeGuiData {
             gui,
+            messages,
 
             cursor_default,
             cursor_pointer,
@@ -101,7 +119,7 @@ impl NativeGui {
 
         SetWindowLongPtrW(window, GWLP_USERDATA, &*data as *const RefCell<NativeGuiData> as isize);
 
-        NativeGui { data }
+        NativeGui { window, data }
     }}
 
     #[inline]
@@ -109,51 +127,123 @@ impl NativeGui {
         f(&mut self.data.borrow_mut().gui)
     }
 
+    /// a handle, that other threads can use to update the gui.
+    /// the messages run in the message loop, then the window is repainted.
+    pub fn message_sender(&self) -> MessageSender {
+        self.data.borrow().messages.sender()
+    }
+
+    /// handles the messages of type `T`, see `MessageSender::send`.
+    pub fn on_message<T: core::any::Any, F: Fn(&mut Gui, T) + 'static>(&mut self, handler: F) {
+        self.data.borrow_mut().messages.on_message(handler);
+    }
+
+    /// the window's contents, rendered with the software renderer.
+    /// it matches Direct2D's output closely, but not exactly.
+    pub fn screenshot(&mut self) -> Image {
+        let mut data = self.data.borrow_mut();
+        let size = [data.rt_size.width, data.rt_size.height];
+        data.gui.screenshot(size)
+    }
+
     pub fn run_message_loop(&mut self) {
         std::panic::set_hook(Box::new(|info| {
             println!("panic: {}", info);
-            loop {}
+            loop { std::thread::park(); }
         }));
 
         // event loop.
+        let mut clock = LoopClock::new();
         loop {unsafe {
-            let mut message = MSG::default();
-            let result = GetMessageW(&mut message, HWND(0), 0, 0).0;
-            if result > 0 {
-                TranslateMessage(&message);
-                DispatchMessageW(&message);
+            let timeout = clock.timeout(&self.data.borrow().gui);
+
+            match timeout {
+                None => {
+                    let mut message = MSG::default();
+                    let result = GetMessageW(&mut message, HWND(0), 0, 0).0;
+                    if result > 0 {
+                        TranslateMessage(&message);
+                        DispatchMessageW(&message);
+                    }
+                    else if result == 0 {
+                        break;
+                    }
+                    else {
+                        panic!();
+                    }
+                }
+
+                Some(timeout) => {
+                    // returns `false` on `WM_QUIT`.
+                    let dispatch_queued = || {
+                        let mut message = MSG::default();
+                        while PeekMessageW(&mut message, HWND(0), 0, 0, PM_REMOVE).as_bool() {
+                            if message.message == WM_QUIT {
+                                return false;
+                            }
+                            TranslateMessage(&message);
+                            DispatchMessageW(&message);
+                        }
+                        true
+                    };
+
+                    // the wait only returns for messages, that arrive after the last peek.
+                    if !dispatch_queued() {
+                        break;
+                    }
+                    let ms = (timeout * 1000.0).ceil().min(u32::MAX as f64 - 1.0) as u32;
+                    MsgWaitForMultipleObjects(None, false, ms, QS_ALLINPUT);
+                    if !dispatch_queued() {
+                        break;
+                    }
+                }
             }
-            else if result == 0 {
-                break;
-            }
-            else {
-                panic!();
+
+            // run the timers & animation frames, then repaint.
+            if clock.tick(&mut self.data.borrow_mut().gui) {
+                InvalidateRect(self.window, None, false);
             }
         }}
     }
 }
 
 
+impl Drop for NativeGui {
+    fn drop(&mut self) {unsafe {
+        // `window_proc` mustn't see the freed data.
+        SetWindowLongPtrW(self.window, GWLP_USERDATA, 0);
+        DestroyWindow(self.window);
+    }}
+}
+
+
 unsafe extern "system" fn window_proc(window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
     fn lo_u16(a: isize) -> u32 { (a as usize as u32) & 0xffff }
     fn hi_u16(a: isize) -> u32 { ((a as usize as u32) >> 16) & 0xffff }
 
     let mut data = {
         let data = GetWindowLongPtrW(window, GWLP_USERDATA) as *const RefCell<NativeGuiData>;
-        if data == core::ptr::null() {
+        if data.is_null() {
             return DefWindowProcW(window, message, wparam, lparam);
         }
 
         (*data).borrow_mut()
     };
 
-    let message = message as u32;
     match message {
         WM_CLOSE => {
             PostQuitMessage(0);
             LRESULT(0)
         },
 
+        WM_APP => {
+            let data = &mut *data;
+            if data.messages.dispatch(&mut data.gui) {
+                InvalidateRect(window, None, false);
+            }
+            LRESULT(0)
+        },
+
         WM_KEYDOWN => {
             data.gui.on_key_down(wparam.0 as u32);
             InvalidateRect(window, None, false);
@@ -264,7 +354,7 @@ unsafe extern "system" fn window_proc(window: HWND, message: u32, wparam: WPARAM
             data.rt.Clear(Some(&D2D1_COLOR_F { r: 1.0, g: 1.0, b: 1.0, a: 1.0 }));
 
             data.gui.set_window_size(size[0] as f32, size[1] as f32);
-            data.gui.paint((&data.rt).into());
+            data.gui.paint(&mut D2dPainter::new((&data.rt).into()));
 
             data.rt.EndDraw(None, None).unwrap();
 
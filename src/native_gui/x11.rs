@@ -0,0 +1,535 @@
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::Event;
+use x11rb::protocol::xproto::{self, ConnectionExt as _};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use crate::ctx::Ctx;
+use crate::common::Cursor;
+use crate::gui::{Gui, IGui};
+use crate::message::{MessageQueue, MessageSender};
+use crate::timer::LoopClock;
+use crate::paint::image::Image;
+use crate::paint::software::{render_frame, Pixmap};
+
+
+/// an X11 window, presenting frames from the software renderer.
+pub struct NativeGui {
+    gui: Gui,
+
+    conn:   RustConnection,
+    window: xproto::Window,
+    gc:     xproto::Gcontext,
+
+    wm_protocols:     xproto::Atom,
+    wm_delete_window: xproto::Atom,
+
+    messages:  MessageQueue,
+    wake_atom: xproto::Atom,
+
+    format: PixelFormat,
+    keymap: Keymap,
+
+    cursor_default: xproto::Cursor,
+    cursor_pointer: xproto::Cursor,
+    cursor_text:    xproto::Cursor,
+    cursor:         Cursor,
+
+    size:   [u32; 2],
+    dirty:  bool,
+    closed: bool,
+}
+
+impl NativeGui {
+    // opens a window, not a default value.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> NativeGui {
+        Self::new_with_ctx(Ctx::new())
+    }
+
+    pub fn new_with_ctx(ctx: Ctx) -> NativeGui {
+        let (conn, screen_num) = x11rb::connect(None).expect("failed to connect to the X server");
+
+        let screen = conn.setup().roots[screen_num].clone();
+        let format = PixelFormat::new(&conn, &screen);
+
+        let size = [800, 600];
+
+        // create window.
+        let window = conn.generate_id().unwrap();
+        let event_mask =
+              xproto::EventMask::EXPOSURE
+            | xproto::EventMask::STRUCTURE_NOTIFY
+            | xproto::EventMask::POINTER_MOTION
+            | xproto::EventMask::BUTTON_PRESS
+            | xproto::EventMask::BUTTON_RELEASE
+            | xproto::EventMask::KEY_PRESS
+            | xproto::EventMask::KEY_RELEASE;
+        conn.create_window(
+            screen.root_depth,
+            window,
+            screen.root,
+            0, 0,
+            size[0] as u16, size[1] as u16,
+            0,
+            xproto::WindowClass::INPUT_OUTPUT,
+            screen.root_visual,
+            &xproto::CreateWindowAux::new()
+                .background_pixel(screen.white_pixel)
+                .event_mask(event_mask)).unwrap();
+
+        conn.change_property8(
+            xproto::PropMode::REPLACE, window,
+            xproto::AtomEnum::WM_NAME, xproto::AtomEnum::STRING,
+            b"window").unwrap();
+
+        // ask for a `ClientMessage` instead of getting killed on close.
+        let atom = |name: &[u8]| conn.intern_atom(false, name).unwrap().reply().unwrap().atom;
+        let wm_protocols     = atom(b"WM_PROTOCOLS");
+        let wm_delete_window = atom(b"WM_DELETE_WINDOW");
+        conn.change_property32(
+            xproto::PropMode::REPLACE, window,
+            wm_protocols, xproto::AtomEnum::ATOM,
+            &[wm_delete_window]).unwrap();
+
+        // other threads wake the loop with a `ClientMessage`,
+        // over their own connection.
+        let wake_atom = atom(b"POGUI_WAKE");
+        let (wake_conn, _) = x11rb::connect(None).expect("failed to connect to the X server");
+        let messages = MessageQueue::new(move || {
+            let event = xproto::ClientMessageEvent::new(32, window, wake_atom, [0u32; 5]);
+            let _ = wake_conn.send_event(false, window, xproto::EventMask::NO_EVENT, event);
+            let _ = wake_conn.flush();
+        });
+
+        let gc = conn.generate_id().unwrap();
+        conn.create_gc(gc, window, &Default::default()).unwrap();
+
+        // cursors from the standard cursor font.
+        let cursor_font = conn.generate_id().unwrap();
+        conn.open_font(cursor_font, b"cursor").unwrap();
+        let mk_cursor = |shape: u16| {
+            let cursor = conn.generate_id().unwrap();
+            conn.create_glyph_cursor(
+                cursor, cursor_font, cursor_font,
+                shape, shape + 1,
+                0, 0, 0,
+                0xffff, 0xffff, 0xffff).unwrap();
+            cursor
+        };
+        const XC_LEFT_PTR: u16 = 68;
+        const XC_HAND2:    u16 = 60;
+        const XC_XTERM:    u16 = 152;
+        let cursor_default = mk_cursor(XC_LEFT_PTR);
+        let cursor_pointer = mk_cursor(XC_HAND2);
+        let cursor_text    = mk_cursor(XC_XTERM);
+        conn.close_font(cursor_font).unwrap();
+
+        conn.change_window_attributes(window,
+            &xproto::ChangeWindowAttributesAux::new().cursor(cursor_default)).unwrap();
+
+        let keymap = Keymap::new(&conn);
+
+        conn.map_window(window).unwrap();
+        conn.flush().unwrap();
+
+
+        let mut gui = Gui::new(ctx);
+        gui.set_window_size(size[0] as f32, size[1] as f32);
+
+        NativeGui {
+            gui,
+            conn, window, gc,
+            wm_protocols, wm_delete_window,
+            messages, wake_atom,
+            format, keymap,
+            cursor_default, cursor_pointer, cursor_text,
+            cursor: Cursor::Default,
+            size,
+            dirty:  true,
+            closed: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_gui<R, F: FnOnce(&mut Gui) -> R>(&mut self, f: F) -> R {
+        f(&mut self.gui)
+    }
+
+    #[inline]
+    pub fn window_id(&self) -> xproto::Window {
+        self.window
+    }
+
+    /// the window's contents, rendered like they're presented.
+    pub fn screenshot(&mut self) -> Image {
+        self.gui.screenshot(self.size)
+    }
+
+    /// handles events & runs the gui's timers, until the window is closed.
+    pub fn run_message_loop(&mut self) {
+        let mut clock = LoopClock::new();
+        while !self.closed {
+            match clock.timeout(&self.gui) {
+                None => {
+                    let event = self.conn.wait_for_event().unwrap();
+                    self.handle_event(event);
+                }
+
+                Some(timeout) => {
+                    // painting may have read events already.
+                    match self.conn.poll_for_event().unwrap() {
+                        Some(event) => self.handle_event(event),
+                        None => self.wait_readable(timeout),
+                    }
+                }
+            }
+
+            if clock.tick(&mut self.gui) {
+                self.dirty = true;
+            }
+
+            self.dispatch_pending();
+        }
+    }
+
+    /// blocks until the connection is readable, or `timeout` seconds have passed.
+    fn wait_readable(&self, timeout: f64) {
+        use std::os::fd::AsRawFd;
+
+        let mut fd = libc::pollfd {
+            fd:      self.conn.stream().as_raw_fd(),
+            events:  libc::POLLIN,
+            revents: 0,
+        };
+        let ms = (timeout * 1000.0).ceil().min(i32::MAX as f64) as i32;
+        unsafe { libc::poll(&mut fd, 1, ms) };
+    }
+
+    /// a handle, that other threads can use to update the gui.
+    /// the messages run in the message loop, then the window is repainted.
+    pub fn message_sender(&self) -> MessageSender {
+        self.messages.sender()
+    }
+
+    /// handles the messages of type `T`, see `MessageSender::send`.
+    pub fn on_message<T: core::any::Any, F: Fn(&mut Gui, T) + 'static>(&mut self, handler: F) {
+        self.messages.on_message(handler);
+    }
+
+    /// handles all queued events & messages & repaints, if necessary.
+    /// doesn't block.
+    /// returns `false`, once the window has been closed.
+    pub fn dispatch_pending(&mut self) -> bool {
+        while !self.closed {
+            match self.conn.poll_for_event().unwrap() {
+                Some(event) => self.handle_event(event),
+                None => break,
+            }
+        }
+
+        if !self.closed && self.messages.dispatch(&mut self.gui) {
+            self.dirty = true;
+        }
+
+        if self.dirty && !self.closed {
+            self.paint();
+        }
+
+        !self.closed
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        fn shift_down(state: xproto::KeyButMask) -> bool {
+            state.contains(xproto::KeyButMask::SHIFT)
+        }
+
+        match event {
+            Event::ClientMessage(e)
+            if e.type_ == self.wm_protocols && e.data.as_data32()[0] == self.wm_delete_window => {
+                self.closed = true;
+            }
+
+            // the messages are dispatched after the events.
+            Event::ClientMessage(e) if e.type_ == self.wake_atom => (),
+
+            Event::Expose(e) if e.count == 0 => {
+                self.dirty = true;
+            }
+
+            Event::ConfigureNotify(e) => {
+                let size = [e.width as u32, e.height as u32];
+                if size != self.size {
+                    self.size = size;
+                    self.gui.set_window_size(size[0] as f32, size[1] as f32);
+                    self.dirty = true;
+                }
+            }
+
+            Event::KeyPress(e) => {
+                let keysym = self.keymap.keysym(e.detail, false);
+                if let Some(vk) = keysym_to_vk(keysym) {
+                    self.gui.on_key_down(vk);
+                }
+
+                let ctrl = e.state.contains(xproto::KeyButMask::CONTROL);
+                if !ctrl {
+                    let mut keysym = self.keymap.keysym(e.detail, shift_down(e.state));
+
+                    // caps lock only affects letters.
+                    if e.state.contains(xproto::KeyButMask::LOCK) && (keysym as u8 as char).is_ascii_alphabetic() && keysym < 0x80 {
+                        keysym ^= 0x20;
+                    }
+
+                    if let Some(cp) = keysym_to_char(keysym) {
+                        self.gui.on_char(cp, shift_down(e.state));
+                    }
+                }
+
+                self.dirty = true;
+            }
+
+            Event::KeyRelease(e) => {
+                let keysym = self.keymap.keysym(e.detail, false);
+                if let Some(vk) = keysym_to_vk(keysym) {
+                    self.gui.on_key_up(vk);
+                }
+
+                self.dirty = true;
+            }
+
+            Event::ButtonPress(e) => {
+                // same scale as on windows: 30 pixels per wheel notch.
+                let scale = 30.0;
+                match e.detail {
+                    1 => self.gui.on_mouse_down(e.event_x as f32, e.event_y as f32),
+                    4 => self.gui.on_mouse_wheel( scale, shift_down(e.state)),
+                    5 => self.gui.on_mouse_wheel(-scale, shift_down(e.state)),
+                    6 => self.gui.on_mouse_wheel( scale, true),
+                    7 => self.gui.on_mouse_wheel(-scale, true),
+                    _ => (),
+                }
+
+                self.dirty = true;
+            }
+
+            Event::ButtonRelease(e) => {
+                if e.detail == 1 {
+                    self.gui.on_mouse_up();
+                }
+
+                self.dirty = true;
+            }
+
+            Event::MotionNotify(e) => {
+                self.gui.on_mouse_move(e.event_x as f32, e.event_y as f32);
+                self.update_cursor();
+
+                self.dirty = true;
+            }
+
+            _ => (),
+        }
+    }
+
+    fn update_cursor(&mut self) {
+        let cursor = self.gui.get_cursor();
+        if cursor == self.cursor {
+            return;
+        }
+        self.cursor = cursor;
+
+        let cursor = match cursor {
+            Cursor::Default => self.cursor_default,
+            Cursor::Pointer => self.cursor_pointer,
+            Cursor::Text    => self.cursor_text,
+        };
+        self.conn.change_window_attributes(self.window,
+            &xproto::ChangeWindowAttributesAux::new().cursor(cursor)).unwrap();
+        self.conn.flush().unwrap();
+    }
+
+    fn paint(&mut self) {
+        self.dirty = false;
+
+        let [w, h] = self.size;
+        if w == 0 || h == 0 {
+            return;
+        }
+
+        let frame = render_frame(&mut self.gui, w, h);
+        self.present(&frame);
+    }
+
+    fn present(&mut self, frame: &Pixmap) {
+        let w = frame.width()  as usize;
+        let h = frame.height() as usize;
+
+        let data = self.format.convert(frame);
+        let stride = data.len() / h;
+
+        // split into requests the server accepts.
+        const HEADER_SIZE: usize = 24;
+        let max_rows = ((self.conn.maximum_request_bytes() - HEADER_SIZE) / stride).max(1);
+
+        let mut y = 0;
+        while y < h {
+            let rows = max_rows.min(h - y);
+            self.conn.put_image(
+                xproto::ImageFormat::Z_PIXMAP,
+                self.window, self.gc,
+                w as u16, rows as u16,
+                0, y as i16,
+                0, self.format.depth,
+                &data[y*stride .. (y + rows)*stride]).unwrap();
+            y += rows;
+        }
+        self.conn.flush().unwrap();
+    }
+}
+
+
+/// how the window's visual stores pixels.
+struct PixelFormat {
+    depth: u8,
+    shifts: [u32; 3],
+    lsb_first: bool,
+}
+
+impl PixelFormat {
+    fn new(conn: &RustConnection, screen: &xproto::Screen) -> PixelFormat {
+        let setup = conn.setup();
+
+        let visual = screen.allowed_depths.iter()
+            .flat_map(|depth| depth.visuals.iter())
+            .find(|visual| visual.visual_id == screen.root_visual)
+            .unwrap();
+        assert!(visual.class == xproto::VisualClass::TRUE_COLOR
+            || visual.class == xproto::VisualClass::DIRECT_COLOR,
+            "unsupported visual");
+
+        let bits_per_pixel = setup.pixmap_formats.iter()
+            .find(|format| format.depth == screen.root_depth)
+            .unwrap()
+            .bits_per_pixel;
+        assert!(bits_per_pixel == 32, "unsupported pixel format");
+
+        let shift = |mask: u32| {
+            assert!(mask >> mask.trailing_zeros() == 0xff, "unsupported pixel format");
+            mask.trailing_zeros()
+        };
+
+        PixelFormat {
+            depth: screen.root_depth,
+            shifts: [shift(visual.red_mask), shift(visual.green_mask), shift(visual.blue_mask)],
+            lsb_first: setup.image_byte_order == xproto::ImageOrder::LSB_FIRST,
+        }
+    }
+
+    fn convert(&self, frame: &Pixmap) -> Vec<u8> {
+        let mut result = Vec::with_capacity(frame.data().len());
+        for rgba in frame.data().chunks_exact(4) {
+            let pixel =
+                  (rgba[0] as u32) << self.shifts[0]
+                | (rgba[1] as u32) << self.shifts[1]
+                | (rgba[2] as u32) << self.shifts[2];
+
+            if self.lsb_first { result.extend(pixel.to_le_bytes()) }
+            else              { result.extend(pixel.to_be_bytes()) }
+        }
+        result
+    }
+}
+
+
+struct Keymap {
+    min_keycode: u8,
+    keysyms_per_keycode: usize,
+    keysyms: Vec<xproto::Keysym>,
+}
+
+impl Keymap {
+    fn new(conn: &RustConnection) -> Keymap {
+        let setup = conn.setup();
+        let min_keycode = setup.min_keycode;
+        let count = setup.max_keycode - min_keycode + 1;
+
+        let mapping = conn.get_keyboard_mapping(min_keycode, count).unwrap().reply().unwrap();
+
+        Keymap {
+            min_keycode,
+            keysyms_per_keycode: mapping.keysyms_per_keycode as usize,
+            keysyms: mapping.keysyms,
+        }
+    }
+
+    fn keysym(&self, keycode: u8, shifted: bool) -> xproto::Keysym {
+        if keycode < self.min_keycode {
+            return 0;
+        }
+
+        let base = (keycode - self.min_keycode) as usize * self.keysyms_per_keycode;
+        let get = |i: usize| {
+            if i < self.keysyms_per_keycode { self.keysyms.get(base + i).copied().unwrap_or(0) }
+            else                            { 0 }
+        };
+
+        let lower = get(0);
+        let upper = match get(1) {
+            0 => ascii_upper(lower),
+            upper => upper,
+        };
+
+        if shifted { upper } else { lower }
+    }
+}
+
+#[inline]
+fn ascii_upper(keysym: xproto::Keysym) -> xproto::Keysym {
+    if (b'a' as u32 ..= b'z' as u32).contains(&keysym) { keysym - 0x20 }
+    else { keysym }
+}
+
+fn keysym_to_char(keysym: xproto::Keysym) -> Option<char> {
+    match keysym {
+        // latin-1 maps directly.
+        0x20..=0x7e | 0xa0..=0xff => char::from_u32(keysym),
+
+        // unicode keysyms.
+        0x0100_0000..=0x0110_ffff => char::from_u32(keysym - 0x0100_0000),
+
+        // same control chars as windows.
+        0xff08 => Some('\x08'), // BackSpace
+        0xff09 => Some('\t'),   // Tab
+        0xfe20 => Some('\t'),   // ISO_Left_Tab (shift + tab)
+        0xff0d => Some('\r'),   // Return
+        0xff8d => Some('\r'),   // KP_Enter
+        0xff1b => Some('\x1b'), // Escape
+
+        _ => None,
+    }
+}
+
+/// maps keysyms to windows virtual key codes.
+fn keysym_to_vk(keysym: xproto::Keysym) -> Option<u32> {
+    Some(match keysym {
+        0x30..=0x39 => keysym,                       // 0-9
+        0x61..=0x7a => keysym - 0x20,                // a-z
+        0x41..=0x5a => keysym,                       // A-Z
+        0x20 => 0x20,                                // space
+        0xff08 => 0x08,                              // BackSpace
+        0xff09 | 0xfe20 => 0x09,                     // Tab
+        0xff0d | 0xff8d => 0x0d,                     // Return
+        0xff1b => 0x1b,                              // Escape
+        0xffe1 | 0xffe2 => 0x10,                     // Shift
+        0xffe3 | 0xffe4 => 0x11,                     // Control
+        0xff50 => 0x24,                              // Home
+        0xff51 => 0x25,                              // Left
+        0xff52 => 0x26,                              // Up
+        0xff53 => 0x27,                              // Right
+        0xff54 => 0x28,                              // Down
+        0xff57 => 0x23,                              // End
+        0xffff => 0x2e,                              // Delete
+        _ => return None,
+    })
+}
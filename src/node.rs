@@ -1,10 +1,13 @@
+use std::collections::HashSet;
+use core::any::Any;
 use std::rc::Rc;
 
-use crate::win::*;
 use crate::ctx::*;
 use crate::common::*;
 use crate::text::*;
 use crate::gui::*;
+use crate::paint::*;
+use crate::mutation::MutationRecord;
 
 
 pub const SCROLLBAR_WIDTH: f32 = 20.0;
@@ -78,6 +81,13 @@ pub(crate) struct NodeData {
     pub text: String,
 
     pub on_click: Option<Rc<dyn EventHandler>>,
+
+    pub id:        Option<String>,
+    pub classes:   Vec<String>,
+    pub user_data: Option<Rc<dyn Any>>,
+
+    // keep the node's signal bindings alive, see `Gui::bind_text`.
+    pub bindings: Vec<Rc<dyn Any>>,
 }
 
 
@@ -85,7 +95,8 @@ impl NodeData {
     pub fn set_style(&mut self, style: Style) {
         assert!(self.kind == NodeKind::Div
             || self.kind == NodeKind::Button
-            || self.kind == NodeKind::Span);
+            || self.kind == NodeKind::Span,
+            "style on a {:?} node, text nodes can't be styled", self.kind);
         self.style = style;
     }
 
@@ -150,6 +161,10 @@ impl NodeData {
             render_children: vec![],
             text: String::new(),
             on_click: None,
+            id:        None,
+            classes:   vec![],
+            user_data: None,
+            bindings: vec![],
         }
     }
 
@@ -167,10 +182,36 @@ impl NodeData {
 // TREE STRUCTURE
 
 impl NodeData {
-    // TODO: handle duplicates.
-    // TODO: new child was old child (don't destroy).
+    /// makes `children` the children of `this`, in order.
+    /// old children, that aren't in `children`, are destroyed.
+    /// the others are only relinked & keep their hover, active & focus state.
+    /// repeated nodes are inserted once, at their first position.
+    /// mutation observers get records for the moved in & destroyed children,
+    /// then one, that replaces the kept old children with `children`.
     pub fn set_children(gui: &mut Gui, this: Node, children: Vec<Node>) {
-        // destroy old children.
+        let mut new_children = HashSet::with_capacity(children.len());
+        let children: Vec<Node> = children.into_iter().filter(|child| new_children.insert(*child)).collect();
+
+        // no cycles.
+        let root = gui.root();
+        let mut at = Some(this);
+        while let Some(node) = at {
+            assert!(!new_children.contains(&node), "set_children: {:?} is an ancestor of {:?}", node, this);
+            at = if node == root { None } else { node.borrow(gui).parent };
+        }
+
+        // detach new children from their old parents first,
+        // they may be descendants of removed children.
+        for child in children.iter().copied() {
+            let parent = child.borrow(gui).parent;
+            if let Some(parent) = parent {
+                if parent != this {
+                    gui.remove_child(parent, child, true);
+                }
+            }
+        }
+
+        // destroy removed children.
         let mut at = this.borrow(gui).first_child;
         while let Some(child) = at {
             let c = child.borrow(gui);
@@ -178,23 +219,36 @@ impl NodeData {
             assert_eq!(c.parent, Some(this));
             drop(c);
 
-            gui.destroy_node(child);
+            if !new_children.contains(&child) {
+                gui.destroy_node(child);
+            }
             at = next;
         }
 
+        // the rest of the old children are relinked.
+        let mut kept = vec![];
+        NodeData::visit_children(gui, this.borrow(gui).first_child, |child| kept.push(child));
+        let record = (kept != children).then(|| MutationRecord::ChildList {
+            target:  this,
+            added:   children.clone(),
+            removed: kept,
+            prev_sibling: None,
+            next_sibling: None,
+        });
+
         let mut first_child = None;
         let mut prev_child: Option<Node> = None;
         for child in children {
-            child.borrow_mut(gui).parent = Some(this.clone());
+            child.borrow_mut(gui).parent = Some(this);
 
             if let Some(prev) = prev_child {
-                prev.borrow_mut(gui).next_sibling  = Some(child.clone());
+                prev.borrow_mut(gui).next_sibling  = Some(child);
                 child.borrow_mut(gui).prev_sibling = Some(prev);
                 prev_child = Some(child);
             }
             else {
                 child.borrow_mut(gui).prev_sibling = None;
-                first_child = Some(child.clone());
+                first_child = Some(child);
                 prev_child  = Some(child);
             }
         }
@@ -205,6 +259,12 @@ impl NodeData {
         let mut me = this.borrow_mut(gui);
         me.first_child = first_child;
         me.last_child  = prev_child;
+        drop(me);
+
+        if let Some(record) = record {
+            gui.record_mutation(record);
+        }
+        debug_assert!(gui.check_tree());
     }
 }
 
@@ -215,10 +275,7 @@ impl NodeData {
 impl NodeData {
     pub fn style(&mut self, gui: &Gui, parent: &Style) {
         fn is_inherited_style(name: &str) -> bool {
-            match name {
-                "text_color" => true,
-                _ => false,
-            }
+            matches!(name, "text_color")
         }
 
         let mut computed = Style::new();
@@ -242,9 +299,9 @@ impl NodeData {
         })
     }
 
-    pub fn render_children(&mut self, ctx: Ctx, gui: &Gui) {
+    pub fn render_children(&mut self, ctx: &Ctx, gui: &Gui) {
         struct ChildRenderer<'a> {
-            ctx: Ctx,
+            ctx: &'a Ctx,
             gui: &'a Gui,
             children: &'a mut Vec<RenderElement>,
             builder: TextLayoutBuilder,
@@ -253,16 +310,16 @@ impl NodeData {
 
         impl<'a> ChildRenderer<'a> {
             fn flush(&mut self) {
-                if self.builder.text().len() == 0 {
+                if self.builder.text().is_empty() {
                     return;
                 }
 
-                let mut new_builder = TextLayoutBuilder::new(self.ctx, self.builder.base_format());
+                let mut new_builder = TextLayoutBuilder::new(self.ctx.clone(), self.builder.base_format());
                 new_builder.set_format(self.builder.current_format());
 
                 let builder = core::mem::replace(&mut self.builder, new_builder);
                 let layout  = builder.build();
-                let objects = core::mem::replace(&mut self.objects, vec![]);
+                let objects = core::mem::take(&mut self.objects);
                 self.children.push(RenderElement::Text { pos: [0.0; 2], layout, objects });
             }
 
@@ -278,6 +335,22 @@ impl NodeData {
                     .unwrap_or(0x000000);
                 self.builder.set_effect(color as usize);
 
+                // eg: `Brand, Noto Sans CJK SC`.
+                // the other families are the fallback chain.
+                if let Some(names) = style.get("font_family") {
+                    let families: Vec<FontFamilyId> = names.split(',')
+                        .map(|name| name.trim().trim_matches(|c| c == '"' || c == '\''))
+                        .filter_map(|name| self.ctx.font_query(name))
+                        .collect();
+
+                    if let Some((font, fallback)) = families.split_first() {
+                        self.builder.set_font(*font);
+                        self.builder.set_fallback(
+                            if fallback.is_empty() { FontFallbackId::NONE }
+                            else { self.ctx.fonts.borrow_mut().add_fallback(fallback) });
+                    }
+                }
+
                 f(self);
 
                 self.builder.set_format(old_format);
@@ -298,7 +371,7 @@ impl NodeData {
                         if e.kind.is_container() {
                             e.render_children(self.ctx, self.gui);
                             self.builder.add_object();
-                            self.objects.push(el.clone());
+                            self.objects.push(el);
                         }
                         else {
                             self.with_style(&e.computed_style, |this| {
@@ -312,7 +385,7 @@ impl NodeData {
                     Display::Block => {
                         e.render_children(self.ctx, self.gui);
                         self.flush();
-                        self.children.push(RenderElement::Element { ptr: el.clone() });
+                        self.children.push(RenderElement::Element { ptr: el });
                     }
                 }
             }
@@ -321,7 +394,6 @@ impl NodeData {
         self.render_children.clear();
 
         let format = TextFormat {
-            font: ctx.font_query("Roboto").unwrap(),
             font_size: 24.0,
             ..Default::default()
         };
@@ -329,7 +401,7 @@ impl NodeData {
         let mut cr = ChildRenderer {
             ctx, gui,
             children: &mut self.render_children,
-            builder: TextLayoutBuilder::new(ctx, format),
+            builder: TextLayoutBuilder::new(ctx.clone(), format),
             objects: vec![],
         };
 
@@ -651,7 +723,7 @@ impl NodeData {
                                 if hit.fraction < 0.5 { hit.text_pos_left  }
                                 else                  { hit.text_pos_right };
 
-                            return Some((this.clone(), cursor + offset as usize));
+                            return Some((this, cursor + offset as usize));
                         }
                     }
 
@@ -672,6 +744,40 @@ impl NodeData {
         None
     }
 
+    /// the position of `this` in window coordinates, as of the last layout.
+    pub fn window_pos(gui: &Gui, this: Node) -> [f32; 2] {
+        let root = gui.root();
+
+        let mut pos = this.borrow(gui).pos;
+        let mut at  = this;
+        while at != root {
+            // positions are relative to the container's content.
+            let mut container = at;
+            loop {
+                let Some(parent) = container.borrow(gui).parent else { return pos };
+                container = parent;
+                if container.borrow(gui).kind.is_container() {
+                    break;
+                }
+            }
+
+            let c = container.borrow(gui);
+
+            // inline objects are relative to their text.
+            for child in &c.render_children {
+                if let RenderElement::Text { pos: text_pos, layout: _, objects } = child {
+                    if objects.contains(&at) {
+                        pos = [pos[0] + text_pos[0], pos[1] + text_pos[1]];
+                    }
+                }
+            }
+
+            pos = [pos[0] + c.pos[0] - c.scroll_pos[0], pos[1] + c.pos[1] - c.scroll_pos[1]];
+            at = container;
+        }
+        pos
+    }
+
     pub fn pointer_events(&self) -> bool {
         // TODO: false by default for some elements?
         self.computed_style.get("pointer_events")
@@ -750,262 +856,153 @@ impl NodeData {
 // PAINT
 
 impl NodeData {
-    pub fn paint(&mut self, gui: &Gui, rt: &ID2D1RenderTarget) {
+    pub fn paint(&mut self, gui: &Gui, painter: &mut dyn Painter) {
         assert!(self.kind == NodeKind::Div
             || self.kind == NodeKind::Button);
 
+        let rect = Rect::from_pos_size(self.pos, self.size).round();
+
         if let Some(color) = self.computed_style.get("background_color") {
             assert!(color.len() == 6);
             let hex = u32::from_str_radix(color, 16).unwrap();
-            let r = ((hex >> 16) & 0xff) as f32 / 255.0;
-            let g = ((hex >>  8) & 0xff) as f32 / 255.0;
-            let b = ((hex      ) & 0xff) as f32 / 255.0;
-
-            unsafe {
-                let color = D2D1_COLOR_F { r, g, b, a: 1.0 };
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
-
-                let rect = D2D_RECT_F {
-                    left:   self.pos[0].round(),
-                    top:    self.pos[1].round(),
-                    right:  (self.pos[0] + self.size[0]).round(),
-                    bottom: (self.pos[1] + self.size[1]).round(),
-                };
-                rt.FillRectangle(&rect, &brush);
-            }
+            painter.fill_rect(rect, Color::from_hex_rgb(hex));
         }
 
         if self.kind == NodeKind::Button {
-            unsafe {
-                let mut color = D2D1_COLOR_F { r: 0.0, g: 0.0, b: 0.0, a: 1.0 };
-                if self.hover && self.active {
-                    color = D2D1_COLOR_F { r: 1.0, g: 0.5, b: 0.2, a: 1.0 };
-                }
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
+            let mut color = Color::BLACK;
+            if self.hover && self.active {
+                color = Color::rgb(1.0, 0.5, 0.2);
+            }
 
-                let width = if self.hover { 2.0 } else { 1.0 };
+            let width = if self.hover { 2.0 } else { 1.0 };
 
-                let rect = D2D_RECT_F {
-                    left:   self.pos[0].round() + 0.5,
-                    top:    self.pos[1].round() + 0.5,
-                    right:  (self.pos[0] + self.size[0]).round() - 0.5,
-                    bottom: (self.pos[1] + self.size[1]).round() - 0.5,
-                };
-                rt.DrawRectangle(&rect, &brush, width, None);
-            }
+            painter.stroke_rect(rect.grow(-0.5), color, width);
         }
 
         // clip_content approximation.
-        if self.scrolling[0] || self.scrolling[1] {unsafe{
-            let rect = D2D_RECT_F {
-                left:   self.pos[0].round(),
-                top:    self.pos[1].round(),
-                right:  (self.pos[0] + self.size[0]).round(),
-                bottom: (self.pos[1] + self.size[1]).round(),
-            };
-            rt.PushAxisAlignedClip(&rect, windows::Win32::Graphics::Direct2D::D2D1_ANTIALIAS_MODE_ALIASED);
-        }}
-
-        let mut old_tfx = Default::default();
-        unsafe {
-            rt.GetTransform(&mut old_tfx);
+        if self.scrolling[0] || self.scrolling[1] {
+            painter.push_clip(rect);
+        }
 
+        let old_tfx = painter.transform();
+        {
             // need to round here, else rounding in children is meaningless.
             let x = self.pos[0] - self.scroll_pos[0];
             let y = self.pos[1] - self.scroll_pos[1];
-            let new_tfx = Matrix3x2::translation(x.round(), y.round()) * old_tfx;
-            rt.SetTransform(&new_tfx);
+            painter.set_transform(Transform::translation(x.round(), y.round()) * old_tfx);
         }
 
         for child in &mut self.render_children {
             match child {
                 RenderElement::Element { ptr } => {
-                    ptr.borrow_mut(gui).paint(gui, rt);
+                    ptr.borrow_mut(gui).paint(gui, painter);
                 }
 
                 RenderElement::Text { pos, layout, objects } => {
-                    struct D2dTextRenderer<'a> {
+                    struct PainterTextRenderer<'a> {
                         gui: &'a Gui,
-                        rt:    &'a ID2D1RenderTarget,
-                        brush: &'a ID2D1SolidColorBrush,
+                        painter: &'a mut dyn Painter,
                         objects: &'a [Node],
+                        // lines use the color of the preceding glyphs.
+                        color: Color,
                     }
 
-                    impl<'a> TextRenderer for D2dTextRenderer<'a> {
-                        fn glyphs(&self, data: &DrawGlyphs) {
-                            let run = DWRITE_GLYPH_RUN {
-                                fontFace: Some(data.font_face.clone()),
-                                fontEmSize: data.format.font_size,
-                                glyphCount: data.indices.len() as u32,
-                                glyphIndices: data.indices.as_ptr(),
-                                glyphAdvances: data.advances.as_ptr(),
-                                glyphOffsets: data.offsets.as_ptr() as *const _,
-                                isSideways: false.into(),
-                                bidiLevel: data.is_rtl as u32,
-                            };
-
-                            let color = data.format.effect as u32;
-                            let color = D2D1_COLOR_F {
-                                r: ((color >> 16) & 0xff) as f32 / 255.0,
-                                g: ((color >>  8) & 0xff) as f32 / 255.0,
-                                b: ((color >>  0) & 0xff) as f32 / 255.0,
-                                a: 1.0,
-                            };
-                            unsafe { self.brush.SetColor(&color) };
-
-                            let pos = D2D_POINT_2F {
-                                x: data.pos[0],
-                                y: data.pos[1],
-                            };
-                            unsafe { self.rt.DrawGlyphRun(pos, &run, self.brush, Default::default()) };
+                    impl<'a> TextRenderer for PainterTextRenderer<'a> {
+                        fn glyphs(&mut self, data: &DrawGlyphs) {
+                            self.color = Color::from_hex_rgb(data.format.effect as u32);
+                            self.painter.glyphs(data, self.color);
                         }
 
-                        fn line(&self, data: &DrawLine, _kind: DrawLineKind) {
-                            let rect = D2D_RECT_F {
-                                left:   data.x0,
-                                top:    data.y - data.thickness/2.0,
-                                right:  data.x1,
-                                bottom: data.y + data.thickness/2.0,
-                            };
-                            unsafe { self.rt.FillRectangle(&rect, self.brush) };
+                        fn line(&mut self, data: &DrawLine, _kind: DrawLineKind) {
+                            self.painter.line(data, self.color);
                         }
 
-                        fn object(&self, data: &DrawObject) {
+                        fn object(&mut self, data: &DrawObject) {
                             let mut o = self.objects[data.index as usize].borrow_mut(self.gui);
-                            o.paint(self.gui, self.rt);
+                            o.paint(self.gui, self.painter);
                         }
                     }
 
-                    let color = D2D1_COLOR_F { r: 0.0, g: 0.0, b: 0.0, a: 1.0 };
-                    let brush = unsafe { rt.CreateSolidColorBrush(&color, None).unwrap() };
-        
-
                     // TODO: not sure this should be here.
-                    let mut old_tfx = Default::default();
-                    unsafe {
-                        rt.GetTransform(&mut old_tfx);
+                    let old_tfx = painter.transform();
+                    // need to round here, else rounding in children is meaningless.
+                    painter.set_transform(Transform::translation(pos[0].round(), pos[1].round()) * old_tfx);
 
-                        // need to round here, else rounding in children is meaningless.
-                        let new_tfx = Matrix3x2::translation(pos[0].round(), pos[1].round()) * old_tfx;
-                        rt.SetTransform(&new_tfx);
-                    }
-
-                    let r = D2dTextRenderer {
+                    let mut r = PainterTextRenderer {
                         gui,
-                        rt: rt.into(),
-                        brush: &brush,
-                        objects: &objects,
+                        painter: &mut *painter,
+                        objects,
+                        color: Color::BLACK,
                     };
-                    layout.draw([0.0, 0.0], &r);
+                    layout.draw([0.0, 0.0], &mut r);
 
-                    unsafe {
-                        rt.SetTransform(&old_tfx);
-                    }
+                    painter.set_transform(old_tfx);
                 }
             }
         }
 
-        unsafe {
-            rt.SetTransform(&old_tfx);
-        }
+        painter.set_transform(old_tfx);
 
         // clip_content approximation.
-        if self.scrolling[0] || self.scrolling[1] {unsafe{
-            rt.PopAxisAlignedClip();
-        }}
+        if self.scrolling[0] || self.scrolling[1] {
+            painter.pop_clip();
+        }
 
 
         // scroll bars.
+        let track_color = Color::rgb(0.8, 0.8, 0.8);
+        let thumb_color = Color::rgb(0.6, 0.6, 0.6);
+
         if self.scrolling[0] {
-            unsafe {
-                let color = D2D1_COLOR_F { r: 0.8, g: 0.8, b: 0.8, a: 1.0 };
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
+            let offset_thing = scrollbar_size(self.scrolling[0]);
 
-                let offset_thing = scrollbar_size(self.scrolling[0]);
+            let track = Rect {
+                min: [rect.min[0],                rect.max[1] - SCROLLBAR_WIDTH],
+                max: [rect.max[0] - offset_thing, rect.max[1]],
+            };
+            painter.fill_rect(track, track_color);
 
-                let rect = D2D_RECT_F {
-                    left:   self.pos[0].round(),
-                    top:    (self.pos[1] + self.size[1]).round() - SCROLLBAR_WIDTH,
-                    right:  (self.pos[0] + self.size[0]).round() - offset_thing,
-                    bottom: (self.pos[1] + self.size[1]).round(),
-                };
-                rt.FillRectangle(&rect, &brush);
-                
-                let viewport = self.size[0] - scrollbar_size(self.scrolling[1]);
-                let hi = self.scroll_pos[0] / self.content_size[0];
-                let lo = (self.scroll_pos[0] + viewport) / self.content_size[0];
-
-                let c2 = D2D1_COLOR_F { r: 0.6, g: 0.6, b: 0.6, a: 1.0 };
-                brush.SetColor(&c2);
-                let r2 = D2D_RECT_F {
-                    left:  (1.0 - hi)*rect.left + hi*rect.right,
-                    right: (1.0 - lo)*rect.left + lo*rect.right,
-                    ..rect
-                };
-                rt.FillRectangle(&r2, &brush);
-            }
+            let viewport = self.size[0] - scrollbar_size(self.scrolling[1]);
+            let hi = self.scroll_pos[0] / self.content_size[0];
+            let lo = (self.scroll_pos[0] + viewport) / self.content_size[0];
+
+            let thumb = Rect {
+                min: [(1.0 - hi)*track.min[0] + hi*track.max[0], track.min[1]],
+                max: [(1.0 - lo)*track.min[0] + lo*track.max[0], track.max[1]],
+            };
+            painter.fill_rect(thumb, thumb_color);
         }
         if self.scrolling[1] {
-            unsafe {
-                let color = D2D1_COLOR_F { r: 0.8, g: 0.8, b: 0.8, a: 1.0 };
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
+            let offset_thing = scrollbar_size(self.scrolling[0]);
 
-                let offset_thing = scrollbar_size(self.scrolling[0]);
+            let track = Rect {
+                min: [rect.max[0] - SCROLLBAR_WIDTH, rect.min[1]],
+                max: [rect.max[0],                   rect.max[1] - offset_thing],
+            };
+            painter.fill_rect(track, track_color);
 
-                let rect = D2D_RECT_F {
-                    left:   (self.pos[0] + self.size[0]).round() - SCROLLBAR_WIDTH,
-                    top:    self.pos[1].round(),
-                    right:  (self.pos[0] + self.size[0]).round(),
-                    bottom: (self.pos[1] + self.size[1]).round() - offset_thing,
-                };
-                rt.FillRectangle(&rect, &brush);
-
-                let viewport = self.size[1] - scrollbar_size(self.scrolling[0]);
-                let hi = self.scroll_pos[1] / self.content_size[1];
-                let lo = (self.scroll_pos[1] + viewport) / self.content_size[1];
-
-                let c2 = D2D1_COLOR_F { r: 0.6, g: 0.6, b: 0.6, a: 1.0 };
-                brush.SetColor(&c2);
-                let r2 = D2D_RECT_F {
-                    top:    (1.0 - hi)*rect.top + hi*rect.bottom,
-                    bottom: (1.0 - lo)*rect.top + lo*rect.bottom,
-                    ..rect
-                };
-                rt.FillRectangle(&r2, &brush);
-            }
+            let viewport = self.size[1] - scrollbar_size(self.scrolling[0]);
+            let hi = self.scroll_pos[1] / self.content_size[1];
+            let lo = (self.scroll_pos[1] + viewport) / self.content_size[1];
+
+            let thumb = Rect {
+                min: [track.min[0], (1.0 - hi)*track.min[1] + hi*track.max[1]],
+                max: [track.max[0], (1.0 - lo)*track.min[1] + lo*track.max[1]],
+            };
+            painter.fill_rect(thumb, thumb_color);
         }
 
         if self.scrolling[0] && self.scrolling[1] {
-            unsafe {
-                let color = D2D1_COLOR_F { r: 0.8, g: 0.8, b: 0.8, a: 1.0 };
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
-
-                let rect = D2D_RECT_F {
-                    left:   (self.pos[0] + self.size[0]).round() - SCROLLBAR_WIDTH,
-                    top:    (self.pos[1] + self.size[1]).round() - SCROLLBAR_WIDTH,
-                    right:  (self.pos[0] + self.size[0]).round(),
-                    bottom: (self.pos[1] + self.size[1]).round(),
-                };
-                rt.FillRectangle(&rect, &brush);
-            }
+            let corner = Rect {
+                min: [rect.max[0] - SCROLLBAR_WIDTH, rect.max[1] - SCROLLBAR_WIDTH],
+                max: rect.max,
+            };
+            painter.fill_rect(corner, track_color);
         }
 
         if self.focus {
-            unsafe {
-                let color = D2D1_COLOR_F { r: 0.5, g: 0.8, b: 1.0, a: 1.0 };
-                let brush = rt.CreateSolidColorBrush(&color, None).unwrap();
-
-                let width = 2.0;
-
-                let rect = D2D_RECT_F {
-                    left:   self.pos[0].round() - 1.0,
-                    top:    self.pos[1].round() - 1.0,
-                    right:  (self.pos[0] + self.size[0]).round() + 1.0,
-                    bottom: (self.pos[1] + self.size[1]).round() + 1.0,
-                };
-                rt.DrawRectangle(&rect, &brush, width, None);
-            }
+            let color = Color::rgb(0.5, 0.8, 1.0);
+            painter.stroke_rect(rect.grow(1.0), color, 2.0);
         }
     }
 }
-
@@ -0,0 +1,101 @@
+use crate::win::*;
+use crate::text::DrawGlyphs;
+use super::*;
+
+
+pub struct D2dPainter<'a> {
+    rt:    &'a ID2D1RenderTarget,
+    brush: ID2D1SolidColorBrush,
+}
+
+impl<'a> D2dPainter<'a> {
+    pub fn new(rt: &'a ID2D1RenderTarget) -> D2dPainter<'a> {
+        let brush = unsafe { rt.CreateSolidColorBrush(&Color::BLACK.into(), None).unwrap() };
+        D2dPainter { rt, brush }
+    }
+
+    #[inline]
+    fn brush(&self, color: Color) -> &ID2D1SolidColorBrush {
+        unsafe { self.brush.SetColor(&color.into()) };
+        &self.brush
+    }
+}
+
+impl<'a> Painter for D2dPainter<'a> {
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        unsafe { self.rt.FillRectangle(&rect.into(), self.brush(color)) };
+    }
+
+    fn stroke_rect(&mut self, rect: Rect, color: Color, width: f32) {
+        unsafe { self.rt.DrawRectangle(&rect.into(), self.brush(color), width, None) };
+    }
+
+    fn push_clip(&mut self, rect: Rect) {
+        unsafe { self.rt.PushAxisAlignedClip(&rect.into(), D2D1_ANTIALIAS_MODE_ALIASED) };
+    }
+
+    fn pop_clip(&mut self) {
+        unsafe { self.rt.PopAxisAlignedClip() };
+    }
+
+    fn transform(&self) -> Transform {
+        let mut tfx = Default::default();
+        unsafe { self.rt.GetTransform(&mut tfx) };
+        tfx.into()
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        unsafe { self.rt.SetTransform(&transform.into()) };
+    }
+
+    fn glyphs(&mut self, data: &DrawGlyphs, color: Color) {
+        // TODO: faces DirectWrite can't load.
+        let Some(dw_face) = &data.font_face.dw_face else { return };
+
+        let run = DWRITE_GLYPH_RUN {
+            fontFace: Some(dw_face.clone()),
+            fontEmSize: data.format.font_size,
+            glyphCount: data.indices.len() as u32,
+            glyphIndices: data.indices.as_ptr(),
+            glyphAdvances: data.advances.as_ptr(),
+            glyphOffsets: data.offsets.as_ptr() as *const _,
+            isSideways: false.into(),
+            bidiLevel: data.is_rtl as u32,
+        };
+
+        let pos = D2D_POINT_2F {
+            x: data.pos[0],
+            y: data.pos[1],
+        };
+        unsafe { self.rt.DrawGlyphRun(pos, &run, self.brush(color), Default::default()) };
+    }
+}
+
+
+impl From<Color> for D2D1_COLOR_F {
+    #[inline]
+    fn from(c: Color) -> Self {
+        D2D1_COLOR_F { r: c.r, g: c.g, b: c.b, a: c.a }
+    }
+}
+
+impl From<Rect> for D2D_RECT_F {
+    #[inline]
+    fn from(r: Rect) -> Self {
+        D2D_RECT_F { left: r.min[0], top: r.min[1], right: r.max[0], bottom: r.max[1] }
+    }
+}
+
+impl From<Transform> for Matrix3x2 {
+    #[inline]
+    fn from(t: Transform) -> Self {
+        Matrix3x2 { M11: t.m11, M12: t.m12, M21: t.m21, M22: t.m22, M31: t.dx, M32: t.dy }
+    }
+}
+
+impl From<Matrix3x2> for Transform {
+    #[inline]
+    fn from(m: Matrix3x2) -> Self {
+        Transform { m11: m.M11, m12: m.M12, m21: m.M21, m22: m.M22, dx: m.M31, dy: m.M32 }
+    }
+}
@@ -0,0 +1,277 @@
+use std::path::Path;
+
+use super::software::Pixmap;
+
+
+/// a captured frame, see `Gui::screenshot`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Image {
+    pub pixmap: Pixmap,
+}
+
+impl Image {
+    #[inline]
+    pub fn new(pixmap: Pixmap) -> Image {
+        Image { pixmap }
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// an RGBA8 PNG file.
+    pub fn encode_png(&self) -> Vec<u8> {
+        encode_png(&self.pixmap)
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.encode_png())
+    }
+}
+
+
+
+// PNG
+
+pub fn encode_png(pixmap: &Pixmap) -> Vec<u8> {
+    let width  = pixmap.width()  as usize;
+    let height = pixmap.height() as usize;
+    let stride = 4 * width;
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut ihdr = vec![];
+    ihdr.extend((width  as u32).to_be_bytes());
+    ihdr.extend((height as u32).to_be_bytes());
+    // 8 bit, rgba, deflate, adaptive filtering, not interlaced.
+    ihdr.extend([8, 6, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &ihdr);
+
+    // each row starts with its filter type.
+    let mut filtered = Vec::with_capacity((stride + 1) * height);
+    let zero_row = vec![0; stride];
+    let mut candidate = vec![0; stride];
+    let mut best      = vec![0; stride];
+    for y in 0..height {
+        let row  = &pixmap.data()[y*stride .. (y + 1)*stride];
+        let prev = if y > 0 { &pixmap.data()[(y - 1)*stride .. y*stride] } else { &zero_row[..] };
+
+        // pick the filter with the smallest sum of absolute differences.
+        let mut best_filter = 0;
+        let mut best_cost   = u64::MAX;
+        for filter in 0..5 {
+            for i in 0..stride {
+                let a = if i >= 4 { row[i - 4]  } else { 0 };
+                let b = prev[i];
+                let c = if i >= 4 { prev[i - 4] } else { 0 };
+                let predictor = match filter {
+                    0 => 0,
+                    1 => a,
+                    2 => b,
+                    3 => ((a as u16 + b as u16) / 2) as u8,
+                    _ => paeth(a, b, c),
+                };
+                candidate[i] = row[i].wrapping_sub(predictor);
+            }
+
+            let cost = candidate.iter().map(|v| (*v as i8).unsigned_abs() as u64).sum();
+            if cost < best_cost {
+                best_cost   = cost;
+                best_filter = filter;
+                core::mem::swap(&mut best, &mut candidate);
+            }
+        }
+
+        filtered.push(best_filter);
+        filtered.extend_from_slice(&best);
+    }
+    write_chunk(&mut png, b"IDAT", &zlib_compress(&filtered));
+
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[inline]
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p  = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc { a }
+    else if pb <= pc        { b }
+    else                    { c }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let begin = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[begin..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+
+
+// DEFLATE
+// fixed huffman codes & greedy LZ77 matching.
+// UI screenshots are mostly flat colors, that compresses well enough.
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits:  u64,
+    count: u32,
+}
+
+impl BitWriter {
+    #[inline]
+    fn write(&mut self, value: u32, count: u32) {
+        self.bits |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits  >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// huffman codes are stored msb first.
+    #[inline]
+    fn write_code(&mut self, code: u32, count: u32) {
+        self.write(code.reverse_bits() >> (32 - count), count);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+const LENGTH_BASE:  [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29]  = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DIST_BASE:  [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DIST_EXTRA: [u8; 30]  = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+fn write_literal(w: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143   => w.write_code(0x30  + symbol,         8),
+        144..=255 => w.write_code(0x190 + symbol - 144,   9),
+        256..=279 => w.write_code(symbol - 256,           7),
+        _         => w.write_code(0xc0  + symbol - 280,   8),
+    }
+}
+
+fn write_match(w: &mut BitWriter, length: usize, distance: usize) {
+    let i = LENGTH_BASE.partition_point(|base| *base as usize <= length) - 1;
+    write_literal(w, 257 + i as u32);
+    w.write((length - LENGTH_BASE[i] as usize) as u32, LENGTH_EXTRA[i] as u32);
+
+    let i = DIST_BASE.partition_point(|base| *base as usize <= distance) - 1;
+    w.write_code(i as u32, 5);
+    w.write((distance - DIST_BASE[i] as usize) as u32, DIST_EXTRA[i] as u32);
+}
+
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    const WINDOW:    usize = 32768;
+    const MIN_MATCH: usize = 3;
+    const MAX_MATCH: usize = 258;
+    const MAX_CHAIN: usize = 32;
+    const HASH_BITS: u32   = 15;
+
+    let hash = |i: usize| {
+        let v = (data[i] as u32) << 16 | (data[i + 1] as u32) << 8 | data[i + 2] as u32;
+        (v.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+    };
+
+    // most recent position of each hash, & the previous one of each position.
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; data.len()];
+    let insert = |i: usize, head: &mut [usize], prev: &mut [usize]| {
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(i);
+            prev[i] = head[h];
+            head[h] = i;
+        }
+    };
+
+    let mut w = BitWriter { bytes: vec![], bits: 0, count: 0 };
+    // a single final block with fixed codes.
+    w.write(1, 1);
+    w.write(1, 2);
+
+    let mut i = 0;
+    while i < data.len() {
+        let mut best_len  = 0;
+        let mut best_dist = 0;
+        if i + MIN_MATCH <= data.len() {
+            let max_len = MAX_MATCH.min(data.len() - i);
+            let mut candidate = head[hash(i)];
+            let mut chain = 0;
+            while candidate != usize::MAX && i - candidate <= WINDOW && chain < MAX_CHAIN {
+                let len = data[candidate..].iter().zip(&data[i .. i + max_len])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if len > best_len {
+                    best_len  = len;
+                    best_dist = i - candidate;
+                    if len == max_len {
+                        break;
+                    }
+                }
+                candidate = prev[candidate];
+                chain += 1;
+            }
+        }
+
+        if best_len >= MIN_MATCH {
+            write_match(&mut w, best_len, best_dist);
+            for j in i .. i + best_len {
+                insert(j, &mut head, &mut prev);
+            }
+            i += best_len;
+        }
+        else {
+            write_literal(&mut w, data[i] as u32);
+            insert(i, &mut head, &mut prev);
+            i += 1;
+        }
+    }
+    write_literal(&mut w, 256);
+
+    let mut result = vec![0x78, 0x01];
+    result.extend(w.finish());
+    result.extend(adler32(data).to_be_bytes());
+    result
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += *byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    b << 16 | a
+}
@@ -0,0 +1,217 @@
+#[cfg(windows)]
+pub mod d2d;
+pub mod software;
+pub mod record;
+pub mod svg;
+pub mod image;
+
+use crate::text::{DrawGlyphs, DrawLine};
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Color = Color::rgb(1.0, 1.0, 1.0);
+
+    #[inline]
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Color {
+        Color { r, g, b, a: 1.0 }
+    }
+
+    /// `hex` is `0xRRGGBB`.
+    #[inline]
+    #[allow(clippy::identity_op)]
+    pub fn from_hex_rgb(hex: u32) -> Color {
+        Color::rgb(
+            ((hex >> 16) & 0xff) as f32 / 255.0,
+            ((hex >>  8) & 0xff) as f32 / 255.0,
+            ((hex >>  0) & 0xff) as f32 / 255.0)
+    }
+
+    /// `0xRRGGBB`, ignores alpha.
+    #[inline]
+    pub fn to_hex_rgb(self) -> u32 {
+        let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u32;
+        c(self.r) << 16 | c(self.g) << 8 | c(self.b)
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+}
+
+impl Rect {
+    #[inline]
+    pub fn from_pos_size(pos: [f32; 2], size: [f32; 2]) -> Rect {
+        Rect { min: pos, max: [pos[0] + size[0], pos[1] + size[1]] }
+    }
+
+    #[inline]
+    pub fn width(self) -> f32 {
+        self.max[0] - self.min[0]
+    }
+
+    #[inline]
+    pub fn height(self) -> f32 {
+        self.max[1] - self.min[1]
+    }
+
+    #[inline]
+    pub fn round(self) -> Rect {
+        Rect {
+            min: [self.min[0].round(), self.min[1].round()],
+            max: [self.max[0].round(), self.max[1].round()],
+        }
+    }
+
+    /// moves all edges outward by `amount` (inward, if negative).
+    #[inline]
+    pub fn grow(self, amount: f32) -> Rect {
+        Rect {
+            min: [self.min[0] - amount, self.min[1] - amount],
+            max: [self.max[0] + amount, self.max[1] + amount],
+        }
+    }
+}
+
+
+/// a 2d affine transform.
+/// uses row vectors, like Direct2D: `a * b` applies `a` first, then `b`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform {
+    pub m11: f32, pub m12: f32,
+    pub m21: f32, pub m22: f32,
+    pub dx:  f32, pub dy:  f32,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        m11: 1.0, m12: 0.0,
+        m21: 0.0, m22: 1.0,
+        dx:  0.0, dy:  0.0,
+    };
+
+    #[inline]
+    pub const fn translation(x: f32, y: f32) -> Transform {
+        Transform { dx: x, dy: y, ..Transform::IDENTITY }
+    }
+
+    #[inline]
+    pub fn apply(&self, p: [f32; 2]) -> [f32; 2] {
+        [p[0]*self.m11 + p[1]*self.m21 + self.dx,
+         p[0]*self.m12 + p[1]*self.m22 + self.dy]
+    }
+
+    #[inline]
+    pub fn is_translation(&self) -> bool {
+        self.m11 == 1.0 && self.m12 == 0.0
+        && self.m21 == 0.0 && self.m22 == 1.0
+    }
+}
+
+impl Default for Transform {
+    #[inline]
+    fn default() -> Self { Self::IDENTITY }
+}
+
+impl core::ops::Mul for Transform {
+    type Output = Transform;
+
+    #[inline]
+    fn mul(self, b: Transform) -> Transform {
+        let a = self;
+        Transform {
+            m11: a.m11*b.m11 + a.m12*b.m21,
+            m12: a.m11*b.m12 + a.m12*b.m22,
+            m21: a.m21*b.m11 + a.m22*b.m21,
+            m22: a.m21*b.m12 + a.m22*b.m22,
+            dx:  a.dx*b.m11  + a.dy*b.m21 + b.dx,
+            dy:  a.dx*b.m12  + a.dy*b.m22 + b.dy,
+        }
+    }
+}
+
+
+/// the drawing operations `Gui::paint` needs from a backend.
+///
+/// coordinates are in pixels and are mapped through the current transform.
+/// clips are axis aligned and intersect with the enclosing clip.
+pub trait Painter {
+    fn fill_rect(&mut self, rect: Rect, color: Color);
+
+    /// strokes are centered on the rect's edges.
+    fn stroke_rect(&mut self, rect: Rect, color: Color, width: f32);
+
+    fn push_clip(&mut self, rect: Rect);
+    fn pop_clip(&mut self);
+
+    fn transform(&self) -> Transform;
+    fn set_transform(&mut self, transform: Transform);
+
+    /// `data.pos` is the baseline origin of the run.
+    fn glyphs(&mut self, data: &DrawGlyphs, color: Color);
+
+    /// underlines & strikethroughs.
+    /// `data.y` is the center of the line.
+    fn line(&mut self, data: &DrawLine, color: Color) {
+        let rect = Rect {
+            min: [data.x0, data.y - data.thickness/2.0],
+            max: [data.x1, data.y + data.thickness/2.0],
+        };
+        self.fill_rect(rect, color);
+    }
+}
+
+
+/// the font units -> pixels transform of each glyph of a run.
+/// font units are y up.
+pub(crate) fn glyph_transforms<'a>(data: &'a DrawGlyphs, units_per_em: u16) -> impl Iterator<Item=(u16, Transform)> + 'a {
+    let scale = data.format.font_size / units_per_em as f32;
+
+    let mut advance = 0.0;
+    (0..data.indices.len()).map(move |i| {
+        let [offset_x, offset_y] = data.offsets[i];
+
+        // rtl runs go right to left from `pos`.
+        let x =
+            if data.is_rtl {
+                advance += data.advances[i];
+                data.pos[0] - advance - offset_x
+            }
+            else {
+                let x = data.pos[0] + advance + offset_x;
+                advance += data.advances[i];
+                x
+            };
+        let y = data.pos[1] - offset_y;
+
+        let transform = Transform {
+            m11: scale, m12: 0.0,
+            m21: 0.0,   m22: -scale,
+            dx: x, dy: y,
+        };
+        (data.indices[i], transform)
+    })
+}
+
+
+/// formats floats with at most 2 decimals.
+pub(crate) struct Rounded (pub f32);
+
+impl core::fmt::Display for Rounded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let v = (self.0 * 100.0).round() / 100.0;
+        // no `-0`.
+        write!(f, "{}", if v == 0.0 { 0.0 } else { v })
+    }
+}
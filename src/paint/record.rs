@@ -0,0 +1,193 @@
+use core::fmt;
+
+use crate::gui::{Gui, IGui};
+use crate::text::{DrawGlyphs, DrawLine};
+use super::*;
+
+
+/// a recorded `Painter` call.
+#[derive(Clone, PartialEq, Debug)]
+pub enum DrawCommand {
+    FillRect   { rect: Rect, color: Color },
+    StrokeRect { rect: Rect, color: Color, width: f32 },
+    PushClip   { rect: Rect },
+    PopClip,
+    SetTransform { transform: Transform },
+    Glyphs {
+        pos:        [f32; 2],
+        text_begin: u32,
+        text_end:   u32,
+        font_size:  f32,
+        is_rtl:     bool,
+        color:      Color,
+        indices:    Vec<u16>,
+        advances:   Vec<f32>,
+    },
+    Line { x0: f32, x1: f32, y: f32, thickness: f32, color: Color },
+}
+
+
+/// records a frame of `gui`, like `software::render_frame` renders one.
+pub fn record_frame(gui: &mut Gui, width: u32, height: u32) -> Vec<DrawCommand> {
+    let mut painter = RecordingPainter::new();
+
+    gui.set_window_size(width as f32, height as f32);
+    gui.paint(&mut painter);
+
+    painter.commands
+}
+
+/// one command per line, see `DrawCommand`'s `Display` impl.
+/// stable, so it can be diffed against golden files.
+pub fn display_list_to_string(commands: &[DrawCommand]) -> String {
+    let mut result = String::new();
+    for command in commands {
+        result.push_str(&command.to_string());
+        result.push('\n');
+    }
+    result
+}
+
+
+/// a `Painter` that records the calls, instead of drawing.
+/// transforms are only recorded, when something is drawn with them.
+pub struct RecordingPainter {
+    pub commands: Vec<DrawCommand>,
+    transform: Transform,
+    recorded_transform: Transform,
+}
+
+impl RecordingPainter {
+    pub fn new() -> RecordingPainter {
+        RecordingPainter {
+            commands: vec![],
+            transform: Transform::IDENTITY,
+            recorded_transform: Transform::IDENTITY,
+        }
+    }
+
+    fn push(&mut self, command: DrawCommand) {
+        if self.transform != self.recorded_transform {
+            self.recorded_transform = self.transform;
+            self.commands.push(DrawCommand::SetTransform { transform: self.transform });
+        }
+        self.commands.push(command);
+    }
+}
+
+impl Default for RecordingPainter {
+    #[inline] fn default() -> RecordingPainter { RecordingPainter::new() }
+}
+
+impl Painter for RecordingPainter {
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        self.push(DrawCommand::FillRect { rect, color });
+    }
+
+    fn stroke_rect(&mut self, rect: Rect, color: Color, width: f32) {
+        self.push(DrawCommand::StrokeRect { rect, color, width });
+    }
+
+    fn push_clip(&mut self, rect: Rect) {
+        self.push(DrawCommand::PushClip { rect });
+    }
+
+    fn pop_clip(&mut self) {
+        self.push(DrawCommand::PopClip);
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn glyphs(&mut self, data: &DrawGlyphs, color: Color) {
+        self.push(DrawCommand::Glyphs {
+            pos:        data.pos,
+            text_begin: data.text_begin,
+            text_end:   data.text_end,
+            font_size:  data.format.font_size,
+            is_rtl:     data.is_rtl,
+            color,
+            indices:    data.indices.into(),
+            advances:   data.advances.into(),
+        });
+    }
+
+    fn line(&mut self, data: &DrawLine, color: Color) {
+        self.push(DrawCommand::Line {
+            x0: data.x0, x1: data.x1, y: data.y,
+            thickness: data.thickness,
+            color,
+        });
+    }
+}
+
+
+
+// TEXT FORMAT
+
+struct Hex (Color);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06x}", self.0.to_hex_rgb())?;
+        if self.0.a != 1.0 {
+            write!(f, "{:02x}", (self.0.a.clamp(0.0, 1.0) * 255.0 + 0.5) as u8)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DrawCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let r = Rounded;
+        let rect = |f: &mut fmt::Formatter<'_>, rect: &Rect| {
+            write!(f, "{} {} {} {}", r(rect.min[0]), r(rect.min[1]), r(rect.max[0]), r(rect.max[1]))
+        };
+
+        match self {
+            DrawCommand::FillRect { rect: rc, color } => {
+                write!(f, "fill_rect ")?;
+                rect(f, rc)?;
+                write!(f, " {}", Hex(*color))
+            }
+
+            DrawCommand::StrokeRect { rect: rc, color, width } => {
+                write!(f, "stroke_rect ")?;
+                rect(f, rc)?;
+                write!(f, " {} {}", Hex(*color), r(*width))
+            }
+
+            DrawCommand::PushClip { rect: rc } => {
+                write!(f, "push_clip ")?;
+                rect(f, rc)
+            }
+
+            DrawCommand::PopClip => write!(f, "pop_clip"),
+
+            DrawCommand::SetTransform { transform: t } => {
+                write!(f, "set_transform {} {} {} {} {} {}",
+                    r(t.m11), r(t.m12), r(t.m21), r(t.m22), r(t.dx), r(t.dy))
+            }
+
+            DrawCommand::Glyphs { pos, text_begin, text_end, font_size, is_rtl, color, indices, advances } => {
+                write!(f, "glyphs {} {} {}..{} {} {} {} [",
+                    r(pos[0]), r(pos[1]), text_begin, text_end,
+                    r(*font_size), if *is_rtl { "rtl" } else { "ltr" }, Hex(*color))?;
+                for (i, (index, advance)) in indices.iter().zip(advances).enumerate() {
+                    if i > 0 { write!(f, " ")?; }
+                    write!(f, "{}:{}", index, r(*advance))?;
+                }
+                write!(f, "]")
+            }
+
+            DrawCommand::Line { x0, x1, y, thickness, color } => {
+                write!(f, "line {} {} {} {} {}", r(*x0), r(*x1), r(*y), r(*thickness), Hex(*color))
+            }
+        }
+    }
+}
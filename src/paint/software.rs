@@ -0,0 +1,421 @@
+use crate::gui::{Gui, IGui};
+use crate::text::{DrawGlyphs, OutlineSink};
+use super::*;
+
+
+/// an RGBA8 image with straight (non-premultiplied) alpha.
+#[derive(Clone, PartialEq)]
+pub struct Pixmap {
+    width:  u32,
+    height: u32,
+    data:   Vec<u8>,
+}
+
+impl Pixmap {
+    /// a fully transparent pixmap.
+    pub fn new(width: u32, height: u32) -> Pixmap {
+        Pixmap { width, height, data: vec![0; 4 * width as usize * height as usize] }
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// rows top to bottom, 4 bytes per pixel.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let i = 4 * (y as usize * self.width as usize + x as usize);
+        [self.data[i], self.data[i + 1], self.data[i + 2], self.data[i + 3]]
+    }
+
+    pub fn fill(&mut self, color: Color) {
+        let rgba = to_rgba8(color);
+        for pixel in self.data.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&rgba);
+        }
+    }
+
+    /// source-over blends `color`, scaled by `coverage`, onto a pixel.
+    #[inline]
+    fn blend(&mut self, x: u32, y: u32, color: Color, coverage: f32) {
+        let sa = color.a * coverage;
+        if sa <= 0.0 {
+            return;
+        }
+
+        let i = 4 * (y as usize * self.width as usize + x as usize);
+        let dst = &mut self.data[i .. i + 4];
+
+        let da = dst[3] as f32 / 255.0;
+        let out_a = sa + da * (1.0 - sa);
+
+        let mix = |s: f32, d: u8| -> u8 {
+            let d = d as f32 / 255.0;
+            let v = (s*sa + d*da*(1.0 - sa)) / out_a;
+            (v * 255.0 + 0.5) as u8
+        };
+        dst[0] = mix(color.r, dst[0]);
+        dst[1] = mix(color.g, dst[1]);
+        dst[2] = mix(color.b, dst[2]);
+        dst[3] = (out_a * 255.0 + 0.5) as u8;
+    }
+}
+
+impl core::fmt::Debug for Pixmap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Pixmap({}x{})", self.width, self.height)
+    }
+}
+
+#[inline]
+fn to_rgba8(color: Color) -> [u8; 4] {
+    let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+    [c(color.r), c(color.g), c(color.b), c(color.a)]
+}
+
+
+/// renders a full frame of `gui`, like `NativeGui` does on `WM_PAINT`.
+pub fn render_frame(gui: &mut Gui, width: u32, height: u32) -> Pixmap {
+    let mut pixmap = Pixmap::new(width, height);
+    pixmap.fill(Color::WHITE);
+
+    gui.set_window_size(width as f32, height as f32);
+    gui.paint(&mut SoftwarePainter::new(&mut pixmap));
+
+    pixmap
+}
+
+
+/// a CPU implementation of `Painter`, drawing into a `Pixmap`.
+/// everything is anti-aliased, except clips, which snap to pixels.
+pub struct SoftwarePainter<'a> {
+    pixmap:    &'a mut Pixmap,
+    transform: Transform,
+    clips:     Vec<Rect>, // device space.
+}
+
+impl<'a> SoftwarePainter<'a> {
+    pub fn new(pixmap: &'a mut Pixmap) -> SoftwarePainter<'a> {
+        SoftwarePainter { pixmap, transform: Transform::IDENTITY, clips: vec![] }
+    }
+
+    #[inline]
+    fn clip(&self) -> Rect {
+        self.clips.last().copied().unwrap_or(Rect {
+            min: [0.0, 0.0],
+            max: [self.pixmap.width as f32, self.pixmap.height as f32],
+        })
+    }
+
+    fn fill_lines(&mut self, lines: &Lines, color: Color) {
+        let clip = self.clip();
+
+        let x0 = lines.min[0].floor().max(clip.min[0]);
+        let y0 = lines.min[1].floor().max(clip.min[1]);
+        let x1 = lines.max[0].ceil() .min(clip.max[0]);
+        let y1 = lines.max[1].ceil() .min(clip.max[1]);
+        if x0 >= x1 || y0 >= y1 {
+            return;
+        }
+
+        let mut r = Rasterizer::new((x1 - x0) as usize, (y1 - y0) as usize);
+        for &(p0, p1) in &lines.lines {
+            r.line([p0[0] - x0, p0[1] - y0], [p1[0] - x0, p1[1] - y0]);
+        }
+
+        let (x0, y0) = (x0 as u32, y0 as u32);
+        r.for_each(|x, y, coverage| {
+            self.pixmap.blend(x0 + x as u32, y0 + y as u32, color, coverage);
+        });
+    }
+}
+
+impl<'a> Painter for SoftwarePainter<'a> {
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        if !self.transform.is_translation() {
+            let t = &self.transform;
+            let mut lines = Lines::new();
+            lines.move_to(t.apply(rect.min));
+            lines.line_to(t.apply([rect.max[0], rect.min[1]]));
+            lines.line_to(t.apply(rect.max));
+            lines.line_to(t.apply([rect.min[0], rect.max[1]]));
+            lines.close();
+            self.fill_lines(&lines, color);
+            return;
+        }
+
+        let (dx, dy) = (self.transform.dx, self.transform.dy);
+        let clip = self.clip();
+        let x0 = (rect.min[0] + dx).max(clip.min[0]);
+        let y0 = (rect.min[1] + dy).max(clip.min[1]);
+        let x1 = (rect.max[0] + dx).min(clip.max[0]);
+        let y1 = (rect.max[1] + dy).min(clip.max[1]);
+        if x0 >= x1 || y0 >= y1 {
+            return;
+        }
+
+        // coverage is the overlap of the pixel with the rect.
+        let overlap = |i: u32, lo: f32, hi: f32| -> f32 {
+            let i = i as f32;
+            (hi.min(i + 1.0) - lo.max(i)).max(0.0)
+        };
+
+        for y in y0.floor() as u32 .. y1.ceil() as u32 {
+            let cy = overlap(y, y0, y1);
+            for x in x0.floor() as u32 .. x1.ceil() as u32 {
+                let cx = overlap(x, x0, x1);
+                self.pixmap.blend(x, y, color, cx * cy);
+            }
+        }
+    }
+
+    fn stroke_rect(&mut self, rect: Rect, color: Color, width: f32) {
+        let outer = rect.grow( width / 2.0);
+        let inner = rect.grow(-width / 2.0);
+
+        if inner.width() <= 0.0 || inner.height() <= 0.0 {
+            self.fill_rect(outer, color);
+            return;
+        }
+
+        // top, bottom, left, right.
+        self.fill_rect(Rect { min: outer.min, max: [outer.max[0], inner.min[1]] }, color);
+        self.fill_rect(Rect { min: [outer.min[0], inner.max[1]], max: outer.max }, color);
+        self.fill_rect(Rect { min: [outer.min[0], inner.min[1]], max: [inner.min[0], inner.max[1]] }, color);
+        self.fill_rect(Rect { min: [inner.max[0], inner.min[1]], max: [outer.max[0], inner.max[1]] }, color);
+    }
+
+    fn push_clip(&mut self, rect: Rect) {
+        // bounding box of the transformed rect, like Direct2D.
+        let t = &self.transform;
+        let corners = [
+            t.apply(rect.min), t.apply(rect.max),
+            t.apply([rect.min[0], rect.max[1]]), t.apply([rect.max[0], rect.min[1]]),
+        ];
+        let mut bounds = Rect { min: corners[0], max: corners[0] };
+        for c in &corners[1..] {
+            bounds.min = [bounds.min[0].min(c[0]), bounds.min[1].min(c[1])];
+            bounds.max = [bounds.max[0].max(c[0]), bounds.max[1].max(c[1])];
+        }
+
+        // aliased: snap to pixels, then intersect.
+        let bounds = bounds.round();
+        let clip = self.clip();
+        let min = [bounds.min[0].max(clip.min[0]), bounds.min[1].max(clip.min[1])];
+        let max = [bounds.max[0].min(clip.max[0]), bounds.max[1].min(clip.max[1])];
+        self.clips.push(Rect { min, max: [max[0].max(min[0]), max[1].max(min[1])] });
+    }
+
+    fn pop_clip(&mut self) {
+        self.clips.pop().unwrap();
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn glyphs(&mut self, data: &DrawGlyphs, color: Color) {
+        let Some(file) = data.font_face.file.as_ref() else { return };
+
+        let mut lines = Lines::new();
+        for (glyph, glyph_tfx) in glyph_transforms(data, file.units_per_em()) {
+            let mut sink = TransformSink { lines: &mut lines, transform: glyph_tfx * self.transform };
+            file.glyph_outline(glyph, &mut sink);
+        }
+
+        self.fill_lines(&lines, color);
+    }
+}
+
+
+
+// PATHS
+
+/// a flattened path.
+struct Lines {
+    lines: Vec<([f32; 2], [f32; 2])>,
+    min: [f32; 2],
+    max: [f32; 2],
+    start:   [f32; 2],
+    current: [f32; 2],
+}
+
+impl Lines {
+    fn new() -> Lines {
+        Lines {
+            lines: vec![],
+            min: [f32::INFINITY; 2],
+            max: [f32::NEG_INFINITY; 2],
+            start:   [0.0; 2],
+            current: [0.0; 2],
+        }
+    }
+
+    fn move_to(&mut self, p: [f32; 2]) {
+        self.close();
+        self.start   = p;
+        self.current = p;
+    }
+
+    fn line_to(&mut self, p: [f32; 2]) {
+        let p0 = self.current;
+        self.lines.push((p0, p));
+        for axis in 0..2 {
+            self.min[axis] = self.min[axis].min(p0[axis]).min(p[axis]);
+            self.max[axis] = self.max[axis].max(p0[axis]).max(p[axis]);
+        }
+        self.current = p;
+    }
+
+    fn quad_to(&mut self, c: [f32; 2], p: [f32; 2]) {
+        let p0 = self.current;
+
+        // subdivide based on the distance of the control point
+        // from the chord.
+        let dev = [p0[0] - 2.0*c[0] + p[0], p0[1] - 2.0*c[1] + p[1]];
+        let dev_sq = dev[0]*dev[0] + dev[1]*dev[1];
+        let n = 1 + (3.0 * dev_sq).sqrt().sqrt() as usize;
+
+        for i in 1..=n {
+            let t = i as f32 / n as f32;
+            let u = 1.0 - t;
+            self.line_to([
+                u*u*p0[0] + 2.0*u*t*c[0] + t*t*p[0],
+                u*u*p0[1] + 2.0*u*t*c[1] + t*t*p[1],
+            ]);
+        }
+    }
+
+    fn close(&mut self) {
+        if self.current != self.start {
+            self.line_to(self.start);
+        }
+    }
+}
+
+struct TransformSink<'a> {
+    lines: &'a mut Lines,
+    transform: Transform,
+}
+
+impl<'a> OutlineSink for TransformSink<'a> {
+    fn move_to(&mut self, p: [f32; 2]) {
+        self.lines.move_to(self.transform.apply(p));
+    }
+
+    fn line_to(&mut self, p: [f32; 2]) {
+        self.lines.line_to(self.transform.apply(p));
+    }
+
+    fn quad_to(&mut self, c: [f32; 2], p: [f32; 2]) {
+        self.lines.quad_to(self.transform.apply(c), self.transform.apply(p));
+    }
+
+    fn close(&mut self) {
+        self.lines.close();
+    }
+}
+
+
+
+// RASTERIZER
+
+/// accumulates signed area per cell.
+/// the running sum along a row is the coverage of a pixel (non-zero winding,
+/// as long as contours don't overlap).
+struct Rasterizer {
+    width:  usize,
+    height: usize,
+    cells:  Vec<f32>, // `width + 2` per row.
+}
+
+impl Rasterizer {
+    fn new(width: usize, height: usize) -> Rasterizer {
+        Rasterizer { width, height, cells: vec![0.0; (width + 2) * height] }
+    }
+
+    fn line(&mut self, p0: [f32; 2], p1: [f32; 2]) {
+        if p0[1] == p1[1] {
+            return;
+        }
+
+        let (dir, p0, p1) =
+            if p0[1] < p1[1] { ( 1.0, p0, p1) }
+            else             { (-1.0, p1, p0) };
+
+        let dxdy = (p1[0] - p0[0]) / (p1[1] - p0[1]);
+        let width = self.width as f32;
+        let x_at = |y: f32| (p0[0] + (y - p0[1]) * dxdy).clamp(0.0, width);
+
+        let y_begin = p0[1].max(0.0);
+        let y_end   = p1[1].min(self.height as f32);
+
+        let mut y = y_begin;
+        while y < y_end {
+            let row = y.floor() as usize;
+            let y_next = ((row + 1) as f32).min(y_end);
+            self.row_segment(row, x_at(y), x_at(y_next), dir * (y_next - y));
+            y = y_next;
+        }
+    }
+
+    fn row_segment(&mut self, row: usize, xa: f32, xb: f32, dy: f32) {
+        let stride = self.width + 2;
+        let cells = &mut self.cells[row * stride .. (row + 1) * stride];
+
+        let (x0, x1) = if xa < xb { (xa, xb) } else { (xb, xa) };
+
+        // vertical, or inside a single cell.
+        if x1 - x0 < 1e-6 || x1.ceil() - 1.0 <= x0.floor() {
+            let i  = x0.floor() as usize;
+            let xm = 0.5 * (x0 + x1) - i as f32;
+            cells[i]     += dy * (1.0 - xm);
+            cells[i + 1] += dy * xm;
+            return;
+        }
+
+        // split at cell boundaries.
+        // dy is distributed linearly over x.
+        let dydx = dy / (x1 - x0);
+        let mut x = x0;
+        while x < x1 {
+            let i = x.floor() as usize;
+            let x_next = ((i + 1) as f32).min(x1);
+            let d  = dydx * (x_next - x);
+            let xm = 0.5 * (x + x_next) - i as f32;
+            cells[i]     += d * (1.0 - xm);
+            cells[i + 1] += d * xm;
+            x = x_next;
+        }
+    }
+
+    fn for_each<F: FnMut(usize, usize, f32)>(&self, mut f: F) {
+        let stride = self.width + 2;
+        for y in 0..self.height {
+            let row = &self.cells[y * stride .. (y + 1) * stride];
+            let mut acc = 0.0f32;
+            for (x, cell) in row[..self.width].iter().enumerate() {
+                acc += cell;
+                let coverage = acc.abs().min(1.0);
+                if coverage > 1.0 / 512.0 {
+                    f(x, y, coverage);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use core::fmt::Write;
+
+use crate::gui::{Gui, IGui};
+use crate::text::{DrawGlyphs, OutlineSink};
+use super::*;
+
+
+/// renders a frame of `gui` to an SVG document,
+/// like `software::render_frame` renders one to pixels.
+pub fn render_svg(gui: &mut Gui, width: u32, height: u32) -> String {
+    let mut painter = SvgPainter::new(width, height);
+
+    gui.set_window_size(width as f32, height as f32);
+    gui.paint(&mut painter);
+
+    painter.finish()
+}
+
+
+/// a `Painter` that writes SVG elements.
+///
+/// rects become `<rect>`s, clips `<clipPath>`s & glyph runs
+/// outlined `<path>`s, so the output doesn't depend on installed fonts.
+/// clips are in device space, like `SoftwarePainter`'s.
+pub struct SvgPainter {
+    svg:       String,
+    transform: Transform,
+    clips:     Vec<Rect>, // device space.
+    num_clips: usize,
+}
+
+impl SvgPainter {
+    pub fn new(width: u32, height: u32) -> SvgPainter {
+        let mut svg = String::new();
+        writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#, width, height).unwrap();
+        writeln!(svg, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##).unwrap();
+        SvgPainter { svg, transform: Transform::IDENTITY, clips: vec![], num_clips: 0 }
+    }
+
+    /// closes open clips & the document.
+    pub fn finish(mut self) -> String {
+        while !self.clips.is_empty() {
+            self.pop_clip();
+        }
+        self.svg.push_str("</svg>\n");
+        self.svg
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.clips.len() {
+            self.svg.push_str("  ");
+        }
+    }
+
+    /// the attributes after the geometry: paint & transform.
+    fn attributes(&mut self, paint: &str, color: Color) {
+        write!(self.svg, r##" {}="#{:06x}""##, paint, color.to_hex_rgb()).unwrap();
+        if color.a != 1.0 {
+            write!(self.svg, r#" {}-opacity="{}""#, paint, Rounded(color.a)).unwrap();
+        }
+
+        let t = self.transform;
+        if t.is_translation() {
+            if t.dx != 0.0 || t.dy != 0.0 {
+                write!(self.svg, r#" transform="translate({} {})""#, Rounded(t.dx), Rounded(t.dy)).unwrap();
+            }
+        }
+        else {
+            write!(self.svg, r#" transform="matrix({} {} {} {} {} {})""#,
+                Rounded(t.m11), Rounded(t.m12), Rounded(t.m21), Rounded(t.m22), Rounded(t.dx), Rounded(t.dy)).unwrap();
+        }
+    }
+
+    fn rect(&mut self, rect: Rect) {
+        write!(self.svg, r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+            Rounded(rect.min[0]), Rounded(rect.min[1]),
+            Rounded(rect.width().max(0.0)), Rounded(rect.height().max(0.0))).unwrap();
+    }
+}
+
+impl Painter for SvgPainter {
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        self.indent();
+        self.rect(rect);
+        self.attributes("fill", color);
+        self.svg.push_str("/>\n");
+    }
+
+    fn stroke_rect(&mut self, rect: Rect, color: Color, width: f32) {
+        self.indent();
+        self.rect(rect);
+        write!(self.svg, r#" fill="none" stroke-width="{}""#, Rounded(width)).unwrap();
+        self.attributes("stroke", color);
+        self.svg.push_str("/>\n");
+    }
+
+    fn push_clip(&mut self, rect: Rect) {
+        // bounding box of the transformed rect, like Direct2D.
+        let t = &self.transform;
+        let corners = [
+            t.apply(rect.min), t.apply(rect.max),
+            t.apply([rect.min[0], rect.max[1]]), t.apply([rect.max[0], rect.min[1]]),
+        ];
+        let mut bounds = Rect { min: corners[0], max: corners[0] };
+        for c in &corners[1..] {
+            bounds.min = [bounds.min[0].min(c[0]), bounds.min[1].min(c[1])];
+            bounds.max = [bounds.max[0].max(c[0]), bounds.max[1].max(c[1])];
+        }
+
+        // aliased: snap to pixels, then intersect.
+        let bounds = bounds.round();
+        let clip = match self.clips.last() {
+            Some(clip) => {
+                let min = [bounds.min[0].max(clip.min[0]), bounds.min[1].max(clip.min[1])];
+                let max = [bounds.max[0].min(clip.max[0]), bounds.max[1].min(clip.max[1])];
+                Rect { min, max: [max[0].max(min[0]), max[1].max(min[1])] }
+            }
+            None => bounds,
+        };
+
+        let id = self.num_clips;
+        self.num_clips += 1;
+
+        self.indent();
+        write!(self.svg, r#"<clipPath id="clip{}">"#, id).unwrap();
+        self.rect(clip);
+        self.svg.push_str("/></clipPath>\n");
+
+        self.indent();
+        writeln!(self.svg, r#"<g clip-path="url(#clip{})">"#, id).unwrap();
+        self.clips.push(clip);
+    }
+
+    fn pop_clip(&mut self) {
+        self.clips.pop().unwrap();
+        self.indent();
+        self.svg.push_str("</g>\n");
+    }
+
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    fn glyphs(&mut self, data: &DrawGlyphs, color: Color) {
+        let Some(file) = data.font_face.file.as_ref() else { return };
+
+        // in the painter's user space, the transform is an attribute.
+        let mut sink = PathSink { d: String::new(), transform: Transform::IDENTITY };
+        for (glyph, glyph_tfx) in glyph_transforms(data, file.units_per_em()) {
+            sink.transform = glyph_tfx;
+            file.glyph_outline(glyph, &mut sink);
+        }
+        if sink.d.is_empty() {
+            return;
+        }
+
+        self.indent();
+        write!(self.svg, r#"<path d="{}""#, sink.d.trim_end()).unwrap();
+        self.attributes("fill", color);
+        write!(self.svg, r#" data-text="{}..{}"/>"#, data.text_begin, data.text_end).unwrap();
+        self.svg.push('\n');
+    }
+}
+
+
+struct PathSink {
+    d: String,
+    transform: Transform,
+}
+
+impl OutlineSink for PathSink {
+    fn move_to(&mut self, p: [f32; 2]) {
+        let [x, y] = self.transform.apply(p);
+        write!(self.d, "M{} {} ", Rounded(x), Rounded(y)).unwrap();
+    }
+
+    fn line_to(&mut self, p: [f32; 2]) {
+        let [x, y] = self.transform.apply(p);
+        write!(self.d, "L{} {} ", Rounded(x), Rounded(y)).unwrap();
+    }
+
+    fn quad_to(&mut self, c: [f32; 2], p: [f32; 2]) {
+        let [cx, cy] = self.transform.apply(c);
+        let [x, y]   = self.transform.apply(p);
+        write!(self.d, "Q{} {} {} {} ", Rounded(cx), Rounded(cy), Rounded(x), Rounded(y)).unwrap();
+    }
+
+    fn close(&mut self) {
+        self.d.push_str("Z ");
+    }
+}
@@ -0,0 +1,228 @@
+use core::fmt;
+
+use crate::gui::*;
+
+
+/// a parsed selector, see `IGui::query_selector`.
+///
+/// supports kinds (`div`, `button`, `span`, `text`, `*`), `#id`s, `.class`es,
+/// the child (`>`) & descendant (` `) combinators & lists (`,`).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Selector {
+    alternatives: Vec<Complex>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SelectorError {
+    /// byte offset in the selector.
+    pub offset:  usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid selector at {}: {}", self.offset, self.message)
+    }
+}
+
+
+#[derive(Clone, PartialEq, Debug)]
+struct Complex {
+    compounds: Vec<Compound>,
+    // `combinators[i]` is between `compounds[i]` & `compounds[i + 1]`.
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+struct Compound {
+    kind:    Option<NodeKind>,
+    id:      Option<String>,
+    classes: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Selector, SelectorError> {
+        Parser { input: selector, at: 0 }.selector()
+    }
+
+    /// whether `node` matches.
+    /// ancestors are matched up to the `Gui`'s root.
+    pub fn matches(&self, gui: &Gui, node: Node) -> bool {
+        self.alternatives.iter().any(|complex| {
+            complex.matches(gui, node, complex.compounds.len() - 1)
+        })
+    }
+}
+
+impl Complex {
+    fn matches(&self, gui: &Gui, node: Node, index: usize) -> bool {
+        if !self.compounds[index].matches(gui, node) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+
+        let parent = |node: Node| {
+            if node == gui.root() { None }
+            else { gui.get_parent(node) }
+        };
+
+        match self.combinators[index - 1] {
+            Combinator::Child => {
+                parent(node).is_some_and(|p| self.matches(gui, p, index - 1))
+            }
+
+            Combinator::Descendant => {
+                let mut at = parent(node);
+                while let Some(ancestor) = at {
+                    if self.matches(gui, ancestor, index - 1) {
+                        return true;
+                    }
+                    at = parent(ancestor);
+                }
+                false
+            }
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, gui: &Gui, node: Node) -> bool {
+        let d = node.borrow(gui);
+        self.kind.is_none_or(|kind| d.kind == kind)
+        && self.id.as_ref().is_none_or(|id| d.id.as_ref() == Some(id))
+        && self.classes.iter().all(|class| d.classes.contains(class))
+    }
+}
+
+
+
+// PARSING
+
+struct Parser<'a> {
+    input: &'a str,
+    at:    usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &'static str) -> Result<T, SelectorError> {
+        Err(SelectorError { offset: self.at, message })
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.input[self.at..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let begin = self.at;
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() { break }
+            self.at += c.len_utf8();
+        }
+        self.at > begin
+    }
+
+    fn ident(&mut self) -> Result<&'a str, SelectorError> {
+        let begin = self.at;
+        while let Some(c) = self.peek() {
+            if !(c.is_alphanumeric() || c == '_' || c == '-') { break }
+            self.at += c.len_utf8();
+        }
+        if self.at == begin {
+            return self.error("expected a name");
+        }
+        Ok(&self.input[begin..self.at])
+    }
+
+    fn selector(mut self) -> Result<Selector, SelectorError> {
+        let mut alternatives = vec![];
+        loop {
+            self.skip_whitespace();
+            alternatives.push(self.complex()?);
+
+            match self.peek() {
+                Some(',') => { self.at += 1; }
+                None      => break,
+                Some(_)   => return self.error("unexpected character"),
+            }
+        }
+        Ok(Selector { alternatives })
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        let mut compounds   = vec![self.compound()?];
+        let mut combinators = vec![];
+        loop {
+            let had_space = self.skip_whitespace();
+
+            let combinator = match self.peek() {
+                Some('>') => {
+                    self.at += 1;
+                    self.skip_whitespace();
+                    Combinator::Child
+                }
+                Some(',') | None => break,
+                Some(_) if had_space => Combinator::Descendant,
+                Some(_) => return self.error("unexpected character"),
+            };
+
+            combinators.push(combinator);
+            compounds.push(self.compound()?);
+        }
+        Ok(Complex { compounds, combinators })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let begin = self.at;
+        let mut result = Compound::default();
+
+        match self.peek() {
+            Some('*') => { self.at += 1; }
+            Some(c) if c.is_alphabetic() => {
+                let name_at = self.at;
+                result.kind = Some(match self.ident()? {
+                    "div"    => NodeKind::Div,
+                    "button" => NodeKind::Button,
+                    "span"   => NodeKind::Span,
+                    "text"   => NodeKind::Text,
+                    _ => {
+                        self.at = name_at;
+                        return self.error("unknown node kind");
+                    }
+                });
+            }
+            _ => (),
+        }
+
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.at += 1;
+                    if result.id.is_some() {
+                        return self.error("multiple ids");
+                    }
+                    result.id = Some(self.ident()?.into());
+                }
+                Some('.') => {
+                    self.at += 1;
+                    result.classes.push(self.ident()?.into());
+                }
+                _ => break,
+            }
+        }
+
+        if self.at == begin {
+            return self.error("expected a selector");
+        }
+        Ok(result)
+    }
+}
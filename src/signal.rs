@@ -0,0 +1,92 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+
+
+/// an observable value.
+/// cheap to clone, clones refer to the same value.
+///
+/// nodes subscribe with `Gui::bind_text` & `Gui::bind_style`,
+/// derived values with `Signal::map`.
+pub struct Signal<T> (Rc<SignalData<T>>);
+
+struct SignalData<T> {
+    value: RefCell<T>,
+    subscribers: RefCell<Vec<Subscriber<T>>>,
+}
+
+// returns false to unsubscribe.
+type Subscriber<T> = Box<dyn FnMut(&T) -> bool>;
+
+impl<T> Clone for Signal<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Signal(self.0.clone())
+    }
+}
+
+impl<T: 'static> Signal<T> {
+    pub fn new(value: T) -> Signal<T> {
+        Signal(Rc::new(SignalData {
+            value: RefCell::new(value),
+            subscribers: RefCell::new(vec![]),
+        }))
+    }
+
+    #[inline]
+    pub fn get(&self) -> T where T: Clone {
+        self.0.value.borrow().clone()
+    }
+
+    #[inline]
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        f(&self.0.value.borrow())
+    }
+
+    pub fn set(&self, value: T) {
+        *self.0.value.borrow_mut() = value;
+        self.notify();
+    }
+
+    pub fn update<F: FnOnce(&mut T)>(&self, f: F) {
+        f(&mut self.0.value.borrow_mut());
+        self.notify();
+    }
+
+    /// calls `f` after every change, until it returns false.
+    /// `f` must not set this signal.
+    pub fn subscribe<F: FnMut(&T) -> bool + 'static>(&self, f: F) {
+        self.0.subscribers.borrow_mut().push(Box::new(f));
+    }
+
+    /// a signal, that's recomputed from this one on every change.
+    /// it stops updating, when all its clones are dropped.
+    pub fn map<U: 'static, F: Fn(&T) -> U + 'static>(&self, f: F) -> Signal<U> {
+        let derived = Signal::new(self.with(&f));
+        let weak = Rc::downgrade(&derived.0);
+        self.subscribe(move |value| {
+            let Some(derived) = weak.upgrade() else { return false };
+            Signal(derived).set(f(value));
+            true
+        });
+        derived
+    }
+
+    fn notify(&self) {
+        let mut subscribers = core::mem::take(&mut *self.0.subscribers.borrow_mut());
+
+        let value = self.0.value.borrow();
+        subscribers.retain_mut(|subscriber| subscriber(&value));
+        drop(value);
+
+        // keep the ones added while notifying.
+        let mut current = self.0.subscribers.borrow_mut();
+        subscribers.append(&mut current);
+        *current = subscribers;
+    }
+}
+
+impl<T: Default + 'static> Default for Signal<T> {
+    fn default() -> Self {
+        Signal::new(T::default())
+    }
+}
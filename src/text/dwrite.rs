@@ -0,0 +1,492 @@
+// DirectWrite text analysis & shaping.
+
+use core::cell::RefCell;
+
+use crate::win::*;
+use crate::ctx::*;
+use crate::unicode::*;
+use crate::unicode::bidi::{self, Level};
+use crate::text::{TextFormat, TextSpan, PreSpan, TextAnalysis, FontFace, analyze_breaks, analyze_bidi};
+
+
+pub(crate) fn analyze(ctx: &Ctx, text: &[u8], pre_spans: &[PreSpan]) -> TextAnalysis {unsafe {
+    let (text16, utf16_to_utf8) = {
+        let mut utf16 = vec![];
+        let mut map   = vec![];
+
+        let bytes = text;
+        let mut cursor = 0;
+        while let Some((cp, new_cursor)) = utf8_next_code_point(bytes, cursor) {
+            let mut buffer = [0; 2];
+            let is_double = utf16_encode(cp, &mut buffer);
+
+            utf16.push(buffer[0]);
+            map.push(cursor as u32);
+
+            if is_double {
+                utf16.push(buffer[1]);
+                map.push(cursor as u32);
+            }
+
+            cursor = new_cursor;
+        }
+        assert_eq!(cursor, bytes.len());
+        map.push(bytes.len() as u32);
+
+        (utf16, map)
+    };
+
+    let source: IDWriteTextAnalysisSource = DwSource {
+        string: text16.as_slice(),
+        locale: w!("en-us").as_ptr(), // TEMP.
+    }.into();
+
+    let dw_spans = RefCell::new(DwSinkSpans {
+        begin: 0,
+        levels: vec![],
+        scripts: vec![],
+    });
+
+    let sink: IDWriteTextAnalysisSink = DwSink {
+        spans: &dw_spans,
+    }.into();
+
+
+    let analyzer = ctx.dw_factory.CreateTextAnalyzer().unwrap();
+
+    // not using `AnalyzeLineBreakpoints`, so line breaking doesn't depend
+    // on the windows version.
+    let mut breaks = analyze_breaks(text);
+
+    let utf8_to_utf16 = {
+        let mut map = vec![0; text.len() + 1];
+        for (i16, i8) in utf16_to_utf8.iter().enumerate().rev() {
+            map[*i8 as usize] = i16 as u32;
+        }
+        map
+    };
+
+    let mut pspan_index = 0;
+    let mut pspan = pre_spans[0];
+
+    let mut hard_lines = vec![];
+    let mut text_spans = vec![];
+    for (begin, end) in breaks.lines.iter() {
+        let line_begin = utf8_to_utf16[*begin as usize];
+        let line_end   = utf8_to_utf16[*end   as usize];
+        let line_len   = line_end - line_begin;
+
+        // empty line
+        if line_len == 0 {
+            let text_begin_utf8 = utf16_to_utf8[line_begin as usize];
+            let text_end_utf8   = text_begin_utf8;
+
+            let pos_utf8 = text_begin_utf8;
+            while pos_utf8 >= pspan.text_end_utf8 && pspan_index + 1 < pre_spans.len() {
+                pspan_index += 1;
+                pspan = pre_spans[pspan_index];
+            }
+
+            let format = pspan.format;
+
+            let face = ctx.fonts.borrow_mut().face(format.font, format.font_weight, format.italic);
+            let (ascent, drop) = match face {
+                Some(face) => {
+                    let m = &face.metrics;
+                    let scale = format.font_size / m.units_per_em as f32;
+                    (scale * m.ascent as f32, scale * (m.descent as f32 + m.line_gap as f32))
+                }
+                None => (0.8 * format.font_size, 0.2 * format.font_size),
+            };
+
+            text_spans.push(TextSpan {
+                text_begin_utf8, text_end_utf8,
+                object_index: u32::MAX,
+                ascent, drop,
+                format,
+                .. Default::default()
+            });
+
+            hard_lines.push(text_spans.len() as u32);
+            continue;
+        }
+
+        // reset spans sink.
+        let mut spans = dw_spans.borrow_mut();
+        spans.begin = line_begin;
+        spans.scripts.clear();
+        spans.scripts.resize(line_len as usize, Default::default());
+
+        // not using `AnalyzeBidi`, see `analyze_bidi`.
+        let line = core::str::from_utf8(&text[*begin as usize .. *end as usize]).unwrap();
+        let levels = analyze_bidi(line);
+        spans.levels.clear();
+        for pos in line_begin..line_end {
+            let at = utf16_to_utf8[pos as usize] - begin;
+            spans.levels.push(levels[at as usize]);
+        }
+        drop(spans);
+
+        // compute spans.
+        analyzer.AnalyzeScript(&source, line_begin, line_len, &sink).unwrap();
+
+        #[derive(Default)]
+        struct RawSpan {
+            text_begin_utf16: u32,
+            text_end_utf16:   u32,
+
+            object_index: u32,
+
+            format: TextFormat,
+            bidi_level: Level,
+            script: DWRITE_SCRIPT_ANALYSIS,
+        }
+
+        let raw_spans = {
+            let spans = dw_spans.borrow();
+
+            let mut result = vec![];
+            let mut span = RawSpan::default();
+
+            for pos in line_begin..line_end {
+                let i = (pos - line_begin) as usize;
+
+                let new_format = {
+                    let mut new_format = false;
+                    let pos_utf8 = utf16_to_utf8[pos as usize];
+                    while pos_utf8 >= pspan.text_end_utf8 {
+                        pspan_index += 1;
+                        pspan = pre_spans[pspan_index];
+                        new_format = true;
+                    }
+                    new_format
+                };
+
+                let bidi_level = spans.levels[i];
+                let script = spans.scripts[i];
+
+                if bidi_level != span.bidi_level
+                || script.script != span.script.script
+                || script.shapes != span.script.shapes
+                || new_format
+                || i == 0 {
+                    if span.text_begin_utf16 != span.text_end_utf16 {
+                        result.push(span);
+                    }
+                    span = RawSpan {
+                        text_begin_utf16: pos,
+                        text_end_utf16:   pos + 1,
+                        object_index: pspan.object_index,
+                        format: pspan.format,
+                        bidi_level,
+                        script,
+                    };
+                }
+                else {
+                    span.text_end_utf16 = pos + 1;
+                }
+            }
+            if span.text_begin_utf16 != span.text_end_utf16 {
+                result.push(span);
+            }
+
+            result
+        };
+
+        for raw_span in &raw_spans {
+            // inline object.
+            if raw_span.object_index != u32::MAX {
+                let text_begin_utf8 = utf16_to_utf8[raw_span.text_begin_utf16 as usize];
+                let text_end_utf8   = utf16_to_utf8[raw_span.text_end_utf16 as usize];
+
+                // add break option before & after.
+                // TODO: maybe don't add one after if next char is whitespace?
+                breaks.options[text_begin_utf8 as usize / 32] |= 1 << (text_begin_utf8 % 32);
+                breaks.options[text_end_utf8   as usize / 32] |= 1 << (text_end_utf8   % 32);
+
+                text_spans.push(TextSpan {
+                    text_begin_utf8, text_end_utf8,
+                    object_index: raw_span.object_index,
+                    bidi_level: raw_span.bidi_level,
+                    .. Default::default()
+                });
+
+                continue;
+            }
+
+            let format = raw_span.format;
+            let bidi_level = raw_span.bidi_level;
+            let is_rtl = bidi::is_rtl(bidi_level);
+            let script = raw_span.script;
+
+            let font: Vec<u16> = ctx.fonts.borrow().font_name_utf16(format.font).into();
+
+            let font_weight = DWRITE_FONT_WEIGHT(format.font_weight as i32);
+            let font_style =
+                if format.italic { DWRITE_FONT_STYLE_ITALIC }
+                else             { DWRITE_FONT_STYLE_NORMAL };
+
+            // the format's family & fallback chains.
+            // registered fonts aren't in the system collection.
+            // text they can't render goes to the system fallback.
+            let runs: Vec<(u32, Option<FontFace>)> = {
+                let begin = utf16_to_utf8[raw_span.text_begin_utf16 as usize] as usize;
+                let end   = utf16_to_utf8[raw_span.text_end_utf16   as usize] as usize;
+                let string = core::str::from_utf8(&text[begin..end]).unwrap();
+
+                ctx.fonts.borrow_mut().fallback_runs(string, &format).into_iter()
+                    .map(|(run_end, face)| (utf8_to_utf16[begin + run_end], face.filter(|face| face.dw_face.is_some())))
+                    .collect()
+            };
+
+            let mut run_index = 0;
+            let mut text_cursor = raw_span.text_begin_utf16;
+            while text_cursor < raw_span.text_end_utf16 {
+                while runs[run_index].0 <= text_cursor {
+                    run_index += 1;
+                }
+                let run_end  = runs[run_index].0;
+                let run_face = runs[run_index].1.clone();
+
+                let mut mapped_len = 0;
+                let mut mapped_face = None;
+                if let Some(face) = &run_face {
+                    mapped_len  = run_end - text_cursor;
+                    mapped_face = face.dw_face.clone();
+                }
+                else {
+                    let mut mapped_font = None;
+                    let mut scale = 0.0; // TODO: use this?
+                    ctx.dw_system_fallback.MapCharacters(
+                        &source,
+                        text_cursor, run_end - text_cursor,
+                        &ctx.dw_system_fonts,
+                        PCWSTR(font.as_ptr()),
+                        font_weight,
+                        font_style,
+                        DWRITE_FONT_STRETCH_NORMAL,
+                        &mut mapped_len,
+                        Some(&mut mapped_font),
+                        &mut scale).unwrap();
+
+                    if let Some(font) = mapped_font {
+                        mapped_face = Some(font.CreateFontFace().unwrap());
+                    }
+                }
+                assert!(mapped_len > 0);
+
+                let cov_begin = text_cursor as usize;
+                let cov_end   = cov_begin + mapped_len as usize;
+                text_cursor += mapped_len;
+
+                let text_begin_utf8 = utf16_to_utf8[cov_begin];
+                let text_end_utf8   = utf16_to_utf8[cov_end];
+                let text_utf8_len = text_end_utf8 - text_begin_utf8;
+
+
+                let Some(face) = mapped_face else { continue };
+
+
+                let string = &text16[cov_begin .. cov_end];
+
+                let mut cluster_map  = vec![0; string.len()];
+                let mut text_props = vec![Default::default(); string.len()];
+
+                let max_len = 3 * string.len() / 2 + 16;
+
+                let mut glyph_indices = vec![0; max_len];
+                let mut glyph_props   = vec![Default::default(); max_len];
+
+                // TODO: loop.
+                let mut glyph_count = 0;
+                analyzer.GetGlyphs(
+                    PCWSTR(string.as_ptr()),
+                    string.len() as u32,
+                    &face,
+                    false, is_rtl, &script,
+                    w!("en-us"), // TEMP
+                    None, None, None, 0,
+                    max_len as u32,
+                    cluster_map.as_mut_ptr(),
+                    text_props.as_mut_ptr(),
+                    glyph_indices.as_mut_ptr(),
+                    glyph_props.as_mut_ptr(),
+                    &mut glyph_count).unwrap();
+
+                glyph_indices.truncate(glyph_count as usize);
+                glyph_props.truncate(glyph_count as usize);
+
+                let mut glyph_advances = vec![0.0; glyph_count as usize];
+                let mut glyph_offsets  = vec![Default::default(); glyph_count as usize];
+                assert_eq!(core::mem::size_of::<DWRITE_GLYPH_OFFSET>(),
+                           core::mem::size_of::<[f32; 2]>());
+
+                analyzer.GetGlyphPlacements(
+                    PCWSTR(string.as_ptr()),
+                    cluster_map.as_ptr(),
+                    text_props.as_mut_ptr(),
+                    string.len() as u32,
+                    glyph_indices.as_ptr(),
+                    glyph_props.as_ptr(),
+                    glyph_count,
+                    &face,
+                    format.font_size,
+                    false, is_rtl, &script,
+                    w!("en-us"),
+                    None, None, 0,
+                    glyph_advances.as_mut_ptr(),
+                    glyph_offsets.as_mut_ptr() as *mut DWRITE_GLYPH_OFFSET,
+                ).unwrap();
+
+
+                let mut width = 0.0;
+                for dx in &glyph_advances {
+                    width += dx;
+                }
+
+                let mut font_metrics = Default::default();
+                face.GetMetrics(&mut font_metrics);
+
+                let font_scale = format.font_size / font_metrics.designUnitsPerEm as f32;
+                let ascent = font_scale * font_metrics.ascent as f32;
+                let drop   = font_scale * (font_metrics.descent as f32 + font_metrics.lineGap as f32);
+
+                // convert utf16 glyph map to utf8.
+                // replace 1-2 entries with 1-4 entries.
+                let cluster_map = {
+                    let mut map = Vec::with_capacity(text_utf8_len as usize);
+
+                    let mut cursor = 0;
+                    while cursor < cluster_map.len() {
+                        let at16 = cov_begin + cursor;
+                        let at8  = utf16_to_utf8[at16] as usize;
+
+                        let cp = utf8_next_code_point(text, at8).unwrap_unchecked().0;
+
+                        let entry = cluster_map[cursor];
+                        for _ in 0..utf8_len(cp) {
+                            map.push(entry);
+                        }
+
+                        cursor += utf16_len(cp);
+                    }
+                    assert_eq!(map.len(), text_utf8_len as usize);
+                    map.push(glyph_indices.len() as u16);
+
+                    map
+                };
+
+                let font_face = match &run_face {
+                    Some(face) => face.clone(),
+                    None       => ctx.fonts.borrow_mut().face_from_dw(&face),
+                };
+
+                text_spans.push(TextSpan {
+                    text_begin_utf8, text_end_utf8,
+                    object_index: u32::MAX,
+                    bidi_level, script,
+                    format,
+                    font_face: Some(font_face),
+                    width, ascent, drop,
+                    cluster_map,
+                    glyph_indices,
+                    glyph_props,
+                    glyph_advances,
+                    glyph_offsets,
+                });
+            }
+        }
+
+        hard_lines.push(text_spans.len() as u32);
+    }
+
+    let break_options = core::mem::take(&mut breaks.options);
+
+    TextAnalysis {
+        spans: text_spans,
+        hard_lines,
+        break_options,
+    }
+}}
+
+
+#[windows::core::implement(IDWriteTextAnalysisSource)]
+struct DwSource {
+    string: *const [u16],
+    locale: *const u16,
+}
+
+impl IDWriteTextAnalysisSource_Impl for DwSource {
+    fn GetLocaleName(&self, _pos: u32, _len: *mut u32, locale: *mut *mut u16) -> windows::core::Result<()> {unsafe{
+        *locale = self.locale as *mut _;
+        Ok(())
+    }}
+
+    fn GetNumberSubstitution(&self, _pos: u32, _len: *mut u32, subst: *mut Option<IDWriteNumberSubstitution>) -> windows::core::Result<()> {unsafe{
+        *subst = None;
+        Ok(())
+    }}
+
+    fn GetParagraphReadingDirection(&self) -> DWRITE_READING_DIRECTION {
+        DWRITE_READING_DIRECTION_LEFT_TO_RIGHT
+    }
+
+    fn GetTextAtPosition(&self, pos: u32, text: *mut *mut u16, len: *mut u32) -> windows::core::Result<()> {unsafe{
+        let string = &*self.string;
+        let sub = &string[pos as usize ..];
+        *text = sub.as_ptr() as *mut _;
+        *len  = sub.len() as u32;
+        Ok(())
+    }}
+
+    fn GetTextBeforePosition(&self, pos: u32, text: *mut *mut u16, len: *mut u32) -> windows::core::Result<()> {unsafe{
+        let string = &*self.string;
+        let sub = &string[.. pos as usize];
+        *text = sub.as_ptr() as *mut _;
+        *len  = sub.len() as u32;
+        Ok(())
+    }}
+}
+
+
+#[windows::core::implement(IDWriteTextAnalysisSink)]
+struct DwSink {
+    spans: *const RefCell<DwSinkSpans>,
+}
+
+struct DwSinkSpans {
+    begin: u32,
+    levels:  Vec<Level>,
+    scripts: Vec<DWRITE_SCRIPT_ANALYSIS>,
+}
+
+impl DwSinkSpans {
+    fn set_script(&mut self, pos: u32, len: u32, script: DWRITE_SCRIPT_ANALYSIS) {
+        let begin = (pos - self.begin) as usize;
+        self.scripts[begin .. begin + len as usize].fill(script);
+    }
+}
+
+impl IDWriteTextAnalysisSink_Impl for DwSink {
+    fn SetLineBreakpoints(&self, _pos: u32, _len: u32, _breaks: *const DWRITE_LINE_BREAKPOINT) -> windows::core::Result<()> {
+        // see `analyze_breaks`.
+        Ok(())
+    }
+
+
+    fn SetBidiLevel(&self, _pos: u32, _len: u32, _explicit_level: u8, _resolved_level: u8) -> windows::core::Result<()> {
+        // see `analyze_bidi`.
+        Ok(())
+    }
+
+    fn SetScriptAnalysis(&self, pos: u32, len: u32, script: *const DWRITE_SCRIPT_ANALYSIS) -> windows::core::Result<()> {
+        let mut this = unsafe { (*self.spans).borrow_mut() };
+        this.set_script(pos, len, unsafe { *script });
+        Ok(())
+    }
+
+
+    fn SetNumberSubstitution(&self, _pos: u32, _len: u32, _subst: &Option<IDWriteNumberSubstitution>) -> windows::core::Result<()> {
+        Err(windows::Win32::Foundation::E_NOTIMPL.into())
+    }
+}
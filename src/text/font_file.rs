@@ -0,0 +1,667 @@
+// minimal OpenType table reader.
+// only reads what the crate needs, returns `None` on malformed data.
+
+
+pub type Tag = [u8; 4];
+
+
+#[inline]
+pub(crate) fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset .. offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+#[inline]
+pub(crate) fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|v| v as i16)
+}
+
+#[inline]
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset .. offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[inline]
+pub(crate) fn read_f2dot14(data: &[u8], offset: usize) -> Option<f32> {
+    read_i16(data, offset).map(|v| v as f32 / 16384.0)
+}
+
+
+#[derive(Clone, Copy, Debug)]
+struct TableRecord {
+    tag:    Tag,
+    offset: u32,
+    len:    u32,
+}
+
+pub struct FontFile {
+    data:   Vec<u8>,
+    tables: Vec<TableRecord>,
+
+    units_per_em: u16,
+    num_glyphs:   u16,
+    long_loca:    bool,
+    num_h_metrics: u16,
+
+    // the preferred unicode subtable.
+    cmap: Option<CmapSubtable>,
+}
+
+impl FontFile {
+    /// parses a TrueType/OpenType file or collection.
+    /// `face_index` selects the face in a collection (`.ttc`).
+    pub fn parse(data: Vec<u8>, face_index: u32) -> Option<FontFile> {
+        let mut dir = 0;
+        if data.get(0..4)? == b"ttcf" {
+            let num_fonts = read_u32(&data, 8)?;
+            if face_index >= num_fonts {
+                return None;
+            }
+            dir = read_u32(&data, 12 + 4*face_index as usize)? as usize;
+        }
+
+        let num_tables = read_u16(&data, dir + 4)? as usize;
+
+        let mut tables = Vec::with_capacity(num_tables);
+        for i in 0..num_tables {
+            let record = dir + 12 + 16*i;
+            let tag    = data.get(record .. record + 4)?;
+            let offset = read_u32(&data, record +  8)?;
+            let len    = read_u32(&data, record + 12)?;
+
+            if offset as usize + len as usize > data.len() {
+                return None;
+            }
+
+            tables.push(TableRecord { tag: [tag[0], tag[1], tag[2], tag[3]], offset, len });
+        }
+
+        Self::new(data, tables)
+    }
+
+    /// builds a font from individual tables.
+    /// used for fonts whose file isn't directly accessible.
+    pub fn from_tables<'a, I: IntoIterator<Item=(Tag, &'a [u8])>>(tables: I) -> Option<FontFile> {
+        let mut data = vec![];
+        let mut records = vec![];
+        for (tag, table) in tables {
+            // keep tables 4 byte aligned.
+            while data.len() % 4 != 0 {
+                data.push(0);
+            }
+
+            records.push(TableRecord {
+                tag,
+                offset: data.len() as u32,
+                len:    table.len() as u32,
+            });
+            data.extend_from_slice(table);
+        }
+
+        Self::new(data, records)
+    }
+
+    fn new(data: Vec<u8>, tables: Vec<TableRecord>) -> Option<FontFile> {
+        let mut result = FontFile {
+            data, tables,
+            units_per_em: 0,
+            num_glyphs:   0,
+            long_loca:    false,
+            num_h_metrics: 0,
+            cmap: None,
+        };
+
+        let head = result.table(*b"head")?;
+        let units_per_em = read_u16(head, 18)?;
+        let long_loca    = read_i16(head, 50)? != 0;
+
+        let maxp = result.table(*b"maxp")?;
+        let num_glyphs = read_u16(maxp, 4)?;
+
+        if units_per_em == 0 {
+            return None;
+        }
+
+        let num_h_metrics = result.table(*b"hhea").and_then(|hhea| read_u16(hhea, 34)).unwrap_or(0);
+        let cmap = result.find_cmap_subtable();
+
+        result.units_per_em  = units_per_em;
+        result.num_glyphs    = num_glyphs;
+        result.long_loca     = long_loca;
+        result.num_h_metrics = num_h_metrics;
+        result.cmap          = cmap;
+        Some(result)
+    }
+
+
+    pub fn table(&self, tag: Tag) -> Option<&[u8]> {
+        let record = self.tables.iter().find(|t| t.tag == tag)?;
+        let begin = record.offset as usize;
+        let end   = begin + record.len as usize;
+        self.data.get(begin..end)
+    }
+
+    #[inline]
+    pub fn units_per_em(&self) -> u16 {
+        self.units_per_em
+    }
+
+    #[inline]
+    pub fn num_glyphs(&self) -> u16 {
+        self.num_glyphs
+    }
+}
+
+
+
+// CHARACTER MAP
+
+#[derive(Clone, Copy, Debug)]
+struct CmapSubtable {
+    offset: u32, // relative to the cmap table.
+    format: u16,
+    symbol: bool,
+}
+
+impl FontFile {
+    fn find_cmap_subtable(&self) -> Option<CmapSubtable> {
+        let cmap = self.table(*b"cmap")?;
+        let num_tables = read_u16(cmap, 2)? as usize;
+
+        // (rank, subtable), lower rank is better.
+        let mut best: Option<(u32, CmapSubtable)> = None;
+        for i in 0..num_tables {
+            let record   = 4 + 8*i;
+            let platform = read_u16(cmap, record)?;
+            let encoding = read_u16(cmap, record + 2)?;
+            let offset   = read_u32(cmap, record + 4)?;
+            let format   = read_u16(cmap, offset as usize)?;
+
+            let rank = match (platform, encoding, format) {
+                (3, 10, 12) | (0, 4, 12) | (0, 6, 12) => 0,
+                (3,  1,  4) | (0, 3,  4)              => 1,
+                (0,  _,  4)                           => 2,
+                (3,  0,  4)                           => 3,
+                _ => continue,
+            };
+
+            if best.map(|(r, _)| rank < r).unwrap_or(true) {
+                let symbol = platform == 3 && encoding == 0;
+                best = Some((rank, CmapSubtable { offset, format, symbol }));
+            }
+        }
+
+        best.map(|(_, subtable)| subtable)
+    }
+
+    /// maps a code point to a glyph.
+    /// returns `None`, if the font has no glyph for `cp`.
+    pub fn glyph_index(&self, cp: u32) -> Option<u16> {
+        let subtable = self.cmap?;
+        let data = self.table(*b"cmap")?.get(subtable.offset as usize ..)?;
+
+        let glyph = match subtable.format {
+            4 => {
+                // symbol fonts map their glyphs into the private use area.
+                let cp = if subtable.symbol && cp < 0x100 { cp | 0xf000 } else { cp };
+                if cp > 0xffff {
+                    return None;
+                }
+                let cp = cp as u16;
+
+                let seg_count = read_u16(data, 6)? as usize / 2;
+                let end_codes   = 14;
+                let start_codes = end_codes + 2*seg_count + 2;
+                let deltas      = start_codes + 2*seg_count;
+                let offsets     = deltas + 2*seg_count;
+
+                // binary search for the first segment with end >= cp.
+                let (mut lo, mut hi) = (0, seg_count);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    if read_u16(data, end_codes + 2*mid)? < cp { lo = mid + 1 }
+                    else                                       { hi = mid }
+                }
+                if lo == seg_count {
+                    return None;
+                }
+
+                let start = read_u16(data, start_codes + 2*lo)?;
+                if cp < start {
+                    return None;
+                }
+
+                let delta  = read_u16(data, deltas  + 2*lo)?;
+                let offset = read_u16(data, offsets + 2*lo)? as usize;
+                if offset == 0 {
+                    cp.wrapping_add(delta)
+                }
+                else {
+                    let at = offsets + 2*lo + offset + 2*(cp - start) as usize;
+                    match read_u16(data, at)? {
+                        0 => 0,
+                        glyph => glyph.wrapping_add(delta),
+                    }
+                }
+            }
+
+            12 => {
+                let num_groups = read_u32(data, 12)? as usize;
+
+                let (mut lo, mut hi) = (0, num_groups);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    let group = 16 + 12*mid;
+                    let start = read_u32(data, group)?;
+                    let end   = read_u32(data, group + 4)?;
+                    if cp < start {
+                        hi = mid;
+                    }
+                    else if cp > end {
+                        lo = mid + 1;
+                    }
+                    else {
+                        let glyph = read_u32(data, group + 8)? + (cp - start);
+                        return (glyph != 0 && glyph < self.num_glyphs as u32).then_some(glyph as u16);
+                    }
+                }
+                return None;
+            }
+
+            _ => return None,
+        };
+
+        (glyph != 0 && glyph < self.num_glyphs).then_some(glyph)
+    }
+}
+
+
+
+// METRICS
+
+/// in font design units.
+/// positions are relative to the baseline, positive is up.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FontMetrics {
+    pub units_per_em: u16,
+    pub ascent:   i16,
+    pub descent:  i16, // positive.
+    pub line_gap: i16,
+    pub underline_position:      i16,
+    pub underline_thickness:     i16,
+    pub strikethrough_position:  i16,
+    pub strikethrough_thickness: i16,
+}
+
+impl FontFile {
+    /// horizontal advance in font units.
+    pub fn glyph_advance(&self, glyph: u16) -> u16 {
+        let Some(hmtx) = self.table(*b"hmtx") else { return 0 };
+        if self.num_h_metrics == 0 {
+            return 0;
+        }
+
+        // glyphs after the last long metric share its advance.
+        let i = glyph.min(self.num_h_metrics - 1) as usize;
+        read_u16(hmtx, 4*i).unwrap_or(0)
+    }
+
+    pub fn metrics(&self) -> FontMetrics {
+        let upem = self.units_per_em as i16;
+        let hhea = self.table(*b"hhea").unwrap_or(&[]);
+        let os2  = self.table(*b"OS/2").unwrap_or(&[]);
+        let post = self.table(*b"post").unwrap_or(&[]);
+
+        const USE_TYPO_METRICS: u16 = 1 << 7;
+        let use_typo = read_u16(os2, 62).map(|sel| sel & USE_TYPO_METRICS != 0).unwrap_or(false);
+
+        let hhea_ascent = read_i16(hhea, 4).unwrap_or(0);
+
+        let (ascent, descent, line_gap) =
+            if use_typo {
+                (read_i16(os2, 68).unwrap_or(0), -read_i16(os2, 70).unwrap_or(0), read_i16(os2, 72).unwrap_or(0))
+            }
+            else if hhea_ascent != 0 {
+                (hhea_ascent, -read_i16(hhea, 6).unwrap_or(0), read_i16(hhea, 8).unwrap_or(0))
+            }
+            else {
+                (read_u16(os2, 74).unwrap_or(0) as i16, read_u16(os2, 76).unwrap_or(0) as i16, 0)
+            };
+
+        let (ascent, descent) =
+            if ascent == 0 && descent == 0 { (upem * 4/5, upem / 5) }
+            else                           { (ascent, descent) };
+
+        FontMetrics {
+            units_per_em: self.units_per_em,
+            ascent, descent, line_gap,
+            underline_position:      read_i16(post, 8) .unwrap_or(-upem / 10),
+            underline_thickness:     read_i16(post, 10).unwrap_or( upem / 20),
+            strikethrough_position:  read_i16(os2, 28) .unwrap_or( upem / 4),
+            strikethrough_thickness: read_i16(os2, 26) .unwrap_or( upem / 20),
+        }
+    }
+}
+
+
+
+// NAMES
+
+impl FontFile {
+    /// the typographic family name, if present, else the legacy family name.
+    pub fn family_name(&self) -> Option<String> {
+        self.name(16).or_else(|| self.name(1))
+    }
+
+    /// reads an english name from the `name` table.
+    pub fn name(&self, name_id: u16) -> Option<String> {
+        let name = self.table(*b"name")?;
+        let count   = read_u16(name, 2)? as usize;
+        let storage = read_u16(name, 4)? as usize;
+
+        let mut fallback = None;
+        for i in 0..count {
+            let record   = 6 + 12*i;
+            let platform = read_u16(name, record)?;
+            let encoding = read_u16(name, record + 2)?;
+            let language = read_u16(name, record + 4)?;
+            let id       = read_u16(name, record + 6)?;
+            let len      = read_u16(name, record + 8)? as usize;
+            let offset   = read_u16(name, record + 10)? as usize;
+            if id != name_id {
+                continue;
+            }
+
+            let bytes = name.get(storage + offset .. storage + offset + len)?;
+            match (platform, encoding) {
+                // utf-16 be.
+                (0, _) | (3, 0) | (3, 1) | (3, 10) => {
+                    let utf16 = bytes.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]]));
+                    let string: String = char::decode_utf16(utf16).map(|c| c.unwrap_or('\u{fffd}')).collect();
+                    if platform == 0 || language == 0x409 {
+                        return Some(string);
+                    }
+                    fallback.get_or_insert(string);
+                }
+
+                // mac roman, ascii is good enough.
+                (1, 0) => {
+                    let string = bytes.iter().map(|b| if b.is_ascii() { *b as char } else { '?' }).collect();
+                    fallback.get_or_insert(string);
+                }
+
+                _ => (),
+            }
+        }
+
+        fallback
+    }
+
+    /// `usWeightClass` from the `OS/2` table, 400 if missing.
+    pub fn weight(&self) -> u16 {
+        self.table(*b"OS/2").and_then(|os2| read_u16(os2, 4)).unwrap_or(400)
+    }
+
+    pub fn is_italic(&self) -> bool {
+        const ITALIC:  u16 = 1 << 0;
+        const OBLIQUE: u16 = 1 << 9;
+        if let Some(selection) = self.table(*b"OS/2").and_then(|os2| read_u16(os2, 62)) {
+            return selection & (ITALIC | OBLIQUE) != 0;
+        }
+
+        // macStyle.
+        self.table(*b"head").and_then(|head| read_u16(head, 44)).map(|style| style & 2 != 0).unwrap_or(false)
+    }
+}
+
+
+
+// OUTLINES
+
+/// receives glyph outlines in font units (y up).
+pub trait OutlineSink {
+    fn move_to(&mut self, p: [f32; 2]);
+    fn line_to(&mut self, p: [f32; 2]);
+    fn quad_to(&mut self, c: [f32; 2], p: [f32; 2]);
+    fn close(&mut self);
+}
+
+impl FontFile {
+    fn glyph_data(&self, glyph: u16) -> Option<&[u8]> {
+        if glyph >= self.num_glyphs {
+            return None;
+        }
+
+        let loca = self.table(*b"loca")?;
+        let glyf = self.table(*b"glyf")?;
+
+        let i = glyph as usize;
+        let (begin, end) =
+            if self.long_loca {
+                (read_u32(loca, 4*i)? as usize, read_u32(loca, 4*i + 4)? as usize)
+            }
+            else {
+                (2*read_u16(loca, 2*i)? as usize, 2*read_u16(loca, 2*i + 2)? as usize)
+            };
+
+        glyf.get(begin..end)
+    }
+
+    /// emits the outline of `glyph`.
+    /// returns false if the glyph has no (supported) outline.
+    /// only `glyf` outlines are supported for now.
+    pub fn glyph_outline<S: OutlineSink>(&self, glyph: u16, sink: &mut S) -> bool {
+        let identity = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+        self.glyph_outline_ex(glyph, identity, 0, sink).is_some()
+    }
+
+    // `tfx` is `[a, b, c, d, e, f]`: `x' = a*x + c*y + e`, `y' = b*x + d*y + f`.
+    fn glyph_outline_ex<S: OutlineSink>(&self, glyph: u16, tfx: [f32; 6], depth: u32, sink: &mut S) -> Option<()> {
+        // components referencing each other.
+        if depth > 8 {
+            return None;
+        }
+
+        let data = self.glyph_data(glyph)?;
+
+        // empty glyph, eg: space.
+        if data.is_empty() {
+            return Some(());
+        }
+
+        let num_contours = read_i16(data, 0)?;
+        if num_contours >= 0 {
+            Self::simple_outline(data, num_contours as usize, tfx, sink)
+        }
+        else {
+            self.composite_outline(data, tfx, depth, sink)
+        }
+    }
+
+    fn simple_outline<S: OutlineSink>(data: &[u8], num_contours: usize, tfx: [f32; 6], sink: &mut S) -> Option<()> {
+        const ON_CURVE: u8 = 0x01;
+        const X_SHORT:  u8 = 0x02;
+        const Y_SHORT:  u8 = 0x04;
+        const REPEAT:   u8 = 0x08;
+        const X_SAME_OR_POSITIVE: u8 = 0x10;
+        const Y_SAME_OR_POSITIVE: u8 = 0x20;
+
+        let mut end_points = Vec::with_capacity(num_contours);
+        for i in 0..num_contours {
+            end_points.push(read_u16(data, 10 + 2*i)? as usize);
+        }
+        let num_points = end_points.last().map(|last| last + 1).unwrap_or(0);
+
+        let instructions_len = read_u16(data, 10 + 2*num_contours)? as usize;
+        let mut cursor = 12 + 2*num_contours + instructions_len;
+
+        // flags.
+        let mut flags = Vec::with_capacity(num_points);
+        while flags.len() < num_points {
+            let flag = *data.get(cursor)?;
+            cursor += 1;
+            flags.push(flag);
+
+            if flag & REPEAT != 0 {
+                let count = *data.get(cursor)?;
+                cursor += 1;
+                for _ in 0..count {
+                    flags.push(flag);
+                }
+            }
+        }
+        flags.truncate(num_points);
+
+        // coordinates.
+        let mut points = vec![[0.0f32; 2]; num_points];
+        for axis in 0..2 {
+            let (short, same_or_positive) =
+                if axis == 0 { (X_SHORT, X_SAME_OR_POSITIVE) }
+                else         { (Y_SHORT, Y_SAME_OR_POSITIVE) };
+
+            let mut value = 0i32;
+            for (flag, point) in flags.iter().zip(&mut points) {
+                if flag & short != 0 {
+                    let delta = *data.get(cursor)? as i32;
+                    cursor += 1;
+                    if flag & same_or_positive != 0 { value += delta }
+                    else                            { value -= delta }
+                }
+                else if flag & same_or_positive == 0 {
+                    value += read_i16(data, cursor)? as i32;
+                    cursor += 2;
+                }
+                point[axis] = value as f32;
+            }
+        }
+
+        let apply = |p: [f32; 2]| -> [f32; 2] {
+            [tfx[0]*p[0] + tfx[2]*p[1] + tfx[4],
+             tfx[1]*p[0] + tfx[3]*p[1] + tfx[5]]
+        };
+        let mid = |a: [f32; 2], b: [f32; 2]| -> [f32; 2] {
+            [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0]
+        };
+
+        // contours.
+        let mut begin = 0;
+        for end in end_points {
+            if end < begin || end >= num_points {
+                return None;
+            }
+
+            let base = begin;
+            let len  = end + 1 - begin;
+            let on_curve = |i: usize| flags[base + i % len] & ON_CURVE != 0;
+            let point    = |i: usize| apply(points[base + i % len]);
+            begin = end + 1;
+
+            if len < 2 {
+                continue;
+            }
+
+            // find a start point on the curve.
+            // if there is none, start at the midpoint of the first two points.
+            let (start, first) =
+                match (0..len).find(|i| on_curve(*i)) {
+                    Some(i) => (point(i), i + 1),
+                    None    => (mid(point(0), point(1)), 1),
+                };
+
+            sink.move_to(start);
+
+            let mut control: Option<[f32; 2]> = None;
+            for i in first .. first + len {
+                let p = point(i);
+                if on_curve(i) {
+                    match control.take() {
+                        Some(c) => sink.quad_to(c, p),
+                        None    => sink.line_to(p),
+                    }
+                }
+                else {
+                    if let Some(c) = control {
+                        sink.quad_to(c, mid(c, p));
+                    }
+                    control = Some(p);
+                }
+            }
+            if let Some(c) = control {
+                sink.quad_to(c, start);
+            }
+
+            sink.close();
+        }
+
+        Some(())
+    }
+
+    fn composite_outline<S: OutlineSink>(&self, data: &[u8], tfx: [f32; 6], depth: u32, sink: &mut S) -> Option<()> {
+        const ARG_1_AND_2_ARE_WORDS:    u16 = 0x0001;
+        const ARGS_ARE_XY_VALUES:       u16 = 0x0002;
+        const WE_HAVE_A_SCALE:          u16 = 0x0008;
+        const MORE_COMPONENTS:          u16 = 0x0020;
+        const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+        const WE_HAVE_A_TWO_BY_TWO:     u16 = 0x0080;
+
+        let mut cursor = 10;
+        loop {
+            let flags = read_u16(data, cursor)?;
+            let glyph = read_u16(data, cursor + 2)?;
+            cursor += 4;
+
+            let (arg1, arg2) =
+                if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+                    cursor += 4;
+                    (read_i16(data, cursor - 4)? as f32, read_i16(data, cursor - 2)? as f32)
+                }
+                else {
+                    cursor += 2;
+                    (*data.get(cursor - 2)? as i8 as f32, *data.get(cursor - 1)? as i8 as f32)
+                };
+
+            // TODO: point matching.
+            let (dx, dy) =
+                if flags & ARGS_ARE_XY_VALUES != 0 { (arg1, arg2) }
+                else                               { (0.0, 0.0) };
+
+            let (mut a, mut b, mut c, mut d) = (1.0, 0.0, 0.0, 1.0);
+            if flags & WE_HAVE_A_SCALE != 0 {
+                a = read_f2dot14(data, cursor)?;
+                d = a;
+                cursor += 2;
+            }
+            else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                a = read_f2dot14(data, cursor)?;
+                d = read_f2dot14(data, cursor + 2)?;
+                cursor += 4;
+            }
+            else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                a = read_f2dot14(data, cursor)?;
+                b = read_f2dot14(data, cursor + 2)?;
+                c = read_f2dot14(data, cursor + 4)?;
+                d = read_f2dot14(data, cursor + 6)?;
+                cursor += 8;
+            }
+
+            // component transform, then parent transform.
+            let t = &tfx;
+            let combined = [
+                a*t[0] + b*t[2],
+                a*t[1] + b*t[3],
+                c*t[0] + d*t[2],
+                c*t[1] + d*t[3],
+                dx*t[0] + dy*t[2] + t[4],
+                dx*t[1] + dy*t[3] + t[5],
+            ];
+            self.glyph_outline_ex(glyph, combined, depth + 1, sink)?;
+
+            if flags & MORE_COMPONENTS == 0 {
+                break;
+            }
+        }
+
+        Some(())
+    }
+}
@@ -1,12 +1,21 @@
+use std::rc::Rc;
+use std::path::{Path, PathBuf};
+
+#[cfg(windows)]
 use crate::win::*;
 use crate::ctx::*;
+use crate::text::font_file::*;
+use crate::text::TextFormat;
+use crate::unicode::{general_category, GeneralCategory};
 
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct FontFamilyId (pub u32);
 
 impl FontFamilyId {
-    pub const DEFAULT: FontFamilyId = FontFamilyId(0);
+    /// the family set with `Fonts::set_default_family`.
+    /// initially the first family.
+    pub const DEFAULT: FontFamilyId = FontFamilyId(u32::MAX);
 }
 
 impl Default for FontFamilyId {
@@ -15,27 +24,124 @@ impl Default for FontFamilyId {
 }
 
 
+/// a fallback chain, see `Fonts::add_fallback`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FontFallbackId (pub u32);
+
+impl FontFallbackId {
+    /// only the global chain.
+    pub const NONE: FontFallbackId = FontFallbackId(u32::MAX);
+}
+
+impl Default for FontFallbackId {
+    #[inline]
+    fn default() -> Self { Self::NONE }
+}
+
+
 pub struct Fonts {
     families: Vec<FontFamilyData>,
+    default:  u32,
+
+    fallbacks:       Vec<Vec<FontFamilyId>>,
+    global_fallback: Vec<FontFamilyId>,
+
+    #[cfg(windows)]
+    dw_factory: IDWriteFactory2,
+    #[cfg(windows)]
+    dw_memory_loader: Option<IDWriteInMemoryFontFileLoader>,
+    #[cfg(windows)]
+    faces:    Vec<FontFace>,
+
+    // scanned on first query.
+    #[cfg(not(windows))]
+    system_faces: Option<Vec<FaceSource>>,
 }
 
 pub struct FontFamilyData {
     pub name_utf8:  String,
+    #[cfg(windows)]
     pub name_utf16: Vec<u16>,
-    pub dw_family:  IDWriteFontFamily,
+    /// `None` for families that only have registered faces.
+    #[cfg(windows)]
+    pub dw_family:  Option<IDWriteFontFamily>,
+    /// the registered faces, preferred over system faces.
+    /// on other platforms, also the system faces.
+    pub faces:      Vec<FaceSource>,
+}
+
+/// where the data of a font comes from.
+#[derive(Clone)]
+pub enum FontSource {
+    File(PathBuf),
+    Memory(Rc<[u8]>),
+}
+
+impl core::fmt::Debug for FontSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FontSource::File(path)   => write!(f, "File({:?})", path),
+            FontSource::Memory(data) => write!(f, "Memory({} bytes)", data.len()),
+        }
+    }
+}
+
+impl FontSource {
+    fn read(&self) -> Option<Vec<u8>> {
+        match self {
+            FontSource::File(path)   => std::fs::read(path).ok(),
+            FontSource::Memory(data) => Some(data.to_vec()),
+        }
+    }
+}
+
+/// a face in a font file, loaded on first use.
+#[derive(Clone, Debug)]
+pub struct FaceSource {
+    pub family: String,
+    pub source: FontSource,
+    pub index:  u32,
+    pub weight: u16,
+    pub italic: bool,
+    face: Option<FontFace>,
 }
 
 
 impl Fonts {
+    #[cfg(windows)]
+    pub fn new(dw_factory: IDWriteFactory2) -> Fonts {
+        Fonts {
+            families: vec![], default: 0,
+            fallbacks: vec![], global_fallback: vec![],
+            dw_factory, dw_memory_loader: None, faces: vec![],
+        }
+    }
+
+    #[cfg(not(windows))]
     pub fn new() -> Fonts {
-        Fonts { families: vec![] }
+        Fonts {
+            families: vec![], default: 0,
+            fallbacks: vec![], global_fallback: vec![],
+            system_faces: None,
+        }
     }
 
-    pub fn query(&mut self, name: &str, ctx: Ctx) -> Option<FontFamilyId> {
-        for (i, family) in self.families.iter().enumerate() {
-            if family.name_utf8 == name {
-                return Some(FontFamilyId(i as u32));
-            }
+    #[inline]
+    fn index(&self, id: FontFamilyId) -> usize {
+        if id == FontFamilyId::DEFAULT { self.default as usize }
+        else                           { id.0 as usize }
+    }
+
+    fn find(&self, name: &str) -> Option<FontFamilyId> {
+        self.families.iter()
+            .position(|family| family.name_utf8.eq_ignore_ascii_case(name))
+            .map(|i| FontFamilyId(i as u32))
+    }
+
+    #[cfg(windows)]
+    pub fn query(&mut self, name: &str, ctx: &Ctx) -> Option<FontFamilyId> {
+        if let Some(id) = self.find(name) {
+            return Some(id);
         }
 
         let name_utf16 = {
@@ -53,7 +159,8 @@ impl Fonts {
             self.families.push(FontFamilyData {
                 name_utf8: name.into(),
                 name_utf16,
-                dw_family,
+                dw_family: Some(dw_family),
+                faces: vec![],
             });
             return Some(FontFamilyId(id));
         }
@@ -61,12 +168,562 @@ impl Fonts {
         None
     }
 
+    #[cfg(not(windows))]
+    pub fn query(&mut self, name: &str, _ctx: &Ctx) -> Option<FontFamilyId> {
+        if let Some(id) = self.find(name) {
+            return Some(id);
+        }
+
+        let system_faces = self.system_faces.get_or_insert_with(scan_system_fonts);
+        let faces: Vec<FaceSource> = system_faces.iter()
+            .filter(|face| face.family.eq_ignore_ascii_case(name))
+            .cloned().collect();
+        if faces.is_empty() {
+            return None;
+        }
+
+        let id = self.families.len() as u32;
+        self.families.push(FontFamilyData { name_utf8: name.into(), faces });
+        Some(FontFamilyId(id))
+    }
+
+    /// a family without faces. text in it has no glyphs.
+    #[cfg(not(windows))]
+    pub(crate) fn add_empty_family(&mut self, name: &str) -> FontFamilyId {
+        let id = self.families.len() as u32;
+        self.families.push(FontFamilyData { name_utf8: name.into(), faces: vec![] });
+        FontFamilyId(id)
+    }
+
+    #[inline]
+    pub fn default_family(&self) -> FontFamilyId {
+        FontFamilyId(self.default)
+    }
+
+    pub fn set_default_family(&mut self, id: FontFamilyId) {
+        assert!(self.index(id) < self.families.len());
+        self.default = self.index(id) as u32;
+    }
+
+
+    /// a fallback chain for `TextFormat::fallback`.
+    /// its families are tried in order, before the global chain.
+    pub fn add_fallback(&mut self, families: &[FontFamilyId]) -> FontFallbackId {
+        if let Some(i) = self.fallbacks.iter().position(|chain| chain == families) {
+            return FontFallbackId(i as u32);
+        }
+
+        let id = FontFallbackId(self.fallbacks.len() as u32);
+        self.fallbacks.push(families.into());
+        id
+    }
+
+    pub fn fallback(&self, id: FontFallbackId) -> &[FontFamilyId] {
+        if id == FontFallbackId::NONE { &[] }
+        else                          { &self.fallbacks[id.0 as usize] }
+    }
+
+    #[inline]
+    pub fn global_fallback(&self) -> &[FontFamilyId] {
+        &self.global_fallback
+    }
+
+    /// the families tried for all text, after the format's chain.
+    pub fn set_global_fallback(&mut self, families: &[FontFamilyId]) {
+        self.global_fallback = families.into();
+    }
+
+
+    /// registers the faces of a font file (`.ttf`, `.otf`, `.ttc`, `.otc`).
+    /// returns the family of the file's first face.
+    pub fn add_file(&mut self, path: &Path) -> Option<FontFamilyId> {
+        let mut faces = vec![];
+        scan_font_file(path, &mut faces)?;
+        self.add_faces(faces)
+    }
+
+    /// registers the faces of a font file in memory.
+    /// returns the family of the first face.
+    pub fn add_data(&mut self, data: &[u8]) -> Option<FontFamilyId> {
+        let data: Rc<[u8]> = data.into();
+        let mut read_at = |offset: u64, len: usize| {
+            data.get(offset as usize .. (offset as usize).checked_add(len)?).map(|bytes| bytes.to_vec())
+        };
+
+        let mut faces = vec![];
+        scan_faces(&mut read_at, FontSource::Memory(data.clone()), &mut faces)?;
+        self.add_faces(faces)
+    }
+
+    /// registers the font files in `dir` & its subdirectories.
+    /// returns the number of faces added.
+    pub fn add_dir(&mut self, dir: &Path) -> usize {
+        let mut faces = vec![];
+        scan_font_dir(dir, &mut faces);
+
+        let count = faces.len();
+        self.add_faces(faces);
+        count
+    }
+
+    fn add_faces(&mut self, faces: Vec<FaceSource>) -> Option<FontFamilyId> {
+        let mut first = None;
+        for face in faces {
+            let id = match self.find(&face.family) {
+                Some(id) => id,
+                None => {
+                    let id = FontFamilyId(self.families.len() as u32);
+                    self.families.push(FontFamilyData {
+                        name_utf8:  face.family.clone(),
+                        #[cfg(windows)]
+                        name_utf16: face.family.encode_utf16().chain([0]).collect(),
+                        #[cfg(windows)]
+                        dw_family:  None,
+                        faces: vec![],
+                    });
+                    id
+                }
+            };
+
+            self.families[id.0 as usize].faces.push(face);
+            first.get_or_insert(id);
+        }
+        first
+    }
+
+
+    /// the face of a family that best matches `weight` & `italic`.
+    #[cfg(windows)]
+    pub fn face(&mut self, id: FontFamilyId, weight: u32, italic: bool) -> Option<FontFace> {unsafe {
+        if !self.font_data(id).faces.is_empty() {
+            return self.registered_face(id, weight, italic);
+        }
+
+        let family = self.families.get(self.index(id))?.dw_family.as_ref()?;
+
+        let font_style =
+            if italic { DWRITE_FONT_STYLE_ITALIC }
+            else      { DWRITE_FONT_STYLE_NORMAL };
+        let font = family.GetFirstMatchingFont(DWRITE_FONT_WEIGHT(weight as i32), DWRITE_FONT_STRETCH_NORMAL, font_style).ok()?;
+        let dw_face = font.CreateFontFace().ok()?;
+
+        Some(self.face_from_dw(&dw_face))
+    }}
+
+    /// the face of a family that best matches `weight` & `italic`.
+    #[cfg(not(windows))]
+    #[inline]
+    pub fn face(&mut self, id: FontFamilyId, weight: u32, italic: bool) -> Option<FontFace> {
+        self.registered_face(id, weight, italic)
+    }
+
+    fn registered_face(&mut self, id: FontFamilyId, weight: u32, italic: bool) -> Option<FontFace> {
+        let index = self.index(id);
+        let family = self.families.get(index)?;
+
+        // TODO: css font matching.
+        let distance = |face: &FaceSource| {
+            let style = if face.italic != italic { 1000 } else { 0 };
+            style + (face.weight as i32 - weight as i32).abs()
+        };
+
+        let mut candidates: Vec<usize> = (0..family.faces.len()).collect();
+        candidates.sort_by_key(|i| distance(&family.faces[*i]));
+
+        // skip faces that fail to load.
+        for i in candidates {
+            let source = &self.families[index].faces[i];
+            if let Some(face) = &source.face {
+                return Some(face.clone());
+            }
+
+            let Some(data) = source.source.read() else { continue };
+            let face_index = source.index;
+
+            #[cfg(windows)]
+            let dw_face = self.dw_face_from_data(&data, face_index);
+
+            let Some(file) = FontFile::parse(data, face_index) else { continue };
+
+            #[cfg(windows)]
+            let face = match dw_face {
+                Some(dw_face) => FontFace::from_file_and_dw(file, dw_face),
+                None          => FontFace::from_file(file),
+            };
+            #[cfg(not(windows))]
+            let face = FontFace::from_file(file);
+
+            self.families[index].faces[i].face = Some(face.clone());
+            return Some(face);
+        }
+        None
+    }
+
+    /// so DirectWrite can shape & draw registered faces.
+    #[cfg(windows)]
+    fn dw_face_from_data(&mut self, data: &[u8], face_index: u32) -> Option<IDWriteFontFace> {unsafe {
+        let factory: IDWriteFactory5 = self.dw_factory.cast().ok()?;
+
+        if self.dw_memory_loader.is_none() {
+            let loader = factory.CreateInMemoryFontFileLoader().ok()?;
+            factory.RegisterFontFileLoader(&loader).ok()?;
+            self.dw_memory_loader = Some(loader);
+        }
+        let loader = self.dw_memory_loader.as_ref()?;
+
+        // copies the data.
+        let file = loader.CreateInMemoryFontFileReference(&factory, data.as_ptr() as *const _, data.len() as u32, None).ok()?;
+
+        let (mut supported, mut file_type, mut face_type, mut num_faces) = Default::default();
+        file.Analyze(&mut supported, &mut file_type, Some(&mut face_type), &mut num_faces).ok()?;
+        if !supported.as_bool() {
+            return None;
+        }
+
+        factory.CreateFontFace(face_type, &[Some(file)], face_index, DWRITE_FONT_SIMULATIONS_NONE).ok()
+    }}
+
+    #[cfg(windows)]
     pub fn font_name_utf16(&self, id: FontFamilyId) -> &[u16] {
-        &self.families[id.0 as usize].name_utf16
+        &self.families[self.index(id)].name_utf16
     }
 
     pub fn font_data(&self, id: FontFamilyId) -> &FontFamilyData {
-        &self.families[id.0 as usize]
+        &self.families[self.index(id)]
+    }
+
+    #[cfg(windows)]
+    pub fn face_from_dw(&mut self, dw_face: &IDWriteFontFace) -> FontFace {
+        if let Some(face) = self.faces.iter().find(|face| face.dw_face.as_ref() == Some(dw_face)) {
+            return face.clone();
+        }
+
+        let face = FontFace::from_dw(dw_face.clone());
+        self.faces.push(face.clone());
+        face
     }
 }
 
+#[cfg(not(windows))]
+impl Default for Fonts {
+    #[inline] fn default() -> Fonts { Fonts::new() }
+}
+
+// the factory is shared, the loader would outlive the context.
+#[cfg(windows)]
+impl Drop for Fonts {
+    fn drop(&mut self) {unsafe {
+        if let Some(loader) = self.dw_memory_loader.take() {
+            if let Ok(factory) = self.dw_factory.cast::<IDWriteFactory5>() {
+                let _ = factory.UnregisterFontFileLoader(&loader);
+            }
+        }
+    }}
+}
+
+
+
+// FALLBACK
+
+impl Fonts {
+    /// splits `text` into runs that a single face can render.
+    /// tries the format's family, then its fallback chain, then the global chain.
+    /// returns the runs' end offsets & faces, `None` where no face has the glyphs.
+    /// marks & joined sequences stay with their base char.
+    pub fn fallback_runs(&mut self, text: &str, format: &TextFormat) -> Vec<(usize, Option<FontFace>)> {
+        let mut families = vec![format.font];
+        families.extend_from_slice(self.fallback(format.fallback));
+        families.extend_from_slice(&self.global_fallback);
+
+        // loaded on first use.
+        let mut faces: Vec<Option<Option<FontFace>>> = vec![None; families.len()];
+
+        let mut runs: Vec<(usize, Option<usize>)> = vec![];
+        let mut begin = 0;
+        while begin < text.len() {
+            let end = cluster_end(text, begin);
+            let cluster = &text[begin..end];
+
+            let mut covers = |i: usize| {
+                let face = faces[i].get_or_insert_with(|| self.face(families[i], format.font_weight, format.italic));
+                face.as_ref().and_then(|face| face.file.as_ref())
+                    .map(|file| has_glyphs(file, cluster))
+                    .unwrap_or(false)
+            };
+
+            // spaces & punctuation stay in the current run.
+            let current = runs.last().and_then(|run| run.1);
+            let base = cluster.chars().next().unwrap();
+            let neutral = matches!(general_category(base),
+                GeneralCategory::Zs | GeneralCategory::Pc | GeneralCategory::Pd | GeneralCategory::Po);
+
+            let choice =
+                if let Some(current) = current.filter(|current| neutral && covers(*current)) { Some(current) }
+                else { (0..families.len()).find(|i| covers(*i)) };
+
+            match runs.last_mut() {
+                Some(run) if run.1 == choice => run.0 = end,
+                _ => runs.push((end, choice)),
+            }
+            begin = end;
+        }
+
+        runs.into_iter()
+            .map(|(end, choice)| (end, choice.and_then(|i| faces[i].clone().flatten())))
+            .collect()
+    }
+}
+
+/// the end of the cluster starting at `begin`.
+/// a char, followed by its marks, variation selectors,
+/// emoji modifiers & chars joined with ZWJ.
+fn cluster_end(text: &str, begin: usize) -> usize {
+    let mut chars = text[begin..].char_indices().peekable();
+    let mut prev = chars.next().unwrap().1;
+
+    let mut end = text.len();
+    for (offset, c) in chars {
+        let extends =
+            general_category(c).is_mark()
+            || c == '\u{200d}' || prev == '\u{200d}'
+            || ('\u{1f3fb}'..='\u{1f3ff}').contains(&c);
+        if !extends {
+            end = begin + offset;
+            break;
+        }
+        prev = c;
+    }
+    end
+}
+
+/// whether `file` has glyphs for the visible chars of `cluster`.
+fn has_glyphs(file: &FontFile, cluster: &str) -> bool {
+    cluster.chars().all(|c| {
+        // invisible formatting chars & variation selectors.
+        let ignorable =
+            matches!(general_category(c), GeneralCategory::Cc | GeneralCategory::Cf)
+            || ('\u{fe00}'..='\u{fe0f}').contains(&c)
+            || ('\u{e0100}'..='\u{e01ef}').contains(&c);
+        ignorable || file.glyph_index(c as u32).map(|glyph| glyph != 0).unwrap_or(false)
+    })
+}
+
+
+
+/// a font face, as referenced by text spans & glyph runs.
+/// cheap to clone.
+#[derive(Clone)]
+pub struct FontFace (Rc<FontFaceData>);
+
+impl core::fmt::Debug for FontFace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FontFace({:?})", Rc::as_ptr(&self.0))
+    }
+}
+
+pub struct FontFaceData {
+    /// `None`, if the font's tables couldn't be read.
+    pub file:    Option<FontFile>,
+    pub metrics: FontMetrics,
+    /// `None` for faces DirectWrite can't load.
+    #[cfg(windows)]
+    pub dw_face: Option<IDWriteFontFace>,
+}
+
+impl FontFace {
+    pub fn from_file(file: FontFile) -> FontFace {
+        let metrics = file.metrics();
+        FontFace(Rc::new(FontFaceData {
+            file: Some(file),
+            metrics,
+            #[cfg(windows)]
+            dw_face: None,
+        }))
+    }
+
+    #[cfg(windows)]
+    pub fn from_file_and_dw(file: FontFile, dw_face: IDWriteFontFace) -> FontFace {
+        let metrics = file.metrics();
+        FontFace(Rc::new(FontFaceData {
+            file: Some(file),
+            metrics,
+            dw_face: Some(dw_face),
+        }))
+    }
+
+    #[cfg(windows)]
+    pub fn from_dw(dw_face: IDWriteFontFace) -> FontFace {
+        // the tables needed to shape & draw glyphs without DirectWrite.
+        let mut tables = vec![];
+        for tag in [
+            *b"head", *b"maxp", *b"loca", *b"glyf", *b"cmap", *b"hhea", *b"hmtx",
+            *b"OS/2", *b"post", *b"name", *b"kern", *b"GDEF", *b"GSUB", *b"GPOS",
+        ] {
+            unsafe {
+                let mut data    = core::ptr::null_mut();
+                let mut size    = 0;
+                let mut context = core::ptr::null_mut();
+                let mut exists  = BOOL::default();
+                let result = dw_face.TryGetFontTable(u32::from_le_bytes(tag), &mut data, &mut size, &mut context, &mut exists);
+                if result.is_ok() && exists.as_bool() {
+                    let table = core::slice::from_raw_parts(data as *const u8, size as usize);
+                    tables.push((tag, table.to_vec()));
+                    dw_face.ReleaseFontTable(context);
+                }
+            }
+        }
+
+        let file = FontFile::from_tables(tables.iter().map(|(tag, table)| (*tag, table.as_slice())));
+
+        let mut m = Default::default();
+        unsafe { dw_face.GetMetrics(&mut m) };
+        let metrics = FontMetrics {
+            units_per_em: m.designUnitsPerEm,
+            ascent:   m.ascent  as i16,
+            descent:  m.descent as i16,
+            line_gap: m.lineGap,
+            underline_position:      m.underlinePosition,
+            underline_thickness:     m.underlineThickness as i16,
+            strikethrough_position:  m.strikethroughPosition,
+            strikethrough_thickness: m.strikethroughThickness as i16,
+        };
+
+        FontFace(Rc::new(FontFaceData { file, metrics, dw_face: Some(dw_face) }))
+    }
+}
+
+impl core::ops::Deref for FontFace {
+    type Target = FontFaceData;
+    #[inline] fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+
+
+// FONT FILES
+
+#[cfg(not(windows))]
+fn system_font_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = vec![];
+
+    if cfg!(target_os = "macos") {
+        dirs.push("/System/Library/Fonts".into());
+        dirs.push("/Library/Fonts".into());
+    }
+    else {
+        dirs.push("/usr/share/fonts".into());
+        dirs.push("/usr/local/share/fonts".into());
+    }
+
+    if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+        if cfg!(target_os = "macos") {
+            dirs.push(home.join("Library/Fonts"));
+        }
+        else {
+            match std::env::var_os("XDG_DATA_HOME") {
+                Some(data) => dirs.push(PathBuf::from(data).join("fonts")),
+                None       => dirs.push(home.join(".local/share/fonts")),
+            }
+            dirs.push(home.join(".fonts"));
+        }
+    }
+
+    dirs
+}
+
+#[cfg(not(windows))]
+fn scan_system_fonts() -> Vec<FaceSource> {
+    let mut faces = vec![];
+    for dir in system_font_dirs() {
+        scan_font_dir(&dir, &mut faces);
+    }
+    faces
+}
+
+/// recursively adds the faces of the font files in `dir`.
+fn scan_font_dir(dir: &Path, faces: &mut Vec<FaceSource>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            scan_font_dir(&path, faces);
+            continue;
+        }
+
+        let is_font = path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| matches!(ext.to_ascii_lowercase().as_str(), "ttf" | "otf" | "ttc" | "otc"))
+            .unwrap_or(false);
+        if is_font {
+            scan_font_file(&path, faces);
+        }
+    }
+}
+
+/// adds the faces of a font file.
+fn scan_font_file(path: &Path, faces: &mut Vec<FaceSource>) -> Option<()> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path).ok()?;
+
+    let mut read_at = |offset: u64, len: usize| -> Option<Vec<u8>> {
+        let mut buffer = vec![0; len];
+        file.seek(SeekFrom::Start(offset)).ok()?;
+        file.read_exact(&mut buffer).ok()?;
+        Some(buffer)
+    };
+
+    scan_faces(&mut read_at, FontSource::File(path.into()), faces)
+}
+
+/// adds the faces of a font, read with `read_at(offset, len)`.
+/// only reads the tables needed to identify the faces.
+/// fails if the data isn't a font.
+fn scan_faces<F: FnMut(u64, usize) -> Option<Vec<u8>>>(read_at: &mut F, source: FontSource, faces: &mut Vec<FaceSource>) -> Option<()> {
+    let begin = faces.len();
+
+    let header = read_at(0, 12)?;
+    let directories =
+        if header.get(0..4)? == b"ttcf" {
+            let num_fonts = read_u32(&header, 8)?;
+            let offsets = read_at(12, 4*num_fonts as usize)?;
+            (0..num_fonts as usize).filter_map(|i| read_u32(&offsets, 4*i)).collect()
+        }
+        else { vec![0] };
+
+    for (index, dir) in directories.into_iter().enumerate() {
+        let Some(dir_header) = read_at(dir as u64, 12) else { continue };
+        let Some(num_tables) = read_u16(&dir_header, 4) else { continue };
+        let Some(records) = read_at(dir as u64 + 12, 16*num_tables as usize) else { continue };
+
+        let mut tables = vec![];
+        for record in records.chunks_exact(16) {
+            let tag: Tag = [record[0], record[1], record[2], record[3]];
+            if !matches!(&tag, b"head" | b"maxp" | b"name" | b"OS/2") {
+                continue;
+            }
+
+            let offset = read_u32(record,  8)?;
+            let len    = read_u32(record, 12)?;
+            if let Some(table) = read_at(offset as u64, len as usize) {
+                tables.push((tag, table));
+            }
+        }
+
+        let Some(font) = FontFile::from_tables(tables.iter().map(|(tag, table)| (*tag, table.as_slice()))) else { continue };
+        let Some(family) = font.family_name() else { continue };
+
+        faces.push(FaceSource {
+            family,
+            source: source.clone(),
+            index:  index as u32,
+            weight: font.weight(),
+            italic: font.is_italic(),
+            face:   None,
+        });
+    }
+
+    (faces.len() > begin).then_some(())
+}
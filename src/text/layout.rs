@@ -1,14 +1,15 @@
-use core::cell::RefCell;
-
-use crate::win::*;
-use crate::text::FontFamilyId;
+use crate::text::{FontFamilyId, FontFallbackId, FontFace};
 use crate::ctx::*;
-use crate::unicode::*;
+use crate::unicode::LineBreak;
+use crate::unicode::bidi::{self, Level};
+#[cfg(windows)]
+use crate::win::{DWRITE_SCRIPT_ANALYSIS, DWRITE_SHAPING_GLYPH_PROPERTIES};
 
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TextFormat {
     pub font:          FontFamilyId,
+    pub fallback:      FontFallbackId,
     pub font_size:     f32,
     pub font_weight:   u32,
     pub italic:        bool,
@@ -21,6 +22,7 @@ impl Default for TextFormat {
     fn default() -> Self {
         TextFormat {
             font:          FontFamilyId::DEFAULT,
+            fallback:      FontFallbackId::NONE,
             font_size:     16.0,
             font_weight:   400,
             italic:        false,
@@ -32,33 +34,134 @@ impl Default for TextFormat {
 }
 
 
+/// the implementation behind `TextLayoutBuilder::build`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextBackend {
+    #[cfg(windows)]
+    DirectWrite,
+
+    /// parses font files directly. available on every platform.
+    Native,
+}
+
+impl Default for TextBackend {
+    #[cfg(windows)]
+    fn default() -> Self { TextBackend::DirectWrite }
+
+    #[cfg(not(windows))]
+    fn default() -> Self { TextBackend::Native }
+}
+
+
 #[allow(dead_code)]
 #[derive(Debug, Default)]
-struct TextSpan {
-    text_begin_utf8: u32,
-    text_end_utf8:   u32,
+pub(crate) struct TextSpan {
+    pub text_begin_utf8: u32,
+    pub text_end_utf8:   u32,
 
-    object_index: u32, // u32::MAX for None
+    pub object_index: u32, // u32::MAX for None
 
-    // TODO: "bidi_level" instead.
-    is_rtl: bool,
-    script: DWRITE_SCRIPT_ANALYSIS,
+    pub bidi_level: Level,
+    #[cfg(windows)]
+    pub script: DWRITE_SCRIPT_ANALYSIS,
 
-    width: f32,
-    ascent: f32,
-    drop:   f32,
+    pub width:  f32,
+    pub ascent: f32,
+    pub drop:   f32,
 
-    format: TextFormat,
-    font_face: Option<IDWriteFontFace>,
+    pub format:    TextFormat,
+    pub font_face: Option<FontFace>,
 
     // utf8 offset (relative to text_begin_utf8)
     // to index of first glyph in glyph cluster.
-    cluster_map: Vec<u16>,
+    pub cluster_map: Vec<u16>,
+
+    pub glyph_indices:  Vec<u16>,
+    #[cfg(windows)]
+    pub glyph_props:    Vec<DWRITE_SHAPING_GLYPH_PROPERTIES>,
+    pub glyph_advances: Vec<f32>,
+    pub glyph_offsets:  Vec<[f32; 2]>,
+}
+
+impl TextSpan {
+    #[inline]
+    pub fn is_rtl(&self) -> bool {
+        bidi::is_rtl(self.bidi_level)
+    }
+}
+
+/// the result of analyzing & shaping the text of a `TextLayoutBuilder`.
+pub(crate) struct TextAnalysis {
+    pub spans:         Vec<TextSpan>,
+    pub hard_lines:    Vec<u32>, // end indices in spans array.
+    pub break_options: Vec<u32>, // bit vector.
+}
 
-    glyph_indices:  Vec<u16>,
-    glyph_props:    Vec<DWRITE_SHAPING_GLYPH_PROPERTIES>,
-    glyph_advances: Vec<f32>,
-    glyph_offsets:  Vec<[f32; 2]>,
+/// line break analysis (UAX #14), shared by the text backends.
+pub(crate) struct Breaks {
+    /// utf8 ranges of the hard lines, excluding the line break chars.
+    /// if the text ends with a line break, the last line is empty.
+    pub lines:   Vec<(u32, u32)>,
+    pub options: Vec<u32>, // bit vector.
+}
+
+pub(crate) fn analyze_breaks(text: &[u8]) -> Breaks {
+    let text = core::str::from_utf8(text).unwrap();
+
+    let mut options = vec![0u32; text.len().div_ceil(32)];
+    let mut lines = vec![];
+
+    // excludes the line break char (or CR LF) from a line.
+    let line_end = |end: usize| -> usize {
+        let Some(last) = text[..end].chars().next_back() else { return end };
+        match crate::unicode::line_break(last) {
+            LineBreak::LF if text[..end - 1].ends_with('\r') => end - 2,
+            LineBreak::BK | LineBreak::CR | LineBreak::LF | LineBreak::NL => end - last.len_utf8(),
+            _ => end,
+        }
+    };
+
+    let mut line_begin = 0;
+    for brk in crate::unicode::line_break::line_breaks(text) {
+        let at = brk.offset;
+        if brk.mandatory {
+            let end = line_end(at);
+            lines.push((line_begin as u32, end as u32));
+            line_begin = at;
+
+            // the last line is empty, if the text ends with a line break.
+            if at == text.len() && end != at {
+                lines.push((at as u32, at as u32));
+            }
+        }
+        else {
+            options[at / 32] |= 1 << (at % 32);
+        }
+    }
+
+    Breaks { lines, options }
+}
+
+/// bidi analysis (UAX #9) of a hard line, shared by the text backends.
+/// each hard line is a paragraph, its direction is detected from its text.
+/// returns the embedding level of each utf8 byte.
+pub(crate) fn analyze_bidi(text: &str) -> Vec<Level> {
+    // inline objects are neutral, like U+FFFC.
+    let chars: Vec<char> = text.chars()
+        .map(|c| if c == '\0' { '\u{fffc}' } else { c })
+        .collect();
+
+    let paragraph = bidi::paragraph_level(&chars);
+    let mut levels = bidi::resolve_levels(&chars, paragraph);
+    bidi::reset_line_levels(&chars, &mut levels, paragraph);
+
+    let mut result = Vec::with_capacity(text.len());
+    for (c, level) in text.chars().zip(levels) {
+        for _ in 0..c.len_utf8() {
+            result.push(level);
+        }
+    }
+    result
 }
 
 #[derive(Debug)]
@@ -79,7 +182,7 @@ struct VisualLine {
     text_begin_utf8: u32,
     text_end_utf8:   u32,
 
-    spans: Vec<VisualSpan>,
+    spans: Vec<VisualSpan>, // visual order.
 
     y: f32,
     width:    f32,
@@ -315,7 +418,6 @@ pub struct PosMetrics {
 }
 
 impl TextLayout {
-    // TODO: support RTL.
     // TODO: return info for grapheme ligature subdivision.
     pub fn hit_test_offset(&self, offset: usize) -> PosMetrics {
         let offset = offset.min(self.text.len()) as u32;
@@ -337,9 +439,15 @@ impl TextLayout {
                         if tspan.object_index == u32::MAX {
                             let glyph_begin = vspan.glyph_begin as usize;
                             let glyph = tspan.cluster_map[local_offset as usize] as usize;
+
+                            let mut advance = 0.0;
                             for i in glyph_begin..glyph {
-                                x += tspan.glyph_advances[i];
+                                advance += tspan.glyph_advances[i];
                             }
+
+                            // rtl spans go right to left.
+                            if tspan.is_rtl() { x += vspan.width - advance }
+                            else              { x += advance }
                         }
 
                         return PosMetrics {
@@ -361,7 +469,7 @@ impl TextLayout {
         }
 
         assert_eq!(self.text.len(), 0);
-        return PosMetrics { x: 0.0, y: 0.0, line_height: 0.0, line_index: 0 };
+        PosMetrics { x: 0.0, y: 0.0, line_height: 0.0, line_index: 0 }
     }
 }
 
@@ -371,6 +479,7 @@ pub struct HitMetrics {
     pub text_pos_left:  u32,
     pub text_pos_right: u32,
 
+    // from the leading edge, the right one for rtl text.
     pub fraction: f32,
     pub out_of_bounds: [bool; 2],
 
@@ -418,6 +527,8 @@ impl TextLayout {
             let text_begin = (vspan.text_begin_utf8 - tspan.text_begin_utf8) as usize;
             let text_end   = (vspan.text_end_utf8   - tspan.text_begin_utf8) as usize;
 
+            // clusters in visual order.
+            let mut clusters = vec![];
             let mut text_left = text_begin;
             while text_left < text_end {
                 let glyph_begin = tspan.cluster_map[text_left];
@@ -429,14 +540,24 @@ impl TextLayout {
                 }
 
                 let glyph_end = tspan.cluster_map[text_right];
+                clusters.push((text_left, text_right, glyph_begin, glyph_end));
+                text_left = text_right;
+            }
+            if tspan.is_rtl() {
+                clusters.reverse();
+            }
 
+            for (text_left, text_right, glyph_begin, glyph_end) in clusters {
                 let mut new_cursor = cursor;
                 for i in glyph_begin as usize .. glyph_end as usize {
                     new_cursor += tspan.glyph_advances[i];
                 }
 
                 if x >= cursor && x < new_cursor {
-                    let fraction = (x - cursor) / (new_cursor - cursor);
+                    let mut fraction = (x - cursor) / (new_cursor - cursor);
+                    if tspan.is_rtl() {
+                        fraction = 1.0 - fraction;
+                    }
 
                     return HitMetrics {
                         text_pos_left:  tspan.text_begin_utf8 + text_left as u32,
@@ -444,27 +565,26 @@ impl TextLayout {
                         fraction,
                         out_of_bounds: [false, false],
                         object: None,
-                        is_rtl: tspan.is_rtl,
+                        is_rtl: tspan.is_rtl(),
                     }
                 }
 
                 cursor = new_cursor;
-                text_left = text_right;
             }
         }
 
-        return HitMetrics {
+        HitMetrics {
             text_pos_left:  line.text_end_utf8,
             text_pos_right: line.text_end_utf8,
             fraction: 0.0,
             out_of_bounds: [true, false],
             object: None,
             is_rtl: false,
-        };
+        }
     }
 
     pub fn hit_test_pos(&self, x: f32, y: f32) -> HitMetrics {
-        if self.lines.len() == 0 {
+        if self.lines.is_empty() {
             return HitMetrics {
                 text_pos_left:  0,
                 text_pos_right: 0,
@@ -494,7 +614,7 @@ impl TextLayout {
         // below.
         let mut result = self.hit_test_line(self.lines.len() - 1, x);
         result.out_of_bounds[1] = true;
-        return result;
+        result
     }
 }
 
@@ -559,21 +679,26 @@ impl TextLayout {
                     let glyph_begin = tspan.cluster_map[rel_begin as usize] as usize;
                     let glyph_end   = tspan.cluster_map[rel_end   as usize] as usize;
 
-                    let mut x0 = x;
+                    let mut advance = 0.0;
                     for i in glyph_zero..glyph_begin {
-                        x0 += tspan.glyph_advances[i];
+                        advance += tspan.glyph_advances[i];
                     }
 
-                    let mut x1 = x0;
+                    let mut width = 0.0;
                     for i in glyph_begin..glyph_end {
-                        x1 += tspan.glyph_advances[i];
+                        width += tspan.glyph_advances[i];
                     }
 
+                    // rtl spans go right to left.
+                    let x0 =
+                        if tspan.is_rtl() { x + vspan.width - advance - width }
+                        else              { x + advance };
+
                     f(&RangeMetrics {
                         text_begin, text_end,
                         pos:     [x0, y],
-                        size:    [x1 - x0, line.height],
-                        is_rtl:  tspan.is_rtl,
+                        size:    [width, line.height],
+                        is_rtl:  tspan.is_rtl(),
                         is_text: true,
                     });
                 }
@@ -594,7 +719,7 @@ pub struct DrawGlyphs<'a> {
     pub text_end:   u32,
 
     pub format:    &'a TextFormat,
-    pub font_face: &'a IDWriteFontFace, // TEMP.
+    pub font_face: &'a FontFace,
     pub is_rtl:    bool,
 
     pub cluster_map: &'a [u16],
@@ -624,13 +749,13 @@ pub enum DrawLineKind {
 }
 
 pub trait TextRenderer {
-    fn glyphs(&self, data: &DrawGlyphs);
-    fn line(&self, data: &DrawLine, kind: DrawLineKind);
-    fn object(&self, data: &DrawObject);
+    fn glyphs(&mut self, data: &DrawGlyphs);
+    fn line(&mut self, data: &DrawLine, kind: DrawLineKind);
+    fn object(&mut self, data: &DrawObject);
 }
 
 impl TextLayout {
-    pub fn draw<Renderer: TextRenderer>(&self, offset: [f32; 2], renderer: &Renderer) {
+    pub fn draw<Renderer: TextRenderer>(&self, offset: [f32; 2], renderer: &mut Renderer) {
         for line in &self.lines {
             let mut x = offset[0];
             for vspan in &line.spans {
@@ -654,8 +779,8 @@ impl TextLayout {
                     };
                     renderer.object(&draw_object);
                 }
-                else {
-                    let rtl_offset = if tspan.is_rtl { vspan.width } else { 0.0 };
+                else if let Some(font_face) = &tspan.font_face {
+                    let rtl_offset = if tspan.is_rtl() { vspan.width } else { 0.0 };
 
                     let gb = vspan.glyph_begin as usize;
                     let ge = vspan.glyph_end   as usize;
@@ -666,8 +791,8 @@ impl TextLayout {
                         text_end:   vspan.text_end_utf8,
 
                         format:     &tspan.format,
-                        font_face:  tspan.font_face.as_ref().unwrap(),
-                        is_rtl:     tspan.is_rtl,
+                        font_face,
+                        is_rtl:     tspan.is_rtl(),
 
                         cluster_map: &tspan.cluster_map[gb..ge],
                         indices:     &tspan.glyph_indices[gb..ge],
@@ -678,15 +803,12 @@ impl TextLayout {
 
                     // TODO: also for inline objects.
                     if tspan.format.underline || tspan.format.strikethrough {
-                        let face = tspan.font_face.as_ref().unwrap();
-                        let mut metrics = Default::default();
-                        unsafe { face.GetMetrics(&mut metrics) };
-
-                        let scale = tspan.format.font_size / metrics.designUnitsPerEm as f32;
+                        let metrics = &font_face.metrics;
+                        let scale = tspan.format.font_size / metrics.units_per_em as f32;
 
                         if tspan.format.underline {
-                            let offset = scale * metrics.underlinePosition as f32;
-                            let height = scale * metrics.underlineThickness as f32;
+                            let offset = scale * metrics.underline_position as f32;
+                            let height = scale * metrics.underline_thickness as f32;
                             renderer.line(&DrawLine {
                                 x0: x,
                                 x1: x + vspan.width,
@@ -696,8 +818,8 @@ impl TextLayout {
                         }
 
                         if tspan.format.strikethrough {
-                            let offset = scale * metrics.strikethroughPosition as f32;
-                            let height = scale * metrics.strikethroughThickness as f32;
+                            let offset = scale * metrics.strikethrough_position as f32;
+                            let height = scale * metrics.strikethrough_thickness as f32;
                             renderer.line(&DrawLine {
                                 x0: x,
                                 x1: x + vspan.width,
@@ -716,11 +838,11 @@ impl TextLayout {
 
 
 #[derive(Clone, Copy, Debug, Default)]
-struct PreSpan {
-    text_end_utf8: u32,
-    format: TextFormat,
+pub(crate) struct PreSpan {
+    pub text_end_utf8: u32,
+    pub format: TextFormat,
 
-    object_index: u32, // u32::MAX for None.
+    pub object_index: u32, // u32::MAX for None.
 }
 
 pub struct TextLayoutBuilder {
@@ -777,7 +899,7 @@ impl TextLayoutBuilder {
     #[inline]
     pub fn add_line(&mut self, line: &str) {
         self.text.extend(line.as_bytes());
-        self.text.push('\n' as u8);
+        self.text.push(b'\n');
     }
 
     pub fn add_object_ex(&mut self, size: [f32; 2], baseline: f32) {
@@ -841,6 +963,19 @@ impl TextLayoutBuilder {
         self.set_font(self.base_format.font);
     }
 
+    pub fn set_fallback(&mut self, fallback: FontFallbackId) {
+        if fallback != self.format.fallback {
+            self.flush_format();
+            self.format.fallback = fallback;
+        }
+    }
+
+    #[allow(dead_code)] // TEMP
+    #[inline]
+    pub fn reset_fallback(&mut self) {
+        self.set_fallback(self.base_format.fallback);
+    }
+
     pub fn set_font_size(&mut self, size: f32) {
         if size != self.format.font_size {
             self.flush_format();
@@ -902,8 +1037,8 @@ impl TextLayoutBuilder {
     }
 
 
-    pub fn build(mut self) -> TextLayout {unsafe{
-        if self.text.len() == 0 {
+    pub fn build(mut self) -> TextLayout {
+        if self.text.is_empty() {
             return TextLayout {
                 ctx: self.ctx,
                 text: vec![],
@@ -917,525 +1052,28 @@ impl TextLayoutBuilder {
             };
         }
         self.flush_format();
-        assert!(self.pre_spans.len() > 0);
+        assert!(!self.pre_spans.is_empty());
 
         let TextLayoutBuilder { ctx, text, objects, pre_spans, .. } = self;
         assert!(text.len() < (u32::MAX / 2) as usize);
 
-        let (text16, utf16_to_utf8) = {
-            let mut utf16 = vec![];
-            let mut map   = vec![];
-
-            let bytes = text.as_slice();
-            let mut cursor = 0;
-            while let Some((cp, new_cursor)) = utf8_next_code_point(bytes, cursor) {
-                let mut buffer = [0; 2];
-                let is_double = utf16_encode(cp, &mut buffer);
-
-                utf16.push(buffer[0]);
-                map.push(cursor as u32);
-
-                if is_double {
-                    utf16.push(buffer[1]);
-                    map.push(cursor as u32);
-                }
-
-                cursor = new_cursor;
-            }
-            assert_eq!(cursor, bytes.len());
-            map.push(bytes.len() as u32);
-
-            (utf16, map)
+        let spans = match ctx.text_backend.get() {
+            #[cfg(windows)]
+            TextBackend::DirectWrite => crate::text::dwrite::analyze(&ctx, &text, &pre_spans),
+            TextBackend::Native      => crate::text::native::analyze(&ctx, &text, &pre_spans),
         };
 
-        let source: IDWriteTextAnalysisSource = DwSource {
-            string: text16.as_slice(),
-            locale: w!("en-us").as_ptr(), // TEMP.
-        }.into();
-
-        let dw_breaks = RefCell::new(DwSinkBreaks {
-            pointer: 0,
-            options: vec![0; (text.len() + 31) / 32],
-            lines:   vec![],
-        });
-        let dw_spans = RefCell::new(DwSinkSpans {
-            begin: 0,
-            is_rtls: vec![],
-            scripts: vec![],
-        });
-
-        let sink: IDWriteTextAnalysisSink = DwSink {
-            utf16_to_utf8: utf16_to_utf8.as_slice(),
-            breaks: &dw_breaks,
-            spans:  &dw_spans,
-        }.into();
-
-
-        let analyzer = ctx.dw_factory.CreateTextAnalyzer().unwrap();
-
-        analyzer.AnalyzeLineBreakpoints(&source, 0, text16.len() as u32, &sink).unwrap();
-
-        let mut breaks = dw_breaks.borrow_mut();
-        let breaks = &mut *breaks;
-
-        // need a hard break at the end of the text to make sure the last line
-        // of text isn't ignored.
-        // note, this break isn't inserted by the break analyzer, as it inserts
-        // hard breaks on the "after" condition, and there's no character at
-        // text16.len().
-        // note, if the last character in the text is a hard break, this
-        // additional break will cause an empty line to be inserted at the end
-        // of the text.  this is the desired behavior, as this empty line is the
-        // cursor position after that last line break character.
-        breaks.lines.push(text16.len() as u32);
-
-        let mut pspan_index = 0;
-        let mut pspan = pre_spans[0];
-
-        let mut hard_lines = vec![];
-        let mut text_spans = vec![];
-        let mut cursor = 0;
-        for end in breaks.lines.iter() {
-            let line_begin = cursor;
-            let line_end   = *end;
-            let line_len   = line_end - line_begin;
-            cursor = line_end + 1;
-
-            // empty line
-            if line_len == 0 {
-                let text_begin_utf8 = utf16_to_utf8[line_begin as usize];
-                let text_end_utf8   = text_begin_utf8;
-
-                let pos_utf8 = text_begin_utf8;
-                while pos_utf8 >= pspan.text_end_utf8 && pspan_index + 1 < pre_spans.len() {
-                    pspan_index += 1;
-                    pspan = pre_spans[pspan_index];
-                }
-
-                let format = pspan.format;
-
-                let fonts = ctx.fonts.borrow();
-                let family = &fonts.font_data(format.font).dw_family;
-
-                let font_weight = DWRITE_FONT_WEIGHT(format.font_weight as i32);
-                let font_style =
-                    if format.italic { DWRITE_FONT_STYLE_ITALIC }
-                    else             { DWRITE_FONT_STYLE_NORMAL };
-
-                let face = family.GetFirstMatchingFont(font_weight, DWRITE_FONT_STRETCH_NORMAL, font_style).unwrap();
-
-                let mut font_metrics = Default::default();
-                face.GetMetrics(&mut font_metrics);
-                let font_scale = format.font_size / font_metrics.designUnitsPerEm as f32;
-                let ascent = font_scale * font_metrics.ascent as f32;
-                let drop   = font_scale * (font_metrics.descent as f32 + font_metrics.lineGap as f32);
-
-                text_spans.push(TextSpan {
-                    text_begin_utf8, text_end_utf8,
-                    object_index: u32::MAX,
-                    ascent, drop,
-                    format,
-                    .. Default::default()
-                });
-
-                hard_lines.push(text_spans.len() as u32);
-                continue;
-            }
-
-            // reset spans sink.
-            let mut spans = dw_spans.borrow_mut();
-            spans.begin = line_begin;
-            spans.is_rtls.clear();
-            spans.is_rtls.resize(line_len as usize, false);
-            spans.scripts.clear();
-            spans.scripts.resize(line_len as usize, Default::default());
-            drop(spans);
-
-            // compute spans.
-            analyzer.AnalyzeBidi  (&source, line_begin, line_len, &sink).unwrap();
-            analyzer.AnalyzeScript(&source, line_begin, line_len, &sink).unwrap();
-
-            #[derive(Default)]
-            struct RawSpan {
-                text_begin_utf16: u32,
-                text_end_utf16:   u32,
-
-                object_index: u32,
-
-                format: TextFormat,
-                is_rtl: bool,
-                script: DWRITE_SCRIPT_ANALYSIS,
-            }
-
-            let raw_spans = {
-                let spans = dw_spans.borrow();
-
-                let mut result = vec![];
-                let mut span = RawSpan::default();
-
-                for pos in line_begin..line_end {
-                    let i = (pos - line_begin) as usize;
-
-                    let new_format = {
-                        let mut new_format = false;
-                        let pos_utf8 = utf16_to_utf8[pos as usize];
-                        while pos_utf8 >= pspan.text_end_utf8 {
-                            pspan_index += 1;
-                            pspan = pre_spans[pspan_index];
-                            new_format = true;
-                        }
-                        new_format
-                    };
-
-                    let is_rtl = spans.is_rtls[i];
-                    let script = spans.scripts[i];
-
-                    if is_rtl != span.is_rtl
-                    || script.script != span.script.script
-                    || script.shapes != span.script.shapes
-                    || new_format
-                    || i == 0 {
-                        if span.text_begin_utf16 != span.text_end_utf16 {
-                            result.push(span);
-                        }
-                        span = RawSpan {
-                            text_begin_utf16: pos,
-                            text_end_utf16:   pos + 1,
-                            object_index: pspan.object_index,
-                            format: pspan.format,
-                            is_rtl,
-                            script,
-                        };
-                    }
-                    else {
-                        span.text_end_utf16 = pos + 1;
-                    }
-                }
-                if span.text_begin_utf16 != span.text_end_utf16 {
-                    result.push(span);
-                }
-
-                result
-            };
-
-            for raw_span in &raw_spans {
-                // inline object.
-                if raw_span.object_index != u32::MAX {
-                    let text_begin_utf8 = utf16_to_utf8[raw_span.text_begin_utf16 as usize];
-                    let text_end_utf8   = utf16_to_utf8[raw_span.text_end_utf16 as usize];
-
-                    // add break option before & after.
-                    // TODO: maybe don't add one after if next char is whitespace?
-                    breaks.options[text_begin_utf8 as usize / 32] |= 1 << (text_begin_utf8 % 32);
-                    breaks.options[text_end_utf8   as usize / 32] |= 1 << (text_end_utf8   % 32);
-
-                    text_spans.push(TextSpan {
-                        text_begin_utf8, text_end_utf8,
-                        object_index: raw_span.object_index,
-                        .. Default::default()
-                    });
-
-                    continue;
-                }
-
-                let format = raw_span.format;
-                let is_rtl = raw_span.is_rtl;
-                let script = raw_span.script;
-
-                let fonts = ctx.fonts.borrow();
-                let font = fonts.font_name_utf16(format.font);
-                let font_weight = DWRITE_FONT_WEIGHT(format.font_weight as i32);
-                let font_style =
-                    if format.italic { DWRITE_FONT_STYLE_ITALIC }
-                    else             { DWRITE_FONT_STYLE_NORMAL };
-
-                let mut text_cursor = raw_span.text_begin_utf16;
-                while text_cursor < raw_span.text_end_utf16 {
-                    let mut mapped_len = 0;
-                    let mut mapped_font = None;
-                    let mut scale = 0.0; // TODO: use this?
-                    ctx.dw_system_fallback.MapCharacters(
-                        &source,
-                        text_cursor, raw_span.text_end_utf16 - text_cursor,
-                        &ctx.dw_system_fonts,
-                        PCWSTR(font.as_ptr()),
-                        font_weight,
-                        font_style,
-                        DWRITE_FONT_STRETCH_NORMAL,
-                        &mut mapped_len,
-                        Some(&mut mapped_font),
-                        &mut scale).unwrap();
-                    assert!(mapped_len > 0);
-
-                    let cov_begin = text_cursor as usize;
-                    let cov_end   = cov_begin + mapped_len as usize;
-                    text_cursor += mapped_len;
-
-                    let text_begin_utf8 = utf16_to_utf8[cov_begin];
-                    let text_end_utf8   = utf16_to_utf8[cov_end];
-                    let text_utf8_len = text_end_utf8 - text_begin_utf8;
-
-
-                    if mapped_font.is_none() {
-                        continue;
-                    }
-                    let font = mapped_font.unwrap();
-                    let face = font.CreateFontFace().unwrap();
-
-
-                    let string = &text16[cov_begin .. cov_end];
-
-                    let mut cluster_map  = vec![0; string.len()];
-                    let mut text_props = vec![Default::default(); string.len()];
-
-                    let max_len = 3 * string.len() / 2 + 16;
-
-                    let mut glyph_indices = vec![0; max_len];
-                    let mut glyph_props   = vec![Default::default(); max_len];
-
-                    // TODO: loop.
-                    let mut glyph_count = 0;
-                    analyzer.GetGlyphs(
-                        PCWSTR(string.as_ptr()),
-                        string.len() as u32,
-                        &face,
-                        false, is_rtl, &script,
-                        w!("en-us"), // TEMP
-                        None, None, None, 0,
-                        max_len as u32,
-                        cluster_map.as_mut_ptr(),
-                        text_props.as_mut_ptr(),
-                        glyph_indices.as_mut_ptr(),
-                        glyph_props.as_mut_ptr(),
-                        &mut glyph_count).unwrap();
-
-                    glyph_indices.truncate(glyph_count as usize);
-                    glyph_props.truncate(glyph_count as usize);
-
-                    let mut glyph_advances = vec![0.0; glyph_count as usize];
-                    let mut glyph_offsets  = vec![Default::default(); glyph_count as usize];
-                    assert_eq!(core::mem::size_of::<DWRITE_GLYPH_OFFSET>(),
-                               core::mem::size_of::<[f32; 2]>());
-
-                    analyzer.GetGlyphPlacements(
-                        PCWSTR(string.as_ptr()),
-                        cluster_map.as_ptr(),
-                        text_props.as_mut_ptr(),
-                        string.len() as u32,
-                        glyph_indices.as_ptr(),
-                        glyph_props.as_ptr(),
-                        glyph_count,
-                        &face,
-                        format.font_size,
-                        false, is_rtl, &script,
-                        w!("en-us"),
-                        None, None, 0,
-                        glyph_advances.as_mut_ptr(),
-                        glyph_offsets.as_mut_ptr() as *mut DWRITE_GLYPH_OFFSET,
-                    ).unwrap();
-
-
-                    let mut width = 0.0;
-                    for dx in &glyph_advances {
-                        width += dx;
-                    }
-
-                    let mut font_metrics = Default::default();
-                    face.GetMetrics(&mut font_metrics);
-
-                    let font_scale = format.font_size / font_metrics.designUnitsPerEm as f32;
-                    let ascent = font_scale * font_metrics.ascent as f32;
-                    let drop   = font_scale * (font_metrics.descent as f32 + font_metrics.lineGap as f32);
-
-                    // convert utf16 glyph map to utf8.
-                    // replace 1-2 entries with 1-4 entries.
-                    let cluster_map = {
-                        let mut map = Vec::with_capacity(text_utf8_len as usize);
-
-                        let mut cursor = 0;
-                        while cursor < cluster_map.len() {
-                            let at16 = cov_begin + cursor;
-                            let at8  = utf16_to_utf8[at16] as usize;
-
-                            let cp = utf8_next_code_point(&text, at8).unwrap_unchecked().0;
-
-                            let entry = cluster_map[cursor];
-                            for _ in 0..utf8_len(cp) {
-                                map.push(entry);
-                            }
-
-                            cursor += utf16_len(cp);
-                        }
-                        assert_eq!(map.len(), text_utf8_len as usize);
-                        map.push(glyph_indices.len() as u16);
-
-                        map
-                    };
-
-                    text_spans.push(TextSpan {
-                        text_begin_utf8, text_end_utf8,
-                        object_index: u32::MAX,
-                        is_rtl, script,
-                        format,
-                        font_face: Some(face),
-                        width, ascent, drop,
-                        cluster_map,
-                        glyph_indices,
-                        glyph_props,
-                        glyph_advances,
-                        glyph_offsets,
-                    });
-                }
-            }
-
-            hard_lines.push(text_spans.len() as u32);
-        }
-
-        let break_options = core::mem::replace(&mut breaks.options, vec![]);
-
-        return TextLayout {
+        TextLayout {
             ctx,
             text,
             objects,
-            spans: text_spans,
-            hard_lines,
+            spans: spans.spans,
+            hard_lines: spans.hard_lines,
             lines: vec![],
-            break_options,
+            break_options: spans.break_options,
             layout_params: Default::default(),
             size: [0.0; 2],
-        };
-    }}
-}
-
-
-#[windows::core::implement(IDWriteTextAnalysisSource)]
-struct DwSource {
-    string: *const [u16],
-    locale: *const u16,
-}
-
-impl IDWriteTextAnalysisSource_Impl for DwSource {
-    fn GetLocaleName(&self, _pos: u32, _len: *mut u32, locale: *mut *mut u16) -> windows::core::Result<()> {unsafe{
-        *locale = self.locale as *mut _;
-        Ok(())
-    }}
-
-    fn GetNumberSubstitution(&self, _pos: u32, _len: *mut u32, subst: *mut Option<IDWriteNumberSubstitution>) -> windows::core::Result<()> {unsafe{
-        *subst = None;
-        Ok(())
-    }}
-
-    fn GetParagraphReadingDirection(&self) -> DWRITE_READING_DIRECTION {
-        DWRITE_READING_DIRECTION_LEFT_TO_RIGHT
-    }
-
-    fn GetTextAtPosition(&self, pos: u32, text: *mut *mut u16, len: *mut u32) -> windows::core::Result<()> {unsafe{
-        let string = &*self.string;
-        let sub = &string[pos as usize ..];
-        *text = sub.as_ptr() as *mut _;
-        *len  = sub.len() as u32;
-        Ok(())
-    }}
-
-    fn GetTextBeforePosition(&self, pos: u32, text: *mut *mut u16, len: *mut u32) -> windows::core::Result<()> {unsafe{
-        let string = &*self.string;
-        let sub = &string[.. pos as usize];
-        *text = sub.as_ptr() as *mut _;
-        *len  = sub.len() as u32;
-        Ok(())
-    }}
-}
-
-
-#[windows::core::implement(IDWriteTextAnalysisSink)]
-struct DwSink {
-    utf16_to_utf8: *const [u32],
-    breaks: *const RefCell<DwSinkBreaks>,
-    spans:  *const RefCell<DwSinkSpans>,
-}
-
-struct DwSinkBreaks {
-    pointer: u32,
-    options: Vec<u32>,
-    lines:   Vec<u32>,
-}
-
-struct DwSinkSpans {
-    begin: u32,
-    is_rtls: Vec<bool>,
-    scripts: Vec<DWRITE_SCRIPT_ANALYSIS>,
-}
-
-impl DwSinkSpans {
-    fn set_bidi(&mut self, pos: u32, len: u32, is_rtl: bool) {
-        let begin = (pos - self.begin) as usize;
-        self.is_rtls[begin .. begin + len as usize].fill(is_rtl);
-    }
-
-    fn set_script(&mut self, pos: u32, len: u32, script: DWRITE_SCRIPT_ANALYSIS) {
-        let begin = (pos - self.begin) as usize;
-        self.scripts[begin .. begin + len as usize].fill(script);
-    }
-}
-
-impl IDWriteTextAnalysisSink_Impl for DwSink {
-    fn SetLineBreakpoints(&self, pos: u32, len: u32, breaks: *const DWRITE_LINE_BREAKPOINT) -> windows::core::Result<()> {
-        let utf16_to_utf8 = unsafe { &*self.utf16_to_utf8 };
-        let mut this = unsafe { (*self.breaks).borrow_mut() };
-
-        // ensure calls are monotonic.
-        // otherwise `break_lines` won't be sorted.
-        // docs don't guarantee anything.
-        if pos < this.pointer {
-            return Err(windows::Win32::Foundation::E_INVALIDARG.into());
         }
-        this.pointer = pos + len;
-
-        let breaks = unsafe { core::slice::from_raw_parts(breaks, len as usize) };
-        for (i, brk) in breaks.iter().enumerate() {
-            let bits = brk._bitfield;
-            let break_before = DWRITE_BREAK_CONDITION(((bits >> 0) & 0b11) as i32);
-            let break_after  = DWRITE_BREAK_CONDITION(((bits >> 2) & 0b11) as i32);
-
-            // for hard line breaks, we want to exclude the break
-            // character from the line (it shouldn't be rendered).
-            // so the line "end" is the current character position.
-            let is_hard = break_after == DWRITE_BREAK_CONDITION_MUST_BREAK;
-
-            // for soft break options, we want to include the last
-            // character. so the break "end" is the next character.
-            let is_soft = break_before == DWRITE_BREAK_CONDITION_CAN_BREAK;
-
-            if is_hard {
-                this.lines.push(pos + i as u32);
-            }
-            else if is_soft {
-                let at = utf16_to_utf8[pos as usize + i] as usize;
-                let word = at / 32;
-                let bit  = at % 32;
-                this.options[word] |= 1 << bit;
-            }
-        }
-
-        Ok(())
-    }
-
-
-    fn SetBidiLevel(&self, pos: u32, len: u32, _explicit_level: u8, resolved_level: u8) -> windows::core::Result<()> {
-        let mut this = unsafe { (*self.spans).borrow_mut() };
-        this.set_bidi(pos, len, (resolved_level & 1) != 0);
-        Ok(())
-    }
-
-    fn SetScriptAnalysis(&self, pos: u32, len: u32, script: *const DWRITE_SCRIPT_ANALYSIS) -> windows::core::Result<()> {
-        let mut this = unsafe { (*self.spans).borrow_mut() };
-        this.set_script(pos, len, unsafe { *script });
-        Ok(())
-    }
-
-
-    fn SetNumberSubstitution(&self, _pos: u32, _len: u32, _subst: &Option<IDWriteNumberSubstitution>) -> windows::core::Result<()> {
-        return Err(windows::Win32::Foundation::E_NOTIMPL.into());
     }
 }
 
@@ -1471,7 +1109,7 @@ impl BreakIter {
             }
         }
 
-        return self.end;
+        self.end
     }
 }
 
@@ -1561,10 +1199,10 @@ impl LineBreaker {
         }
         text_cursor = next_break;
 
-        return Some(BreakSegment {
+        Some(BreakSegment {
             text_cursor, span_cursor, cluster_cursor,
             line_width, span_width, width: seg_width
-        });
+        })
     }
 
     fn add_to_line(&mut self, segment: BreakSegment) {
@@ -1665,6 +1303,14 @@ impl LineBreaker {
         }
 
 
+        // L2: visual order.
+        // TODO: L1 for the trailing whitespace of soft lines.
+        let levels: Vec<Level> = spans.iter().map(|span| tl.spans[span.span_index as usize].bidi_level).collect();
+        let mut spans: Vec<Option<VisualSpan>> = spans.into_iter().map(Some).collect();
+        let spans: Vec<VisualSpan> = bidi::visual_order(&levels).into_iter()
+            .map(|i| spans[i].take().unwrap())
+            .collect();
+
         let width    = self.segment.line_width;
         let height   = max_ascent + max_drop;
         let baseline = max_ascent;
@@ -3,6 +3,13 @@
 
 pub mod layout;
 pub mod fonts;
+pub mod font_file;
+pub mod shaping;
+
+#[cfg(windows)]
+mod dwrite;
+mod native;
 
 pub use layout::*;
 pub use fonts::*;
+pub use font_file::*;
@@ -0,0 +1,169 @@
+// text analysis without platform apis.
+// parses font files directly, see `font_file` & `shaping`.
+
+use crate::ctx::*;
+use crate::text::{TextSpan, PreSpan, TextAnalysis, TextFormat, FontFace, Breaks, analyze_breaks, analyze_bidi};
+use crate::text::shaping::*;
+use crate::unicode::bidi::{self, Level};
+
+
+pub(crate) fn analyze(ctx: &Ctx, text: &[u8], pre_spans: &[PreSpan]) -> TextAnalysis {
+    let Breaks { lines, mut options } = analyze_breaks(text);
+    let mut set_break = |at: usize| {
+        if at < text.len() {
+            options[at / 32] |= 1 << (at % 32);
+        }
+    };
+
+    let mut spans = vec![];
+    let mut hard_lines = vec![];
+
+    let mut pspan_index = 0;
+    for (line_begin, line_end) in lines {
+        // empty line.
+        if line_begin == line_end {
+            while line_begin >= pre_spans[pspan_index].text_end_utf8 && pspan_index + 1 < pre_spans.len() {
+                pspan_index += 1;
+            }
+
+            let format = pre_spans[pspan_index].format;
+            let face = face_for(ctx, format);
+            let (ascent, drop) = vertical_metrics(face.as_ref(), format);
+            spans.push(TextSpan {
+                text_begin_utf8: line_begin,
+                text_end_utf8:   line_begin,
+                object_index: u32::MAX,
+                ascent, drop,
+                format,
+                .. Default::default()
+            });
+        }
+
+        let line = core::str::from_utf8(&text[line_begin as usize .. line_end as usize]).unwrap();
+        let levels = analyze_bidi(line);
+        let level_at = |at: u32| levels[(at - line_begin) as usize];
+
+        // one span per pre span & bidi level.
+        let mut cursor = line_begin;
+        while cursor < line_end {
+            while cursor >= pre_spans[pspan_index].text_end_utf8 {
+                pspan_index += 1;
+            }
+            let pspan = pre_spans[pspan_index];
+
+            let begin = cursor;
+            let level = level_at(begin);
+            let end = (begin..pspan.text_end_utf8.min(line_end))
+                .find(|at| level_at(*at) != level)
+                .unwrap_or(pspan.text_end_utf8.min(line_end));
+            cursor = end;
+
+            if pspan.object_index != u32::MAX {
+                set_break(begin as usize);
+                set_break(end   as usize);
+
+                spans.push(TextSpan {
+                    text_begin_utf8: begin,
+                    text_end_utf8:   end,
+                    object_index: pspan.object_index,
+                    bidi_level: level,
+                    .. Default::default()
+                });
+                continue;
+            }
+
+            // one span per fallback face.
+            let text = core::str::from_utf8(&text[begin as usize .. end as usize]).unwrap();
+            let runs = ctx.fonts.borrow_mut().fallback_runs(text, &pspan.format);
+
+            let mut run_begin = 0;
+            for (run_end, face) in runs {
+                // no face has the glyphs, use the format's.
+                let face = face.or_else(|| face_for(ctx, pspan.format));
+
+                let run = &text[run_begin..run_end];
+                spans.push(shape_span(run, begin + run_begin as u32, pspan.format, level, face));
+                run_begin = run_end;
+            }
+        }
+
+        hard_lines.push(spans.len() as u32);
+    }
+
+    TextAnalysis { spans, hard_lines, break_options: options }
+}
+
+
+fn face_for(ctx: &Ctx, format: TextFormat) -> Option<FontFace> {
+    ctx.fonts.borrow_mut().face(format.font, format.font_weight, format.italic)
+}
+
+/// ascent & drop in pixels.
+fn vertical_metrics(face: Option<&FontFace>, format: TextFormat) -> (f32, f32) {
+    match face {
+        Some(face) => {
+            let m = &face.metrics;
+            let scale = format.font_size / m.units_per_em as f32;
+            (scale * m.ascent as f32, scale * (m.descent as f32 + m.line_gap as f32))
+        }
+
+        None => (0.8 * format.font_size, 0.2 * format.font_size),
+    }
+}
+
+/// shapes a run of text with a single format, bidi level & face.
+fn shape_span(text: &str, text_begin_utf8: u32, format: TextFormat, bidi_level: Level, face: Option<FontFace>) -> TextSpan {
+    let is_rtl = bidi::is_rtl(bidi_level);
+    let (ascent, drop) = vertical_metrics(face.as_ref(), format);
+
+    let mut span = TextSpan {
+        text_begin_utf8,
+        text_end_utf8: text_begin_utf8 + text.len() as u32,
+        object_index: u32::MAX,
+        bidi_level,
+        ascent, drop,
+        format,
+        cluster_map: vec![0; text.len() + 1],
+        .. Default::default()
+    };
+
+    // no font, no glyphs.
+    let Some(file) = face.as_ref().and_then(|face| face.file.as_ref()) else {
+        span.font_face = face;
+        return span;
+    };
+
+    let chars: Vec<char> = text.chars().map(|c| bidi::mirrored(c, bidi_level)).collect();
+    let glyphs = shape(file, &chars, is_rtl);
+
+    // first glyph of each cluster, by char index.
+    let mut cluster_glyphs = vec![u16::MAX; chars.len()];
+    for (i, glyph) in glyphs.iter().enumerate().rev() {
+        cluster_glyphs[glyph.cluster as usize] = i as u16;
+    }
+
+    // chars merged into a previous cluster map to its first glyph.
+    let mut glyph = 0;
+    for ((at, c), cluster_glyph) in text.char_indices().zip(&cluster_glyphs) {
+        if *cluster_glyph != u16::MAX {
+            glyph = *cluster_glyph;
+        }
+        span.cluster_map[at .. at + c.len_utf8()].fill(glyph);
+    }
+    span.cluster_map[text.len()] = glyphs.len() as u16;
+
+    // font units to pixels.
+    // offsets are in advance direction, like DirectWrite's.
+    let scale = format.font_size / file.units_per_em() as f32;
+    let direction = if is_rtl { -1.0 } else { 1.0 };
+    for glyph in &glyphs {
+        let advance = scale * glyph.advance as f32;
+        span.glyph_indices.push(glyph.glyph);
+        span.glyph_advances.push(advance);
+        span.glyph_offsets.push([direction * scale * glyph.offset[0] as f32, scale * glyph.offset[1] as f32]);
+        span.width += advance;
+    }
+
+    span.font_face = face;
+    span
+}
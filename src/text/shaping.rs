@@ -0,0 +1,950 @@
+// OpenType shaping.
+// maps chars to glyphs (cmap), applies GSUB substitutions and GPOS
+// positioning (or the legacy `kern` table).
+//
+// TODO: complex scripts (arabic joining, indic reordering), cursive
+//  attachment, mark filtering sets, device tables.
+
+use crate::text::font_file::*;
+
+
+/// a shaped glyph. metrics are in font units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapedGlyph {
+    pub glyph: u16,
+
+    /// index of the first char of the glyph's cluster.
+    pub cluster: u32,
+
+    pub advance: i32,
+
+    /// `[x, y]`, y up.
+    /// x is in visual direction (positive is right, even for rtl runs).
+    pub offset: [i32; 2],
+}
+
+
+/// shapes a run of text with a single font & direction.
+/// glyphs are returned in logical order, with non-decreasing clusters.
+/// chars the font can't map get glyph 0 (`.notdef`).
+pub fn shape(font: &FontFile, chars: &[char], is_rtl: bool) -> Vec<ShapedGlyph> {
+    let gdef = Gdef::new(font.table(*b"GDEF"));
+
+    let mut buffer: Vec<Glyph> = Vec::with_capacity(chars.len());
+    for (i, c) in chars.iter().enumerate() {
+        let glyph = font.glyph_index(*c as u32).unwrap_or(0);
+
+        // keep combining marks with their base.
+        let cluster =
+            if i > 0 && (is_combining(*c) || gdef.glyph_class(glyph) == CLASS_MARK) {
+                buffer[i - 1].cluster
+            }
+            else { i as u32 };
+
+        buffer.push(Glyph { glyph, cluster, ..Default::default() });
+    }
+
+    let script = script_tag(chars);
+
+    // substitution.
+    if let Some(gsub) = font.table(*b"GSUB") {
+        let features = [*b"ccmp", *b"locl", *b"rlig", *b"liga", *b"clig", *b"calt"];
+        let table = LayoutTable { data: gsub, gdef: &gdef, is_gpos: false };
+        for lookup in table.lookups(script, &features) {
+            table.apply_lookup(lookup, &mut buffer);
+        }
+    }
+
+    // positioning.
+    for g in &mut buffer {
+        g.advance = font.glyph_advance(g.glyph) as i32;
+    }
+
+    let mut has_kern = false;
+    if let Some(gpos) = font.table(*b"GPOS") {
+        // marks are positioned relative to their base.
+        if gdef.has_classes() {
+            for g in &mut buffer {
+                if gdef.glyph_class(g.glyph) == CLASS_MARK {
+                    g.advance = 0;
+                }
+            }
+        }
+
+        let table = LayoutTable { data: gpos, gdef: &gdef, is_gpos: true };
+        has_kern = !table.lookups(script, &[*b"kern"]).is_empty();
+
+        let features = [*b"kern", *b"mark", *b"mkmk", *b"dist"];
+        for lookup in table.lookups(script, &features) {
+            table.apply_lookup(lookup, &mut buffer);
+        }
+    }
+
+    if !has_kern {
+        if let Some(kern) = font.table(*b"kern") {
+            apply_kern_table(kern, &mut buffer);
+        }
+    }
+
+    resolve_attachments(&mut buffer, is_rtl);
+
+    buffer.iter().map(|g| ShapedGlyph {
+        glyph:   g.glyph,
+        cluster: g.cluster,
+        advance: g.advance,
+        offset:  g.offset,
+    }).collect()
+}
+
+
+// TODO: use the general category, once we have unicode tables for it.
+fn is_combining(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036f | 0x0483..=0x0489 | 0x0591..=0x05bd | 0x05bf | 0x05c1..=0x05c2
+        | 0x05c4..=0x05c5 | 0x05c7 | 0x0610..=0x061a | 0x064b..=0x065f | 0x0670
+        | 0x06d6..=0x06dc | 0x06df..=0x06e4 | 0x06e7..=0x06e8 | 0x06ea..=0x06ed
+        | 0x0e31 | 0x0e34..=0x0e3a | 0x0e47..=0x0e4e
+        | 0x1ab0..=0x1aff | 0x1dc0..=0x1dff | 0x200c..=0x200d | 0x20d0..=0x20ff
+        | 0xfe00..=0xfe0f | 0xfe20..=0xfe2f
+        | 0x1f3fb..=0x1f3ff | 0xe0100..=0xe01ef)
+}
+
+/// the OpenType script tag of the first char with a distinctive script.
+fn script_tag(chars: &[char]) -> Tag {
+    for c in chars {
+        let tag = match *c as u32 {
+            0x0041..=0x005a | 0x0061..=0x007a | 0x00c0..=0x024f | 0x1e00..=0x1eff => b"latn",
+            0x0370..=0x03ff | 0x1f00..=0x1fff => b"grek",
+            0x0400..=0x052f => b"cyrl",
+            0x0590..=0x05ff | 0xfb1d..=0xfb4f => b"hebr",
+            0x0600..=0x06ff | 0x0750..=0x077f | 0xfb50..=0xfdff | 0xfe70..=0xfeff => b"arab",
+            0x0900..=0x097f => b"deva",
+            0x0e00..=0x0e7f => b"thai",
+            0x3040..=0x30ff => b"kana",
+            0xac00..=0xd7af | 0x1100..=0x11ff => b"hang",
+            0x4e00..=0x9fff | 0x3400..=0x4dbf => b"hani",
+            _ => continue,
+        };
+        return *tag;
+    }
+    *b"DFLT"
+}
+
+
+
+// BUFFER
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Glyph {
+    glyph:   u16,
+    cluster: u32,
+
+    advance: i32,
+    offset:  [i32; 2],
+
+    // mark attachment: base index & anchor delta (base anchor - mark anchor).
+    attach: Option<(usize, [i32; 2])>,
+}
+
+/// turns mark attachments into offsets.
+fn resolve_attachments(buffer: &mut [Glyph], is_rtl: bool) {
+    for i in 0..buffer.len() {
+        let Some((base, delta)) = buffer[i].attach else { continue };
+        if base >= i {
+            continue;
+        }
+
+        // distance from the base's origin to the mark's origin,
+        // in visual direction.
+        let mut distance = 0;
+        for g in &buffer[base..i] {
+            distance += g.advance;
+        }
+
+        let base_offset = buffer[base].offset;
+        let x =
+            if !is_rtl { base_offset[0] + delta[0] - distance }
+            // rtl: the mark's origin is to the left of the base's origin.
+            else { base_offset[0] + delta[0] + distance - buffer[base].advance + buffer[i].advance };
+
+        buffer[i].offset = [x, base_offset[1] + delta[1]];
+    }
+}
+
+
+
+// COMMON TABLES
+
+const CLASS_BASE:      u16 = 1;
+const CLASS_LIGATURE:  u16 = 2;
+const CLASS_MARK:      u16 = 3;
+
+/// index of `glyph` in a coverage table.
+fn coverage(data: &[u8], glyph: u16) -> Option<u16> {
+    match read_u16(data, 0)? {
+        1 => {
+            let count = read_u16(data, 2)? as usize;
+            let (mut lo, mut hi) = (0, count);
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                let g = read_u16(data, 4 + 2*mid)?;
+                if      g < glyph { lo = mid + 1 }
+                else if g > glyph { hi = mid }
+                else { return Some(mid as u16) }
+            }
+            None
+        }
+
+        2 => {
+            let count = read_u16(data, 2)? as usize;
+            let (mut lo, mut hi) = (0, count);
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                let range = 4 + 6*mid;
+                let start = read_u16(data, range)?;
+                let end   = read_u16(data, range + 2)?;
+                if      end   < glyph { lo = mid + 1 }
+                else if start > glyph { hi = mid }
+                else { return Some(read_u16(data, range + 4)? + (glyph - start)) }
+            }
+            None
+        }
+
+        _ => None,
+    }
+}
+
+/// class of `glyph` in a class definition table. 0 if not listed.
+fn class_of(data: &[u8], glyph: u16) -> u16 {
+    fn get(data: &[u8], glyph: u16) -> Option<u16> {
+        match read_u16(data, 0)? {
+            1 => {
+                let start = read_u16(data, 2)?;
+                let count = read_u16(data, 4)?;
+                if glyph < start || glyph - start >= count {
+                    return None;
+                }
+                read_u16(data, 6 + 2*(glyph - start) as usize)
+            }
+
+            2 => {
+                let count = read_u16(data, 2)? as usize;
+                let (mut lo, mut hi) = (0, count);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    let range = 4 + 6*mid;
+                    let start = read_u16(data, range)?;
+                    let end   = read_u16(data, range + 2)?;
+                    if      end   < glyph { lo = mid + 1 }
+                    else if start > glyph { hi = mid }
+                    else { return read_u16(data, range + 4) }
+                }
+                None
+            }
+
+            _ => None,
+        }
+    }
+    get(data, glyph).unwrap_or(0)
+}
+
+/// sub-slice at a 16 bit offset stored at `data[at]`.
+#[inline]
+fn offset16(data: &[u8], at: usize) -> Option<&[u8]> {
+    let offset = read_u16(data, at)? as usize;
+    if offset == 0 {
+        return None;
+    }
+    data.get(offset..)
+}
+
+
+struct Gdef<'a> {
+    glyph_classes: Option<&'a [u8]>,
+    mark_classes:  Option<&'a [u8]>,
+}
+
+impl<'a> Gdef<'a> {
+    fn new(data: Option<&'a [u8]>) -> Gdef<'a> {
+        Gdef {
+            glyph_classes: data.and_then(|data| offset16(data, 4)),
+            mark_classes:  data.and_then(|data| offset16(data, 10)),
+        }
+    }
+
+    #[inline]
+    fn has_classes(&self) -> bool {
+        self.glyph_classes.is_some()
+    }
+
+    #[inline]
+    fn glyph_class(&self, glyph: u16) -> u16 {
+        self.glyph_classes.map(|data| class_of(data, glyph)).unwrap_or(0)
+    }
+
+    #[inline]
+    fn mark_class(&self, glyph: u16) -> u16 {
+        self.mark_classes.map(|data| class_of(data, glyph)).unwrap_or(0)
+    }
+}
+
+
+
+// GSUB & GPOS
+
+// nested lookups (through contexts).
+const MAX_NESTING: u32 = 8;
+
+const IGNORE_BASE_GLYPHS: u16 = 0x0002;
+const IGNORE_LIGATURES:   u16 = 0x0004;
+const IGNORE_MARKS:       u16 = 0x0008;
+const MARK_ATTACHMENT_TYPE_MASK: u16 = 0xff00;
+
+struct LayoutTable<'a> {
+    data: &'a [u8],
+    gdef: &'a Gdef<'a>,
+    is_gpos: bool,
+}
+
+/// how context rules match glyphs.
+#[derive(Clone, Copy)]
+enum Matcher<'a> {
+    Glyph,
+    Class(&'a [u8]),
+    // values are offsets to coverage tables, relative to the subtable.
+    Coverage(&'a [u8]),
+}
+
+impl<'a> Matcher<'a> {
+    #[inline]
+    fn matches(&self, glyph: u16, value: u16) -> bool {
+        match self {
+            Matcher::Glyph => glyph == value,
+            Matcher::Class(class_def) => class_of(class_def, glyph) == value,
+            Matcher::Coverage(subtable) =>
+                subtable.get(value as usize ..)
+                .and_then(|cov| coverage(cov, glyph))
+                .is_some(),
+        }
+    }
+}
+
+/// a list of `count` u16 values at `data[offset..]`.
+#[derive(Clone, Copy)]
+struct Values<'a> {
+    data:   &'a [u8],
+    offset: usize,
+    count:  usize,
+}
+
+impl<'a> Values<'a> {
+    #[inline]
+    fn get(&self, i: usize) -> u16 {
+        read_u16(self.data, self.offset + 2*i).unwrap_or(0)
+    }
+}
+
+impl<'a> LayoutTable<'a> {
+    /// the lookups for `features`, sorted by lookup index.
+    fn lookups(&self, script: Tag, features: &[Tag]) -> Vec<u16> {
+        self.lookups_ex(script, features).unwrap_or_default()
+    }
+
+    fn lookups_ex(&self, script: Tag, features: &[Tag]) -> Option<Vec<u16>> {
+        let scripts      = offset16(self.data, 4)?;
+        let feature_list = offset16(self.data, 6)?;
+
+        let find_script = |tag: Tag| -> Option<&[u8]> {
+            let count = read_u16(scripts, 0)? as usize;
+            for i in 0..count {
+                let record = 2 + 6*i;
+                if scripts.get(record .. record + 4)? == tag {
+                    return offset16(scripts, record + 4);
+                }
+            }
+            None
+        };
+        let script = find_script(script)
+            .or_else(|| find_script(*b"DFLT"))
+            .or_else(|| find_script(*b"latn"))?;
+
+        // TODO: languages.
+        let lang_sys = offset16(script, 0)?;
+
+        let mut result = vec![];
+        let required = read_u16(lang_sys, 2)?;
+        let count    = read_u16(lang_sys, 4)? as usize;
+        let indices = (0..count).filter_map(|i| read_u16(lang_sys, 6 + 2*i))
+            .chain((required != 0xffff).then_some(required));
+
+        for index in indices {
+            let record = 2 + 6*index as usize;
+            let tag = feature_list.get(record .. record + 4)?;
+            if !features.iter().any(|f| f == tag) {
+                continue;
+            }
+
+            let feature = offset16(feature_list, record + 4)?;
+            let lookup_count = read_u16(feature, 2)? as usize;
+            for i in 0..lookup_count {
+                result.push(read_u16(feature, 4 + 2*i)?);
+            }
+        }
+
+        result.sort_unstable();
+        result.dedup();
+        Some(result)
+    }
+
+    fn lookup(&self, index: u16) -> Option<&'a [u8]> {
+        let lookups = offset16(self.data, 8)?;
+        offset16(lookups, 2 + 2*index as usize)
+    }
+
+    /// applies a lookup to the entire buffer.
+    fn apply_lookup(&self, index: u16, buffer: &mut Vec<Glyph>) {
+        let Some(lookup) = self.lookup(index) else { return };
+        let flag = read_u16(lookup, 2).unwrap_or(0);
+
+        let mut i = 0;
+        while i < buffer.len() {
+            if self.skip(buffer[i].glyph, flag) {
+                i += 1;
+                continue;
+            }
+
+            match self.apply_subtables(lookup, i, buffer, 0) {
+                Some(next) => i = next.max(i + 1),
+                None       => i += 1,
+            }
+        }
+    }
+
+    /// applies a lookup at a single position (from a context rule).
+    fn apply_lookup_at(&self, index: u16, at: usize, buffer: &mut Vec<Glyph>, depth: u32) -> bool {
+        if depth > MAX_NESTING || at >= buffer.len() {
+            return false;
+        }
+        let Some(lookup) = self.lookup(index) else { return false };
+        let flag = read_u16(lookup, 2).unwrap_or(0);
+        if self.skip(buffer[at].glyph, flag) {
+            return false;
+        }
+        self.apply_subtables(lookup, at, buffer, depth).is_some()
+    }
+
+    /// tries the lookup's subtables in order.
+    /// returns the index to continue at, if one applied.
+    fn apply_subtables(&self, lookup: &[u8], at: usize, buffer: &mut Vec<Glyph>, depth: u32) -> Option<usize> {
+        let kind  = read_u16(lookup, 0)?;
+        let flag  = read_u16(lookup, 2)?;
+        let count = read_u16(lookup, 4)? as usize;
+
+        for i in 0..count {
+            let Some(mut subtable) = offset16(lookup, 6 + 2*i) else { continue };
+
+            // extension subtables.
+            let mut kind = kind;
+            let extension = if self.is_gpos { 9 } else { 7 };
+            if kind == extension {
+                kind = read_u16(subtable, 2)?;
+                let offset = read_u32(subtable, 4)? as usize;
+                subtable = subtable.get(offset..)?;
+            }
+
+            let result =
+                if self.is_gpos { self.apply_gpos(kind, flag, subtable, at, buffer, depth) }
+                else            { self.apply_gsub(kind, flag, subtable, at, buffer, depth) };
+            if result.is_some() {
+                return result;
+            }
+        }
+        None
+    }
+
+    fn skip(&self, glyph: u16, flag: u16) -> bool {
+        let class = self.gdef.glyph_class(glyph);
+        match class {
+            CLASS_BASE     => flag & IGNORE_BASE_GLYPHS != 0,
+            CLASS_LIGATURE => flag & IGNORE_LIGATURES   != 0,
+            CLASS_MARK => {
+                if flag & IGNORE_MARKS != 0 {
+                    return true;
+                }
+                let attach_type = (flag & MARK_ATTACHMENT_TYPE_MASK) >> 8;
+                attach_type != 0 && self.gdef.mark_class(glyph) != attach_type
+            }
+            _ => false,
+        }
+    }
+
+    fn next(&self, buffer: &[Glyph], at: usize, flag: u16) -> Option<usize> {
+        (at + 1 .. buffer.len()).find(|j| !self.skip(buffer[*j].glyph, flag))
+    }
+
+    fn prev(&self, buffer: &[Glyph], at: usize, flag: u16) -> Option<usize> {
+        (0..at).rev().find(|j| !self.skip(buffer[*j].glyph, flag))
+    }
+
+    /// matches `input` (excluding the first glyph, which is at `at`),
+    /// and optionally backtrack & lookahead.
+    /// returns the positions of the input glyphs.
+    #[allow(clippy::too_many_arguments)]
+    fn match_context(&self, buffer: &[Glyph], at: usize, flag: u16,
+        backtrack: (Matcher, Values),
+        input:     (Matcher, Values),
+        lookahead: (Matcher, Values),
+    ) -> Option<Vec<usize>> {
+        let mut positions = vec![at];
+
+        let mut j = at;
+        for k in 0..input.1.count {
+            j = self.next(buffer, j, flag)?;
+            if !input.0.matches(buffer[j].glyph, input.1.get(k)) {
+                return None;
+            }
+            positions.push(j);
+        }
+
+        let mut j = *positions.last().unwrap();
+        for k in 0..lookahead.1.count {
+            j = self.next(buffer, j, flag)?;
+            if !lookahead.0.matches(buffer[j].glyph, lookahead.1.get(k)) {
+                return None;
+            }
+        }
+
+        let mut j = at;
+        for k in 0..backtrack.1.count {
+            j = self.prev(buffer, j, flag)?;
+            if !backtrack.0.matches(buffer[j].glyph, backtrack.1.get(k)) {
+                return None;
+            }
+        }
+
+        Some(positions)
+    }
+
+    /// applies the sequence lookup records of a matched context.
+    fn apply_sequence(&self, records: Values, mut positions: Vec<usize>, buffer: &mut Vec<Glyph>, depth: u32) -> usize {
+        for k in 0..records.count / 2 {
+            let seq_index    = records.get(2*k) as usize;
+            let lookup_index = records.get(2*k + 1);
+            let Some(&at) = positions.get(seq_index) else { continue };
+
+            let old_len = buffer.len();
+            self.apply_lookup_at(lookup_index, at, buffer, depth + 1);
+
+            // keep later positions in sync with insertions & deletions.
+            let delta = buffer.len() as isize - old_len as isize;
+            if delta != 0 {
+                for p in positions.iter_mut().skip(seq_index + 1) {
+                    *p = (*p as isize + delta).max(at as isize) as usize;
+                }
+            }
+        }
+        positions.last().unwrap() + 1
+    }
+
+    /// contextual & chained contextual lookups.
+    /// the formats are the same for GSUB & GPOS.
+    fn apply_context(&self, chained: bool, flag: u16, st: &[u8], at: usize, buffer: &mut Vec<Glyph>, depth: u32) -> Option<usize> {
+        let glyph = buffer[at].glyph;
+        let none = Values { data: st, offset: 0, count: 0 };
+
+        match read_u16(st, 0)? {
+            // glyph & class based rules.
+            format @ (1 | 2) => {
+                let cov = offset16(st, 2)?;
+                let cov_index = coverage(cov, glyph)?;
+
+                let (sets, set_index, matchers) =
+                    if format == 1 {
+                        (4, cov_index, [Matcher::Glyph; 3])
+                    }
+                    else if !chained {
+                        let input = offset16(st, 4)?;
+                        (6, class_of(input, glyph), [Matcher::Glyph, Matcher::Class(input), Matcher::Glyph])
+                    }
+                    else {
+                        let backtrack = offset16(st, 4).unwrap_or(&[]);
+                        let input     = offset16(st, 6)?;
+                        let lookahead = offset16(st, 8).unwrap_or(&[]);
+                        (10, class_of(input, glyph), [Matcher::Class(backtrack), Matcher::Class(input), Matcher::Class(lookahead)])
+                    };
+
+                let set_count = read_u16(st, sets)?;
+                if set_index >= set_count {
+                    return None;
+                }
+                let set = offset16(st, sets + 2 + 2*set_index as usize)?;
+
+                let rule_count = read_u16(set, 0)? as usize;
+                for r in 0..rule_count {
+                    let Some(rule) = offset16(set, 2 + 2*r) else { continue };
+
+                    let (backtrack, input, lookahead, records);
+                    if !chained {
+                        let input_count  = read_u16(rule, 0)? as usize;
+                        let record_count = read_u16(rule, 2)? as usize;
+                        backtrack = none;
+                        lookahead = none;
+                        input   = Values { data: rule, offset: 4, count: input_count.saturating_sub(1) };
+                        records = Values { data: rule, offset: 4 + 2*input.count, count: 2*record_count };
+                    }
+                    else {
+                        let mut cursor = 0;
+                        let backtrack_count = read_u16(rule, cursor)? as usize;
+                        backtrack = Values { data: rule, offset: cursor + 2, count: backtrack_count };
+                        cursor += 2 + 2*backtrack_count;
+
+                        let input_count = read_u16(rule, cursor)? as usize;
+                        input = Values { data: rule, offset: cursor + 2, count: input_count.saturating_sub(1) };
+                        cursor += 2 + 2*input.count;
+
+                        let lookahead_count = read_u16(rule, cursor)? as usize;
+                        lookahead = Values { data: rule, offset: cursor + 2, count: lookahead_count };
+                        cursor += 2 + 2*lookahead_count;
+
+                        let record_count = read_u16(rule, cursor)? as usize;
+                        records = Values { data: rule, offset: cursor + 2, count: 2*record_count };
+                    }
+
+                    let positions = self.match_context(buffer, at, flag,
+                        (matchers[0], backtrack),
+                        (matchers[1], input),
+                        (matchers[2], lookahead));
+                    if let Some(positions) = positions {
+                        return Some(self.apply_sequence(records, positions, buffer, depth));
+                    }
+                }
+                None
+            }
+
+            // coverage based.
+            3 => {
+                let (backtrack, input, lookahead, records);
+                if !chained {
+                    let input_count  = read_u16(st, 2)? as usize;
+                    let record_count = read_u16(st, 4)? as usize;
+                    backtrack = none;
+                    lookahead = none;
+                    input   = Values { data: st, offset: 6, count: input_count };
+                    records = Values { data: st, offset: 6 + 2*input_count, count: 2*record_count };
+                }
+                else {
+                    let mut cursor = 2;
+                    let backtrack_count = read_u16(st, cursor)? as usize;
+                    backtrack = Values { data: st, offset: cursor + 2, count: backtrack_count };
+                    cursor += 2 + 2*backtrack_count;
+
+                    let input_count = read_u16(st, cursor)? as usize;
+                    input = Values { data: st, offset: cursor + 2, count: input_count };
+                    cursor += 2 + 2*input_count;
+
+                    let lookahead_count = read_u16(st, cursor)? as usize;
+                    lookahead = Values { data: st, offset: cursor + 2, count: lookahead_count };
+                    cursor += 2 + 2*lookahead_count;
+
+                    let record_count = read_u16(st, cursor)? as usize;
+                    records = Values { data: st, offset: cursor + 2, count: 2*record_count };
+                }
+
+                if input.count == 0 || !Matcher::Coverage(st).matches(glyph, input.get(0)) {
+                    return None;
+                }
+                let rest = Values { offset: input.offset + 2, count: input.count - 1, ..input };
+
+                let positions = self.match_context(buffer, at, flag,
+                    (Matcher::Coverage(st), backtrack),
+                    (Matcher::Coverage(st), rest),
+                    (Matcher::Coverage(st), lookahead))?;
+                Some(self.apply_sequence(records, positions, buffer, depth))
+            }
+
+            _ => None,
+        }
+    }
+
+
+    // GSUB
+
+    fn apply_gsub(&self, kind: u16, flag: u16, st: &[u8], at: usize, buffer: &mut Vec<Glyph>, depth: u32) -> Option<usize> {
+        let glyph = buffer[at].glyph;
+
+        match kind {
+            // single.
+            1 => {
+                let index = coverage(offset16(st, 2)?, glyph)?;
+                buffer[at].glyph = match read_u16(st, 0)? {
+                    1 => glyph.wrapping_add(read_u16(st, 4)?),
+                    2 => read_u16(st, 6 + 2*index as usize)?,
+                    _ => return None,
+                };
+                Some(at + 1)
+            }
+
+            // multiple & alternate.
+            2 | 3 => {
+                let index = coverage(offset16(st, 2)?, glyph)?;
+                let sequence = offset16(st, 6 + 2*index as usize)?;
+                let count = read_u16(sequence, 0)? as usize;
+
+                // alternates: always pick the first.
+                let count = if kind == 3 { count.min(1) } else { count };
+
+                let template = buffer[at];
+                let glyphs: Vec<Glyph> = (0..count)
+                    .filter_map(|i| read_u16(sequence, 2 + 2*i))
+                    .map(|glyph| Glyph { glyph, ..template })
+                    .collect();
+                let len = glyphs.len();
+                buffer.splice(at .. at + 1, glyphs);
+                Some(at + len)
+            }
+
+            // ligature.
+            4 => {
+                let index = coverage(offset16(st, 2)?, glyph)?;
+                let set = offset16(st, 6 + 2*index as usize)?;
+                let count = read_u16(set, 0)? as usize;
+
+                for i in 0..count {
+                    let Some(lig) = offset16(set, 2 + 2*i) else { continue };
+                    let lig_glyph  = read_u16(lig, 0)?;
+                    let components = read_u16(lig, 2)? as usize;
+
+                    let input = Values { data: lig, offset: 4, count: components.saturating_sub(1) };
+                    let none  = Values { data: lig, offset: 0, count: 0 };
+                    let Some(positions) = self.match_context(buffer, at, flag,
+                        (Matcher::Glyph, none), (Matcher::Glyph, input), (Matcher::Glyph, none))
+                    else { continue };
+
+                    // skipped glyphs (marks) in between join the cluster.
+                    let cluster = buffer[at].cluster;
+                    let last = *positions.last().unwrap();
+                    for g in &mut buffer[at ..= last] {
+                        g.cluster = cluster;
+                    }
+
+                    buffer[at].glyph = lig_glyph;
+                    for p in positions[1..].iter().rev() {
+                        buffer.remove(*p);
+                    }
+                    return Some(at + 1);
+                }
+                None
+            }
+
+            5 => self.apply_context(false, flag, st, at, buffer, depth),
+            6 => self.apply_context(true,  flag, st, at, buffer, depth),
+
+            // TODO: reverse chaining.
+            _ => None,
+        }
+    }
+
+
+    // GPOS
+
+    fn apply_gpos(&self, kind: u16, flag: u16, st: &[u8], at: usize, buffer: &mut Vec<Glyph>, depth: u32) -> Option<usize> {
+        let glyph = buffer[at].glyph;
+
+        match kind {
+            // single adjustment.
+            1 => {
+                let index = coverage(offset16(st, 2)?, glyph)?;
+                let format = read_u16(st, 4)?;
+                let value = match read_u16(st, 0)? {
+                    1 => 6,
+                    2 => 8 + index as usize * value_size(format),
+                    _ => return None,
+                };
+                apply_value(st, value, format, &mut buffer[at]);
+                Some(at + 1)
+            }
+
+            // pair adjustment.
+            2 => {
+                let first = coverage(offset16(st, 2)?, glyph)?;
+                let format1 = read_u16(st, 4)?;
+                let format2 = read_u16(st, 6)?;
+                let size1 = value_size(format1);
+                let size2 = value_size(format2);
+
+                let second = self.next(buffer, at, flag)?;
+                let glyph2 = buffer[second].glyph;
+
+                let record = match read_u16(st, 0)? {
+                    1 => {
+                        let set = offset16(st, 10 + 2*first as usize)?;
+                        let count = read_u16(set, 0)? as usize;
+                        let record_size = 2 + size1 + size2;
+
+                        let (mut lo, mut hi) = (0, count);
+                        let mut found = None;
+                        while lo < hi {
+                            let mid = (lo + hi) / 2;
+                            let record = 2 + mid*record_size;
+                            let g = read_u16(set, record)?;
+                            if      g < glyph2 { lo = mid + 1 }
+                            else if g > glyph2 { hi = mid }
+                            else { found = Some(record + 2); break }
+                        }
+                        (set, found?)
+                    }
+
+                    2 => {
+                        let class1 = class_of(offset16(st, 8)?,  glyph)  as usize;
+                        let class2 = class_of(offset16(st, 10)?, glyph2) as usize;
+                        let class1_count = read_u16(st, 12)? as usize;
+                        let class2_count = read_u16(st, 14)? as usize;
+                        if class1 >= class1_count || class2 >= class2_count {
+                            return None;
+                        }
+                        (st, 16 + (class1*class2_count + class2)*(size1 + size2))
+                    }
+
+                    _ => return None,
+                };
+
+                let (data, offset) = record;
+                apply_value(data, offset,         format1, &mut buffer[at]);
+                apply_value(data, offset + size1, format2, &mut buffer[second]);
+
+                if format2 != 0 { Some(second + 1) }
+                else            { Some(at + 1) }
+            }
+
+            // mark to base, mark to ligature, mark to mark.
+            4..=6 => {
+                let mark_index = coverage(offset16(st, 2)?, glyph)?;
+                let class_count = read_u16(st, 6)? as usize;
+                let mark_array = offset16(st, 8)?;
+                let base_array = offset16(st, 10)?;
+
+                // find the glyph to attach to.
+                let base =
+                    if kind == 6 {
+                        let prev = self.prev(buffer, at, flag)?;
+                        if self.gdef.glyph_class(buffer[prev].glyph) != CLASS_MARK {
+                            return None;
+                        }
+                        prev
+                    }
+                    else {
+                        (0..at).rev().find(|j| self.gdef.glyph_class(buffer[*j].glyph) != CLASS_MARK)?
+                    };
+                let base_index = coverage(offset16(st, 4)?, buffer[base].glyph)? as usize;
+
+                let mark_record = 2 + 4*mark_index as usize;
+                let mark_class  = read_u16(mark_array, mark_record)? as usize;
+                let mark_anchor = anchor(offset16(mark_array, mark_record + 2)?)?;
+                if mark_class >= class_count {
+                    return None;
+                }
+
+                let base_anchor =
+                    if kind == 5 {
+                        // TODO: attach to the right component.
+                        let attach = offset16(base_array, 2 + 2*base_index)?;
+                        let components = read_u16(attach, 0)? as usize;
+                        if components == 0 {
+                            return None;
+                        }
+                        let record = 2 + 2*((components - 1)*class_count + mark_class);
+                        anchor(offset16(attach, record)?)?
+                    }
+                    else {
+                        let record = 2 + 2*(base_index*class_count + mark_class);
+                        anchor(offset16(base_array, record)?)?
+                    };
+
+                let delta = [base_anchor[0] - mark_anchor[0], base_anchor[1] - mark_anchor[1]];
+                buffer[at].attach = Some((base, delta));
+                Some(at + 1)
+            }
+
+            7 => self.apply_context(false, flag, st, at, buffer, depth),
+            8 => self.apply_context(true,  flag, st, at, buffer, depth),
+
+            // TODO: cursive attachment.
+            _ => None,
+        }
+    }
+}
+
+
+#[inline]
+fn value_size(format: u16) -> usize {
+    2 * (format & 0xff).count_ones() as usize
+}
+
+/// applies a value record. device tables are ignored.
+fn apply_value(data: &[u8], offset: usize, format: u16, glyph: &mut Glyph) {
+    let mut cursor = offset;
+    let mut next = |bit: u16| -> i32 {
+        if format & bit == 0 {
+            return 0;
+        }
+        cursor += 2;
+        read_i16(data, cursor - 2).unwrap_or(0) as i32
+    };
+
+    let x_placement = next(0x1);
+    let y_placement = next(0x2);
+    let x_advance   = next(0x4);
+
+    glyph.offset[0] += x_placement;
+    glyph.offset[1] += y_placement;
+    glyph.advance   += x_advance;
+}
+
+fn anchor(data: &[u8]) -> Option<[i32; 2]> {
+    Some([read_i16(data, 2)? as i32, read_i16(data, 4)? as i32])
+}
+
+
+/// the legacy `kern` table (format 0 subtables).
+fn apply_kern_table(kern: &[u8], buffer: &mut [Glyph]) {
+    fn pair_value(subtable: &[u8], left: u16, right: u16) -> Option<i16> {
+        let count = read_u16(subtable, 6)? as usize;
+        let key = (left as u32) << 16 | right as u32;
+
+        let (mut lo, mut hi) = (0, count);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let pair = 14 + 6*mid;
+            let k = read_u32(subtable, pair)?;
+            if      k < key { lo = mid + 1 }
+            else if k > key { hi = mid }
+            else { return read_i16(subtable, pair + 4) }
+        }
+        None
+    }
+
+    let Some(version) = read_u16(kern, 0) else { return };
+    if version != 0 {
+        return;
+    }
+    let count = read_u16(kern, 2).unwrap_or(0);
+
+    let mut offset = 4;
+    for _ in 0..count {
+        let Some(subtable) = kern.get(offset..) else { return };
+        let Some(len) = read_u16(subtable, 2) else { return };
+        let Some(coverage) = read_u16(subtable, 4) else { return };
+        offset += len as usize;
+
+        // horizontal, format 0, no cross-stream / minimum values.
+        if coverage & 0xff07 != 0x0001 {
+            continue;
+        }
+
+        for i in 1..buffer.len() {
+            if let Some(value) = pair_value(subtable, buffer[i - 1].glyph, buffer[i].glyph) {
+                buffer[i - 1].advance += value as i32;
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::Instant;
+
+use crate::gui::Gui;
+
+
+/// see `Gui::set_timeout` & `Gui::set_interval`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TimerId (pub u32);
+
+/// see `Gui::request_animation_frame`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AnimationFrameId (pub u32);
+
+
+pub(crate) type TimerCallback = Box<dyn FnMut(&mut Gui)>;
+pub(crate) type FrameCallback = Box<dyn FnOnce(&mut Gui, f64)>;
+
+pub(crate) struct Timer {
+    pub id:       TimerId,
+    /// in seconds, on the gui's clock.
+    pub due:      f64,
+    pub interval: Option<f64>,
+    // taken while the callback runs.
+    pub callback: Option<TimerCallback>,
+}
+
+#[derive(Default)]
+pub(crate) struct Timers {
+    pub now:     f64,
+    pub next_id: u32,
+    pub timers:  Vec<Timer>,
+    pub frames:  Vec<(AnimationFrameId, FrameCallback)>,
+}
+
+impl Timers {
+    pub fn alloc_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// the index of the earliest timer, that's due by `time`,
+    /// ignoring the timers created since `next_id` was `first_new`.
+    /// ties run in creation order.
+    pub fn next_due(&self, time: f64, first_new: u32) -> Option<usize> {
+        self.timers.iter().enumerate()
+            .filter(|(_, timer)| timer.callback.is_some() && timer.due <= time && timer.id.0 < first_new)
+            .min_by(|(_, a), (_, b)| a.due.total_cmp(&b.due).then(a.id.0.cmp(&b.id.0)))
+            .map(|(i, _)| i)
+    }
+}
+
+
+/// animation frames run at about 60 fps in the native message loops.
+pub(crate) const FRAME_TIME: f64 = 1.0 / 60.0;
+
+/// drives a `Gui`'s timers & animation frames from a native message loop.
+pub(crate) struct LoopClock {
+    last:       Instant,
+    last_frame: Option<Instant>,
+}
+
+impl LoopClock {
+    pub fn new() -> LoopClock {
+        LoopClock { last: Instant::now(), last_frame: None }
+    }
+
+    /// how long the loop may block, in seconds.
+    pub fn timeout(&self, gui: &Gui) -> Option<f64> {
+        let timeout = gui.time_to_next_timer();
+        if gui.has_animation_frames() {
+            let since_frame = self.last_frame.map_or(FRAME_TIME, |at| at.elapsed().as_secs_f64());
+            let frame = (FRAME_TIME - since_frame).max(0.0);
+            return Some(timeout.map_or(frame, |t| t.min(frame)));
+        }
+        timeout
+    }
+
+    /// runs the due timers & the animation frames,
+    /// if a frame time has passed since the last ones.
+    /// returns whether any callbacks ran.
+    pub fn tick(&mut self, gui: &mut Gui) -> bool {
+        let now = Instant::now();
+        let mut ran = gui.advance_timers((now - self.last).as_secs_f64(), true);
+        self.last = now;
+
+        let frame_due = self.last_frame.is_none_or(|at| (now - at).as_secs_f64() >= FRAME_TIME);
+        if frame_due && gui.has_animation_frames() {
+            ran |= gui.run_animation_frames();
+            self.last_frame = Some(now);
+        }
+        ran
+    }
+}
@@ -0,0 +1,555 @@
+// bidirectional text, UAX #9 (unicode 17).
+// implements the rules for a single paragraph, no higher-level protocols.
+// https://www.unicode.org/reports/tr9/
+
+use super::*;
+use super::BidiClass::*;
+
+
+/// an embedding level. odd levels are right-to-left.
+pub type Level = u8;
+
+/// the maximum explicit embedding depth (BD2).
+pub const MAX_DEPTH: Level = 125;
+
+#[inline]
+pub fn is_rtl(level: Level) -> bool {
+    level % 2 == 1
+}
+
+
+/// the paragraph embedding level of `chars` (P2, P3).
+/// `0` if the paragraph contains no strong characters.
+pub fn paragraph_level(chars: &[char]) -> Level {
+    let classes: Vec<BidiClass> = chars.iter().map(|c| bidi_class(*c)).collect();
+    first_strong(&classes).map(|class| (class != L) as Level).unwrap_or(0)
+}
+
+/// the resolved embedding levels of a paragraph (X1 - I2).
+/// chars removed by X9 get the level of the preceding char.
+/// the line based rule L1 is applied separately, see `reset_line_levels`.
+pub fn resolve_levels(chars: &[char], paragraph_level: Level) -> Vec<Level> {
+    let original: Vec<BidiClass> = chars.iter().map(|c| bidi_class(*c)).collect();
+    let matching_pdis = matching_pdis(&original);
+
+    let (mut classes, mut levels) = explicit_levels(&original, &matching_pdis, paragraph_level);
+
+    let explicit = levels.clone();
+    for sequence in isolating_run_sequences(&original, &explicit, &matching_pdis) {
+        resolve_sequence(chars, &original, &explicit, &mut classes, &mut levels, &sequence, paragraph_level);
+    }
+
+    // removed chars.
+    let mut prev_level = paragraph_level;
+    for i in 0..chars.len() {
+        if is_removed(original[i]) {
+            levels[i] = prev_level;
+        }
+        prev_level = levels[i];
+    }
+
+    levels
+}
+
+/// resets the levels of separators & trailing whitespace of a line
+/// to the paragraph level (L1).
+pub fn reset_line_levels(chars: &[char], levels: &mut [Level], paragraph_level: Level) {
+    let mut reset = true;
+    for i in (0..chars.len()).rev() {
+        match bidi_class(chars[i]) {
+            S | B => {
+                levels[i] = paragraph_level;
+                reset = true;
+            }
+
+            WS | FSI | LRI | RLI | PDI | BN | LRE | RLE | LRO | RLO | PDF => {
+                if reset {
+                    levels[i] = paragraph_level;
+                }
+            }
+
+            _ => reset = false,
+        }
+    }
+}
+
+/// the logical indices of a line's chars (or runs) in visual order,
+/// left to right (L2).
+pub fn visual_order(levels: &[Level]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+
+    let Some(max) = levels.iter().copied().max() else { return order };
+    let Some(min_odd) = levels.iter().copied().filter(|l| is_rtl(*l)).min() else { return order };
+
+    for level in (min_odd..=max).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+
+            let mut end = i + 1;
+            while end < order.len() && levels[order[end]] >= level {
+                end += 1;
+            }
+            order[i..end].reverse();
+            i = end;
+        }
+    }
+
+    order
+}
+
+/// the char to display for `c` at `level` (L4).
+#[inline]
+pub fn mirrored(c: char, level: Level) -> char {
+    if is_rtl(level) { bidi_mirror(c).unwrap_or(c) }
+    else             { c }
+}
+
+/// whether X9 removes chars of `class`.
+#[inline]
+pub fn is_removed(class: BidiClass) -> bool {
+    matches!(class, RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+
+
+// EXPLICIT LEVELS
+
+#[inline]
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(class, LRI | RLI | FSI)
+}
+
+// P2: the first strong class, skipping isolates.
+fn first_strong(classes: &[BidiClass]) -> Option<BidiClass> {
+    let mut depth = 0usize;
+    for class in classes.iter().copied() {
+        match class {
+            LRI | RLI | FSI => depth += 1,
+            PDI => depth = depth.saturating_sub(1),
+            L | R | AL if depth == 0 => return Some(class),
+            _ => (),
+        }
+    }
+    None
+}
+
+// BD9: the matching PDI of each isolate initiator.
+fn matching_pdis(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut result = vec![None; classes.len()];
+    let mut open = vec![];
+    for (i, class) in classes.iter().copied().enumerate() {
+        match class {
+            LRI | RLI | FSI => open.push(i),
+            PDI => {
+                if let Some(initiator) = open.pop() {
+                    result[initiator] = Some(i);
+                }
+            }
+            B => open.clear(),
+            _ => (),
+        }
+    }
+    result
+}
+
+#[derive(Clone, Copy)]
+struct Status {
+    level:    Level,
+    override_class: Option<BidiClass>,
+    isolate:  bool,
+}
+
+// X1 - X8.
+fn explicit_levels(original: &[BidiClass], matching_pdis: &[Option<usize>], paragraph_level: Level)
+    -> (Vec<BidiClass>, Vec<Level>)
+{
+    let mut classes = original.to_vec();
+    let mut levels  = vec![paragraph_level; original.len()];
+
+    let mut stack = vec![Status { level: paragraph_level, override_class: None, isolate: false }];
+    let mut overflow_isolates   = 0;
+    let mut overflow_embeddings = 0;
+    let mut valid_isolates      = 0;
+
+    let next_level = |level: Level, rtl: bool| {
+        if rtl { (level + 1) | 1 }
+        else   { (level + 2) & !1 }
+    };
+
+    for i in 0..original.len() {
+        let top = *stack.last().unwrap();
+
+        match original[i] {
+            // X2 - X5.
+            RLE | LRE | RLO | LRO => {
+                levels[i] = top.level;
+
+                let level = next_level(top.level, matches!(original[i], RLE | RLO));
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    let override_class = match original[i] {
+                        RLO => Some(R),
+                        LRO => Some(L),
+                        _   => None,
+                    };
+                    stack.push(Status { level, override_class, isolate: false });
+                }
+                else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+
+            // X5a - X5c.
+            RLI | LRI | FSI => {
+                levels[i] = top.level;
+                if let Some(class) = top.override_class {
+                    classes[i] = class;
+                }
+
+                let rtl = match original[i] {
+                    RLI => true,
+                    LRI => false,
+                    _ => {
+                        let end = matching_pdis[i].unwrap_or(original.len());
+                        first_strong(&original[i+1..end]).map(|class| class != L).unwrap_or(false)
+                    }
+                };
+
+                let level = next_level(top.level, rtl);
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status { level, override_class: None, isolate: true });
+                }
+                else {
+                    overflow_isolates += 1;
+                }
+            }
+
+            // X6a.
+            PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                }
+                else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while !stack.last().unwrap().isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+
+                let top = *stack.last().unwrap();
+                levels[i] = top.level;
+                if let Some(class) = top.override_class {
+                    classes[i] = class;
+                }
+            }
+
+            // X7.
+            PDF => {
+                levels[i] = top.level;
+
+                if overflow_isolates > 0 {
+                }
+                else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                }
+                else if !top.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+
+            // X8.
+            B => levels[i] = paragraph_level,
+
+            BN => levels[i] = top.level,
+
+            // X6.
+            _ => {
+                levels[i] = top.level;
+                if let Some(class) = top.override_class {
+                    classes[i] = class;
+                }
+            }
+        }
+    }
+
+    (classes, levels)
+}
+
+// X9, X10: the isolating run sequences, as indices of chars not removed by X9.
+fn isolating_run_sequences(original: &[BidiClass], levels: &[Level], matching_pdis: &[Option<usize>]) -> Vec<Vec<usize>> {
+    // BD7: level runs.
+    let mut runs: Vec<Vec<usize>> = vec![];
+    let mut run_of = vec![usize::MAX; original.len()];
+    let mut prev_level = None;
+    for i in 0..original.len() {
+        if is_removed(original[i]) {
+            continue;
+        }
+
+        if prev_level != Some(levels[i]) {
+            runs.push(vec![]);
+            prev_level = Some(levels[i]);
+        }
+        runs.last_mut().unwrap().push(i);
+        run_of[i] = runs.len() - 1;
+    }
+
+    let mut is_matched_pdi = vec![false; original.len()];
+    for pdi in matching_pdis.iter().flatten() {
+        is_matched_pdi[*pdi] = true;
+    }
+
+    // BD13.
+    let mut sequences = vec![];
+    for run in &runs {
+        if is_matched_pdi[run[0]] {
+            continue;
+        }
+
+        let mut sequence = run.clone();
+        loop {
+            let last = *sequence.last().unwrap();
+            if !is_isolate_initiator(original[last]) {
+                break;
+            }
+            let Some(pdi) = matching_pdis[last] else { break };
+            sequence.extend_from_slice(&runs[run_of[pdi]]);
+        }
+        sequences.push(sequence);
+    }
+
+    sequences
+}
+
+
+
+// IMPLICIT LEVELS
+
+#[inline]
+fn direction(level: Level) -> BidiClass {
+    if is_rtl(level) { R } else { L }
+}
+
+// the strong direction of a resolved class for N0 - N2.
+#[inline]
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+#[inline]
+fn is_neutral_or_isolate(class: BidiClass) -> bool {
+    matches!(class, B | S | WS | ON | LRI | RLI | FSI | PDI)
+}
+
+// BD16 compares canonical equivalents.
+#[inline]
+fn canonical_bracket(c: char) -> char {
+    match c {
+        '\u{2329}' => '\u{3008}',
+        '\u{232a}' => '\u{3009}',
+        c => c,
+    }
+}
+
+// BD16: the bracket pairs of a sequence, sorted by opening position.
+fn bracket_pairs(chars: &[char], sequence: &[usize], types: &[BidiClass]) -> Vec<(usize, usize)> {
+    const MAX_OPEN: usize = 63;
+
+    let mut pairs = vec![];
+    let mut open: Vec<(char, usize)> = vec![];
+    for (k, i) in sequence.iter().copied().enumerate() {
+        if types[k] != ON {
+            continue;
+        }
+        let Some((pair, is_open)) = bidi_bracket(chars[i]) else { continue };
+
+        if is_open {
+            if open.len() == MAX_OPEN {
+                break;
+            }
+            open.push((canonical_bracket(pair), k));
+        }
+        else {
+            let c = canonical_bracket(chars[i]);
+            if let Some(at) = open.iter().rposition(|(closing, _)| *closing == c) {
+                pairs.push((open[at].1, k));
+                open.truncate(at);
+            }
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+// W1 - I2 for one isolating run sequence.
+fn resolve_sequence(chars: &[char], original: &[BidiClass], explicit: &[Level],
+    classes: &mut [BidiClass], levels: &mut [Level], sequence: &[usize], paragraph_level: Level)
+{
+    let len   = sequence.len();
+    let level = explicit[sequence[0]];
+    let embedding = direction(level);
+
+    // X10: sos & eos.
+    let first = sequence[0];
+    let last  = sequence[len - 1];
+    let prev_level = (0..first).rev()
+        .find(|i| !is_removed(original[*i]))
+        .map(|i| explicit[i])
+        .unwrap_or(paragraph_level);
+    let next_level =
+        if is_isolate_initiator(original[last]) { paragraph_level }
+        else {
+            (last + 1..original.len())
+                .find(|i| !is_removed(original[*i]))
+                .map(|i| explicit[i])
+                .unwrap_or(paragraph_level)
+        };
+    let sos = direction(level.max(prev_level));
+    let eos = direction(level.max(next_level));
+
+    let mut types: Vec<BidiClass> = sequence.iter().map(|i| classes[*i]).collect();
+
+    // W1.
+    for k in 0..len {
+        if types[k] == NSM {
+            types[k] =
+                if k == 0 { sos }
+                else if matches!(types[k - 1], LRI | RLI | FSI | PDI) { ON }
+                else { types[k - 1] };
+        }
+    }
+
+    // W2, W3.
+    let mut last_strong = sos;
+    for t in types.iter_mut() {
+        match *t {
+            L | R => last_strong = *t,
+            AL => {
+                last_strong = AL;
+                *t = R;
+            }
+            EN if last_strong == AL => *t = AN,
+            _ => (),
+        }
+    }
+
+    // W4.
+    for k in 1..len.saturating_sub(1) {
+        let (before, after) = (types[k - 1], types[k + 1]);
+        match types[k] {
+            ES if before == EN && after == EN => types[k] = EN,
+            CS if before == after && matches!(before, EN | AN) => types[k] = before,
+            _ => (),
+        }
+    }
+
+    // W5.
+    let mut k = 0;
+    while k < len {
+        if types[k] != ET {
+            k += 1;
+            continue;
+        }
+
+        let mut end = k + 1;
+        while end < len && types[end] == ET {
+            end += 1;
+        }
+        if (k > 0 && types[k - 1] == EN) || (end < len && types[end] == EN) {
+            types[k..end].fill(EN);
+        }
+        k = end;
+    }
+
+    // W6.
+    for t in types.iter_mut() {
+        if matches!(*t, ES | ET | CS) {
+            *t = ON;
+        }
+    }
+
+    // W7.
+    let mut last_strong = sos;
+    for t in types.iter_mut() {
+        match *t {
+            L | R => last_strong = *t,
+            EN if last_strong == L => *t = L,
+            _ => (),
+        }
+    }
+
+    // N0.
+    for (open, close) in bracket_pairs(chars, sequence, &types) {
+        let mut found_embedding = false;
+        let mut found_opposite  = false;
+        for t in &types[open + 1 .. close] {
+            match strong_direction(*t) {
+                Some(d) if d == embedding => found_embedding = true,
+                Some(_) => found_opposite = true,
+                None => (),
+            }
+        }
+
+        let resolved =
+            if found_embedding { embedding }
+            else if found_opposite {
+                let context = types[..open].iter().rev()
+                    .find_map(|t| strong_direction(*t))
+                    .unwrap_or(sos);
+                if context != embedding { context } else { embedding }
+            }
+            else { continue };
+
+        for bracket in [open, close] {
+            types[bracket] = resolved;
+            for k in bracket + 1 .. len {
+                if original[sequence[k]] != NSM {
+                    break;
+                }
+                types[k] = resolved;
+            }
+        }
+    }
+
+    // N1, N2.
+    let mut k = 0;
+    while k < len {
+        if !is_neutral_or_isolate(types[k]) {
+            k += 1;
+            continue;
+        }
+
+        let mut end = k + 1;
+        while end < len && is_neutral_or_isolate(types[end]) {
+            end += 1;
+        }
+
+        let before = if k == 0   { Some(sos) } else { strong_direction(types[k - 1]) };
+        let after  = if end == len { Some(eos) } else { strong_direction(types[end]) };
+        let resolved = if before == after { before.unwrap_or(embedding) } else { embedding };
+        types[k..end].fill(resolved);
+        k = end;
+    }
+
+    // I1, I2.
+    for (k, i) in sequence.iter().copied().enumerate() {
+        classes[i] = types[k];
+        levels[i] += match (is_rtl(level), types[k]) {
+            (false, R)       => 1,
+            (false, AN | EN) => 2,
+            (true,  L | EN | AN) => 1,
+            _ => 0,
+        };
+    }
+}
@@ -0,0 +1,291 @@
+// line breaking, UAX #14 (unicode 17).
+// implements the default rules, no tailoring.
+// https://www.unicode.org/reports/tr14/
+
+use super::*;
+use super::LineBreak::*;
+
+
+/// a line break opportunity before `text[offset..]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Break {
+    pub offset:    usize,
+    pub mandatory: bool,
+}
+
+
+/// the line break opportunities of `text`, sorted by offset.
+/// includes the mandatory break at the end of the text (LB3),
+/// but not the one at the start.
+pub fn line_breaks(text: &str) -> Vec<Break> {
+    let mut result = vec![];
+    if text.is_empty() {
+        return result;
+    }
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+
+    // LB9 & LB10: combining marks become part of the previous unit.
+    let mut units: Vec<Unit> = Vec::with_capacity(chars.len());
+    let mut prev_char = None;
+    for (offset, c) in chars.iter().copied() {
+        let class = resolve_class(c);
+
+        if matches!(class, CM | ZWJ) {
+            let absorbs = units.last()
+                .map(|unit| !matches!(unit.class, BK | CR | LF | NL | SP | ZW))
+                .unwrap_or(false);
+            if absorbs {
+                prev_char = Some(c);
+                continue;
+            }
+        }
+
+        let class = if matches!(class, CM | ZWJ) { AL } else { class };
+        units.push(Unit { class, c, offset, after_zwj: prev_char == Some('\u{200d}') });
+        prev_char = Some(c);
+    }
+
+    let rules = Rules { units: &units };
+    for (k, unit) in units.iter().enumerate().skip(1) {
+        match rules.decide(k) {
+            Decision::Mandatory => result.push(Break { offset: unit.offset, mandatory: true }),
+            Decision::Allowed   => result.push(Break { offset: unit.offset, mandatory: false }),
+            Decision::Prohibited => (),
+        }
+    }
+
+    // LB3.
+    result.push(Break { offset: text.len(), mandatory: true });
+    result
+}
+
+
+// LB1.
+fn resolve_class(c: char) -> LineBreak {
+    match line_break(c) {
+        AI | SG | XX => AL,
+        SA => {
+            if matches!(general_category(c), GeneralCategory::Mn | GeneralCategory::Mc) { CM }
+            else { AL }
+        }
+        CJ => NS,
+        class => class,
+    }
+}
+
+
+#[derive(Clone, Copy, Debug)]
+struct Unit {
+    class:  LineBreak,
+    // the unit's first char.
+    c:      char,
+    offset: usize,
+    // the char before the unit is a ZWJ (LB8a).
+    after_zwj: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Decision {
+    Mandatory,
+    Allowed,
+    Prohibited,
+}
+
+struct Rules<'a> {
+    units: &'a [Unit],
+}
+
+impl<'a> Rules<'a> {
+    #[inline]
+    fn class(&self, k: usize) -> Option<LineBreak> {
+        self.units.get(k).map(|u| u.class)
+    }
+
+    #[inline]
+    fn is(&self, k: usize, classes: &[LineBreak]) -> bool {
+        self.class(k).map(|c| classes.contains(&c)).unwrap_or(false)
+    }
+
+    /// the last unit before `k` that isn't a space.
+    fn before_spaces(&self, k: usize) -> Option<usize> {
+        (0..k).rev().find(|j| self.units[*j].class != SP)
+    }
+
+    #[inline]
+    fn category(&self, k: usize) -> GeneralCategory {
+        general_category(self.units[k].c)
+    }
+
+    #[inline]
+    fn east_asian(&self, k: usize) -> bool {
+        is_east_asian_wide(self.units[k].c)
+    }
+
+    // LB28a: AK, AS & dotted circle.
+    #[inline]
+    fn aksara(&self, k: usize) -> bool {
+        self.is(k, &[AK, AS]) || self.units.get(k).map(|u| u.c == '\u{25cc}').unwrap_or(false)
+    }
+
+    /// the break between units `k - 1` and `k`.
+    fn decide(&self, k: usize) -> Decision {
+        use Decision::*;
+
+        let before = self.units[k - 1].class;
+        let after  = self.units[k].class;
+        let next   = self.class(k + 1);
+        let before_spaces = self.before_spaces(k).map(|j| self.units[j].class);
+
+        // LB4, LB5.
+        if before == BK { return Mandatory }
+        if before == CR && after == LF { return Prohibited }
+        if matches!(before, CR | LF | NL) { return Mandatory }
+
+        // LB6, LB7.
+        if matches!(after, BK | CR | LF | NL | SP | ZW) { return Prohibited }
+
+        // LB8, LB8a.
+        if before_spaces == Some(ZW) { return Allowed }
+        if self.units[k].after_zwj { return Prohibited }
+
+        // LB11, LB12, LB12a.
+        if before == WJ || after == WJ { return Prohibited }
+        if before == GL { return Prohibited }
+        if after == GL && !matches!(before, SP | BA | HY | HH) { return Prohibited }
+
+        // LB13, LB14.
+        if matches!(after, CL | CP | EX | SY) { return Prohibited }
+        if before_spaces == Some(OP) { return Prohibited }
+
+        // LB15a.
+        if let Some(j) = self.before_spaces(k) {
+            if self.units[j].class == QU && self.category(j) == GeneralCategory::Pi
+            && (j == 0 || self.is(j - 1, &[BK, CR, LF, NL, OP, QU, GL, SP, ZW])) {
+                return Prohibited;
+            }
+        }
+
+        // LB15b.
+        if after == QU && self.category(k) == GeneralCategory::Pf
+        && (next.is_none() || self.is(k + 1, &[SP, GL, WJ, CL, QU, CP, EX, IS, SY, BK, CR, LF, NL, ZW])) {
+            return Prohibited;
+        }
+
+        // LB15c, LB15d.
+        if before == SP && after == IS && next == Some(NU) { return Allowed }
+        if after == IS { return Prohibited }
+
+        // LB16, LB17.
+        if after == NS && matches!(before_spaces, Some(CL | CP)) { return Prohibited }
+        if after == B2 && before_spaces == Some(B2) { return Prohibited }
+
+        // LB18.
+        if before == SP { return Allowed }
+
+        // LB19.
+        if after  == QU && self.category(k)     != GeneralCategory::Pi { return Prohibited }
+        if before == QU && self.category(k - 1) != GeneralCategory::Pf { return Prohibited }
+
+        // LB19a.
+        if after == QU {
+            if !self.east_asian(k - 1) { return Prohibited }
+            if next.is_none() || !self.east_asian(k + 1) { return Prohibited }
+        }
+        if before == QU {
+            if !self.east_asian(k) { return Prohibited }
+            if k < 2 || !self.east_asian(k - 2) { return Prohibited }
+        }
+
+        // LB20.
+        if before == CB || after == CB { return Allowed }
+
+        // LB20a.
+        if matches!(before, HY | HH) && matches!(after, AL | HL)
+        && (k < 2 || self.is(k - 2, &[BK, CR, LF, NL, SP, ZW, CB, GL])) {
+            return Prohibited;
+        }
+
+        // LB21, LB21a, LB21b.
+        if matches!(after, BA | HH | HY | NS) || before == BB { return Prohibited }
+        if k >= 2 && self.is(k - 2, &[HL]) && matches!(before, HY | HH) && after != HL { return Prohibited }
+        if before == SY && after == HL { return Prohibited }
+
+        // LB22.
+        if after == IN { return Prohibited }
+
+        // LB23, LB23a, LB24.
+        if matches!(before, AL | HL) && after == NU { return Prohibited }
+        if before == NU && matches!(after, AL | HL) { return Prohibited }
+        if before == PR && matches!(after, ID | EB | EM) { return Prohibited }
+        if matches!(before, ID | EB | EM) && after == PO { return Prohibited }
+        if matches!(before, PR | PO) && matches!(after, AL | HL) { return Prohibited }
+        if matches!(before, AL | HL) && matches!(after, PR | PO) { return Prohibited }
+
+        // LB25.
+        if matches!(after, PO | PR | NU) {
+            // NU (SY | IS)* (CL | CP)? × (PO | PR)
+            // NU (SY | IS)* × NU
+            let mut j = k - 1;
+            if after != NU && matches!(self.units[j].class, CL | CP) && j > 0 {
+                j -= 1;
+            }
+            while matches!(self.units[j].class, SY | IS) && j > 0 {
+                j -= 1;
+            }
+            if self.units[j].class == NU { return Prohibited }
+        }
+        if matches!(before, PO | PR) {
+            if after == NU { return Prohibited }
+            if after == OP {
+                if next == Some(NU) { return Prohibited }
+                if next == Some(IS) && self.class(k + 2) == Some(NU) { return Prohibited }
+            }
+        }
+        if matches!(before, HY | IS) && after == NU { return Prohibited }
+
+        // LB26, LB27.
+        if before == JL && matches!(after, JL | JV | H2 | H3) { return Prohibited }
+        if matches!(before, JV | H2) && matches!(after, JV | JT) { return Prohibited }
+        if matches!(before, JT | H3) && after == JT { return Prohibited }
+        if matches!(before, JL | JV | JT | H2 | H3) && after == PO { return Prohibited }
+        if before == PR && matches!(after, JL | JV | JT | H2 | H3) { return Prohibited }
+
+        // LB28.
+        if matches!(before, AL | HL) && matches!(after, AL | HL) { return Prohibited }
+
+        // LB28a.
+        if before == AP && self.aksara(k) { return Prohibited }
+        if self.aksara(k - 1) && matches!(after, VF | VI) { return Prohibited }
+        if before == VI && k >= 2 && self.aksara(k - 2)
+        && (after == AK || self.units[k].c == '\u{25cc}') {
+            return Prohibited;
+        }
+        if self.aksara(k - 1) && self.aksara(k) && next == Some(VF) { return Prohibited }
+
+        // LB29.
+        if before == IS && matches!(after, AL | HL) { return Prohibited }
+
+        // LB30.
+        if matches!(before, AL | HL | NU) && after == OP && !self.east_asian(k) { return Prohibited }
+        if before == CP && !self.east_asian(k - 1) && matches!(after, AL | HL | NU) { return Prohibited }
+
+        // LB30a: pairs of regional indicators.
+        if before == RI && after == RI {
+            let count = self.units[..k].iter().rev().take_while(|u| u.class == RI).count();
+            if count % 2 == 1 { return Prohibited }
+        }
+
+        // LB30b.
+        if after == EM {
+            if before == EB { return Prohibited }
+            let c = self.units[k - 1].c;
+            if is_extended_pictographic(c) && general_category(c) == GeneralCategory::Cn {
+                return Prohibited;
+            }
+        }
+
+        // LB31.
+        Allowed
+    }
+}
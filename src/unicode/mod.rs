@@ -1,3 +1,10 @@
+pub mod line_break;
+pub mod bidi;
+mod tables;
+
+
+
+// UTF-8 & UTF-16
 // taken from the nightly stdlib.
 // modified to be useful.
 
@@ -96,7 +103,7 @@ pub const fn utf16_len(cp: u32) -> usize {
 
 
 /// Encodes cp as utf16 into buffer, returning whether cp requires two u16s.
-/// # Safety:
+/// # Safety
 ///   - requires `buffer.len() >= 2`
 #[inline]
 pub unsafe fn utf16_encode(mut cp: u32, buffer: &mut [u16]) -> bool {
@@ -113,3 +120,98 @@ pub unsafe fn utf16_encode(mut cp: u32, buffer: &mut [u16]) -> bool {
     }
 }
 
+
+
+// PROPERTIES
+// see tools/unicode_tables.py.
+
+/// the `Line_Break` property (UAX #14).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineBreak {
+    AI, AK, AL, AP, AS, B2, BA, BB, BK, CB, CJ, CL, CM, CP, CR, EB, EM, EX,
+    GL, H2, H3, HH, HL, HY, ID, IN, IS, JL, JT, JV, LF, NL, NS, NU, OP, PO,
+    PR, QU, RI, SA, SG, SP, SY, VF, VI, WJ, XX, ZW, ZWJ,
+}
+
+/// the `General_Category` property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneralCategory {
+    Lu, Ll, Lt, Lm, Lo,
+    Mn, Mc, Me,
+    Nd, Nl, No,
+    Pc, Pd, Ps, Pe, Pi, Pf, Po,
+    Sm, Sc, Sk, So,
+    Zs, Zl, Zp,
+    Cc, Cf, Cs, Co, Cn,
+}
+
+/// the `Bidi_Class` property (UAX #9).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BidiClass {
+    L, R, AL,
+    EN, ES, ET, AN, CS, NSM, BN,
+    B, S, WS, ON,
+    LRE, LRO, RLE, RLO, PDF, LRI, RLI, FSI, PDI,
+}
+
+impl GeneralCategory {
+    #[inline]
+    pub fn is_mark(self) -> bool {
+        matches!(self, GeneralCategory::Mn | GeneralCategory::Mc | GeneralCategory::Me)
+    }
+}
+
+
+fn lookup<T: Copy>(table: &[(u32, u32, T)], cp: u32) -> Option<T> {
+    let i = table.partition_point(|(_, last, _)| *last < cp);
+    let (first, _, value) = *table.get(i)?;
+    (first <= cp).then_some(value)
+}
+
+fn contains(table: &[(u32, u32)], cp: u32) -> bool {
+    let i = table.partition_point(|(_, last)| *last < cp);
+    table.get(i).map(|(first, _)| *first <= cp).unwrap_or(false)
+}
+
+#[inline]
+pub fn line_break(c: char) -> LineBreak {
+    lookup(tables::LINE_BREAK, c as u32).unwrap_or(LineBreak::XX)
+}
+
+#[inline]
+pub fn general_category(c: char) -> GeneralCategory {
+    lookup(tables::GENERAL_CATEGORY, c as u32).unwrap_or(GeneralCategory::Cn)
+}
+
+/// `East_Asian_Width` is `F`, `W` or `H`.
+#[inline]
+pub fn is_east_asian_wide(c: char) -> bool {
+    contains(tables::EAST_ASIAN_WIDE, c as u32)
+}
+
+#[inline]
+pub fn is_extended_pictographic(c: char) -> bool {
+    contains(tables::EXTENDED_PICTOGRAPHIC, c as u32)
+}
+
+#[inline]
+pub fn bidi_class(c: char) -> BidiClass {
+    lookup(tables::BIDI_CLASS, c as u32).unwrap_or(BidiClass::L)
+}
+
+/// the `Bidi_Mirroring_Glyph` property.
+pub fn bidi_mirror(c: char) -> Option<char> {
+    let table = tables::BIDI_MIRRORING;
+    let i = table.binary_search_by_key(&(c as u32), |(c, _)| *c).ok()?;
+    char::from_u32(table[i].1)
+}
+
+/// the `Bidi_Paired_Bracket` & whether `c` is an opening bracket.
+pub fn bidi_bracket(c: char) -> Option<(char, bool)> {
+    let table = tables::BIDI_BRACKETS;
+    let i = table.binary_search_by_key(&(c as u32), |(c, _, _)| *c).ok()?;
+    let (_, pair, is_open) = table[i];
+    Some((char::from_u32(pair)?, is_open))
+}
@@ -0,0 +1,7959 @@
+// generated by tools/unicode_tables.py, do not edit.
+// ranges are sorted & inclusive.
+
+use super::*;
+
+
+pub(crate) static LINE_BREAK: &[(u32, u32, LineBreak)] = &[
+    (0x0000, 0x0008, LineBreak::CM),
+    (0x0009, 0x0009, LineBreak::BA),
+    (0x000a, 0x000a, LineBreak::LF),
+    (0x000b, 0x000c, LineBreak::BK),
+    (0x000d, 0x000d, LineBreak::CR),
+    (0x000e, 0x001f, LineBreak::CM),
+    (0x0020, 0x0020, LineBreak::SP),
+    (0x0021, 0x0021, LineBreak::EX),
+    (0x0022, 0x0022, LineBreak::QU),
+    (0x0023, 0x0023, LineBreak::AL),
+    (0x0024, 0x0024, LineBreak::PR),
+    (0x0025, 0x0025, LineBreak::PO),
+    (0x0026, 0x0026, LineBreak::AL),
+    (0x0027, 0x0027, LineBreak::QU),
+    (0x0028, 0x0028, LineBreak::OP),
+    (0x0029, 0x0029, LineBreak::CP),
+    (0x002a, 0x002a, LineBreak::AL),
+    (0x002b, 0x002b, LineBreak::PR),
+    (0x002c, 0x002c, LineBreak::IS),
+    (0x002d, 0x002d, LineBreak::HY),
+    (0x002e, 0x002e, LineBreak::IS),
+    (0x002f, 0x002f, LineBreak::SY),
+    (0x0030, 0x0039, LineBreak::NU),
+    (0x003a, 0x003b, LineBreak::IS),
+    (0x003c, 0x003e, LineBreak::AL),
+    (0x003f, 0x003f, LineBreak::EX),
+    (0x0040, 0x005a, LineBreak::AL),
+    (0x005b, 0x005b, LineBreak::OP),
+    (0x005c, 0x005c, LineBreak::PR),
+    (0x005d, 0x005d, LineBreak::CP),
+    (0x005e, 0x007a, LineBreak::AL),
+    (0x007b, 0x007b, LineBreak::OP),
+    (0x007c, 0x007c, LineBreak::BA),
+    (0x007d, 0x007d, LineBreak::CL),
+    (0x007e, 0x007e, LineBreak::AL),
+    (0x007f, 0x0084, LineBreak::CM),
+    (0x0085, 0x0085, LineBreak::NL),
+    (0x0086, 0x009f, LineBreak::CM),
+    (0x00a0, 0x00a0, LineBreak::GL),
+    (0x00a1, 0x00a1, LineBreak::OP),
+    (0x00a2, 0x00a2, LineBreak::PO),
+    (0x00a3, 0x00a5, LineBreak::PR),
+    (0x00a6, 0x00a6, LineBreak::AL),
+    (0x00a7, 0x00a8, LineBreak::AI),
+    (0x00a9, 0x00a9, LineBreak::AL),
+    (0x00aa, 0x00aa, LineBreak::AI),
+    (0x00ab, 0x00ab, LineBreak::QU),
+    (0x00ac, 0x00ac, LineBreak::AL),
+    (0x00ad, 0x00ad, LineBreak::BA),
+    (0x00ae, 0x00af, LineBreak::AL),
+    (0x00b0, 0x00b0, LineBreak::PO),
+    (0x00b1, 0x00b1, LineBreak::PR),
+    (0x00b2, 0x00b3, LineBreak::AI),
+    (0x00b4, 0x00b4, LineBreak::BB),
+    (0x00b5, 0x00b5, LineBreak::AL),
+    (0x00b6, 0x00ba, LineBreak::AI),
+    (0x00bb, 0x00bb, LineBreak::QU),
+    (0x00bc, 0x00be, LineBreak::AI),
+    (0x00bf, 0x00bf, LineBreak::OP),
+    (0x00c0, 0x00d6, LineBreak::AL),
+    (0x00d7, 0x00d7, LineBreak::AI),
+    (0x00d8, 0x00f6, LineBreak::AL),
+    (0x00f7, 0x00f7, LineBreak::AI),
+    (0x00f8, 0x02c6, LineBreak::AL),
+    (0x02c7, 0x02c7, LineBreak::AI),
+    (0x02c8, 0x02c8, LineBreak::BB),
+    (0x02c9, 0x02cb, LineBreak::AI),
+    (0x02cc, 0x02cc, LineBreak::BB),
+    (0x02cd, 0x02cd, LineBreak::AI),
+    (0x02ce, 0x02cf, LineBreak::AL),
+    (0x02d0, 0x02d0, LineBreak::AI),
+    (0x02d1, 0x02d7, LineBreak::AL),
+    (0x02d8, 0x02db, LineBreak::AI),
+    (0x02dc, 0x02dc, LineBreak::AL),
+    (0x02dd, 0x02dd, LineBreak::AI),
+    (0x02de, 0x02de, LineBreak::AL),
+    (0x02df, 0x02df, LineBreak::BB),
+    (0x02e0, 0x02ff, LineBreak::AL),
+    (0x0300, 0x035b, LineBreak::CM),
+    (0x035c, 0x0362, LineBreak::GL),
+    (0x0363, 0x036f, LineBreak::CM),
+    (0x0370, 0x0377, LineBreak::AL),
+    (0x037a, 0x037d, LineBreak::AL),
+    (0x037e, 0x037e, LineBreak::IS),
+    (0x037f, 0x037f, LineBreak::AL),
+    (0x0384, 0x038a, LineBreak::AL),
+    (0x038c, 0x038c, LineBreak::AL),
+    (0x038e, 0x03a1, LineBreak::AL),
+    (0x03a3, 0x0482, LineBreak::AL),
+    (0x0483, 0x0489, LineBreak::CM),
+    (0x048a, 0x052f, LineBreak::AL),
+    (0x0531, 0x0556, LineBreak::AL),
+    (0x0559, 0x0588, LineBreak::AL),
+    (0x0589, 0x0589, LineBreak::IS),
+    (0x058a, 0x058a, LineBreak::HH),
+    (0x058d, 0x058e, LineBreak::AL),
+    (0x058f, 0x058f, LineBreak::PR),
+    (0x0591, 0x05bd, LineBreak::CM),
+    (0x05be, 0x05be, LineBreak::HH),
+    (0x05bf, 0x05bf, LineBreak::CM),
+    (0x05c0, 0x05c0, LineBreak::AL),
+    (0x05c1, 0x05c2, LineBreak::CM),
+    (0x05c3, 0x05c3, LineBreak::AL),
+    (0x05c4, 0x05c5, LineBreak::CM),
+    (0x05c6, 0x05c6, LineBreak::EX),
+    (0x05c7, 0x05c7, LineBreak::CM),
+    (0x05d0, 0x05ea, LineBreak::HL),
+    (0x05ef, 0x05f2, LineBreak::HL),
+    (0x05f3, 0x05f4, LineBreak::AL),
+    (0x0600, 0x0605, LineBreak::NU),
+    (0x0606, 0x0608, LineBreak::AL),
+    (0x0609, 0x060b, LineBreak::PO),
+    (0x060c, 0x060d, LineBreak::IS),
+    (0x060e, 0x060f, LineBreak::AL),
+    (0x0610, 0x061a, LineBreak::CM),
+    (0x061b, 0x061b, LineBreak::EX),
+    (0x061c, 0x061c, LineBreak::CM),
+    (0x061d, 0x061f, LineBreak::EX),
+    (0x0620, 0x064a, LineBreak::AL),
+    (0x064b, 0x065f, LineBreak::CM),
+    (0x0660, 0x0669, LineBreak::NU),
+    (0x066a, 0x066a, LineBreak::PO),
+    (0x066b, 0x066c, LineBreak::NU),
+    (0x066d, 0x066f, LineBreak::AL),
+    (0x0670, 0x0670, LineBreak::CM),
+    (0x0671, 0x06d3, LineBreak::AL),
+    (0x06d4, 0x06d4, LineBreak::EX),
+    (0x06d5, 0x06d5, LineBreak::AL),
+    (0x06d6, 0x06dc, LineBreak::CM),
+    (0x06dd, 0x06dd, LineBreak::NU),
+    (0x06de, 0x06de, LineBreak::AL),
+    (0x06df, 0x06e4, LineBreak::CM),
+    (0x06e5, 0x06e6, LineBreak::AL),
+    (0x06e7, 0x06e8, LineBreak::CM),
+    (0x06e9, 0x06e9, LineBreak::AL),
+    (0x06ea, 0x06ed, LineBreak::CM),
+    (0x06ee, 0x06ef, LineBreak::AL),
+    (0x06f0, 0x06f9, LineBreak::NU),
+    (0x06fa, 0x070d, LineBreak::AL),
+    (0x070f, 0x0710, LineBreak::AL),
+    (0x0711, 0x0711, LineBreak::CM),
+    (0x0712, 0x072f, LineBreak::AL),
+    (0x0730, 0x074a, LineBreak::CM),
+    (0x074d, 0x07a5, LineBreak::AL),
+    (0x07a6, 0x07b0, LineBreak::CM),
+    (0x07b1, 0x07b1, LineBreak::AL),
+    (0x07c0, 0x07c9, LineBreak::NU),
+    (0x07ca, 0x07ea, LineBreak::AL),
+    (0x07eb, 0x07f3, LineBreak::CM),
+    (0x07f4, 0x07f7, LineBreak::AL),
+    (0x07f8, 0x07f8, LineBreak::IS),
+    (0x07f9, 0x07f9, LineBreak::EX),
+    (0x07fa, 0x07fa, LineBreak::AL),
+    (0x07fd, 0x07fd, LineBreak::CM),
+    (0x07fe, 0x07ff, LineBreak::PR),
+    (0x0800, 0x0815, LineBreak::AL),
+    (0x0816, 0x0819, LineBreak::CM),
+    (0x081a, 0x081a, LineBreak::AL),
+    (0x081b, 0x0823, LineBreak::CM),
+    (0x0824, 0x0824, LineBreak::AL),
+    (0x0825, 0x0827, LineBreak::CM),
+    (0x0828, 0x0828, LineBreak::AL),
+    (0x0829, 0x082d, LineBreak::CM),
+    (0x0830, 0x083e, LineBreak::AL),
+    (0x0840, 0x0858, LineBreak::AL),
+    (0x0859, 0x085b, LineBreak::CM),
+    (0x085e, 0x085e, LineBreak::AL),
+    (0x0860, 0x086a, LineBreak::AL),
+    (0x0870, 0x088f, LineBreak::AL),
+    (0x0890, 0x0891, LineBreak::NU),
+    (0x0897, 0x089f, LineBreak::CM),
+    (0x08a0, 0x08c9, LineBreak::AL),
+    (0x08ca, 0x08e1, LineBreak::CM),
+    (0x08e2, 0x08e2, LineBreak::NU),
+    (0x08e3, 0x0903, LineBreak::CM),
+    (0x0904, 0x0939, LineBreak::AL),
+    (0x093a, 0x093c, LineBreak::CM),
+    (0x093d, 0x093d, LineBreak::AL),
+    (0x093e, 0x094f, LineBreak::CM),
+    (0x0950, 0x0950, LineBreak::AL),
+    (0x0951, 0x0957, LineBreak::CM),
+    (0x0958, 0x0961, LineBreak::AL),
+    (0x0962, 0x0963, LineBreak::CM),
+    (0x0964, 0x0965, LineBreak::BA),
+    (0x0966, 0x096f, LineBreak::NU),
+    (0x0970, 0x0980, LineBreak::AL),
+    (0x0981, 0x0983, LineBreak::CM),
+    (0x0985, 0x098c, LineBreak::AL),
+    (0x098f, 0x0990, LineBreak::AL),
+    (0x0993, 0x09a8, LineBreak::AL),
+    (0x09aa, 0x09b0, LineBreak::AL),
+    (0x09b2, 0x09b2, LineBreak::AL),
+    (0x09b6, 0x09b9, LineBreak::AL),
+    (0x09bc, 0x09bc, LineBreak::CM),
+    (0x09bd, 0x09bd, LineBreak::AL),
+    (0x09be, 0x09c4, LineBreak::CM),
+    (0x09c7, 0x09c8, LineBreak::CM),
+    (0x09cb, 0x09cd, LineBreak::CM),
+    (0x09ce, 0x09ce, LineBreak::AL),
+    (0x09d7, 0x09d7, LineBreak::CM),
+    (0x09dc, 0x09dd, LineBreak::AL),
+    (0x09df, 0x09e1, LineBreak::AL),
+    (0x09e2, 0x09e3, LineBreak::CM),
+    (0x09e6, 0x09ef, LineBreak::NU),
+    (0x09f0, 0x09f1, LineBreak::AL),
+    (0x09f2, 0x09f3, LineBreak::PO),
+    (0x09f4, 0x09f8, LineBreak::AL),
+    (0x09f9, 0x09f9, LineBreak::PO),
+    (0x09fa, 0x09fa, LineBreak::AL),
+    (0x09fb, 0x09fb, LineBreak::PR),
+    (0x09fc, 0x09fd, LineBreak::AL),
+    (0x09fe, 0x09fe, LineBreak::CM),
+    (0x0a01, 0x0a03, LineBreak::CM),
+    (0x0a05, 0x0a0a, LineBreak::AL),
+    (0x0a0f, 0x0a10, LineBreak::AL),
+    (0x0a13, 0x0a28, LineBreak::AL),
+    (0x0a2a, 0x0a30, LineBreak::AL),
+    (0x0a32, 0x0a33, LineBreak::AL),
+    (0x0a35, 0x0a36, LineBreak::AL),
+    (0x0a38, 0x0a39, LineBreak::AL),
+    (0x0a3c, 0x0a3c, LineBreak::CM),
+    (0x0a3e, 0x0a42, LineBreak::CM),
+    (0x0a47, 0x0a48, LineBreak::CM),
+    (0x0a4b, 0x0a4d, LineBreak::CM),
+    (0x0a51, 0x0a51, LineBreak::CM),
+    (0x0a59, 0x0a5c, LineBreak::AL),
+    (0x0a5e, 0x0a5e, LineBreak::AL),
+    (0x0a66, 0x0a6f, LineBreak::NU),
+    (0x0a70, 0x0a71, LineBreak::CM),
+    (0x0a72, 0x0a74, LineBreak::AL),
+    (0x0a75, 0x0a75, LineBreak::CM),
+    (0x0a76, 0x0a76, LineBreak::AL),
+    (0x0a81, 0x0a83, LineBreak::CM),
+    (0x0a85, 0x0a8d, LineBreak::AL),
+    (0x0a8f, 0x0a91, LineBreak::AL),
+    (0x0a93, 0x0aa8, LineBreak::AL),
+    (0x0aaa, 0x0ab0, LineBreak::AL),
+    (0x0ab2, 0x0ab3, LineBreak::AL),
+    (0x0ab5, 0x0ab9, LineBreak::AL),
+    (0x0abc, 0x0abc, LineBreak::CM),
+    (0x0abd, 0x0abd, LineBreak::AL),
+    (0x0abe, 0x0ac5, LineBreak::CM),
+    (0x0ac7, 0x0ac9, LineBreak::CM),
+    (0x0acb, 0x0acd, LineBreak::CM),
+    (0x0ad0, 0x0ad0, LineBreak::AL),
+    (0x0ae0, 0x0ae1, LineBreak::AL),
+    (0x0ae2, 0x0ae3, LineBreak::CM),
+    (0x0ae6, 0x0aef, LineBreak::NU),
+    (0x0af0, 0x0af0, LineBreak::AL),
+    (0x0af1, 0x0af1, LineBreak::PR),
+    (0x0af9, 0x0af9, LineBreak::AL),
+    (0x0afa, 0x0aff, LineBreak::CM),
+    (0x0b01, 0x0b03, LineBreak::CM),
+    (0x0b05, 0x0b0c, LineBreak::AL),
+    (0x0b0f, 0x0b10, LineBreak::AL),
+    (0x0b13, 0x0b28, LineBreak::AL),
+    (0x0b2a, 0x0b30, LineBreak::AL),
+    (0x0b32, 0x0b33, LineBreak::AL),
+    (0x0b35, 0x0b39, LineBreak::AL),
+    (0x0b3c, 0x0b3c, LineBreak::CM),
+    (0x0b3d, 0x0b3d, LineBreak::AL),
+    (0x0b3e, 0x0b44, LineBreak::CM),
+    (0x0b47, 0x0b48, LineBreak::CM),
+    (0x0b4b, 0x0b4d, LineBreak::CM),
+    (0x0b55, 0x0b57, LineBreak::CM),
+    (0x0b5c, 0x0b5d, LineBreak::AL),
+    (0x0b5f, 0x0b61, LineBreak::AL),
+    (0x0b62, 0x0b63, LineBreak::CM),
+    (0x0b66, 0x0b6f, LineBreak::NU),
+    (0x0b70, 0x0b77, LineBreak::AL),
+    (0x0b82, 0x0b82, LineBreak::CM),
+    (0x0b83, 0x0b83, LineBreak::AL),
+    (0x0b85, 0x0b8a, LineBreak::AL),
+    (0x0b8e, 0x0b90, LineBreak::AL),
+    (0x0b92, 0x0b95, LineBreak::AL),
+    (0x0b99, 0x0b9a, LineBreak::AL),
+    (0x0b9c, 0x0b9c, LineBreak::AL),
+    (0x0b9e, 0x0b9f, LineBreak::AL),
+    (0x0ba3, 0x0ba4, LineBreak::AL),
+    (0x0ba8, 0x0baa, LineBreak::AL),
+    (0x0bae, 0x0bb9, LineBreak::AL),
+    (0x0bbe, 0x0bc2, LineBreak::CM),
+    (0x0bc6, 0x0bc8, LineBreak::CM),
+    (0x0bca, 0x0bcd, LineBreak::CM),
+    (0x0bd0, 0x0bd0, LineBreak::AL),
+    (0x0bd7, 0x0bd7, LineBreak::CM),
+    (0x0be6, 0x0bef, LineBreak::NU),
+    (0x0bf0, 0x0bf8, LineBreak::AL),
+    (0x0bf9, 0x0bf9, LineBreak::PR),
+    (0x0bfa, 0x0bfa, LineBreak::AL),
+    (0x0c00, 0x0c04, LineBreak::CM),
+    (0x0c05, 0x0c0c, LineBreak::AL),
+    (0x0c0e, 0x0c10, LineBreak::AL),
+    (0x0c12, 0x0c28, LineBreak::AL),
+    (0x0c2a, 0x0c39, LineBreak::AL),
+    (0x0c3c, 0x0c3c, LineBreak::CM),
+    (0x0c3d, 0x0c3d, LineBreak::AL),
+    (0x0c3e, 0x0c44, LineBreak::CM),
+    (0x0c46, 0x0c48, LineBreak::CM),
+    (0x0c4a, 0x0c4d, LineBreak::CM),
+    (0x0c55, 0x0c56, LineBreak::CM),
+    (0x0c58, 0x0c5a, LineBreak::AL),
+    (0x0c5c, 0x0c5d, LineBreak::AL),
+    (0x0c60, 0x0c61, LineBreak::AL),
+    (0x0c62, 0x0c63, LineBreak::CM),
+    (0x0c66, 0x0c6f, LineBreak::NU),
+    (0x0c77, 0x0c77, LineBreak::BB),
+    (0x0c78, 0x0c80, LineBreak::AL),
+    (0x0c81, 0x0c83, LineBreak::CM),
+    (0x0c84, 0x0c84, LineBreak::BB),
+    (0x0c85, 0x0c8c, LineBreak::AL),
+    (0x0c8e, 0x0c90, LineBreak::AL),
+    (0x0c92, 0x0ca8, LineBreak::AL),
+    (0x0caa, 0x0cb3, LineBreak::AL),
+    (0x0cb5, 0x0cb9, LineBreak::AL),
+    (0x0cbc, 0x0cbc, LineBreak::CM),
+    (0x0cbd, 0x0cbd, LineBreak::AL),
+    (0x0cbe, 0x0cc4, LineBreak::CM),
+    (0x0cc6, 0x0cc8, LineBreak::CM),
+    (0x0cca, 0x0ccd, LineBreak::CM),
+    (0x0cd5, 0x0cd6, LineBreak::CM),
+    (0x0cdc, 0x0cde, LineBreak::AL),
+    (0x0ce0, 0x0ce1, LineBreak::AL),
+    (0x0ce2, 0x0ce3, LineBreak::CM),
+    (0x0ce6, 0x0cef, LineBreak::NU),
+    (0x0cf1, 0x0cf2, LineBreak::AL),
+    (0x0cf3, 0x0cf3, LineBreak::CM),
+    (0x0d00, 0x0d03, LineBreak::CM),
+    (0x0d04, 0x0d0c, LineBreak::AL),
+    (0x0d0e, 0x0d10, LineBreak::AL),
+    (0x0d12, 0x0d3a, LineBreak::AL),
+    (0x0d3b, 0x0d3c, LineBreak::CM),
+    (0x0d3d, 0x0d3d, LineBreak::AL),
+    (0x0d3e, 0x0d44, LineBreak::CM),
+    (0x0d46, 0x0d48, LineBreak::CM),
+    (0x0d4a, 0x0d4d, LineBreak::CM),
+    (0x0d4e, 0x0d4f, LineBreak::AL),
+    (0x0d54, 0x0d56, LineBreak::AL),
+    (0x0d57, 0x0d57, LineBreak::CM),
+    (0x0d58, 0x0d61, LineBreak::AL),
+    (0x0d62, 0x0d63, LineBreak::CM),
+    (0x0d66, 0x0d6f, LineBreak::NU),
+    (0x0d70, 0x0d78, LineBreak::AL),
+    (0x0d79, 0x0d79, LineBreak::PO),
+    (0x0d7a, 0x0d7f, LineBreak::AL),
+    (0x0d81, 0x0d83, LineBreak::CM),
+    (0x0d85, 0x0d96, LineBreak::AL),
+    (0x0d9a, 0x0db1, LineBreak::AL),
+    (0x0db3, 0x0dbb, LineBreak::AL),
+    (0x0dbd, 0x0dbd, LineBreak::AL),
+    (0x0dc0, 0x0dc6, LineBreak::AL),
+    (0x0dca, 0x0dca, LineBreak::CM),
+    (0x0dcf, 0x0dd4, LineBreak::CM),
+    (0x0dd6, 0x0dd6, LineBreak::CM),
+    (0x0dd8, 0x0ddf, LineBreak::CM),
+    (0x0de6, 0x0def, LineBreak::NU),
+    (0x0df2, 0x0df3, LineBreak::CM),
+    (0x0df4, 0x0df4, LineBreak::AL),
+    (0x0e01, 0x0e3a, LineBreak::SA),
+    (0x0e3f, 0x0e3f, LineBreak::PR),
+    (0x0e40, 0x0e4e, LineBreak::SA),
+    (0x0e4f, 0x0e4f, LineBreak::AL),
+    (0x0e50, 0x0e59, LineBreak::NU),
+    (0x0e5a, 0x0e5b, LineBreak::BA),
+    (0x0e81, 0x0e82, LineBreak::SA),
+    (0x0e84, 0x0e84, LineBreak::SA),
+    (0x0e86, 0x0e8a, LineBreak::SA),
+    (0x0e8c, 0x0ea3, LineBreak::SA),
+    (0x0ea5, 0x0ea5, LineBreak::SA),
+    (0x0ea7, 0x0ebd, LineBreak::SA),
+    (0x0ec0, 0x0ec4, LineBreak::SA),
+    (0x0ec6, 0x0ec6, LineBreak::SA),
+    (0x0ec8, 0x0ece, LineBreak::SA),
+    (0x0ed0, 0x0ed9, LineBreak::NU),
+    (0x0edc, 0x0edf, LineBreak::SA),
+    (0x0f00, 0x0f00, LineBreak::AL),
+    (0x0f01, 0x0f04, LineBreak::BB),
+    (0x0f05, 0x0f05, LineBreak::AL),
+    (0x0f06, 0x0f07, LineBreak::BB),
+    (0x0f08, 0x0f08, LineBreak::GL),
+    (0x0f09, 0x0f0a, LineBreak::BB),
+    (0x0f0b, 0x0f0b, LineBreak::BA),
+    (0x0f0c, 0x0f0c, LineBreak::GL),
+    (0x0f0d, 0x0f11, LineBreak::EX),
+    (0x0f12, 0x0f12, LineBreak::GL),
+    (0x0f13, 0x0f13, LineBreak::AL),
+    (0x0f14, 0x0f14, LineBreak::EX),
+    (0x0f15, 0x0f17, LineBreak::AL),
+    (0x0f18, 0x0f19, LineBreak::CM),
+    (0x0f1a, 0x0f1f, LineBreak::AL),
+    (0x0f20, 0x0f29, LineBreak::NU),
+    (0x0f2a, 0x0f33, LineBreak::AL),
+    (0x0f34, 0x0f34, LineBreak::BA),
+    (0x0f35, 0x0f35, LineBreak::CM),
+    (0x0f36, 0x0f36, LineBreak::AL),
+    (0x0f37, 0x0f37, LineBreak::CM),
+    (0x0f38, 0x0f38, LineBreak::AL),
+    (0x0f39, 0x0f39, LineBreak::CM),
+    (0x0f3a, 0x0f3a, LineBreak::OP),
+    (0x0f3b, 0x0f3b, LineBreak::CL),
+    (0x0f3c, 0x0f3c, LineBreak::OP),
+    (0x0f3d, 0x0f3d, LineBreak::CL),
+    (0x0f3e, 0x0f3f, LineBreak::CM),
+    (0x0f40, 0x0f47, LineBreak::AL),
+    (0x0f49, 0x0f6c, LineBreak::AL),
+    (0x0f71, 0x0f7e, LineBreak::CM),
+    (0x0f7f, 0x0f7f, LineBreak::BA),
+    (0x0f80, 0x0f84, LineBreak::CM),
+    (0x0f85, 0x0f85, LineBreak::BA),
+    (0x0f86, 0x0f87, LineBreak::CM),
+    (0x0f88, 0x0f8c, LineBreak::AL),
+    (0x0f8d, 0x0f97, LineBreak::CM),
+    (0x0f99, 0x0fbc, LineBreak::CM),
+    (0x0fbe, 0x0fbf, LineBreak::BA),
+    (0x0fc0, 0x0fc5, LineBreak::AL),
+    (0x0fc6, 0x0fc6, LineBreak::CM),
+    (0x0fc7, 0x0fcc, LineBreak::AL),
+    (0x0fce, 0x0fcf, LineBreak::AL),
+    (0x0fd0, 0x0fd1, LineBreak::BB),
+    (0x0fd2, 0x0fd2, LineBreak::BA),
+    (0x0fd3, 0x0fd3, LineBreak::BB),
+    (0x0fd4, 0x0fd8, LineBreak::AL),
+    (0x0fd9, 0x0fda, LineBreak::GL),
+    (0x1000, 0x103f, LineBreak::SA),
+    (0x1040, 0x1049, LineBreak::NU),
+    (0x104a, 0x104b, LineBreak::BA),
+    (0x104c, 0x104f, LineBreak::AL),
+    (0x1050, 0x108f, LineBreak::SA),
+    (0x1090, 0x1099, LineBreak::NU),
+    (0x109a, 0x109f, LineBreak::SA),
+    (0x10a0, 0x10c5, LineBreak::AL),
+    (0x10c7, 0x10c7, LineBreak::AL),
+    (0x10cd, 0x10cd, LineBreak::AL),
+    (0x10d0, 0x10ff, LineBreak::AL),
+    (0x1100, 0x115f, LineBreak::JL),
+    (0x1160, 0x11a7, LineBreak::JV),
+    (0x11a8, 0x11ff, LineBreak::JT),
+    (0x1200, 0x1248, LineBreak::AL),
+    (0x124a, 0x124d, LineBreak::AL),
+    (0x1250, 0x1256, LineBreak::AL),
+    (0x1258, 0x1258, LineBreak::AL),
+    (0x125a, 0x125d, LineBreak::AL),
+    (0x1260, 0x1288, LineBreak::AL),
+    (0x128a, 0x128d, LineBreak::AL),
+    (0x1290, 0x12b0, LineBreak::AL),
+    (0x12b2, 0x12b5, LineBreak::AL),
+    (0x12b8, 0x12be, LineBreak::AL),
+    (0x12c0, 0x12c0, LineBreak::AL),
+    (0x12c2, 0x12c5, LineBreak::AL),
+    (0x12c8, 0x12d6, LineBreak::AL),
+    (0x12d8, 0x1310, LineBreak::AL),
+    (0x1312, 0x1315, LineBreak::AL),
+    (0x1318, 0x135a, LineBreak::AL),
+    (0x135d, 0x135f, LineBreak::CM),
+    (0x1360, 0x1360, LineBreak::AL),
+    (0x1361, 0x1361, LineBreak::BA),
+    (0x1362, 0x137c, LineBreak::AL),
+    (0x1380, 0x1399, LineBreak::AL),
+    (0x13a0, 0x13f5, LineBreak::AL),
+    (0x13f8, 0x13fd, LineBreak::AL),
+    (0x1400, 0x1400, LineBreak::HH),
+    (0x1401, 0x167f, LineBreak::AL),
+    (0x1680, 0x1680, LineBreak::BA),
+    (0x1681, 0x169a, LineBreak::AL),
+    (0x169b, 0x169b, LineBreak::OP),
+    (0x169c, 0x169c, LineBreak::CL),
+    (0x16a0, 0x16ea, LineBreak::AL),
+    (0x16eb, 0x16ed, LineBreak::BA),
+    (0x16ee, 0x16f8, LineBreak::AL),
+    (0x1700, 0x1711, LineBreak::AL),
+    (0x1712, 0x1715, LineBreak::CM),
+    (0x171f, 0x1731, LineBreak::AL),
+    (0x1732, 0x1734, LineBreak::CM),
+    (0x1735, 0x1736, LineBreak::BA),
+    (0x1740, 0x1751, LineBreak::AL),
+    (0x1752, 0x1753, LineBreak::CM),
+    (0x1760, 0x176c, LineBreak::AL),
+    (0x176e, 0x1770, LineBreak::AL),
+    (0x1772, 0x1773, LineBreak::CM),
+    (0x1780, 0x17d3, LineBreak::SA),
+    (0x17d4, 0x17d5, LineBreak::BA),
+    (0x17d6, 0x17d6, LineBreak::NS),
+    (0x17d7, 0x17d7, LineBreak::SA),
+    (0x17d8, 0x17d8, LineBreak::BA),
+    (0x17d9, 0x17d9, LineBreak::AL),
+    (0x17da, 0x17da, LineBreak::BA),
+    (0x17db, 0x17db, LineBreak::PR),
+    (0x17dc, 0x17dd, LineBreak::SA),
+    (0x17e0, 0x17e9, LineBreak::NU),
+    (0x17f0, 0x17f9, LineBreak::AL),
+    (0x1800, 0x1801, LineBreak::AL),
+    (0x1802, 0x1803, LineBreak::EX),
+    (0x1804, 0x1805, LineBreak::BA),
+    (0x1806, 0x1806, LineBreak::BB),
+    (0x1807, 0x1807, LineBreak::AL),
+    (0x1808, 0x1809, LineBreak::EX),
+    (0x180a, 0x180a, LineBreak::AL),
+    (0x180b, 0x180d, LineBreak::CM),
+    (0x180e, 0x180e, LineBreak::GL),
+    (0x180f, 0x180f, LineBreak::CM),
+    (0x1810, 0x1819, LineBreak::NU),
+    (0x1820, 0x1878, LineBreak::AL),
+    (0x1880, 0x1884, LineBreak::AL),
+    (0x1885, 0x1886, LineBreak::CM),
+    (0x1887, 0x18a8, LineBreak::AL),
+    (0x18a9, 0x18a9, LineBreak::CM),
+    (0x18aa, 0x18aa, LineBreak::AL),
+    (0x18b0, 0x18f5, LineBreak::AL),
+    (0x1900, 0x191e, LineBreak::AL),
+    (0x1920, 0x192b, LineBreak::CM),
+    (0x1930, 0x193b, LineBreak::CM),
+    (0x1940, 0x1940, LineBreak::AL),
+    (0x1944, 0x1945, LineBreak::EX),
+    (0x1946, 0x194f, LineBreak::NU),
+    (0x1950, 0x196d, LineBreak::SA),
+    (0x1970, 0x1974, LineBreak::SA),
+    (0x1980, 0x19ab, LineBreak::SA),
+    (0x19b0, 0x19c9, LineBreak::SA),
+    (0x19d0, 0x19da, LineBreak::NU),
+    (0x19de, 0x19df, LineBreak::SA),
+    (0x19e0, 0x1a16, LineBreak::AL),
+    (0x1a17, 0x1a1b, LineBreak::CM),
+    (0x1a1e, 0x1a1f, LineBreak::AL),
+    (0x1a20, 0x1a5e, LineBreak::SA),
+    (0x1a60, 0x1a7c, LineBreak::SA),
+    (0x1a7f, 0x1a7f, LineBreak::CM),
+    (0x1a80, 0x1a89, LineBreak::NU),
+    (0x1a90, 0x1a99, LineBreak::NU),
+    (0x1aa0, 0x1aad, LineBreak::SA),
+    (0x1ab0, 0x1add, LineBreak::CM),
+    (0x1ae0, 0x1aea, LineBreak::CM),
+    (0x1aeb, 0x1aeb, LineBreak::GL),
+    (0x1b00, 0x1b04, LineBreak::CM),
+    (0x1b05, 0x1b33, LineBreak::AK),
+    (0x1b34, 0x1b43, LineBreak::CM),
+    (0x1b44, 0x1b44, LineBreak::VI),
+    (0x1b45, 0x1b4c, LineBreak::AK),
+    (0x1b4e, 0x1b4f, LineBreak::BA),
+    (0x1b50, 0x1b59, LineBreak::AS),
+    (0x1b5a, 0x1b5b, LineBreak::BA),
+    (0x1b5c, 0x1b5c, LineBreak::ID),
+    (0x1b5d, 0x1b60, LineBreak::BA),
+    (0x1b61, 0x1b6a, LineBreak::ID),
+    (0x1b6b, 0x1b73, LineBreak::CM),
+    (0x1b74, 0x1b7c, LineBreak::ID),
+    (0x1b7d, 0x1b7f, LineBreak::BA),
+    (0x1b80, 0x1b82, LineBreak::CM),
+    (0x1b83, 0x1ba0, LineBreak::AL),
+    (0x1ba1, 0x1bad, LineBreak::CM),
+    (0x1bae, 0x1baf, LineBreak::AL),
+    (0x1bb0, 0x1bb9, LineBreak::NU),
+    (0x1bba, 0x1bbf, LineBreak::AL),
+    (0x1bc0, 0x1be5, LineBreak::AS),
+    (0x1be6, 0x1bf1, LineBreak::CM),
+    (0x1bf2, 0x1bf3, LineBreak::VF),
+    (0x1bfc, 0x1c23, LineBreak::AL),
+    (0x1c24, 0x1c37, LineBreak::CM),
+    (0x1c3b, 0x1c3f, LineBreak::BA),
+    (0x1c40, 0x1c49, LineBreak::NU),
+    (0x1c4d, 0x1c4f, LineBreak::AL),
+    (0x1c50, 0x1c59, LineBreak::NU),
+    (0x1c5a, 0x1c7d, LineBreak::AL),
+    (0x1c7e, 0x1c7f, LineBreak::BA),
+    (0x1c80, 0x1c8a, LineBreak::AL),
+    (0x1c90, 0x1cba, LineBreak::AL),
+    (0x1cbd, 0x1cc7, LineBreak::AL),
+    (0x1cd0, 0x1cd2, LineBreak::CM),
+    (0x1cd3, 0x1cd3, LineBreak::AL),
+    (0x1cd4, 0x1ce8, LineBreak::CM),
+    (0x1ce9, 0x1cec, LineBreak::AL),
+    (0x1ced, 0x1ced, LineBreak::CM),
+    (0x1cee, 0x1cf3, LineBreak::AL),
+    (0x1cf4, 0x1cf4, LineBreak::CM),
+    (0x1cf5, 0x1cf6, LineBreak::AL),
+    (0x1cf7, 0x1cf9, LineBreak::CM),
+    (0x1cfa, 0x1cfa, LineBreak::AL),
+    (0x1d00, 0x1dbf, LineBreak::AL),
+    (0x1dc0, 0x1dcc, LineBreak::CM),
+    (0x1dcd, 0x1dcd, LineBreak::GL),
+    (0x1dce, 0x1dfb, LineBreak::CM),
+    (0x1dfc, 0x1dfc, LineBreak::GL),
+    (0x1dfd, 0x1dff, LineBreak::CM),
+    (0x1e00, 0x1f15, LineBreak::AL),
+    (0x1f18, 0x1f1d, LineBreak::AL),
+    (0x1f20, 0x1f45, LineBreak::AL),
+    (0x1f48, 0x1f4d, LineBreak::AL),
+    (0x1f50, 0x1f57, LineBreak::AL),
+    (0x1f59, 0x1f59, LineBreak::AL),
+    (0x1f5b, 0x1f5b, LineBreak::AL),
+    (0x1f5d, 0x1f5d, LineBreak::AL),
+    (0x1f5f, 0x1f7d, LineBreak::AL),
+    (0x1f80, 0x1fb4, LineBreak::AL),
+    (0x1fb6, 0x1fc4, LineBreak::AL),
+    (0x1fc6, 0x1fd3, LineBreak::AL),
+    (0x1fd6, 0x1fdb, LineBreak::AL),
+    (0x1fdd, 0x1fef, LineBreak::AL),
+    (0x1ff2, 0x1ff4, LineBreak::AL),
+    (0x1ff6, 0x1ffc, LineBreak::AL),
+    (0x1ffd, 0x1ffd, LineBreak::BB),
+    (0x1ffe, 0x1ffe, LineBreak::AL),
+    (0x2000, 0x2006, LineBreak::BA),
+    (0x2007, 0x2007, LineBreak::GL),
+    (0x2008, 0x200a, LineBreak::BA),
+    (0x200b, 0x200b, LineBreak::ZW),
+    (0x200c, 0x200c, LineBreak::CM),
+    (0x200d, 0x200d, LineBreak::ZWJ),
+    (0x200e, 0x200f, LineBreak::CM),
+    (0x2010, 0x2010, LineBreak::HH),
+    (0x2011, 0x2011, LineBreak::GL),
+    (0x2012, 0x2013, LineBreak::HH),
+    (0x2014, 0x2014, LineBreak::B2),
+    (0x2015, 0x2016, LineBreak::AI),
+    (0x2017, 0x2017, LineBreak::AL),
+    (0x2018, 0x2019, LineBreak::QU),
+    (0x201a, 0x201a, LineBreak::OP),
+    (0x201b, 0x201d, LineBreak::QU),
+    (0x201e, 0x201e, LineBreak::OP),
+    (0x201f, 0x201f, LineBreak::QU),
+    (0x2020, 0x2021, LineBreak::AI),
+    (0x2022, 0x2023, LineBreak::AL),
+    (0x2024, 0x2026, LineBreak::IN),
+    (0x2027, 0x2027, LineBreak::BA),
+    (0x2028, 0x2029, LineBreak::BK),
+    (0x202a, 0x202e, LineBreak::CM),
+    (0x202f, 0x202f, LineBreak::GL),
+    (0x2030, 0x2037, LineBreak::PO),
+    (0x2038, 0x2038, LineBreak::AL),
+    (0x2039, 0x203a, LineBreak::QU),
+    (0x203b, 0x203b, LineBreak::AI),
+    (0x203c, 0x203d, LineBreak::NS),
+    (0x203e, 0x2043, LineBreak::AL),
+    (0x2044, 0x2044, LineBreak::IS),
+    (0x2045, 0x2045, LineBreak::OP),
+    (0x2046, 0x2046, LineBreak::CL),
+    (0x2047, 0x2049, LineBreak::NS),
+    (0x204a, 0x2055, LineBreak::AL),
+    (0x2056, 0x2056, LineBreak::BA),
+    (0x2057, 0x2057, LineBreak::PO),
+    (0x2058, 0x205b, LineBreak::BA),
+    (0x205c, 0x205c, LineBreak::AL),
+    (0x205d, 0x205f, LineBreak::BA),
+    (0x2060, 0x2060, LineBreak::WJ),
+    (0x2061, 0x2064, LineBreak::AL),
+    (0x2066, 0x206f, LineBreak::CM),
+    (0x2070, 0x2071, LineBreak::AL),
+    (0x2074, 0x2074, LineBreak::AI),
+    (0x2075, 0x207c, LineBreak::AL),
+    (0x207d, 0x207d, LineBreak::OP),
+    (0x207e, 0x207e, LineBreak::CL),
+    (0x207f, 0x207f, LineBreak::AI),
+    (0x2080, 0x2080, LineBreak::AL),
+    (0x2081, 0x2084, LineBreak::AI),
+    (0x2085, 0x208c, LineBreak::AL),
+    (0x208d, 0x208d, LineBreak::OP),
+    (0x208e, 0x208e, LineBreak::CL),
+    (0x2090, 0x209c, LineBreak::AL),
+    (0x20a0, 0x20a6, LineBreak::PR),
+    (0x20a7, 0x20a7, LineBreak::PO),
+    (0x20a8, 0x20b5, LineBreak::PR),
+    (0x20b6, 0x20b6, LineBreak::PO),
+    (0x20b7, 0x20ba, LineBreak::PR),
+    (0x20bb, 0x20bb, LineBreak::PO),
+    (0x20bc, 0x20bd, LineBreak::PR),
+    (0x20be, 0x20be, LineBreak::PO),
+    (0x20bf, 0x20bf, LineBreak::PR),
+    (0x20c0, 0x20c0, LineBreak::PO),
+    (0x20c1, 0x20cf, LineBreak::PR),
+    (0x20d0, 0x20f0, LineBreak::CM),
+    (0x2100, 0x2102, LineBreak::AL),
+    (0x2103, 0x2103, LineBreak::PO),
+    (0x2104, 0x2104, LineBreak::AL),
+    (0x2105, 0x2105, LineBreak::AI),
+    (0x2106, 0x2108, LineBreak::AL),
+    (0x2109, 0x2109, LineBreak::PO),
+    (0x210a, 0x2112, LineBreak::AL),
+    (0x2113, 0x2113, LineBreak::AI),
+    (0x2114, 0x2115, LineBreak::AL),
+    (0x2116, 0x2116, LineBreak::PR),
+    (0x2117, 0x2120, LineBreak::AL),
+    (0x2121, 0x2122, LineBreak::AI),
+    (0x2123, 0x212a, LineBreak::AL),
+    (0x212b, 0x212b, LineBreak::AI),
+    (0x212c, 0x214f, LineBreak::AL),
+    (0x2150, 0x215e, LineBreak::AI),
+    (0x215f, 0x215f, LineBreak::AL),
+    (0x2160, 0x216b, LineBreak::AI),
+    (0x216c, 0x216f, LineBreak::AL),
+    (0x2170, 0x2179, LineBreak::AI),
+    (0x217a, 0x2188, LineBreak::AL),
+    (0x2189, 0x2189, LineBreak::AI),
+    (0x218a, 0x218b, LineBreak::AL),
+    (0x2190, 0x2199, LineBreak::AI),
+    (0x219a, 0x21d1, LineBreak::AL),
+    (0x21d2, 0x21d2, LineBreak::AI),
+    (0x21d3, 0x21d3, LineBreak::AL),
+    (0x21d4, 0x21d4, LineBreak::AI),
+    (0x21d5, 0x21ff, LineBreak::AL),
+    (0x2200, 0x2200, LineBreak::AI),
+    (0x2201, 0x2201, LineBreak::AL),
+    (0x2202, 0x2203, LineBreak::AI),
+    (0x2204, 0x2206, LineBreak::AL),
+    (0x2207, 0x2208, LineBreak::AI),
+    (0x2209, 0x220a, LineBreak::AL),
+    (0x220b, 0x220b, LineBreak::AI),
+    (0x220c, 0x220e, LineBreak::AL),
+    (0x220f, 0x220f, LineBreak::AI),
+    (0x2210, 0x2210, LineBreak::AL),
+    (0x2211, 0x2211, LineBreak::AI),
+    (0x2212, 0x2213, LineBreak::PR),
+    (0x2214, 0x2214, LineBreak::AL),
+    (0x2215, 0x2215, LineBreak::AI),
+    (0x2216, 0x2219, LineBreak::AL),
+    (0x221a, 0x221a, LineBreak::AI),
+    (0x221b, 0x221c, LineBreak::AL),
+    (0x221d, 0x2220, LineBreak::AI),
+    (0x2221, 0x2222, LineBreak::AL),
+    (0x2223, 0x2223, LineBreak::AI),
+    (0x2224, 0x2224, LineBreak::AL),
+    (0x2225, 0x2225, LineBreak::AI),
+    (0x2226, 0x2226, LineBreak::AL),
+    (0x2227, 0x222c, LineBreak::AI),
+    (0x222d, 0x222d, LineBreak::AL),
+    (0x222e, 0x222e, LineBreak::AI),
+    (0x222f, 0x2233, LineBreak::AL),
+    (0x2234, 0x2237, LineBreak::AI),
+    (0x2238, 0x223b, LineBreak::AL),
+    (0x223c, 0x223d, LineBreak::AI),
+    (0x223e, 0x2247, LineBreak::AL),
+    (0x2248, 0x2248, LineBreak::AI),
+    (0x2249, 0x224b, LineBreak::AL),
+    (0x224c, 0x224c, LineBreak::AI),
+    (0x224d, 0x2251, LineBreak::AL),
+    (0x2252, 0x2252, LineBreak::AI),
+    (0x2253, 0x225f, LineBreak::AL),
+    (0x2260, 0x2261, LineBreak::AI),
+    (0x2262, 0x2263, LineBreak::AL),
+    (0x2264, 0x2267, LineBreak::AI),
+    (0x2268, 0x2269, LineBreak::AL),
+    (0x226a, 0x226b, LineBreak::AI),
+    (0x226c, 0x226d, LineBreak::AL),
+    (0x226e, 0x226f, LineBreak::AI),
+    (0x2270, 0x2281, LineBreak::AL),
+    (0x2282, 0x2283, LineBreak::AI),
+    (0x2284, 0x2285, LineBreak::AL),
+    (0x2286, 0x2287, LineBreak::AI),
+    (0x2288, 0x2294, LineBreak::AL),
+    (0x2295, 0x2295, LineBreak::AI),
+    (0x2296, 0x2298, LineBreak::AL),
+    (0x2299, 0x2299, LineBreak::AI),
+    (0x229a, 0x22a4, LineBreak::AL),
+    (0x22a5, 0x22a5, LineBreak::AI),
+    (0x22a6, 0x22be, LineBreak::AL),
+    (0x22bf, 0x22bf, LineBreak::AI),
+    (0x22c0, 0x22ee, LineBreak::AL),
+    (0x22ef, 0x22ef, LineBreak::IN),
+    (0x22f0, 0x2307, LineBreak::AL),
+    (0x2308, 0x2308, LineBreak::OP),
+    (0x2309, 0x2309, LineBreak::CL),
+    (0x230a, 0x230a, LineBreak::OP),
+    (0x230b, 0x230b, LineBreak::CL),
+    (0x230c, 0x2311, LineBreak::AL),
+    (0x2312, 0x2312, LineBreak::AI),
+    (0x2313, 0x2319, LineBreak::AL),
+    (0x231a, 0x231b, LineBreak::ID),
+    (0x231c, 0x2328, LineBreak::AL),
+    (0x2329, 0x2329, LineBreak::OP),
+    (0x232a, 0x232a, LineBreak::CL),
+    (0x232b, 0x23ef, LineBreak::AL),
+    (0x23f0, 0x23f3, LineBreak::ID),
+    (0x23f4, 0x2429, LineBreak::AL),
+    (0x2440, 0x244a, LineBreak::AL),
+    (0x2460, 0x24fe, LineBreak::AI),
+    (0x24ff, 0x24ff, LineBreak::AL),
+    (0x2500, 0x254b, LineBreak::AI),
+    (0x254c, 0x254f, LineBreak::AL),
+    (0x2550, 0x2574, LineBreak::AI),
+    (0x2575, 0x257f, LineBreak::AL),
+    (0x2580, 0x258f, LineBreak::AI),
+    (0x2590, 0x2591, LineBreak::AL),
+    (0x2592, 0x2595, LineBreak::AI),
+    (0x2596, 0x259f, LineBreak::AL),
+    (0x25a0, 0x25a1, LineBreak::AI),
+    (0x25a2, 0x25a2, LineBreak::AL),
+    (0x25a3, 0x25a9, LineBreak::AI),
+    (0x25aa, 0x25b1, LineBreak::AL),
+    (0x25b2, 0x25b3, LineBreak::AI),
+    (0x25b4, 0x25b5, LineBreak::AL),
+    (0x25b6, 0x25b7, LineBreak::AI),
+    (0x25b8, 0x25bb, LineBreak::AL),
+    (0x25bc, 0x25bd, LineBreak::AI),
+    (0x25be, 0x25bf, LineBreak::AL),
+    (0x25c0, 0x25c1, LineBreak::AI),
+    (0x25c2, 0x25c5, LineBreak::AL),
+    (0x25c6, 0x25c8, LineBreak::AI),
+    (0x25c9, 0x25ca, LineBreak::AL),
+    (0x25cb, 0x25cb, LineBreak::AI),
+    (0x25cc, 0x25cd, LineBreak::AL),
+    (0x25ce, 0x25d1, LineBreak::AI),
+    (0x25d2, 0x25e1, LineBreak::AL),
+    (0x25e2, 0x25e5, LineBreak::AI),
+    (0x25e6, 0x25ee, LineBreak::AL),
+    (0x25ef, 0x25ef, LineBreak::AI),
+    (0x25f0, 0x25ff, LineBreak::AL),
+    (0x2600, 0x2603, LineBreak::ID),
+    (0x2604, 0x2604, LineBreak::AL),
+    (0x2605, 0x2606, LineBreak::AI),
+    (0x2607, 0x2608, LineBreak::AL),
+    (0x2609, 0x2609, LineBreak::AI),
+    (0x260a, 0x260d, LineBreak::AL),
+    (0x260e, 0x260f, LineBreak::AI),
+    (0x2610, 0x2613, LineBreak::AL),
+    (0x2614, 0x2615, LineBreak::ID),
+    (0x2616, 0x2617, LineBreak::AI),
+    (0x2618, 0x2618, LineBreak::ID),
+    (0x2619, 0x2619, LineBreak::AL),
+    (0x261a, 0x261c, LineBreak::ID),
+    (0x261d, 0x261d, LineBreak::EB),
+    (0x261e, 0x261f, LineBreak::ID),
+    (0x2620, 0x2638, LineBreak::AL),
+    (0x2639, 0x263b, LineBreak::ID),
+    (0x263c, 0x263f, LineBreak::AL),
+    (0x2640, 0x2640, LineBreak::AI),
+    (0x2641, 0x2641, LineBreak::AL),
+    (0x2642, 0x2642, LineBreak::AI),
+    (0x2643, 0x265f, LineBreak::AL),
+    (0x2660, 0x2661, LineBreak::AI),
+    (0x2662, 0x2662, LineBreak::AL),
+    (0x2663, 0x2665, LineBreak::AI),
+    (0x2666, 0x2666, LineBreak::AL),
+    (0x2667, 0x2667, LineBreak::AI),
+    (0x2668, 0x2668, LineBreak::ID),
+    (0x2669, 0x266a, LineBreak::AI),
+    (0x266b, 0x266b, LineBreak::AL),
+    (0x266c, 0x266d, LineBreak::AI),
+    (0x266e, 0x266e, LineBreak::AL),
+    (0x266f, 0x266f, LineBreak::AI),
+    (0x2670, 0x267e, LineBreak::AL),
+    (0x267f, 0x267f, LineBreak::ID),
+    (0x2680, 0x269d, LineBreak::AL),
+    (0x269e, 0x269f, LineBreak::AI),
+    (0x26a0, 0x26bc, LineBreak::AL),
+    (0x26bd, 0x26c8, LineBreak::ID),
+    (0x26c9, 0x26cc, LineBreak::AI),
+    (0x26cd, 0x26cd, LineBreak::ID),
+    (0x26ce, 0x26ce, LineBreak::AL),
+    (0x26cf, 0x26d1, LineBreak::ID),
+    (0x26d2, 0x26d2, LineBreak::AI),
+    (0x26d3, 0x26d4, LineBreak::ID),
+    (0x26d5, 0x26d7, LineBreak::AI),
+    (0x26d8, 0x26d9, LineBreak::ID),
+    (0x26da, 0x26db, LineBreak::AI),
+    (0x26dc, 0x26dc, LineBreak::ID),
+    (0x26dd, 0x26de, LineBreak::AI),
+    (0x26df, 0x26e1, LineBreak::ID),
+    (0x26e2, 0x26e2, LineBreak::AL),
+    (0x26e3, 0x26e3, LineBreak::AI),
+    (0x26e4, 0x26e7, LineBreak::AL),
+    (0x26e8, 0x26e9, LineBreak::AI),
+    (0x26ea, 0x26ea, LineBreak::ID),
+    (0x26eb, 0x26f0, LineBreak::AI),
+    (0x26f1, 0x26f5, LineBreak::ID),
+    (0x26f6, 0x26f6, LineBreak::AI),
+    (0x26f7, 0x26f8, LineBreak::ID),
+    (0x26f9, 0x26f9, LineBreak::EB),
+    (0x26fa, 0x26fa, LineBreak::ID),
+    (0x26fb, 0x26fc, LineBreak::AI),
+    (0x26fd, 0x2704, LineBreak::ID),
+    (0x2705, 0x2707, LineBreak::AL),
+    (0x2708, 0x2709, LineBreak::ID),
+    (0x270a, 0x270d, LineBreak::EB),
+    (0x270e, 0x2756, LineBreak::AL),
+    (0x2757, 0x2757, LineBreak::AI),
+    (0x2758, 0x275a, LineBreak::AL),
+    (0x275b, 0x2760, LineBreak::QU),
+    (0x2761, 0x2761, LineBreak::AL),
+    (0x2762, 0x2763, LineBreak::EX),
+    (0x2764, 0x2764, LineBreak::ID),
+    (0x2765, 0x2767, LineBreak::AL),
+    (0x2768, 0x2768, LineBreak::OP),
+    (0x2769, 0x2769, LineBreak::CL),
+    (0x276a, 0x276a, LineBreak::OP),
+    (0x276b, 0x276b, LineBreak::CL),
+    (0x276c, 0x276c, LineBreak::OP),
+    (0x276d, 0x276d, LineBreak::CL),
+    (0x276e, 0x276e, LineBreak::OP),
+    (0x276f, 0x276f, LineBreak::CL),
+    (0x2770, 0x2770, LineBreak::OP),
+    (0x2771, 0x2771, LineBreak::CL),
+    (0x2772, 0x2772, LineBreak::OP),
+    (0x2773, 0x2773, LineBreak::CL),
+    (0x2774, 0x2774, LineBreak::OP),
+    (0x2775, 0x2775, LineBreak::CL),
+    (0x2776, 0x2793, LineBreak::AI),
+    (0x2794, 0x27c4, LineBreak::AL),
+    (0x27c5, 0x27c5, LineBreak::OP),
+    (0x27c6, 0x27c6, LineBreak::CL),
+    (0x27c7, 0x27e5, LineBreak::AL),
+    (0x27e6, 0x27e6, LineBreak::OP),
+    (0x27e7, 0x27e7, LineBreak::CL),
+    (0x27e8, 0x27e8, LineBreak::OP),
+    (0x27e9, 0x27e9, LineBreak::CL),
+    (0x27ea, 0x27ea, LineBreak::OP),
+    (0x27eb, 0x27eb, LineBreak::CL),
+    (0x27ec, 0x27ec, LineBreak::OP),
+    (0x27ed, 0x27ed, LineBreak::CL),
+    (0x27ee, 0x27ee, LineBreak::OP),
+    (0x27ef, 0x27ef, LineBreak::CL),
+    (0x27f0, 0x27ff, LineBreak::AL),
+    (0x2800, 0x2800, LineBreak::BA),
+    (0x2801, 0x2982, LineBreak::AL),
+    (0x2983, 0x2983, LineBreak::OP),
+    (0x2984, 0x2984, LineBreak::CL),
+    (0x2985, 0x2985, LineBreak::OP),
+    (0x2986, 0x2986, LineBreak::CL),
+    (0x2987, 0x2987, LineBreak::OP),
+    (0x2988, 0x2988, LineBreak::CL),
+    (0x2989, 0x2989, LineBreak::OP),
+    (0x298a, 0x298a, LineBreak::CL),
+    (0x298b, 0x298b, LineBreak::OP),
+    (0x298c, 0x298c, LineBreak::CL),
+    (0x298d, 0x298d, LineBreak::OP),
+    (0x298e, 0x298e, LineBreak::CL),
+    (0x298f, 0x298f, LineBreak::OP),
+    (0x2990, 0x2990, LineBreak::CL),
+    (0x2991, 0x2991, LineBreak::OP),
+    (0x2992, 0x2992, LineBreak::CL),
+    (0x2993, 0x2993, LineBreak::OP),
+    (0x2994, 0x2994, LineBreak::CL),
+    (0x2995, 0x2995, LineBreak::OP),
+    (0x2996, 0x2996, LineBreak::CL),
+    (0x2997, 0x2997, LineBreak::OP),
+    (0x2998, 0x2998, LineBreak::CL),
+    (0x2999, 0x29d7, LineBreak::AL),
+    (0x29d8, 0x29d8, LineBreak::OP),
+    (0x29d9, 0x29d9, LineBreak::CL),
+    (0x29da, 0x29da, LineBreak::OP),
+    (0x29db, 0x29db, LineBreak::CL),
+    (0x29dc, 0x29fb, LineBreak::AL),
+    (0x29fc, 0x29fc, LineBreak::OP),
+    (0x29fd, 0x29fd, LineBreak::CL),
+    (0x29fe, 0x2b54, LineBreak::AL),
+    (0x2b55, 0x2b59, LineBreak::AI),
+    (0x2b5a, 0x2b73, LineBreak::AL),
+    (0x2b76, 0x2cee, LineBreak::AL),
+    (0x2cef, 0x2cf1, LineBreak::CM),
+    (0x2cf2, 0x2cf3, LineBreak::AL),
+    (0x2cf9, 0x2cf9, LineBreak::EX),
+    (0x2cfa, 0x2cfc, LineBreak::BA),
+    (0x2cfd, 0x2cfd, LineBreak::AL),
+    (0x2cfe, 0x2cfe, LineBreak::EX),
+    (0x2cff, 0x2cff, LineBreak::BA),
+    (0x2d00, 0x2d25, LineBreak::AL),
+    (0x2d27, 0x2d27, LineBreak::AL),
+    (0x2d2d, 0x2d2d, LineBreak::AL),
+    (0x2d30, 0x2d67, LineBreak::AL),
+    (0x2d6f, 0x2d6f, LineBreak::AL),
+    (0x2d70, 0x2d70, LineBreak::BA),
+    (0x2d7f, 0x2d7f, LineBreak::CM),
+    (0x2d80, 0x2d96, LineBreak::AL),
+    (0x2da0, 0x2da6, LineBreak::AL),
+    (0x2da8, 0x2dae, LineBreak::AL),
+    (0x2db0, 0x2db6, LineBreak::AL),
+    (0x2db8, 0x2dbe, LineBreak::AL),
+    (0x2dc0, 0x2dc6, LineBreak::AL),
+    (0x2dc8, 0x2dce, LineBreak::AL),
+    (0x2dd0, 0x2dd6, LineBreak::AL),
+    (0x2dd8, 0x2dde, LineBreak::AL),
+    (0x2de0, 0x2dff, LineBreak::CM),
+    (0x2e00, 0x2e0d, LineBreak::QU),
+    (0x2e0e, 0x2e15, LineBreak::BA),
+    (0x2e16, 0x2e16, LineBreak::AL),
+    (0x2e17, 0x2e17, LineBreak::HH),
+    (0x2e18, 0x2e18, LineBreak::OP),
+    (0x2e19, 0x2e19, LineBreak::BA),
+    (0x2e1a, 0x2e1b, LineBreak::AL),
+    (0x2e1c, 0x2e1d, LineBreak::QU),
+    (0x2e1e, 0x2e1f, LineBreak::AL),
+    (0x2e20, 0x2e21, LineBreak::QU),
+    (0x2e22, 0x2e22, LineBreak::OP),
+    (0x2e23, 0x2e23, LineBreak::CL),
+    (0x2e24, 0x2e24, LineBreak::OP),
+    (0x2e25, 0x2e25, LineBreak::CL),
+    (0x2e26, 0x2e26, LineBreak::OP),
+    (0x2e27, 0x2e27, LineBreak::CL),
+    (0x2e28, 0x2e28, LineBreak::OP),
+    (0x2e29, 0x2e29, LineBreak::CL),
+    (0x2e2a, 0x2e2d, LineBreak::BA),
+    (0x2e2e, 0x2e2e, LineBreak::EX),
+    (0x2e2f, 0x2e2f, LineBreak::AL),
+    (0x2e30, 0x2e31, LineBreak::BA),
+    (0x2e32, 0x2e32, LineBreak::AL),
+    (0x2e33, 0x2e34, LineBreak::BA),
+    (0x2e35, 0x2e39, LineBreak::AL),
+    (0x2e3a, 0x2e3b, LineBreak::B2),
+    (0x2e3c, 0x2e3e, LineBreak::BA),
+    (0x2e3f, 0x2e3f, LineBreak::AL),
+    (0x2e40, 0x2e40, LineBreak::HH),
+    (0x2e41, 0x2e41, LineBreak::BA),
+    (0x2e42, 0x2e42, LineBreak::OP),
+    (0x2e43, 0x2e4a, LineBreak::BA),
+    (0x2e4b, 0x2e4b, LineBreak::AL),
+    (0x2e4c, 0x2e4c, LineBreak::BA),
+    (0x2e4d, 0x2e4d, LineBreak::AL),
+    (0x2e4e, 0x2e4f, LineBreak::BA),
+    (0x2e50, 0x2e52, LineBreak::AL),
+    (0x2e53, 0x2e54, LineBreak::EX),
+    (0x2e55, 0x2e55, LineBreak::OP),
+    (0x2e56, 0x2e56, LineBreak::CP),
+    (0x2e57, 0x2e57, LineBreak::OP),
+    (0x2e58, 0x2e58, LineBreak::CP),
+    (0x2e59, 0x2e59, LineBreak::OP),
+    (0x2e5a, 0x2e5a, LineBreak::CP),
+    (0x2e5b, 0x2e5b, LineBreak::OP),
+    (0x2e5c, 0x2e5c, LineBreak::CP),
+    (0x2e5d, 0x2e5d, LineBreak::HH),
+    (0x2e80, 0x2e99, LineBreak::ID),
+    (0x2e9b, 0x2ef3, LineBreak::ID),
+    (0x2f00, 0x2fd5, LineBreak::ID),
+    (0x2ff0, 0x2fff, LineBreak::ID),
+    (0x3000, 0x3000, LineBreak::BA),
+    (0x3001, 0x3002, LineBreak::CL),
+    (0x3003, 0x3004, LineBreak::ID),
+    (0x3005, 0x3005, LineBreak::NS),
+    (0x3006, 0x3007, LineBreak::ID),
+    (0x3008, 0x3008, LineBreak::OP),
+    (0x3009, 0x3009, LineBreak::CL),
+    (0x300a, 0x300a, LineBreak::OP),
+    (0x300b, 0x300b, LineBreak::CL),
+    (0x300c, 0x300c, LineBreak::OP),
+    (0x300d, 0x300d, LineBreak::CL),
+    (0x300e, 0x300e, LineBreak::OP),
+    (0x300f, 0x300f, LineBreak::CL),
+    (0x3010, 0x3010, LineBreak::OP),
+    (0x3011, 0x3011, LineBreak::CL),
+    (0x3012, 0x3013, LineBreak::ID),
+    (0x3014, 0x3014, LineBreak::OP),
+    (0x3015, 0x3015, LineBreak::CL),
+    (0x3016, 0x3016, LineBreak::OP),
+    (0x3017, 0x3017, LineBreak::CL),
+    (0x3018, 0x3018, LineBreak::OP),
+    (0x3019, 0x3019, LineBreak::CL),
+    (0x301a, 0x301a, LineBreak::OP),
+    (0x301b, 0x301b, LineBreak::CL),
+    (0x301c, 0x301c, LineBreak::NS),
+    (0x301d, 0x301d, LineBreak::OP),
+    (0x301e, 0x301f, LineBreak::CL),
+    (0x3020, 0x3029, LineBreak::ID),
+    (0x302a, 0x302f, LineBreak::CM),
+    (0x3030, 0x3034, LineBreak::ID),
+    (0x3035, 0x3035, LineBreak::CM),
+    (0x3036, 0x303a, LineBreak::ID),
+    (0x303b, 0x303c, LineBreak::NS),
+    (0x303d, 0x303f, LineBreak::ID),
+    (0x3041, 0x3041, LineBreak::CJ),
+    (0x3042, 0x3042, LineBreak::ID),
+    (0x3043, 0x3043, LineBreak::CJ),
+    (0x3044, 0x3044, LineBreak::ID),
+    (0x3045, 0x3045, LineBreak::CJ),
+    (0x3046, 0x3046, LineBreak::ID),
+    (0x3047, 0x3047, LineBreak::CJ),
+    (0x3048, 0x3048, LineBreak::ID),
+    (0x3049, 0x3049, LineBreak::CJ),
+    (0x304a, 0x3062, LineBreak::ID),
+    (0x3063, 0x3063, LineBreak::CJ),
+    (0x3064, 0x3082, LineBreak::ID),
+    (0x3083, 0x3083, LineBreak::CJ),
+    (0x3084, 0x3084, LineBreak::ID),
+    (0x3085, 0x3085, LineBreak::CJ),
+    (0x3086, 0x3086, LineBreak::ID),
+    (0x3087, 0x3087, LineBreak::CJ),
+    (0x3088, 0x308d, LineBreak::ID),
+    (0x308e, 0x308e, LineBreak::CJ),
+    (0x308f, 0x3094, LineBreak::ID),
+    (0x3095, 0x3096, LineBreak::CJ),
+    (0x3099, 0x309a, LineBreak::CM),
+    (0x309b, 0x309e, LineBreak::NS),
+    (0x309f, 0x309f, LineBreak::ID),
+    (0x30a0, 0x30a0, LineBreak::NS),
+    (0x30a1, 0x30a1, LineBreak::CJ),
+    (0x30a2, 0x30a2, LineBreak::ID),
+    (0x30a3, 0x30a3, LineBreak::CJ),
+    (0x30a4, 0x30a4, LineBreak::ID),
+    (0x30a5, 0x30a5, LineBreak::CJ),
+    (0x30a6, 0x30a6, LineBreak::ID),
+    (0x30a7, 0x30a7, LineBreak::CJ),
+    (0x30a8, 0x30a8, LineBreak::ID),
+    (0x30a9, 0x30a9, LineBreak::CJ),
+    (0x30aa, 0x30c2, LineBreak::ID),
+    (0x30c3, 0x30c3, LineBreak::CJ),
+    (0x30c4, 0x30e2, LineBreak::ID),
+    (0x30e3, 0x30e3, LineBreak::CJ),
+    (0x30e4, 0x30e4, LineBreak::ID),
+    (0x30e5, 0x30e5, LineBreak::CJ),
+    (0x30e6, 0x30e6, LineBreak::ID),
+    (0x30e7, 0x30e7, LineBreak::CJ),
+    (0x30e8, 0x30ed, LineBreak::ID),
+    (0x30ee, 0x30ee, LineBreak::CJ),
+    (0x30ef, 0x30f4, LineBreak::ID),
+    (0x30f5, 0x30f6, LineBreak::CJ),
+    (0x30f7, 0x30fa, LineBreak::ID),
+    (0x30fb, 0x30fb, LineBreak::NS),
+    (0x30fc, 0x30fc, LineBreak::CJ),
+    (0x30fd, 0x30fe, LineBreak::NS),
+    (0x30ff, 0x30ff, LineBreak::ID),
+    (0x3105, 0x312f, LineBreak::ID),
+    (0x3131, 0x318e, LineBreak::ID),
+    (0x3190, 0x31e5, LineBreak::ID),
+    (0x31ef, 0x31ef, LineBreak::ID),
+    (0x31f0, 0x31ff, LineBreak::CJ),
+    (0x3200, 0x321e, LineBreak::ID),
+    (0x3220, 0x3247, LineBreak::ID),
+    (0x3248, 0x324f, LineBreak::AI),
+    (0x3250, 0x4dbf, LineBreak::ID),
+    (0x4dc0, 0x4dff, LineBreak::AL),
+    (0x4e00, 0xa014, LineBreak::ID),
+    (0xa015, 0xa015, LineBreak::NS),
+    (0xa016, 0xa48c, LineBreak::ID),
+    (0xa490, 0xa4c6, LineBreak::ID),
+    (0xa4d0, 0xa4fd, LineBreak::AL),
+    (0xa4fe, 0xa4ff, LineBreak::BA),
+    (0xa500, 0xa60c, LineBreak::AL),
+    (0xa60d, 0xa60d, LineBreak::BA),
+    (0xa60e, 0xa60e, LineBreak::EX),
+    (0xa60f, 0xa60f, LineBreak::BA),
+    (0xa610, 0xa61f, LineBreak::AL),
+    (0xa620, 0xa629, LineBreak::NU),
+    (0xa62a, 0xa62b, LineBreak::AL),
+    (0xa640, 0xa66e, LineBreak::AL),
+    (0xa66f, 0xa672, LineBreak::CM),
+    (0xa673, 0xa673, LineBreak::AL),
+    (0xa674, 0xa67d, LineBreak::CM),
+    (0xa67e, 0xa69d, LineBreak::AL),
+    (0xa69e, 0xa69f, LineBreak::CM),
+    (0xa6a0, 0xa6ef, LineBreak::AL),
+    (0xa6f0, 0xa6f1, LineBreak::CM),
+    (0xa6f2, 0xa6f2, LineBreak::AL),
+    (0xa6f3, 0xa6f7, LineBreak::BA),
+    (0xa700, 0xa7dc, LineBreak::AL),
+    (0xa7f1, 0xa801, LineBreak::AL),
+    (0xa802, 0xa802, LineBreak::CM),
+    (0xa803, 0xa805, LineBreak::AL),
+    (0xa806, 0xa806, LineBreak::CM),
+    (0xa807, 0xa80a, LineBreak::AL),
+    (0xa80b, 0xa80b, LineBreak::CM),
+    (0xa80c, 0xa822, LineBreak::AL),
+    (0xa823, 0xa827, LineBreak::CM),
+    (0xa828, 0xa82b, LineBreak::AL),
+    (0xa82c, 0xa82c, LineBreak::CM),
+    (0xa830, 0xa837, LineBreak::AL),
+    (0xa838, 0xa838, LineBreak::PO),
+    (0xa839, 0xa839, LineBreak::AL),
+    (0xa840, 0xa873, LineBreak::AL),
+    (0xa874, 0xa875, LineBreak::BB),
+    (0xa876, 0xa877, LineBreak::EX),
+    (0xa880, 0xa881, LineBreak::CM),
+    (0xa882, 0xa8b3, LineBreak::AL),
+    (0xa8b4, 0xa8c5, LineBreak::CM),
+    (0xa8ce, 0xa8cf, LineBreak::BA),
+    (0xa8d0, 0xa8d9, LineBreak::NU),
+    (0xa8e0, 0xa8f1, LineBreak::CM),
+    (0xa8f2, 0xa8fb, LineBreak::AL),
+    (0xa8fc, 0xa8fc, LineBreak::BB),
+    (0xa8fd, 0xa8fe, LineBreak::AL),
+    (0xa8ff, 0xa8ff, LineBreak::CM),
+    (0xa900, 0xa909, LineBreak::NU),
+    (0xa90a, 0xa925, LineBreak::AL),
+    (0xa926, 0xa92d, LineBreak::CM),
+    (0xa92e, 0xa92f, LineBreak::BA),
+    (0xa930, 0xa946, LineBreak::AL),
+    (0xa947, 0xa953, LineBreak::CM),
+    (0xa95f, 0xa95f, LineBreak::AL),
+    (0xa960, 0xa97c, LineBreak::JL),
+    (0xa980, 0xa983, LineBreak::CM),
+    (0xa984, 0xa9b2, LineBreak::AK),
+    (0xa9b3, 0xa9bf, LineBreak::CM),
+    (0xa9c0, 0xa9c0, LineBreak::VI),
+    (0xa9c1, 0xa9c6, LineBreak::ID),
+    (0xa9c7, 0xa9c9, LineBreak::BA),
+    (0xa9ca, 0xa9cd, LineBreak::ID),
+    (0xa9cf, 0xa9cf, LineBreak::BA),
+    (0xa9d0, 0xa9d9, LineBreak::AS),
+    (0xa9de, 0xa9df, LineBreak::ID),
+    (0xa9e0, 0xa9ef, LineBreak::SA),
+    (0xa9f0, 0xa9f9, LineBreak::NU),
+    (0xa9fa, 0xa9fe, LineBreak::SA),
+    (0xaa00, 0xaa28, LineBreak::AS),
+    (0xaa29, 0xaa36, LineBreak::CM),
+    (0xaa40, 0xaa42, LineBreak::BA),
+    (0xaa43, 0xaa43, LineBreak::CM),
+    (0xaa44, 0xaa4b, LineBreak::BA),
+    (0xaa4c, 0xaa4d, LineBreak::CM),
+    (0xaa50, 0xaa59, LineBreak::AS),
+    (0xaa5c, 0xaa5c, LineBreak::ID),
+    (0xaa5d, 0xaa5f, LineBreak::BA),
+    (0xaa60, 0xaac2, LineBreak::SA),
+    (0xaadb, 0xaadf, LineBreak::SA),
+    (0xaae0, 0xaaea, LineBreak::AL),
+    (0xaaeb, 0xaaef, LineBreak::CM),
+    (0xaaf0, 0xaaf1, LineBreak::BA),
+    (0xaaf2, 0xaaf4, LineBreak::AL),
+    (0xaaf5, 0xaaf6, LineBreak::CM),
+    (0xab01, 0xab06, LineBreak::AL),
+    (0xab09, 0xab0e, LineBreak::AL),
+    (0xab11, 0xab16, LineBreak::AL),
+    (0xab20, 0xab26, LineBreak::AL),
+    (0xab28, 0xab2e, LineBreak::AL),
+    (0xab30, 0xab6b, LineBreak::AL),
+    (0xab70, 0xabe2, LineBreak::AL),
+    (0xabe3, 0xabea, LineBreak::CM),
+    (0xabeb, 0xabeb, LineBreak::BA),
+    (0xabec, 0xabed, LineBreak::CM),
+    (0xabf0, 0xabf9, LineBreak::NU),
+    (0xac00, 0xac00, LineBreak::H2),
+    (0xac01, 0xac1b, LineBreak::H3),
+    (0xac1c, 0xac1c, LineBreak::H2),
+    (0xac1d, 0xac37, LineBreak::H3),
+    (0xac38, 0xac38, LineBreak::H2),
+    (0xac39, 0xac53, LineBreak::H3),
+    (0xac54, 0xac54, LineBreak::H2),
+    (0xac55, 0xac6f, LineBreak::H3),
+    (0xac70, 0xac70, LineBreak::H2),
+    (0xac71, 0xac8b, LineBreak::H3),
+    (0xac8c, 0xac8c, LineBreak::H2),
+    (0xac8d, 0xaca7, LineBreak::H3),
+    (0xaca8, 0xaca8, LineBreak::H2),
+    (0xaca9, 0xacc3, LineBreak::H3),
+    (0xacc4, 0xacc4, LineBreak::H2),
+    (0xacc5, 0xacdf, LineBreak::H3),
+    (0xace0, 0xace0, LineBreak::H2),
+    (0xace1, 0xacfb, LineBreak::H3),
+    (0xacfc, 0xacfc, LineBreak::H2),
+    (0xacfd, 0xad17, LineBreak::H3),
+    (0xad18, 0xad18, LineBreak::H2),
+    (0xad19, 0xad33, LineBreak::H3),
+    (0xad34, 0xad34, LineBreak::H2),
+    (0xad35, 0xad4f, LineBreak::H3),
+    (0xad50, 0xad50, LineBreak::H2),
+    (0xad51, 0xad6b, LineBreak::H3),
+    (0xad6c, 0xad6c, LineBreak::H2),
+    (0xad6d, 0xad87, LineBreak::H3),
+    (0xad88, 0xad88, LineBreak::H2),
+    (0xad89, 0xada3, LineBreak::H3),
+    (0xada4, 0xada4, LineBreak::H2),
+    (0xada5, 0xadbf, LineBreak::H3),
+    (0xadc0, 0xadc0, LineBreak::H2),
+    (0xadc1, 0xaddb, LineBreak::H3),
+    (0xaddc, 0xaddc, LineBreak::H2),
+    (0xaddd, 0xadf7, LineBreak::H3),
+    (0xadf8, 0xadf8, LineBreak::H2),
+    (0xadf9, 0xae13, LineBreak::H3),
+    (0xae14, 0xae14, LineBreak::H2),
+    (0xae15, 0xae2f, LineBreak::H3),
+    (0xae30, 0xae30, LineBreak::H2),
+    (0xae31, 0xae4b, LineBreak::H3),
+    (0xae4c, 0xae4c, LineBreak::H2),
+    (0xae4d, 0xae67, LineBreak::H3),
+    (0xae68, 0xae68, LineBreak::H2),
+    (0xae69, 0xae83, LineBreak::H3),
+    (0xae84, 0xae84, LineBreak::H2),
+    (0xae85, 0xae9f, LineBreak::H3),
+    (0xaea0, 0xaea0, LineBreak::H2),
+    (0xaea1, 0xaebb, LineBreak::H3),
+    (0xaebc, 0xaebc, LineBreak::H2),
+    (0xaebd, 0xaed7, LineBreak::H3),
+    (0xaed8, 0xaed8, LineBreak::H2),
+    (0xaed9, 0xaef3, LineBreak::H3),
+    (0xaef4, 0xaef4, LineBreak::H2),
+    (0xaef5, 0xaf0f, LineBreak::H3),
+    (0xaf10, 0xaf10, LineBreak::H2),
+    (0xaf11, 0xaf2b, LineBreak::H3),
+    (0xaf2c, 0xaf2c, LineBreak::H2),
+    (0xaf2d, 0xaf47, LineBreak::H3),
+    (0xaf48, 0xaf48, LineBreak::H2),
+    (0xaf49, 0xaf63, LineBreak::H3),
+    (0xaf64, 0xaf64, LineBreak::H2),
+    (0xaf65, 0xaf7f, LineBreak::H3),
+    (0xaf80, 0xaf80, LineBreak::H2),
+    (0xaf81, 0xaf9b, LineBreak::H3),
+    (0xaf9c, 0xaf9c, LineBreak::H2),
+    (0xaf9d, 0xafb7, LineBreak::H3),
+    (0xafb8, 0xafb8, LineBreak::H2),
+    (0xafb9, 0xafd3, LineBreak::H3),
+    (0xafd4, 0xafd4, LineBreak::H2),
+    (0xafd5, 0xafef, LineBreak::H3),
+    (0xaff0, 0xaff0, LineBreak::H2),
+    (0xaff1, 0xb00b, LineBreak::H3),
+    (0xb00c, 0xb00c, LineBreak::H2),
+    (0xb00d, 0xb027, LineBreak::H3),
+    (0xb028, 0xb028, LineBreak::H2),
+    (0xb029, 0xb043, LineBreak::H3),
+    (0xb044, 0xb044, LineBreak::H2),
+    (0xb045, 0xb05f, LineBreak::H3),
+    (0xb060, 0xb060, LineBreak::H2),
+    (0xb061, 0xb07b, LineBreak::H3),
+    (0xb07c, 0xb07c, LineBreak::H2),
+    (0xb07d, 0xb097, LineBreak::H3),
+    (0xb098, 0xb098, LineBreak::H2),
+    (0xb099, 0xb0b3, LineBreak::H3),
+    (0xb0b4, 0xb0b4, LineBreak::H2),
+    (0xb0b5, 0xb0cf, LineBreak::H3),
+    (0xb0d0, 0xb0d0, LineBreak::H2),
+    (0xb0d1, 0xb0eb, LineBreak::H3),
+    (0xb0ec, 0xb0ec, LineBreak::H2),
+    (0xb0ed, 0xb107, LineBreak::H3),
+    (0xb108, 0xb108, LineBreak::H2),
+    (0xb109, 0xb123, LineBreak::H3),
+    (0xb124, 0xb124, LineBreak::H2),
+    (0xb125, 0xb13f, LineBreak::H3),
+    (0xb140, 0xb140, LineBreak::H2),
+    (0xb141, 0xb15b, LineBreak::H3),
+    (0xb15c, 0xb15c, LineBreak::H2),
+    (0xb15d, 0xb177, LineBreak::H3),
+    (0xb178, 0xb178, LineBreak::H2),
+    (0xb179, 0xb193, LineBreak::H3),
+    (0xb194, 0xb194, LineBreak::H2),
+    (0xb195, 0xb1af, LineBreak::H3),
+    (0xb1b0, 0xb1b0, LineBreak::H2),
+    (0xb1b1, 0xb1cb, LineBreak::H3),
+    (0xb1cc, 0xb1cc, LineBreak::H2),
+    (0xb1cd, 0xb1e7, LineBreak::H3),
+    (0xb1e8, 0xb1e8, LineBreak::H2),
+    (0xb1e9, 0xb203, LineBreak::H3),
+    (0xb204, 0xb204, LineBreak::H2),
+    (0xb205, 0xb21f, LineBreak::H3),
+    (0xb220, 0xb220, LineBreak::H2),
+    (0xb221, 0xb23b, LineBreak::H3),
+    (0xb23c, 0xb23c, LineBreak::H2),
+    (0xb23d, 0xb257, LineBreak::H3),
+    (0xb258, 0xb258, LineBreak::H2),
+    (0xb259, 0xb273, LineBreak::H3),
+    (0xb274, 0xb274, LineBreak::H2),
+    (0xb275, 0xb28f, LineBreak::H3),
+    (0xb290, 0xb290, LineBreak::H2),
+    (0xb291, 0xb2ab, LineBreak::H3),
+    (0xb2ac, 0xb2ac, LineBreak::H2),
+    (0xb2ad, 0xb2c7, LineBreak::H3),
+    (0xb2c8, 0xb2c8, LineBreak::H2),
+    (0xb2c9, 0xb2e3, LineBreak::H3),
+    (0xb2e4, 0xb2e4, LineBreak::H2),
+    (0xb2e5, 0xb2ff, LineBreak::H3),
+    (0xb300, 0xb300, LineBreak::H2),
+    (0xb301, 0xb31b, LineBreak::H3),
+    (0xb31c, 0xb31c, LineBreak::H2),
+    (0xb31d, 0xb337, LineBreak::H3),
+    (0xb338, 0xb338, LineBreak::H2),
+    (0xb339, 0xb353, LineBreak::H3),
+    (0xb354, 0xb354, LineBreak::H2),
+    (0xb355, 0xb36f, LineBreak::H3),
+    (0xb370, 0xb370, LineBreak::H2),
+    (0xb371, 0xb38b, LineBreak::H3),
+    (0xb38c, 0xb38c, LineBreak::H2),
+    (0xb38d, 0xb3a7, LineBreak::H3),
+    (0xb3a8, 0xb3a8, LineBreak::H2),
+    (0xb3a9, 0xb3c3, LineBreak::H3),
+    (0xb3c4, 0xb3c4, LineBreak::H2),
+    (0xb3c5, 0xb3df, LineBreak::H3),
+    (0xb3e0, 0xb3e0, LineBreak::H2),
+    (0xb3e1, 0xb3fb, LineBreak::H3),
+    (0xb3fc, 0xb3fc, LineBreak::H2),
+    (0xb3fd, 0xb417, LineBreak::H3),
+    (0xb418, 0xb418, LineBreak::H2),
+    (0xb419, 0xb433, LineBreak::H3),
+    (0xb434, 0xb434, LineBreak::H2),
+    (0xb435, 0xb44f, LineBreak::H3),
+    (0xb450, 0xb450, LineBreak::H2),
+    (0xb451, 0xb46b, LineBreak::H3),
+    (0xb46c, 0xb46c, LineBreak::H2),
+    (0xb46d, 0xb487, LineBreak::H3),
+    (0xb488, 0xb488, LineBreak::H2),
+    (0xb489, 0xb4a3, LineBreak::H3),
+    (0xb4a4, 0xb4a4, LineBreak::H2),
+    (0xb4a5, 0xb4bf, LineBreak::H3),
+    (0xb4c0, 0xb4c0, LineBreak::H2),
+    (0xb4c1, 0xb4db, LineBreak::H3),
+    (0xb4dc, 0xb4dc, LineBreak::H2),
+    (0xb4dd, 0xb4f7, LineBreak::H3),
+    (0xb4f8, 0xb4f8, LineBreak::H2),
+    (0xb4f9, 0xb513, LineBreak::H3),
+    (0xb514, 0xb514, LineBreak::H2),
+    (0xb515, 0xb52f, LineBreak::H3),
+    (0xb530, 0xb530, LineBreak::H2),
+    (0xb531, 0xb54b, LineBreak::H3),
+    (0xb54c, 0xb54c, LineBreak::H2),
+    (0xb54d, 0xb567, LineBreak::H3),
+    (0xb568, 0xb568, LineBreak::H2),
+    (0xb569, 0xb583, LineBreak::H3),
+    (0xb584, 0xb584, LineBreak::H2),
+    (0xb585, 0xb59f, LineBreak::H3),
+    (0xb5a0, 0xb5a0, LineBreak::H2),
+    (0xb5a1, 0xb5bb, LineBreak::H3),
+    (0xb5bc, 0xb5bc, LineBreak::H2),
+    (0xb5bd, 0xb5d7, LineBreak::H3),
+    (0xb5d8, 0xb5d8, LineBreak::H2),
+    (0xb5d9, 0xb5f3, LineBreak::H3),
+    (0xb5f4, 0xb5f4, LineBreak::H2),
+    (0xb5f5, 0xb60f, LineBreak::H3),
+    (0xb610, 0xb610, LineBreak::H2),
+    (0xb611, 0xb62b, LineBreak::H3),
+    (0xb62c, 0xb62c, LineBreak::H2),
+    (0xb62d, 0xb647, LineBreak::H3),
+    (0xb648, 0xb648, LineBreak::H2),
+    (0xb649, 0xb663, LineBreak::H3),
+    (0xb664, 0xb664, LineBreak::H2),
+    (0xb665, 0xb67f, LineBreak::H3),
+    (0xb680, 0xb680, LineBreak::H2),
+    (0xb681, 0xb69b, LineBreak::H3),
+    (0xb69c, 0xb69c, LineBreak::H2),
+    (0xb69d, 0xb6b7, LineBreak::H3),
+    (0xb6b8, 0xb6b8, LineBreak::H2),
+    (0xb6b9, 0xb6d3, LineBreak::H3),
+    (0xb6d4, 0xb6d4, LineBreak::H2),
+    (0xb6d5, 0xb6ef, LineBreak::H3),
+    (0xb6f0, 0xb6f0, LineBreak::H2),
+    (0xb6f1, 0xb70b, LineBreak::H3),
+    (0xb70c, 0xb70c, LineBreak::H2),
+    (0xb70d, 0xb727, LineBreak::H3),
+    (0xb728, 0xb728, LineBreak::H2),
+    (0xb729, 0xb743, LineBreak::H3),
+    (0xb744, 0xb744, LineBreak::H2),
+    (0xb745, 0xb75f, LineBreak::H3),
+    (0xb760, 0xb760, LineBreak::H2),
+    (0xb761, 0xb77b, LineBreak::H3),
+    (0xb77c, 0xb77c, LineBreak::H2),
+    (0xb77d, 0xb797, LineBreak::H3),
+    (0xb798, 0xb798, LineBreak::H2),
+    (0xb799, 0xb7b3, LineBreak::H3),
+    (0xb7b4, 0xb7b4, LineBreak::H2),
+    (0xb7b5, 0xb7cf, LineBreak::H3),
+    (0xb7d0, 0xb7d0, LineBreak::H2),
+    (0xb7d1, 0xb7eb, LineBreak::H3),
+    (0xb7ec, 0xb7ec, LineBreak::H2),
+    (0xb7ed, 0xb807, LineBreak::H3),
+    (0xb808, 0xb808, LineBreak::H2),
+    (0xb809, 0xb823, LineBreak::H3),
+    (0xb824, 0xb824, LineBreak::H2),
+    (0xb825, 0xb83f, LineBreak::H3),
+    (0xb840, 0xb840, LineBreak::H2),
+    (0xb841, 0xb85b, LineBreak::H3),
+    (0xb85c, 0xb85c, LineBreak::H2),
+    (0xb85d, 0xb877, LineBreak::H3),
+    (0xb878, 0xb878, LineBreak::H2),
+    (0xb879, 0xb893, LineBreak::H3),
+    (0xb894, 0xb894, LineBreak::H2),
+    (0xb895, 0xb8af, LineBreak::H3),
+    (0xb8b0, 0xb8b0, LineBreak::H2),
+    (0xb8b1, 0xb8cb, LineBreak::H3),
+    (0xb8cc, 0xb8cc, LineBreak::H2),
+    (0xb8cd, 0xb8e7, LineBreak::H3),
+    (0xb8e8, 0xb8e8, LineBreak::H2),
+    (0xb8e9, 0xb903, LineBreak::H3),
+    (0xb904, 0xb904, LineBreak::H2),
+    (0xb905, 0xb91f, LineBreak::H3),
+    (0xb920, 0xb920, LineBreak::H2),
+    (0xb921, 0xb93b, LineBreak::H3),
+    (0xb93c, 0xb93c, LineBreak::H2),
+    (0xb93d, 0xb957, LineBreak::H3),
+    (0xb958, 0xb958, LineBreak::H2),
+    (0xb959, 0xb973, LineBreak::H3),
+    (0xb974, 0xb974, LineBreak::H2),
+    (0xb975, 0xb98f, LineBreak::H3),
+    (0xb990, 0xb990, LineBreak::H2),
+    (0xb991, 0xb9ab, LineBreak::H3),
+    (0xb9ac, 0xb9ac, LineBreak::H2),
+    (0xb9ad, 0xb9c7, LineBreak::H3),
+    (0xb9c8, 0xb9c8, LineBreak::H2),
+    (0xb9c9, 0xb9e3, LineBreak::H3),
+    (0xb9e4, 0xb9e4, LineBreak::H2),
+    (0xb9e5, 0xb9ff, LineBreak::H3),
+    (0xba00, 0xba00, LineBreak::H2),
+    (0xba01, 0xba1b, LineBreak::H3),
+    (0xba1c, 0xba1c, LineBreak::H2),
+    (0xba1d, 0xba37, LineBreak::H3),
+    (0xba38, 0xba38, LineBreak::H2),
+    (0xba39, 0xba53, LineBreak::H3),
+    (0xba54, 0xba54, LineBreak::H2),
+    (0xba55, 0xba6f, LineBreak::H3),
+    (0xba70, 0xba70, LineBreak::H2),
+    (0xba71, 0xba8b, LineBreak::H3),
+    (0xba8c, 0xba8c, LineBreak::H2),
+    (0xba8d, 0xbaa7, LineBreak::H3),
+    (0xbaa8, 0xbaa8, LineBreak::H2),
+    (0xbaa9, 0xbac3, LineBreak::H3),
+    (0xbac4, 0xbac4, LineBreak::H2),
+    (0xbac5, 0xbadf, LineBreak::H3),
+    (0xbae0, 0xbae0, LineBreak::H2),
+    (0xbae1, 0xbafb, LineBreak::H3),
+    (0xbafc, 0xbafc, LineBreak::H2),
+    (0xbafd, 0xbb17, LineBreak::H3),
+    (0xbb18, 0xbb18, LineBreak::H2),
+    (0xbb19, 0xbb33, LineBreak::H3),
+    (0xbb34, 0xbb34, LineBreak::H2),
+    (0xbb35, 0xbb4f, LineBreak::H3),
+    (0xbb50, 0xbb50, LineBreak::H2),
+    (0xbb51, 0xbb6b, LineBreak::H3),
+    (0xbb6c, 0xbb6c, LineBreak::H2),
+    (0xbb6d, 0xbb87, LineBreak::H3),
+    (0xbb88, 0xbb88, LineBreak::H2),
+    (0xbb89, 0xbba3, LineBreak::H3),
+    (0xbba4, 0xbba4, LineBreak::H2),
+    (0xbba5, 0xbbbf, LineBreak::H3),
+    (0xbbc0, 0xbbc0, LineBreak::H2),
+    (0xbbc1, 0xbbdb, LineBreak::H3),
+    (0xbbdc, 0xbbdc, LineBreak::H2),
+    (0xbbdd, 0xbbf7, LineBreak::H3),
+    (0xbbf8, 0xbbf8, LineBreak::H2),
+    (0xbbf9, 0xbc13, LineBreak::H3),
+    (0xbc14, 0xbc14, LineBreak::H2),
+    (0xbc15, 0xbc2f, LineBreak::H3),
+    (0xbc30, 0xbc30, LineBreak::H2),
+    (0xbc31, 0xbc4b, LineBreak::H3),
+    (0xbc4c, 0xbc4c, LineBreak::H2),
+    (0xbc4d, 0xbc67, LineBreak::H3),
+    (0xbc68, 0xbc68, LineBreak::H2),
+    (0xbc69, 0xbc83, LineBreak::H3),
+    (0xbc84, 0xbc84, LineBreak::H2),
+    (0xbc85, 0xbc9f, LineBreak::H3),
+    (0xbca0, 0xbca0, LineBreak::H2),
+    (0xbca1, 0xbcbb, LineBreak::H3),
+    (0xbcbc, 0xbcbc, LineBreak::H2),
+    (0xbcbd, 0xbcd7, LineBreak::H3),
+    (0xbcd8, 0xbcd8, LineBreak::H2),
+    (0xbcd9, 0xbcf3, LineBreak::H3),
+    (0xbcf4, 0xbcf4, LineBreak::H2),
+    (0xbcf5, 0xbd0f, LineBreak::H3),
+    (0xbd10, 0xbd10, LineBreak::H2),
+    (0xbd11, 0xbd2b, LineBreak::H3),
+    (0xbd2c, 0xbd2c, LineBreak::H2),
+    (0xbd2d, 0xbd47, LineBreak::H3),
+    (0xbd48, 0xbd48, LineBreak::H2),
+    (0xbd49, 0xbd63, LineBreak::H3),
+    (0xbd64, 0xbd64, LineBreak::H2),
+    (0xbd65, 0xbd7f, LineBreak::H3),
+    (0xbd80, 0xbd80, LineBreak::H2),
+    (0xbd81, 0xbd9b, LineBreak::H3),
+    (0xbd9c, 0xbd9c, LineBreak::H2),
+    (0xbd9d, 0xbdb7, LineBreak::H3),
+    (0xbdb8, 0xbdb8, LineBreak::H2),
+    (0xbdb9, 0xbdd3, LineBreak::H3),
+    (0xbdd4, 0xbdd4, LineBreak::H2),
+    (0xbdd5, 0xbdef, LineBreak::H3),
+    (0xbdf0, 0xbdf0, LineBreak::H2),
+    (0xbdf1, 0xbe0b, LineBreak::H3),
+    (0xbe0c, 0xbe0c, LineBreak::H2),
+    (0xbe0d, 0xbe27, LineBreak::H3),
+    (0xbe28, 0xbe28, LineBreak::H2),
+    (0xbe29, 0xbe43, LineBreak::H3),
+    (0xbe44, 0xbe44, LineBreak::H2),
+    (0xbe45, 0xbe5f, LineBreak::H3),
+    (0xbe60, 0xbe60, LineBreak::H2),
+    (0xbe61, 0xbe7b, LineBreak::H3),
+    (0xbe7c, 0xbe7c, LineBreak::H2),
+    (0xbe7d, 0xbe97, LineBreak::H3),
+    (0xbe98, 0xbe98, LineBreak::H2),
+    (0xbe99, 0xbeb3, LineBreak::H3),
+    (0xbeb4, 0xbeb4, LineBreak::H2),
+    (0xbeb5, 0xbecf, LineBreak::H3),
+    (0xbed0, 0xbed0, LineBreak::H2),
+    (0xbed1, 0xbeeb, LineBreak::H3),
+    (0xbeec, 0xbeec, LineBreak::H2),
+    (0xbeed, 0xbf07, LineBreak::H3),
+    (0xbf08, 0xbf08, LineBreak::H2),
+    (0xbf09, 0xbf23, LineBreak::H3),
+    (0xbf24, 0xbf24, LineBreak::H2),
+    (0xbf25, 0xbf3f, LineBreak::H3),
+    (0xbf40, 0xbf40, LineBreak::H2),
+    (0xbf41, 0xbf5b, LineBreak::H3),
+    (0xbf5c, 0xbf5c, LineBreak::H2),
+    (0xbf5d, 0xbf77, LineBreak::H3),
+    (0xbf78, 0xbf78, LineBreak::H2),
+    (0xbf79, 0xbf93, LineBreak::H3),
+    (0xbf94, 0xbf94, LineBreak::H2),
+    (0xbf95, 0xbfaf, LineBreak::H3),
+    (0xbfb0, 0xbfb0, LineBreak::H2),
+    (0xbfb1, 0xbfcb, LineBreak::H3),
+    (0xbfcc, 0xbfcc, LineBreak::H2),
+    (0xbfcd, 0xbfe7, LineBreak::H3),
+    (0xbfe8, 0xbfe8, LineBreak::H2),
+    (0xbfe9, 0xc003, LineBreak::H3),
+    (0xc004, 0xc004, LineBreak::H2),
+    (0xc005, 0xc01f, LineBreak::H3),
+    (0xc020, 0xc020, LineBreak::H2),
+    (0xc021, 0xc03b, LineBreak::H3),
+    (0xc03c, 0xc03c, LineBreak::H2),
+    (0xc03d, 0xc057, LineBreak::H3),
+    (0xc058, 0xc058, LineBreak::H2),
+    (0xc059, 0xc073, LineBreak::H3),
+    (0xc074, 0xc074, LineBreak::H2),
+    (0xc075, 0xc08f, LineBreak::H3),
+    (0xc090, 0xc090, LineBreak::H2),
+    (0xc091, 0xc0ab, LineBreak::H3),
+    (0xc0ac, 0xc0ac, LineBreak::H2),
+    (0xc0ad, 0xc0c7, LineBreak::H3),
+    (0xc0c8, 0xc0c8, LineBreak::H2),
+    (0xc0c9, 0xc0e3, LineBreak::H3),
+    (0xc0e4, 0xc0e4, LineBreak::H2),
+    (0xc0e5, 0xc0ff, LineBreak::H3),
+    (0xc100, 0xc100, LineBreak::H2),
+    (0xc101, 0xc11b, LineBreak::H3),
+    (0xc11c, 0xc11c, LineBreak::H2),
+    (0xc11d, 0xc137, LineBreak::H3),
+    (0xc138, 0xc138, LineBreak::H2),
+    (0xc139, 0xc153, LineBreak::H3),
+    (0xc154, 0xc154, LineBreak::H2),
+    (0xc155, 0xc16f, LineBreak::H3),
+    (0xc170, 0xc170, LineBreak::H2),
+    (0xc171, 0xc18b, LineBreak::H3),
+    (0xc18c, 0xc18c, LineBreak::H2),
+    (0xc18d, 0xc1a7, LineBreak::H3),
+    (0xc1a8, 0xc1a8, LineBreak::H2),
+    (0xc1a9, 0xc1c3, LineBreak::H3),
+    (0xc1c4, 0xc1c4, LineBreak::H2),
+    (0xc1c5, 0xc1df, LineBreak::H3),
+    (0xc1e0, 0xc1e0, LineBreak::H2),
+    (0xc1e1, 0xc1fb, LineBreak::H3),
+    (0xc1fc, 0xc1fc, LineBreak::H2),
+    (0xc1fd, 0xc217, LineBreak::H3),
+    (0xc218, 0xc218, LineBreak::H2),
+    (0xc219, 0xc233, LineBreak::H3),
+    (0xc234, 0xc234, LineBreak::H2),
+    (0xc235, 0xc24f, LineBreak::H3),
+    (0xc250, 0xc250, LineBreak::H2),
+    (0xc251, 0xc26b, LineBreak::H3),
+    (0xc26c, 0xc26c, LineBreak::H2),
+    (0xc26d, 0xc287, LineBreak::H3),
+    (0xc288, 0xc288, LineBreak::H2),
+    (0xc289, 0xc2a3, LineBreak::H3),
+    (0xc2a4, 0xc2a4, LineBreak::H2),
+    (0xc2a5, 0xc2bf, LineBreak::H3),
+    (0xc2c0, 0xc2c0, LineBreak::H2),
+    (0xc2c1, 0xc2db, LineBreak::H3),
+    (0xc2dc, 0xc2dc, LineBreak::H2),
+    (0xc2dd, 0xc2f7, LineBreak::H3),
+    (0xc2f8, 0xc2f8, LineBreak::H2),
+    (0xc2f9, 0xc313, LineBreak::H3),
+    (0xc314, 0xc314, LineBreak::H2),
+    (0xc315, 0xc32f, LineBreak::H3),
+    (0xc330, 0xc330, LineBreak::H2),
+    (0xc331, 0xc34b, LineBreak::H3),
+    (0xc34c, 0xc34c, LineBreak::H2),
+    (0xc34d, 0xc367, LineBreak::H3),
+    (0xc368, 0xc368, LineBreak::H2),
+    (0xc369, 0xc383, LineBreak::H3),
+    (0xc384, 0xc384, LineBreak::H2),
+    (0xc385, 0xc39f, LineBreak::H3),
+    (0xc3a0, 0xc3a0, LineBreak::H2),
+    (0xc3a1, 0xc3bb, LineBreak::H3),
+    (0xc3bc, 0xc3bc, LineBreak::H2),
+    (0xc3bd, 0xc3d7, LineBreak::H3),
+    (0xc3d8, 0xc3d8, LineBreak::H2),
+    (0xc3d9, 0xc3f3, LineBreak::H3),
+    (0xc3f4, 0xc3f4, LineBreak::H2),
+    (0xc3f5, 0xc40f, LineBreak::H3),
+    (0xc410, 0xc410, LineBreak::H2),
+    (0xc411, 0xc42b, LineBreak::H3),
+    (0xc42c, 0xc42c, LineBreak::H2),
+    (0xc42d, 0xc447, LineBreak::H3),
+    (0xc448, 0xc448, LineBreak::H2),
+    (0xc449, 0xc463, LineBreak::H3),
+    (0xc464, 0xc464, LineBreak::H2),
+    (0xc465, 0xc47f, LineBreak::H3),
+    (0xc480, 0xc480, LineBreak::H2),
+    (0xc481, 0xc49b, LineBreak::H3),
+    (0xc49c, 0xc49c, LineBreak::H2),
+    (0xc49d, 0xc4b7, LineBreak::H3),
+    (0xc4b8, 0xc4b8, LineBreak::H2),
+    (0xc4b9, 0xc4d3, LineBreak::H3),
+    (0xc4d4, 0xc4d4, LineBreak::H2),
+    (0xc4d5, 0xc4ef, LineBreak::H3),
+    (0xc4f0, 0xc4f0, LineBreak::H2),
+    (0xc4f1, 0xc50b, LineBreak::H3),
+    (0xc50c, 0xc50c, LineBreak::H2),
+    (0xc50d, 0xc527, LineBreak::H3),
+    (0xc528, 0xc528, LineBreak::H2),
+    (0xc529, 0xc543, LineBreak::H3),
+    (0xc544, 0xc544, LineBreak::H2),
+    (0xc545, 0xc55f, LineBreak::H3),
+    (0xc560, 0xc560, LineBreak::H2),
+    (0xc561, 0xc57b, LineBreak::H3),
+    (0xc57c, 0xc57c, LineBreak::H2),
+    (0xc57d, 0xc597, LineBreak::H3),
+    (0xc598, 0xc598, LineBreak::H2),
+    (0xc599, 0xc5b3, LineBreak::H3),
+    (0xc5b4, 0xc5b4, LineBreak::H2),
+    (0xc5b5, 0xc5cf, LineBreak::H3),
+    (0xc5d0, 0xc5d0, LineBreak::H2),
+    (0xc5d1, 0xc5eb, LineBreak::H3),
+    (0xc5ec, 0xc5ec, LineBreak::H2),
+    (0xc5ed, 0xc607, LineBreak::H3),
+    (0xc608, 0xc608, LineBreak::H2),
+    (0xc609, 0xc623, LineBreak::H3),
+    (0xc624, 0xc624, LineBreak::H2),
+    (0xc625, 0xc63f, LineBreak::H3),
+    (0xc640, 0xc640, LineBreak::H2),
+    (0xc641, 0xc65b, LineBreak::H3),
+    (0xc65c, 0xc65c, LineBreak::H2),
+    (0xc65d, 0xc677, LineBreak::H3),
+    (0xc678, 0xc678, LineBreak::H2),
+    (0xc679, 0xc693, LineBreak::H3),
+    (0xc694, 0xc694, LineBreak::H2),
+    (0xc695, 0xc6af, LineBreak::H3),
+    (0xc6b0, 0xc6b0, LineBreak::H2),
+    (0xc6b1, 0xc6cb, LineBreak::H3),
+    (0xc6cc, 0xc6cc, LineBreak::H2),
+    (0xc6cd, 0xc6e7, LineBreak::H3),
+    (0xc6e8, 0xc6e8, LineBreak::H2),
+    (0xc6e9, 0xc703, LineBreak::H3),
+    (0xc704, 0xc704, LineBreak::H2),
+    (0xc705, 0xc71f, LineBreak::H3),
+    (0xc720, 0xc720, LineBreak::H2),
+    (0xc721, 0xc73b, LineBreak::H3),
+    (0xc73c, 0xc73c, LineBreak::H2),
+    (0xc73d, 0xc757, LineBreak::H3),
+    (0xc758, 0xc758, LineBreak::H2),
+    (0xc759, 0xc773, LineBreak::H3),
+    (0xc774, 0xc774, LineBreak::H2),
+    (0xc775, 0xc78f, LineBreak::H3),
+    (0xc790, 0xc790, LineBreak::H2),
+    (0xc791, 0xc7ab, LineBreak::H3),
+    (0xc7ac, 0xc7ac, LineBreak::H2),
+    (0xc7ad, 0xc7c7, LineBreak::H3),
+    (0xc7c8, 0xc7c8, LineBreak::H2),
+    (0xc7c9, 0xc7e3, LineBreak::H3),
+    (0xc7e4, 0xc7e4, LineBreak::H2),
+    (0xc7e5, 0xc7ff, LineBreak::H3),
+    (0xc800, 0xc800, LineBreak::H2),
+    (0xc801, 0xc81b, LineBreak::H3),
+    (0xc81c, 0xc81c, LineBreak::H2),
+    (0xc81d, 0xc837, LineBreak::H3),
+    (0xc838, 0xc838, LineBreak::H2),
+    (0xc839, 0xc853, LineBreak::H3),
+    (0xc854, 0xc854, LineBreak::H2),
+    (0xc855, 0xc86f, LineBreak::H3),
+    (0xc870, 0xc870, LineBreak::H2),
+    (0xc871, 0xc88b, LineBreak::H3),
+    (0xc88c, 0xc88c, LineBreak::H2),
+    (0xc88d, 0xc8a7, LineBreak::H3),
+    (0xc8a8, 0xc8a8, LineBreak::H2),
+    (0xc8a9, 0xc8c3, LineBreak::H3),
+    (0xc8c4, 0xc8c4, LineBreak::H2),
+    (0xc8c5, 0xc8df, LineBreak::H3),
+    (0xc8e0, 0xc8e0, LineBreak::H2),
+    (0xc8e1, 0xc8fb, LineBreak::H3),
+    (0xc8fc, 0xc8fc, LineBreak::H2),
+    (0xc8fd, 0xc917, LineBreak::H3),
+    (0xc918, 0xc918, LineBreak::H2),
+    (0xc919, 0xc933, LineBreak::H3),
+    (0xc934, 0xc934, LineBreak::H2),
+    (0xc935, 0xc94f, LineBreak::H3),
+    (0xc950, 0xc950, LineBreak::H2),
+    (0xc951, 0xc96b, LineBreak::H3),
+    (0xc96c, 0xc96c, LineBreak::H2),
+    (0xc96d, 0xc987, LineBreak::H3),
+    (0xc988, 0xc988, LineBreak::H2),
+    (0xc989, 0xc9a3, LineBreak::H3),
+    (0xc9a4, 0xc9a4, LineBreak::H2),
+    (0xc9a5, 0xc9bf, LineBreak::H3),
+    (0xc9c0, 0xc9c0, LineBreak::H2),
+    (0xc9c1, 0xc9db, LineBreak::H3),
+    (0xc9dc, 0xc9dc, LineBreak::H2),
+    (0xc9dd, 0xc9f7, LineBreak::H3),
+    (0xc9f8, 0xc9f8, LineBreak::H2),
+    (0xc9f9, 0xca13, LineBreak::H3),
+    (0xca14, 0xca14, LineBreak::H2),
+    (0xca15, 0xca2f, LineBreak::H3),
+    (0xca30, 0xca30, LineBreak::H2),
+    (0xca31, 0xca4b, LineBreak::H3),
+    (0xca4c, 0xca4c, LineBreak::H2),
+    (0xca4d, 0xca67, LineBreak::H3),
+    (0xca68, 0xca68, LineBreak::H2),
+    (0xca69, 0xca83, LineBreak::H3),
+    (0xca84, 0xca84, LineBreak::H2),
+    (0xca85, 0xca9f, LineBreak::H3),
+    (0xcaa0, 0xcaa0, LineBreak::H2),
+    (0xcaa1, 0xcabb, LineBreak::H3),
+    (0xcabc, 0xcabc, LineBreak::H2),
+    (0xcabd, 0xcad7, LineBreak::H3),
+    (0xcad8, 0xcad8, LineBreak::H2),
+    (0xcad9, 0xcaf3, LineBreak::H3),
+    (0xcaf4, 0xcaf4, LineBreak::H2),
+    (0xcaf5, 0xcb0f, LineBreak::H3),
+    (0xcb10, 0xcb10, LineBreak::H2),
+    (0xcb11, 0xcb2b, LineBreak::H3),
+    (0xcb2c, 0xcb2c, LineBreak::H2),
+    (0xcb2d, 0xcb47, LineBreak::H3),
+    (0xcb48, 0xcb48, LineBreak::H2),
+    (0xcb49, 0xcb63, LineBreak::H3),
+    (0xcb64, 0xcb64, LineBreak::H2),
+    (0xcb65, 0xcb7f, LineBreak::H3),
+    (0xcb80, 0xcb80, LineBreak::H2),
+    (0xcb81, 0xcb9b, LineBreak::H3),
+    (0xcb9c, 0xcb9c, LineBreak::H2),
+    (0xcb9d, 0xcbb7, LineBreak::H3),
+    (0xcbb8, 0xcbb8, LineBreak::H2),
+    (0xcbb9, 0xcbd3, LineBreak::H3),
+    (0xcbd4, 0xcbd4, LineBreak::H2),
+    (0xcbd5, 0xcbef, LineBreak::H3),
+    (0xcbf0, 0xcbf0, LineBreak::H2),
+    (0xcbf1, 0xcc0b, LineBreak::H3),
+    (0xcc0c, 0xcc0c, LineBreak::H2),
+    (0xcc0d, 0xcc27, LineBreak::H3),
+    (0xcc28, 0xcc28, LineBreak::H2),
+    (0xcc29, 0xcc43, LineBreak::H3),
+    (0xcc44, 0xcc44, LineBreak::H2),
+    (0xcc45, 0xcc5f, LineBreak::H3),
+    (0xcc60, 0xcc60, LineBreak::H2),
+    (0xcc61, 0xcc7b, LineBreak::H3),
+    (0xcc7c, 0xcc7c, LineBreak::H2),
+    (0xcc7d, 0xcc97, LineBreak::H3),
+    (0xcc98, 0xcc98, LineBreak::H2),
+    (0xcc99, 0xccb3, LineBreak::H3),
+    (0xccb4, 0xccb4, LineBreak::H2),
+    (0xccb5, 0xcccf, LineBreak::H3),
+    (0xccd0, 0xccd0, LineBreak::H2),
+    (0xccd1, 0xcceb, LineBreak::H3),
+    (0xccec, 0xccec, LineBreak::H2),
+    (0xcced, 0xcd07, LineBreak::H3),
+    (0xcd08, 0xcd08, LineBreak::H2),
+    (0xcd09, 0xcd23, LineBreak::H3),
+    (0xcd24, 0xcd24, LineBreak::H2),
+    (0xcd25, 0xcd3f, LineBreak::H3),
+    (0xcd40, 0xcd40, LineBreak::H2),
+    (0xcd41, 0xcd5b, LineBreak::H3),
+    (0xcd5c, 0xcd5c, LineBreak::H2),
+    (0xcd5d, 0xcd77, LineBreak::H3),
+    (0xcd78, 0xcd78, LineBreak::H2),
+    (0xcd79, 0xcd93, LineBreak::H3),
+    (0xcd94, 0xcd94, LineBreak::H2),
+    (0xcd95, 0xcdaf, LineBreak::H3),
+    (0xcdb0, 0xcdb0, LineBreak::H2),
+    (0xcdb1, 0xcdcb, LineBreak::H3),
+    (0xcdcc, 0xcdcc, LineBreak::H2),
+    (0xcdcd, 0xcde7, LineBreak::H3),
+    (0xcde8, 0xcde8, LineBreak::H2),
+    (0xcde9, 0xce03, LineBreak::H3),
+    (0xce04, 0xce04, LineBreak::H2),
+    (0xce05, 0xce1f, LineBreak::H3),
+    (0xce20, 0xce20, LineBreak::H2),
+    (0xce21, 0xce3b, LineBreak::H3),
+    (0xce3c, 0xce3c, LineBreak::H2),
+    (0xce3d, 0xce57, LineBreak::H3),
+    (0xce58, 0xce58, LineBreak::H2),
+    (0xce59, 0xce73, LineBreak::H3),
+    (0xce74, 0xce74, LineBreak::H2),
+    (0xce75, 0xce8f, LineBreak::H3),
+    (0xce90, 0xce90, LineBreak::H2),
+    (0xce91, 0xceab, LineBreak::H3),
+    (0xceac, 0xceac, LineBreak::H2),
+    (0xcead, 0xcec7, LineBreak::H3),
+    (0xcec8, 0xcec8, LineBreak::H2),
+    (0xcec9, 0xcee3, LineBreak::H3),
+    (0xcee4, 0xcee4, LineBreak::H2),
+    (0xcee5, 0xceff, LineBreak::H3),
+    (0xcf00, 0xcf00, LineBreak::H2),
+    (0xcf01, 0xcf1b, LineBreak::H3),
+    (0xcf1c, 0xcf1c, LineBreak::H2),
+    (0xcf1d, 0xcf37, LineBreak::H3),
+    (0xcf38, 0xcf38, LineBreak::H2),
+    (0xcf39, 0xcf53, LineBreak::H3),
+    (0xcf54, 0xcf54, LineBreak::H2),
+    (0xcf55, 0xcf6f, LineBreak::H3),
+    (0xcf70, 0xcf70, LineBreak::H2),
+    (0xcf71, 0xcf8b, LineBreak::H3),
+    (0xcf8c, 0xcf8c, LineBreak::H2),
+    (0xcf8d, 0xcfa7, LineBreak::H3),
+    (0xcfa8, 0xcfa8, LineBreak::H2),
+    (0xcfa9, 0xcfc3, LineBreak::H3),
+    (0xcfc4, 0xcfc4, LineBreak::H2),
+    (0xcfc5, 0xcfdf, LineBreak::H3),
+    (0xcfe0, 0xcfe0, LineBreak::H2),
+    (0xcfe1, 0xcffb, LineBreak::H3),
+    (0xcffc, 0xcffc, LineBreak::H2),
+    (0xcffd, 0xd017, LineBreak::H3),
+    (0xd018, 0xd018, LineBreak::H2),
+    (0xd019, 0xd033, LineBreak::H3),
+    (0xd034, 0xd034, LineBreak::H2),
+    (0xd035, 0xd04f, LineBreak::H3),
+    (0xd050, 0xd050, LineBreak::H2),
+    (0xd051, 0xd06b, LineBreak::H3),
+    (0xd06c, 0xd06c, LineBreak::H2),
+    (0xd06d, 0xd087, LineBreak::H3),
+    (0xd088, 0xd088, LineBreak::H2),
+    (0xd089, 0xd0a3, LineBreak::H3),
+    (0xd0a4, 0xd0a4, LineBreak::H2),
+    (0xd0a5, 0xd0bf, LineBreak::H3),
+    (0xd0c0, 0xd0c0, LineBreak::H2),
+    (0xd0c1, 0xd0db, LineBreak::H3),
+    (0xd0dc, 0xd0dc, LineBreak::H2),
+    (0xd0dd, 0xd0f7, LineBreak::H3),
+    (0xd0f8, 0xd0f8, LineBreak::H2),
+    (0xd0f9, 0xd113, LineBreak::H3),
+    (0xd114, 0xd114, LineBreak::H2),
+    (0xd115, 0xd12f, LineBreak::H3),
+    (0xd130, 0xd130, LineBreak::H2),
+    (0xd131, 0xd14b, LineBreak::H3),
+    (0xd14c, 0xd14c, LineBreak::H2),
+    (0xd14d, 0xd167, LineBreak::H3),
+    (0xd168, 0xd168, LineBreak::H2),
+    (0xd169, 0xd183, LineBreak::H3),
+    (0xd184, 0xd184, LineBreak::H2),
+    (0xd185, 0xd19f, LineBreak::H3),
+    (0xd1a0, 0xd1a0, LineBreak::H2),
+    (0xd1a1, 0xd1bb, LineBreak::H3),
+    (0xd1bc, 0xd1bc, LineBreak::H2),
+    (0xd1bd, 0xd1d7, LineBreak::H3),
+    (0xd1d8, 0xd1d8, LineBreak::H2),
+    (0xd1d9, 0xd1f3, LineBreak::H3),
+    (0xd1f4, 0xd1f4, LineBreak::H2),
+    (0xd1f5, 0xd20f, LineBreak::H3),
+    (0xd210, 0xd210, LineBreak::H2),
+    (0xd211, 0xd22b, LineBreak::H3),
+    (0xd22c, 0xd22c, LineBreak::H2),
+    (0xd22d, 0xd247, LineBreak::H3),
+    (0xd248, 0xd248, LineBreak::H2),
+    (0xd249, 0xd263, LineBreak::H3),
+    (0xd264, 0xd264, LineBreak::H2),
+    (0xd265, 0xd27f, LineBreak::H3),
+    (0xd280, 0xd280, LineBreak::H2),
+    (0xd281, 0xd29b, LineBreak::H3),
+    (0xd29c, 0xd29c, LineBreak::H2),
+    (0xd29d, 0xd2b7, LineBreak::H3),
+    (0xd2b8, 0xd2b8, LineBreak::H2),
+    (0xd2b9, 0xd2d3, LineBreak::H3),
+    (0xd2d4, 0xd2d4, LineBreak::H2),
+    (0xd2d5, 0xd2ef, LineBreak::H3),
+    (0xd2f0, 0xd2f0, LineBreak::H2),
+    (0xd2f1, 0xd30b, LineBreak::H3),
+    (0xd30c, 0xd30c, LineBreak::H2),
+    (0xd30d, 0xd327, LineBreak::H3),
+    (0xd328, 0xd328, LineBreak::H2),
+    (0xd329, 0xd343, LineBreak::H3),
+    (0xd344, 0xd344, LineBreak::H2),
+    (0xd345, 0xd35f, LineBreak::H3),
+    (0xd360, 0xd360, LineBreak::H2),
+    (0xd361, 0xd37b, LineBreak::H3),
+    (0xd37c, 0xd37c, LineBreak::H2),
+    (0xd37d, 0xd397, LineBreak::H3),
+    (0xd398, 0xd398, LineBreak::H2),
+    (0xd399, 0xd3b3, LineBreak::H3),
+    (0xd3b4, 0xd3b4, LineBreak::H2),
+    (0xd3b5, 0xd3cf, LineBreak::H3),
+    (0xd3d0, 0xd3d0, LineBreak::H2),
+    (0xd3d1, 0xd3eb, LineBreak::H3),
+    (0xd3ec, 0xd3ec, LineBreak::H2),
+    (0xd3ed, 0xd407, LineBreak::H3),
+    (0xd408, 0xd408, LineBreak::H2),
+    (0xd409, 0xd423, LineBreak::H3),
+    (0xd424, 0xd424, LineBreak::H2),
+    (0xd425, 0xd43f, LineBreak::H3),
+    (0xd440, 0xd440, LineBreak::H2),
+    (0xd441, 0xd45b, LineBreak::H3),
+    (0xd45c, 0xd45c, LineBreak::H2),
+    (0xd45d, 0xd477, LineBreak::H3),
+    (0xd478, 0xd478, LineBreak::H2),
+    (0xd479, 0xd493, LineBreak::H3),
+    (0xd494, 0xd494, LineBreak::H2),
+    (0xd495, 0xd4af, LineBreak::H3),
+    (0xd4b0, 0xd4b0, LineBreak::H2),
+    (0xd4b1, 0xd4cb, LineBreak::H3),
+    (0xd4cc, 0xd4cc, LineBreak::H2),
+    (0xd4cd, 0xd4e7, LineBreak::H3),
+    (0xd4e8, 0xd4e8, LineBreak::H2),
+    (0xd4e9, 0xd503, LineBreak::H3),
+    (0xd504, 0xd504, LineBreak::H2),
+    (0xd505, 0xd51f, LineBreak::H3),
+    (0xd520, 0xd520, LineBreak::H2),
+    (0xd521, 0xd53b, LineBreak::H3),
+    (0xd53c, 0xd53c, LineBreak::H2),
+    (0xd53d, 0xd557, LineBreak::H3),
+    (0xd558, 0xd558, LineBreak::H2),
+    (0xd559, 0xd573, LineBreak::H3),
+    (0xd574, 0xd574, LineBreak::H2),
+    (0xd575, 0xd58f, LineBreak::H3),
+    (0xd590, 0xd590, LineBreak::H2),
+    (0xd591, 0xd5ab, LineBreak::H3),
+    (0xd5ac, 0xd5ac, LineBreak::H2),
+    (0xd5ad, 0xd5c7, LineBreak::H3),
+    (0xd5c8, 0xd5c8, LineBreak::H2),
+    (0xd5c9, 0xd5e3, LineBreak::H3),
+    (0xd5e4, 0xd5e4, LineBreak::H2),
+    (0xd5e5, 0xd5ff, LineBreak::H3),
+    (0xd600, 0xd600, LineBreak::H2),
+    (0xd601, 0xd61b, LineBreak::H3),
+    (0xd61c, 0xd61c, LineBreak::H2),
+    (0xd61d, 0xd637, LineBreak::H3),
+    (0xd638, 0xd638, LineBreak::H2),
+    (0xd639, 0xd653, LineBreak::H3),
+    (0xd654, 0xd654, LineBreak::H2),
+    (0xd655, 0xd66f, LineBreak::H3),
+    (0xd670, 0xd670, LineBreak::H2),
+    (0xd671, 0xd68b, LineBreak::H3),
+    (0xd68c, 0xd68c, LineBreak::H2),
+    (0xd68d, 0xd6a7, LineBreak::H3),
+    (0xd6a8, 0xd6a8, LineBreak::H2),
+    (0xd6a9, 0xd6c3, LineBreak::H3),
+    (0xd6c4, 0xd6c4, LineBreak::H2),
+    (0xd6c5, 0xd6df, LineBreak::H3),
+    (0xd6e0, 0xd6e0, LineBreak::H2),
+    (0xd6e1, 0xd6fb, LineBreak::H3),
+    (0xd6fc, 0xd6fc, LineBreak::H2),
+    (0xd6fd, 0xd717, LineBreak::H3),
+    (0xd718, 0xd718, LineBreak::H2),
+    (0xd719, 0xd733, LineBreak::H3),
+    (0xd734, 0xd734, LineBreak::H2),
+    (0xd735, 0xd74f, LineBreak::H3),
+    (0xd750, 0xd750, LineBreak::H2),
+    (0xd751, 0xd76b, LineBreak::H3),
+    (0xd76c, 0xd76c, LineBreak::H2),
+    (0xd76d, 0xd787, LineBreak::H3),
+    (0xd788, 0xd788, LineBreak::H2),
+    (0xd789, 0xd7a3, LineBreak::H3),
+    (0xd7b0, 0xd7c6, LineBreak::JV),
+    (0xd7cb, 0xd7fb, LineBreak::JT),
+    (0xd800, 0xdfff, LineBreak::SG),
+    (0xf900, 0xfaff, LineBreak::ID),
+    (0xfb00, 0xfb06, LineBreak::AL),
+    (0xfb13, 0xfb17, LineBreak::AL),
+    (0xfb1d, 0xfb1d, LineBreak::HL),
+    (0xfb1e, 0xfb1e, LineBreak::CM),
+    (0xfb1f, 0xfb28, LineBreak::HL),
+    (0xfb29, 0xfb29, LineBreak::AL),
+    (0xfb2a, 0xfb36, LineBreak::HL),
+    (0xfb38, 0xfb3c, LineBreak::HL),
+    (0xfb3e, 0xfb3e, LineBreak::HL),
+    (0xfb40, 0xfb41, LineBreak::HL),
+    (0xfb43, 0xfb44, LineBreak::HL),
+    (0xfb46, 0xfb4f, LineBreak::HL),
+    (0xfb50, 0xfd3d, LineBreak::AL),
+    (0xfd3e, 0xfd3e, LineBreak::CL),
+    (0xfd3f, 0xfd3f, LineBreak::OP),
+    (0xfd40, 0xfdcf, LineBreak::AL),
+    (0xfdf0, 0xfdfb, LineBreak::AL),
+    (0xfdfc, 0xfdfc, LineBreak::PO),
+    (0xfdfd, 0xfdff, LineBreak::AL),
+    (0xfe00, 0xfe0f, LineBreak::CM),
+    (0xfe10, 0xfe12, LineBreak::CL),
+    (0xfe13, 0xfe14, LineBreak::NS),
+    (0xfe15, 0xfe16, LineBreak::EX),
+    (0xfe17, 0xfe17, LineBreak::OP),
+    (0xfe18, 0xfe18, LineBreak::CL),
+    (0xfe19, 0xfe19, LineBreak::IN),
+    (0xfe20, 0xfe20, LineBreak::GL),
+    (0xfe21, 0xfe21, LineBreak::CM),
+    (0xfe22, 0xfe22, LineBreak::GL),
+    (0xfe23, 0xfe23, LineBreak::CM),
+    (0xfe24, 0xfe24, LineBreak::GL),
+    (0xfe25, 0xfe25, LineBreak::CM),
+    (0xfe26, 0xfe27, LineBreak::GL),
+    (0xfe28, 0xfe28, LineBreak::CM),
+    (0xfe29, 0xfe29, LineBreak::GL),
+    (0xfe2a, 0xfe2a, LineBreak::CM),
+    (0xfe2b, 0xfe2b, LineBreak::GL),
+    (0xfe2c, 0xfe2c, LineBreak::CM),
+    (0xfe2d, 0xfe2e, LineBreak::GL),
+    (0xfe2f, 0xfe2f, LineBreak::CM),
+    (0xfe30, 0xfe34, LineBreak::ID),
+    (0xfe35, 0xfe35, LineBreak::OP),
+    (0xfe36, 0xfe36, LineBreak::CL),
+    (0xfe37, 0xfe37, LineBreak::OP),
+    (0xfe38, 0xfe38, LineBreak::CL),
+    (0xfe39, 0xfe39, LineBreak::OP),
+    (0xfe3a, 0xfe3a, LineBreak::CL),
+    (0xfe3b, 0xfe3b, LineBreak::OP),
+    (0xfe3c, 0xfe3c, LineBreak::CL),
+    (0xfe3d, 0xfe3d, LineBreak::OP),
+    (0xfe3e, 0xfe3e, LineBreak::CL),
+    (0xfe3f, 0xfe3f, LineBreak::OP),
+    (0xfe40, 0xfe40, LineBreak::CL),
+    (0xfe41, 0xfe41, LineBreak::OP),
+    (0xfe42, 0xfe42, LineBreak::CL),
+    (0xfe43, 0xfe43, LineBreak::OP),
+    (0xfe44, 0xfe44, LineBreak::CL),
+    (0xfe45, 0xfe46, LineBreak::ID),
+    (0xfe47, 0xfe47, LineBreak::OP),
+    (0xfe48, 0xfe48, LineBreak::CL),
+    (0xfe49, 0xfe4f, LineBreak::ID),
+    (0xfe50, 0xfe50, LineBreak::CL),
+    (0xfe51, 0xfe51, LineBreak::ID),
+    (0xfe52, 0xfe52, LineBreak::CL),
+    (0xfe54, 0xfe55, LineBreak::NS),
+    (0xfe56, 0xfe57, LineBreak::EX),
+    (0xfe58, 0xfe58, LineBreak::ID),
+    (0xfe59, 0xfe59, LineBreak::OP),
+    (0xfe5a, 0xfe5a, LineBreak::CL),
+    (0xfe5b, 0xfe5b, LineBreak::OP),
+    (0xfe5c, 0xfe5c, LineBreak::CL),
+    (0xfe5d, 0xfe5d, LineBreak::OP),
+    (0xfe5e, 0xfe5e, LineBreak::CL),
+    (0xfe5f, 0xfe66, LineBreak::ID),
+    (0xfe68, 0xfe68, LineBreak::ID),
+    (0xfe69, 0xfe69, LineBreak::PR),
+    (0xfe6a, 0xfe6a, LineBreak::PO),
+    (0xfe6b, 0xfe6b, LineBreak::ID),
+    (0xfe70, 0xfe74, LineBreak::AL),
+    (0xfe76, 0xfefc, LineBreak::AL),
+    (0xfeff, 0xfeff, LineBreak::WJ),
+    (0xff01, 0xff01, LineBreak::EX),
+    (0xff02, 0xff03, LineBreak::ID),
+    (0xff04, 0xff04, LineBreak::PR),
+    (0xff05, 0xff05, LineBreak::PO),
+    (0xff06, 0xff07, LineBreak::ID),
+    (0xff08, 0xff08, LineBreak::OP),
+    (0xff09, 0xff09, LineBreak::CL),
+    (0xff0a, 0xff0b, LineBreak::ID),
+    (0xff0c, 0xff0c, LineBreak::CL),
+    (0xff0d, 0xff0d, LineBreak::ID),
+    (0xff0e, 0xff0e, LineBreak::CL),
+    (0xff0f, 0xff19, LineBreak::ID),
+    (0xff1a, 0xff1b, LineBreak::NS),
+    (0xff1c, 0xff1e, LineBreak::ID),
+    (0xff1f, 0xff1f, LineBreak::EX),
+    (0xff20, 0xff3a, LineBreak::ID),
+    (0xff3b, 0xff3b, LineBreak::OP),
+    (0xff3c, 0xff3c, LineBreak::ID),
+    (0xff3d, 0xff3d, LineBreak::CL),
+    (0xff3e, 0xff5a, LineBreak::ID),
+    (0xff5b, 0xff5b, LineBreak::OP),
+    (0xff5c, 0xff5c, LineBreak::ID),
+    (0xff5d, 0xff5d, LineBreak::CL),
+    (0xff5e, 0xff5e, LineBreak::ID),
+    (0xff5f, 0xff5f, LineBreak::OP),
+    (0xff60, 0xff61, LineBreak::CL),
+    (0xff62, 0xff62, LineBreak::OP),
+    (0xff63, 0xff64, LineBreak::CL),
+    (0xff65, 0xff65, LineBreak::NS),
+    (0xff66, 0xff66, LineBreak::ID),
+    (0xff67, 0xff70, LineBreak::CJ),
+    (0xff71, 0xff9d, LineBreak::ID),
+    (0xff9e, 0xff9f, LineBreak::NS),
+    (0xffa0, 0xffbe, LineBreak::ID),
+    (0xffc2, 0xffc7, LineBreak::ID),
+    (0xffca, 0xffcf, LineBreak::ID),
+    (0xffd2, 0xffd7, LineBreak::ID),
+    (0xffda, 0xffdc, LineBreak::ID),
+    (0xffe0, 0xffe0, LineBreak::PO),
+    (0xffe1, 0xffe1, LineBreak::PR),
+    (0xffe2, 0xffe4, LineBreak::ID),
+    (0xffe5, 0xffe6, LineBreak::PR),
+    (0xffe8, 0xffee, LineBreak::AL),
+    (0xfff9, 0xfffb, LineBreak::CM),
+    (0xfffc, 0xfffc, LineBreak::CB),
+    (0xfffd, 0xfffd, LineBreak::AI),
+    (0x10000, 0x1000b, LineBreak::AL),
+    (0x1000d, 0x10026, LineBreak::AL),
+    (0x10028, 0x1003a, LineBreak::AL),
+    (0x1003c, 0x1003d, LineBreak::AL),
+    (0x1003f, 0x1004d, LineBreak::AL),
+    (0x10050, 0x1005d, LineBreak::AL),
+    (0x10080, 0x100fa, LineBreak::AL),
+    (0x10100, 0x10102, LineBreak::BA),
+    (0x10107, 0x10133, LineBreak::AL),
+    (0x10137, 0x1018e, LineBreak::AL),
+    (0x10190, 0x1019c, LineBreak::AL),
+    (0x101a0, 0x101a0, LineBreak::AL),
+    (0x101d0, 0x101fc, LineBreak::AL),
+    (0x101fd, 0x101fd, LineBreak::CM),
+    (0x10280, 0x1029c, LineBreak::AL),
+    (0x102a0, 0x102d0, LineBreak::AL),
+    (0x102e0, 0x102e0, LineBreak::CM),
+    (0x102e1, 0x102fb, LineBreak::AL),
+    (0x10300, 0x10323, LineBreak::AL),
+    (0x1032d, 0x1034a, LineBreak::AL),
+    (0x10350, 0x10375, LineBreak::AL),
+    (0x10376, 0x1037a, LineBreak::CM),
+    (0x10380, 0x1039d, LineBreak::AL),
+    (0x1039f, 0x1039f, LineBreak::BA),
+    (0x103a0, 0x103c3, LineBreak::AL),
+    (0x103c8, 0x103cf, LineBreak::AL),
+    (0x103d0, 0x103d0, LineBreak::BA),
+    (0x103d1, 0x103d5, LineBreak::AL),
+    (0x10400, 0x1049d, LineBreak::AL),
+    (0x104a0, 0x104a9, LineBreak::NU),
+    (0x104b0, 0x104d3, LineBreak::AL),
+    (0x104d8, 0x104fb, LineBreak::AL),
+    (0x10500, 0x10527, LineBreak::AL),
+    (0x10530, 0x10563, LineBreak::AL),
+    (0x1056f, 0x1057a, LineBreak::AL),
+    (0x1057c, 0x1058a, LineBreak::AL),
+    (0x1058c, 0x10592, LineBreak::AL),
+    (0x10594, 0x10595, LineBreak::AL),
+    (0x10597, 0x105a1, LineBreak::AL),
+    (0x105a3, 0x105b1, LineBreak::AL),
+    (0x105b3, 0x105b9, LineBreak::AL),
+    (0x105bb, 0x105bc, LineBreak::AL),
+    (0x105c0, 0x105f3, LineBreak::AL),
+    (0x10600, 0x10736, LineBreak::AL),
+    (0x10740, 0x10755, LineBreak::AL),
+    (0x10760, 0x10767, LineBreak::AL),
+    (0x10780, 0x10785, LineBreak::AL),
+    (0x10787, 0x107b0, LineBreak::AL),
+    (0x107b2, 0x107ba, LineBreak::AL),
+    (0x10800, 0x10805, LineBreak::AL),
+    (0x10808, 0x10808, LineBreak::AL),
+    (0x1080a, 0x10835, LineBreak::AL),
+    (0x10837, 0x10838, LineBreak::AL),
+    (0x1083c, 0x1083c, LineBreak::AL),
+    (0x1083f, 0x10855, LineBreak::AL),
+    (0x10857, 0x10857, LineBreak::BA),
+    (0x10858, 0x1089e, LineBreak::AL),
+    (0x108a7, 0x108af, LineBreak::AL),
+    (0x108e0, 0x108f2, LineBreak::AL),
+    (0x108f4, 0x108f5, LineBreak::AL),
+    (0x108fb, 0x1091b, LineBreak::AL),
+    (0x1091f, 0x1091f, LineBreak::BA),
+    (0x10920, 0x10939, LineBreak::AL),
+    (0x1093f, 0x10959, LineBreak::AL),
+    (0x10980, 0x109b7, LineBreak::AL),
+    (0x109bc, 0x109cf, LineBreak::AL),
+    (0x109d2, 0x10a00, LineBreak::AL),
+    (0x10a01, 0x10a03, LineBreak::CM),
+    (0x10a05, 0x10a06, LineBreak::CM),
+    (0x10a0c, 0x10a0f, LineBreak::CM),
+    (0x10a10, 0x10a13, LineBreak::AL),
+    (0x10a15, 0x10a17, LineBreak::AL),
+    (0x10a19, 0x10a35, LineBreak::AL),
+    (0x10a38, 0x10a3a, LineBreak::CM),
+    (0x10a3f, 0x10a3f, LineBreak::CM),
+    (0x10a40, 0x10a48, LineBreak::AL),
+    (0x10a50, 0x10a57, LineBreak::BA),
+    (0x10a58, 0x10a58, LineBreak::AL),
+    (0x10a60, 0x10a9f, LineBreak::AL),
+    (0x10ac0, 0x10ae4, LineBreak::AL),
+    (0x10ae5, 0x10ae6, LineBreak::CM),
+    (0x10aeb, 0x10aef, LineBreak::AL),
+    (0x10af0, 0x10af5, LineBreak::BA),
+    (0x10af6, 0x10af6, LineBreak::IN),
+    (0x10b00, 0x10b35, LineBreak::AL),
+    (0x10b39, 0x10b3f, LineBreak::BA),
+    (0x10b40, 0x10b55, LineBreak::AL),
+    (0x10b58, 0x10b72, LineBreak::AL),
+    (0x10b78, 0x10b91, LineBreak::AL),
+    (0x10b99, 0x10b9c, LineBreak::AL),
+    (0x10ba9, 0x10baf, LineBreak::AL),
+    (0x10c00, 0x10c48, LineBreak::AL),
+    (0x10c80, 0x10cb2, LineBreak::AL),
+    (0x10cc0, 0x10cf2, LineBreak::AL),
+    (0x10cfa, 0x10d23, LineBreak::AL),
+    (0x10d24, 0x10d27, LineBreak::CM),
+    (0x10d30, 0x10d39, LineBreak::NU),
+    (0x10d40, 0x10d49, LineBreak::NU),
+    (0x10d4a, 0x10d65, LineBreak::AL),
+    (0x10d69, 0x10d6d, LineBreak::CM),
+    (0x10d6e, 0x10d6e, LineBreak::HH),
+    (0x10d6f, 0x10d85, LineBreak::AL),
+    (0x10d8e, 0x10d8f, LineBreak::AL),
+    (0x10e60, 0x10e7e, LineBreak::AL),
+    (0x10e80, 0x10ea9, LineBreak::AL),
+    (0x10eab, 0x10eac, LineBreak::CM),
+    (0x10ead, 0x10ead, LineBreak::HH),
+    (0x10eb0, 0x10eb1, LineBreak::AL),
+    (0x10ec2, 0x10ec7, LineBreak::AL),
+    (0x10ed0, 0x10ed0, LineBreak::BA),
+    (0x10ed1, 0x10ed8, LineBreak::AL),
+    (0x10efa, 0x10eff, LineBreak::CM),
+    (0x10f00, 0x10f27, LineBreak::AL),
+    (0x10f30, 0x10f45, LineBreak::AL),
+    (0x10f46, 0x10f50, LineBreak::CM),
+    (0x10f51, 0x10f59, LineBreak::AL),
+    (0x10f70, 0x10f81, LineBreak::AL),
+    (0x10f82, 0x10f85, LineBreak::CM),
+    (0x10f86, 0x10f89, LineBreak::AL),
+    (0x10fb0, 0x10fcb, LineBreak::AL),
+    (0x10fe0, 0x10ff6, LineBreak::AL),
+    (0x11000, 0x11002, LineBreak::CM),
+    (0x11003, 0x11004, LineBreak::AP),
+    (0x11005, 0x11037, LineBreak::AK),
+    (0x11038, 0x11045, LineBreak::CM),
+    (0x11046, 0x11046, LineBreak::VI),
+    (0x11047, 0x11048, LineBreak::BA),
+    (0x11049, 0x1104d, LineBreak::ID),
+    (0x11052, 0x11065, LineBreak::ID),
+    (0x11066, 0x1106f, LineBreak::AS),
+    (0x11070, 0x11070, LineBreak::CM),
+    (0x11071, 0x11072, LineBreak::AK),
+    (0x11073, 0x11074, LineBreak::CM),
+    (0x11075, 0x11075, LineBreak::AK),
+    (0x1107f, 0x1107f, LineBreak::GL),
+    (0x11080, 0x11082, LineBreak::CM),
+    (0x11083, 0x110af, LineBreak::AL),
+    (0x110b0, 0x110ba, LineBreak::CM),
+    (0x110bb, 0x110bc, LineBreak::AL),
+    (0x110bd, 0x110bd, LineBreak::NU),
+    (0x110be, 0x110c1, LineBreak::BA),
+    (0x110c2, 0x110c2, LineBreak::CM),
+    (0x110cd, 0x110cd, LineBreak::NU),
+    (0x110d0, 0x110e8, LineBreak::AL),
+    (0x110f0, 0x110f9, LineBreak::NU),
+    (0x11100, 0x11102, LineBreak::CM),
+    (0x11103, 0x11126, LineBreak::AL),
+    (0x11127, 0x11134, LineBreak::CM),
+    (0x11136, 0x1113f, LineBreak::NU),
+    (0x11140, 0x11143, LineBreak::BA),
+    (0x11144, 0x11144, LineBreak::AL),
+    (0x11145, 0x11146, LineBreak::CM),
+    (0x11147, 0x11147, LineBreak::AL),
+    (0x11150, 0x11172, LineBreak::AL),
+    (0x11173, 0x11173, LineBreak::CM),
+    (0x11174, 0x11174, LineBreak::AL),
+    (0x11175, 0x11175, LineBreak::BB),
+    (0x11176, 0x11176, LineBreak::AL),
+    (0x11180, 0x11182, LineBreak::CM),
+    (0x11183, 0x111b2, LineBreak::AL),
+    (0x111b3, 0x111c0, LineBreak::CM),
+    (0x111c1, 0x111c4, LineBreak::AL),
+    (0x111c5, 0x111c6, LineBreak::BA),
+    (0x111c7, 0x111c7, LineBreak::AL),
+    (0x111c8, 0x111c8, LineBreak::BA),
+    (0x111c9, 0x111cc, LineBreak::CM),
+    (0x111cd, 0x111cd, LineBreak::AL),
+    (0x111ce, 0x111cf, LineBreak::CM),
+    (0x111d0, 0x111d9, LineBreak::NU),
+    (0x111da, 0x111da, LineBreak::AL),
+    (0x111db, 0x111db, LineBreak::BB),
+    (0x111dc, 0x111dc, LineBreak::AL),
+    (0x111dd, 0x111df, LineBreak::BA),
+    (0x111e1, 0x111f4, LineBreak::AL),
+    (0x11200, 0x11211, LineBreak::AL),
+    (0x11213, 0x1122b, LineBreak::AL),
+    (0x1122c, 0x11237, LineBreak::CM),
+    (0x11238, 0x11239, LineBreak::BA),
+    (0x1123a, 0x1123a, LineBreak::AL),
+    (0x1123b, 0x1123c, LineBreak::BA),
+    (0x1123d, 0x1123d, LineBreak::AL),
+    (0x1123e, 0x1123e, LineBreak::CM),
+    (0x1123f, 0x11240, LineBreak::AL),
+    (0x11241, 0x11241, LineBreak::CM),
+    (0x11280, 0x11286, LineBreak::AL),
+    (0x11288, 0x11288, LineBreak::AL),
+    (0x1128a, 0x1128d, LineBreak::AL),
+    (0x1128f, 0x1129d, LineBreak::AL),
+    (0x1129f, 0x112a8, LineBreak::AL),
+    (0x112a9, 0x112a9, LineBreak::BA),
+    (0x112b0, 0x112de, LineBreak::AL),
+    (0x112df, 0x112ea, LineBreak::CM),
+    (0x112f0, 0x112f9, LineBreak::NU),
+    (0x11300, 0x11303, LineBreak::CM),
+    (0x11305, 0x1130c, LineBreak::AK),
+    (0x1130f, 0x11310, LineBreak::AK),
+    (0x11313, 0x11328, LineBreak::AK),
+    (0x1132a, 0x11330, LineBreak::AK),
+    (0x11332, 0x11333, LineBreak::AK),
+    (0x11335, 0x11339, LineBreak::AK),
+    (0x1133b, 0x1133c, LineBreak::CM),
+    (0x1133d, 0x1133d, LineBreak::BA),
+    (0x1133e, 0x11344, LineBreak::CM),
+    (0x11347, 0x11348, LineBreak::CM),
+    (0x1134b, 0x1134c, LineBreak::CM),
+    (0x1134d, 0x1134d, LineBreak::VI),
+    (0x11350, 0x11350, LineBreak::AS),
+    (0x11357, 0x11357, LineBreak::CM),
+    (0x1135d, 0x1135d, LineBreak::BA),
+    (0x1135e, 0x1135f, LineBreak::AS),
+    (0x11360, 0x11361, LineBreak::AK),
+    (0x11362, 0x11363, LineBreak::CM),
+    (0x11366, 0x1136c, LineBreak::CM),
+    (0x11370, 0x11374, LineBreak::CM),
+    (0x11380, 0x11389, LineBreak::AS),
+    (0x1138b, 0x1138b, LineBreak::AS),
+    (0x1138e, 0x1138e, LineBreak::AS),
+    (0x11390, 0x11391, LineBreak::AS),
+    (0x11392, 0x113b5, LineBreak::AK),
+    (0x113b7, 0x113b7, LineBreak::ID),
+    (0x113b8, 0x113c0, LineBreak::CM),
+    (0x113c2, 0x113c2, LineBreak::CM),
+    (0x113c5, 0x113c5, LineBreak::CM),
+    (0x113c7, 0x113ca, LineBreak::CM),
+    (0x113cc, 0x113cf, LineBreak::CM),
+    (0x113d0, 0x113d0, LineBreak::VI),
+    (0x113d1, 0x113d1, LineBreak::AP),
+    (0x113d2, 0x113d2, LineBreak::CM),
+    (0x113d3, 0x113d5, LineBreak::ID),
+    (0x113d7, 0x113d8, LineBreak::ID),
+    (0x113e1, 0x113e2, LineBreak::CM),
+    (0x11400, 0x11434, LineBreak::AL),
+    (0x11435, 0x11446, LineBreak::CM),
+    (0x11447, 0x1144a, LineBreak::AL),
+    (0x1144b, 0x1144e, LineBreak::BA),
+    (0x1144f, 0x1144f, LineBreak::AL),
+    (0x11450, 0x11459, LineBreak::NU),
+    (0x1145a, 0x1145b, LineBreak::BA),
+    (0x1145d, 0x1145d, LineBreak::AL),
+    (0x1145e, 0x1145e, LineBreak::CM),
+    (0x1145f, 0x11461, LineBreak::AL),
+    (0x11480, 0x114af, LineBreak::AL),
+    (0x114b0, 0x114c3, LineBreak::CM),
+    (0x114c4, 0x114c7, LineBreak::AL),
+    (0x114d0, 0x114d9, LineBreak::NU),
+    (0x11580, 0x115ae, LineBreak::AL),
+    (0x115af, 0x115b5, LineBreak::CM),
+    (0x115b8, 0x115c0, LineBreak::CM),
+    (0x115c1, 0x115c1, LineBreak::BB),
+    (0x115c2, 0x115c3, LineBreak::BA),
+    (0x115c4, 0x115c5, LineBreak::EX),
+    (0x115c6, 0x115c8, LineBreak::AL),
+    (0x115c9, 0x115d7, LineBreak::BA),
+    (0x115d8, 0x115db, LineBreak::AL),
+    (0x115dc, 0x115dd, LineBreak::CM),
+    (0x11600, 0x1162f, LineBreak::AL),
+    (0x11630, 0x11640, LineBreak::CM),
+    (0x11641, 0x11642, LineBreak::BA),
+    (0x11643, 0x11644, LineBreak::AL),
+    (0x11650, 0x11659, LineBreak::NU),
+    (0x11660, 0x1166c, LineBreak::BB),
+    (0x11680, 0x116aa, LineBreak::AL),
+    (0x116ab, 0x116b7, LineBreak::CM),
+    (0x116b8, 0x116b9, LineBreak::AL),
+    (0x116c0, 0x116c9, LineBreak::NU),
+    (0x116d0, 0x116e3, LineBreak::NU),
+    (0x11700, 0x1171a, LineBreak::SA),
+    (0x1171d, 0x1172b, LineBreak::SA),
+    (0x11730, 0x11739, LineBreak::NU),
+    (0x1173a, 0x1173b, LineBreak::SA),
+    (0x1173c, 0x1173e, LineBreak::BA),
+    (0x1173f, 0x11746, LineBreak::SA),
+    (0x11800, 0x1182b, LineBreak::AL),
+    (0x1182c, 0x1183a, LineBreak::CM),
+    (0x1183b, 0x1183b, LineBreak::AL),
+    (0x118a0, 0x118df, LineBreak::AL),
+    (0x118e0, 0x118e9, LineBreak::NU),
+    (0x118ea, 0x118f2, LineBreak::AL),
+    (0x118ff, 0x118ff, LineBreak::AL),
+    (0x11900, 0x11906, LineBreak::AK),
+    (0x11909, 0x11909, LineBreak::AK),
+    (0x1190c, 0x11913, LineBreak::AK),
+    (0x11915, 0x11916, LineBreak::AK),
+    (0x11918, 0x1192f, LineBreak::AK),
+    (0x11930, 0x11935, LineBreak::CM),
+    (0x11937, 0x11938, LineBreak::CM),
+    (0x1193b, 0x1193d, LineBreak::CM),
+    (0x1193e, 0x1193e, LineBreak::VI),
+    (0x1193f, 0x1193f, LineBreak::AP),
+    (0x11940, 0x11940, LineBreak::CM),
+    (0x11941, 0x11941, LineBreak::AP),
+    (0x11942, 0x11943, LineBreak::CM),
+    (0x11944, 0x11946, LineBreak::BA),
+    (0x11950, 0x11959, LineBreak::AS),
+    (0x119a0, 0x119a7, LineBreak::AL),
+    (0x119aa, 0x119d0, LineBreak::AL),
+    (0x119d1, 0x119d7, LineBreak::CM),
+    (0x119da, 0x119e0, LineBreak::CM),
+    (0x119e1, 0x119e1, LineBreak::AL),
+    (0x119e2, 0x119e2, LineBreak::BB),
+    (0x119e3, 0x119e3, LineBreak::AL),
+    (0x119e4, 0x119e4, LineBreak::CM),
+    (0x11a00, 0x11a00, LineBreak::AL),
+    (0x11a01, 0x11a0a, LineBreak::CM),
+    (0x11a0b, 0x11a32, LineBreak::AL),
+    (0x11a33, 0x11a39, LineBreak::CM),
+    (0x11a3a, 0x11a3a, LineBreak::AL),
+    (0x11a3b, 0x11a3e, LineBreak::CM),
+    (0x11a3f, 0x11a3f, LineBreak::BB),
+    (0x11a40, 0x11a40, LineBreak::AL),
+    (0x11a41, 0x11a44, LineBreak::BA),
+    (0x11a45, 0x11a45, LineBreak::BB),
+    (0x11a46, 0x11a46, LineBreak::AL),
+    (0x11a47, 0x11a47, LineBreak::CM),
+    (0x11a50, 0x11a50, LineBreak::AL),
+    (0x11a51, 0x11a5b, LineBreak::CM),
+    (0x11a5c, 0x11a89, LineBreak::AL),
+    (0x11a8a, 0x11a99, LineBreak::CM),
+    (0x11a9a, 0x11a9c, LineBreak::BA),
+    (0x11a9d, 0x11a9d, LineBreak::AL),
+    (0x11a9e, 0x11aa0, LineBreak::BB),
+    (0x11aa1, 0x11aa2, LineBreak::BA),
+    (0x11ab0, 0x11af8, LineBreak::AL),
+    (0x11b00, 0x11b09, LineBreak::BB),
+    (0x11b60, 0x11b67, LineBreak::CM),
+    (0x11bc0, 0x11be1, LineBreak::AL),
+    (0x11bf0, 0x11bf9, LineBreak::NU),
+    (0x11c00, 0x11c08, LineBreak::AL),
+    (0x11c0a, 0x11c2e, LineBreak::AL),
+    (0x11c2f, 0x11c36, LineBreak::CM),
+    (0x11c38, 0x11c3f, LineBreak::CM),
+    (0x11c40, 0x11c40, LineBreak::AL),
+    (0x11c41, 0x11c45, LineBreak::BA),
+    (0x11c50, 0x11c59, LineBreak::NU),
+    (0x11c5a, 0x11c6c, LineBreak::AL),
+    (0x11c70, 0x11c70, LineBreak::BB),
+    (0x11c71, 0x11c71, LineBreak::EX),
+    (0x11c72, 0x11c8f, LineBreak::AL),
+    (0x11c92, 0x11ca7, LineBreak::CM),
+    (0x11ca9, 0x11cb6, LineBreak::CM),
+    (0x11d00, 0x11d06, LineBreak::AL),
+    (0x11d08, 0x11d09, LineBreak::AL),
+    (0x11d0b, 0x11d30, LineBreak::AL),
+    (0x11d31, 0x11d36, LineBreak::CM),
+    (0x11d3a, 0x11d3a, LineBreak::CM),
+    (0x11d3c, 0x11d3d, LineBreak::CM),
+    (0x11d3f, 0x11d45, LineBreak::CM),
+    (0x11d46, 0x11d46, LineBreak::AL),
+    (0x11d47, 0x11d47, LineBreak::CM),
+    (0x11d50, 0x11d59, LineBreak::NU),
+    (0x11d60, 0x11d65, LineBreak::AL),
+    (0x11d67, 0x11d68, LineBreak::AL),
+    (0x11d6a, 0x11d89, LineBreak::AL),
+    (0x11d8a, 0x11d8e, LineBreak::CM),
+    (0x11d90, 0x11d91, LineBreak::CM),
+    (0x11d93, 0x11d97, LineBreak::CM),
+    (0x11d98, 0x11d98, LineBreak::AL),
+    (0x11da0, 0x11da9, LineBreak::NU),
+    (0x11db0, 0x11ddb, LineBreak::AL),
+    (0x11de0, 0x11de9, LineBreak::NU),
+    (0x11ee0, 0x11ef1, LineBreak::AS),
+    (0x11ef2, 0x11ef2, LineBreak::BA),
+    (0x11ef3, 0x11ef6, LineBreak::CM),
+    (0x11ef7, 0x11ef8, LineBreak::BA),
+    (0x11f00, 0x11f01, LineBreak::CM),
+    (0x11f02, 0x11f02, LineBreak::AP),
+    (0x11f03, 0x11f03, LineBreak::CM),
+    (0x11f04, 0x11f10, LineBreak::AK),
+    (0x11f12, 0x11f33, LineBreak::AK),
+    (0x11f34, 0x11f3a, LineBreak::CM),
+    (0x11f3e, 0x11f41, LineBreak::CM),
+    (0x11f42, 0x11f42, LineBreak::VI),
+    (0x11f43, 0x11f44, LineBreak::BA),
+    (0x11f45, 0x11f4f, LineBreak::ID),
+    (0x11f50, 0x11f59, LineBreak::AS),
+    (0x11f5a, 0x11f5a, LineBreak::CM),
+    (0x11fb0, 0x11fb0, LineBreak::AL),
+    (0x11fc0, 0x11fdc, LineBreak::AL),
+    (0x11fdd, 0x11fe0, LineBreak::PO),
+    (0x11fe1, 0x11ff1, LineBreak::AL),
+    (0x11fff, 0x11fff, LineBreak::BA),
+    (0x12000, 0x12399, LineBreak::AL),
+    (0x12400, 0x1246e, LineBreak::AL),
+    (0x12470, 0x12474, LineBreak::BA),
+    (0x12480, 0x12543, LineBreak::AL),
+    (0x12f90, 0x12ff2, LineBreak::AL),
+    (0x13000, 0x13257, LineBreak::AL),
+    (0x13258, 0x1325a, LineBreak::OP),
+    (0x1325b, 0x1325d, LineBreak::CL),
+    (0x1325e, 0x13281, LineBreak::AL),
+    (0x13282, 0x13282, LineBreak::CL),
+    (0x13283, 0x13285, LineBreak::AL),
+    (0x13286, 0x13286, LineBreak::OP),
+    (0x13287, 0x13287, LineBreak::CL),
+    (0x13288, 0x13288, LineBreak::OP),
+    (0x13289, 0x13289, LineBreak::CL),
+    (0x1328a, 0x13378, LineBreak::AL),
+    (0x13379, 0x13379, LineBreak::OP),
+    (0x1337a, 0x1337b, LineBreak::CL),
+    (0x1337c, 0x1342e, LineBreak::AL),
+    (0x1342f, 0x1342f, LineBreak::OP),
+    (0x13430, 0x13436, LineBreak::GL),
+    (0x13437, 0x13437, LineBreak::OP),
+    (0x13438, 0x13438, LineBreak::CL),
+    (0x13439, 0x1343b, LineBreak::GL),
+    (0x1343c, 0x1343c, LineBreak::OP),
+    (0x1343d, 0x1343d, LineBreak::CL),
+    (0x1343e, 0x1343e, LineBreak::OP),
+    (0x1343f, 0x1343f, LineBreak::CL),
+    (0x13440, 0x13440, LineBreak::CM),
+    (0x13441, 0x13446, LineBreak::AL),
+    (0x13447, 0x13455, LineBreak::CM),
+    (0x13460, 0x143fa, LineBreak::AL),
+    (0x14400, 0x145cd, LineBreak::AL),
+    (0x145ce, 0x145ce, LineBreak::OP),
+    (0x145cf, 0x145cf, LineBreak::CL),
+    (0x145d0, 0x14646, LineBreak::AL),
+    (0x16100, 0x1611d, LineBreak::AS),
+    (0x1611e, 0x1612f, LineBreak::CM),
+    (0x16130, 0x16139, LineBreak::AS),
+    (0x16800, 0x16a38, LineBreak::AL),
+    (0x16a40, 0x16a5e, LineBreak::AL),
+    (0x16a60, 0x16a69, LineBreak::NU),
+    (0x16a6e, 0x16a6f, LineBreak::BA),
+    (0x16a70, 0x16abe, LineBreak::AL),
+    (0x16ac0, 0x16ac9, LineBreak::NU),
+    (0x16ad0, 0x16aed, LineBreak::AL),
+    (0x16af0, 0x16af4, LineBreak::CM),
+    (0x16af5, 0x16af5, LineBreak::BA),
+    (0x16b00, 0x16b2f, LineBreak::AL),
+    (0x16b30, 0x16b36, LineBreak::CM),
+    (0x16b37, 0x16b39, LineBreak::BA),
+    (0x16b3a, 0x16b43, LineBreak::AL),
+    (0x16b44, 0x16b44, LineBreak::BA),
+    (0x16b45, 0x16b45, LineBreak::AL),
+    (0x16b50, 0x16b59, LineBreak::NU),
+    (0x16b5b, 0x16b61, LineBreak::AL),
+    (0x16b63, 0x16b77, LineBreak::AL),
+    (0x16b7d, 0x16b8f, LineBreak::AL),
+    (0x16d40, 0x16d6d, LineBreak::AL),
+    (0x16d6e, 0x16d6f, LineBreak::BA),
+    (0x16d70, 0x16d79, LineBreak::NU),
+    (0x16e40, 0x16e96, LineBreak::AL),
+    (0x16e97, 0x16e98, LineBreak::BA),
+    (0x16e99, 0x16e9a, LineBreak::AL),
+    (0x16ea0, 0x16eb8, LineBreak::AL),
+    (0x16ebb, 0x16ed3, LineBreak::AL),
+    (0x16f00, 0x16f4a, LineBreak::AL),
+    (0x16f4f, 0x16f4f, LineBreak::CM),
+    (0x16f50, 0x16f50, LineBreak::AL),
+    (0x16f51, 0x16f87, LineBreak::CM),
+    (0x16f8f, 0x16f92, LineBreak::CM),
+    (0x16f93, 0x16f9f, LineBreak::AL),
+    (0x16fe0, 0x16fe3, LineBreak::NS),
+    (0x16fe4, 0x16fe4, LineBreak::GL),
+    (0x16ff0, 0x16ff1, LineBreak::CM),
+    (0x16ff2, 0x16ff3, LineBreak::NS),
+    (0x16ff4, 0x16ff6, LineBreak::ID),
+    (0x17000, 0x18aff, LineBreak::ID),
+    (0x18b00, 0x18cd5, LineBreak::AL),
+    (0x18cff, 0x18cff, LineBreak::AL),
+    (0x18d00, 0x18d1e, LineBreak::ID),
+    (0x18d80, 0x18df2, LineBreak::ID),
+    (0x1aff0, 0x1aff3, LineBreak::AL),
+    (0x1aff5, 0x1affb, LineBreak::AL),
+    (0x1affd, 0x1affe, LineBreak::AL),
+    (0x1b000, 0x1b122, LineBreak::ID),
+    (0x1b132, 0x1b132, LineBreak::CJ),
+    (0x1b150, 0x1b152, LineBreak::CJ),
+    (0x1b155, 0x1b155, LineBreak::CJ),
+    (0x1b164, 0x1b167, LineBreak::CJ),
+    (0x1b170, 0x1b2fb, LineBreak::ID),
+    (0x1bc00, 0x1bc6a, LineBreak::AL),
+    (0x1bc70, 0x1bc7c, LineBreak::AL),
+    (0x1bc80, 0x1bc88, LineBreak::AL),
+    (0x1bc90, 0x1bc99, LineBreak::AL),
+    (0x1bc9c, 0x1bc9c, LineBreak::AL),
+    (0x1bc9d, 0x1bc9e, LineBreak::CM),
+    (0x1bc9f, 0x1bc9f, LineBreak::BA),
+    (0x1bca0, 0x1bca3, LineBreak::CM),
+    (0x1cc00, 0x1ccef, LineBreak::AL),
+    (0x1ccf0, 0x1ccf9, LineBreak::NU),
+    (0x1ccfa, 0x1ccfc, LineBreak::AL),
+    (0x1cd00, 0x1ceb3, LineBreak::AL),
+    (0x1ceba, 0x1ced0, LineBreak::AL),
+    (0x1cee0, 0x1cef0, LineBreak::AL),
+    (0x1cf00, 0x1cf2d, LineBreak::CM),
+    (0x1cf30, 0x1cf46, LineBreak::CM),
+    (0x1cf50, 0x1cfc3, LineBreak::AL),
+    (0x1d000, 0x1d0f5, LineBreak::AL),
+    (0x1d100, 0x1d126, LineBreak::AL),
+    (0x1d129, 0x1d164, LineBreak::AL),
+    (0x1d165, 0x1d169, LineBreak::CM),
+    (0x1d16a, 0x1d16c, LineBreak::AL),
+    (0x1d16d, 0x1d182, LineBreak::CM),
+    (0x1d183, 0x1d184, LineBreak::AL),
+    (0x1d185, 0x1d18b, LineBreak::CM),
+    (0x1d18c, 0x1d1a9, LineBreak::AL),
+    (0x1d1aa, 0x1d1ad, LineBreak::CM),
+    (0x1d1ae, 0x1d1ea, LineBreak::AL),
+    (0x1d200, 0x1d241, LineBreak::AL),
+    (0x1d242, 0x1d244, LineBreak::CM),
+    (0x1d245, 0x1d245, LineBreak::AL),
+    (0x1d2c0, 0x1d2d3, LineBreak::AL),
+    (0x1d2e0, 0x1d2f3, LineBreak::AL),
+    (0x1d300, 0x1d356, LineBreak::AL),
+    (0x1d360, 0x1d378, LineBreak::AL),
+    (0x1d400, 0x1d454, LineBreak::AL),
+    (0x1d456, 0x1d49c, LineBreak::AL),
+    (0x1d49e, 0x1d49f, LineBreak::AL),
+    (0x1d4a2, 0x1d4a2, LineBreak::AL),
+    (0x1d4a5, 0x1d4a6, LineBreak::AL),
+    (0x1d4a9, 0x1d4ac, LineBreak::AL),
+    (0x1d4ae, 0x1d4b9, LineBreak::AL),
+    (0x1d4bb, 0x1d4bb, LineBreak::AL),
+    (0x1d4bd, 0x1d4c3, LineBreak::AL),
+    (0x1d4c5, 0x1d505, LineBreak::AL),
+    (0x1d507, 0x1d50a, LineBreak::AL),
+    (0x1d50d, 0x1d514, LineBreak::AL),
+    (0x1d516, 0x1d51c, LineBreak::AL),
+    (0x1d51e, 0x1d539, LineBreak::AL),
+    (0x1d53b, 0x1d53e, LineBreak::AL),
+    (0x1d540, 0x1d544, LineBreak::AL),
+    (0x1d546, 0x1d546, LineBreak::AL),
+    (0x1d54a, 0x1d550, LineBreak::AL),
+    (0x1d552, 0x1d6a5, LineBreak::AL),
+    (0x1d6a8, 0x1d7cb, LineBreak::AL),
+    (0x1d7ce, 0x1d7ff, LineBreak::NU),
+    (0x1d800, 0x1d9ff, LineBreak::AL),
+    (0x1da00, 0x1da36, LineBreak::CM),
+    (0x1da37, 0x1da3a, LineBreak::AL),
+    (0x1da3b, 0x1da6c, LineBreak::CM),
+    (0x1da6d, 0x1da74, LineBreak::AL),
+    (0x1da75, 0x1da75, LineBreak::CM),
+    (0x1da76, 0x1da83, LineBreak::AL),
+    (0x1da84, 0x1da84, LineBreak::CM),
+    (0x1da85, 0x1da86, LineBreak::AL),
+    (0x1da87, 0x1da8a, LineBreak::BA),
+    (0x1da8b, 0x1da8b, LineBreak::AL),
+    (0x1da9b, 0x1da9f, LineBreak::CM),
+    (0x1daa1, 0x1daaf, LineBreak::CM),
+    (0x1df00, 0x1df1e, LineBreak::AL),
+    (0x1df25, 0x1df2a, LineBreak::AL),
+    (0x1e000, 0x1e006, LineBreak::CM),
+    (0x1e008, 0x1e018, LineBreak::CM),
+    (0x1e01b, 0x1e021, LineBreak::CM),
+    (0x1e023, 0x1e024, LineBreak::CM),
+    (0x1e026, 0x1e02a, LineBreak::CM),
+    (0x1e030, 0x1e06d, LineBreak::AL),
+    (0x1e08f, 0x1e08f, LineBreak::CM),
+    (0x1e100, 0x1e12c, LineBreak::AL),
+    (0x1e130, 0x1e136, LineBreak::CM),
+    (0x1e137, 0x1e13d, LineBreak::AL),
+    (0x1e140, 0x1e149, LineBreak::NU),
+    (0x1e14e, 0x1e14f, LineBreak::AL),
+    (0x1e290, 0x1e2ad, LineBreak::AL),
+    (0x1e2ae, 0x1e2ae, LineBreak::CM),
+    (0x1e2c0, 0x1e2eb, LineBreak::AL),
+    (0x1e2ec, 0x1e2ef, LineBreak::CM),
+    (0x1e2f0, 0x1e2f9, LineBreak::NU),
+    (0x1e2ff, 0x1e2ff, LineBreak::PR),
+    (0x1e4d0, 0x1e4eb, LineBreak::AL),
+    (0x1e4ec, 0x1e4ef, LineBreak::CM),
+    (0x1e4f0, 0x1e4f9, LineBreak::NU),
+    (0x1e5d0, 0x1e5ed, LineBreak::AL),
+    (0x1e5ee, 0x1e5ef, LineBreak::CM),
+    (0x1e5f0, 0x1e5f0, LineBreak::AL),
+    (0x1e5f1, 0x1e5fa, LineBreak::NU),
+    (0x1e5ff, 0x1e5ff, LineBreak::AL),
+    (0x1e6c0, 0x1e6de, LineBreak::AL),
+    (0x1e6e0, 0x1e6e2, LineBreak::AL),
+    (0x1e6e3, 0x1e6e3, LineBreak::CM),
+    (0x1e6e4, 0x1e6e5, LineBreak::AL),
+    (0x1e6e6, 0x1e6e6, LineBreak::CM),
+    (0x1e6e7, 0x1e6ed, LineBreak::AL),
+    (0x1e6ee, 0x1e6ef, LineBreak::CM),
+    (0x1e6f0, 0x1e6f4, LineBreak::AL),
+    (0x1e6f5, 0x1e6f5, LineBreak::CM),
+    (0x1e6fe, 0x1e6ff, LineBreak::AL),
+    (0x1e7e0, 0x1e7e6, LineBreak::AL),
+    (0x1e7e8, 0x1e7eb, LineBreak::AL),
+    (0x1e7ed, 0x1e7ee, LineBreak::AL),
+    (0x1e7f0, 0x1e7fe, LineBreak::AL),
+    (0x1e800, 0x1e8c4, LineBreak::AL),
+    (0x1e8c7, 0x1e8cf, LineBreak::AL),
+    (0x1e8d0, 0x1e8d6, LineBreak::CM),
+    (0x1e900, 0x1e943, LineBreak::AL),
+    (0x1e944, 0x1e94a, LineBreak::CM),
+    (0x1e94b, 0x1e94b, LineBreak::AL),
+    (0x1e950, 0x1e959, LineBreak::NU),
+    (0x1e95e, 0x1e95f, LineBreak::OP),
+    (0x1ec71, 0x1ecab, LineBreak::AL),
+    (0x1ecac, 0x1ecac, LineBreak::PO),
+    (0x1ecad, 0x1ecaf, LineBreak::AL),
+    (0x1ecb0, 0x1ecb0, LineBreak::PO),
+    (0x1ecb1, 0x1ecb4, LineBreak::AL),
+    (0x1ed01, 0x1ed3d, LineBreak::AL),
+    (0x1ee00, 0x1ee03, LineBreak::AL),
+    (0x1ee05, 0x1ee1f, LineBreak::AL),
+    (0x1ee21, 0x1ee22, LineBreak::AL),
+    (0x1ee24, 0x1ee24, LineBreak::AL),
+    (0x1ee27, 0x1ee27, LineBreak::AL),
+    (0x1ee29, 0x1ee32, LineBreak::AL),
+    (0x1ee34, 0x1ee37, LineBreak::AL),
+    (0x1ee39, 0x1ee39, LineBreak::AL),
+    (0x1ee3b, 0x1ee3b, LineBreak::AL),
+    (0x1ee42, 0x1ee42, LineBreak::AL),
+    (0x1ee47, 0x1ee47, LineBreak::AL),
+    (0x1ee49, 0x1ee49, LineBreak::AL),
+    (0x1ee4b, 0x1ee4b, LineBreak::AL),
+    (0x1ee4d, 0x1ee4f, LineBreak::AL),
+    (0x1ee51, 0x1ee52, LineBreak::AL),
+    (0x1ee54, 0x1ee54, LineBreak::AL),
+    (0x1ee57, 0x1ee57, LineBreak::AL),
+    (0x1ee59, 0x1ee59, LineBreak::AL),
+    (0x1ee5b, 0x1ee5b, LineBreak::AL),
+    (0x1ee5d, 0x1ee5d, LineBreak::AL),
+    (0x1ee5f, 0x1ee5f, LineBreak::AL),
+    (0x1ee61, 0x1ee62, LineBreak::AL),
+    (0x1ee64, 0x1ee64, LineBreak::AL),
+    (0x1ee67, 0x1ee6a, LineBreak::AL),
+    (0x1ee6c, 0x1ee72, LineBreak::AL),
+    (0x1ee74, 0x1ee77, LineBreak::AL),
+    (0x1ee79, 0x1ee7c, LineBreak::AL),
+    (0x1ee7e, 0x1ee7e, LineBreak::AL),
+    (0x1ee80, 0x1ee89, LineBreak::AL),
+    (0x1ee8b, 0x1ee9b, LineBreak::AL),
+    (0x1eea1, 0x1eea3, LineBreak::AL),
+    (0x1eea5, 0x1eea9, LineBreak::AL),
+    (0x1eeab, 0x1eebb, LineBreak::AL),
+    (0x1eef0, 0x1eef1, LineBreak::AL),
+    (0x1f000, 0x1f0ff, LineBreak::ID),
+    (0x1f100, 0x1f10c, LineBreak::AI),
+    (0x1f10d, 0x1f10f, LineBreak::AL),
+    (0x1f110, 0x1f12d, LineBreak::AI),
+    (0x1f12e, 0x1f12f, LineBreak::AL),
+    (0x1f130, 0x1f169, LineBreak::AI),
+    (0x1f16a, 0x1f16f, LineBreak::AL),
+    (0x1f170, 0x1f1ac, LineBreak::AI),
+    (0x1f1ad, 0x1f1ad, LineBreak::AL),
+    (0x1f1ae, 0x1f1e5, LineBreak::ID),
+    (0x1f1e6, 0x1f1ff, LineBreak::RI),
+    (0x1f200, 0x1f384, LineBreak::ID),
+    (0x1f385, 0x1f385, LineBreak::EB),
+    (0x1f386, 0x1f39b, LineBreak::ID),
+    (0x1f39c, 0x1f39d, LineBreak::AL),
+    (0x1f39e, 0x1f3b4, LineBreak::ID),
+    (0x1f3b5, 0x1f3b6, LineBreak::AL),
+    (0x1f3b7, 0x1f3bb, LineBreak::ID),
+    (0x1f3bc, 0x1f3bc, LineBreak::AL),
+    (0x1f3bd, 0x1f3c1, LineBreak::ID),
+    (0x1f3c2, 0x1f3c4, LineBreak::EB),
+    (0x1f3c5, 0x1f3c6, LineBreak::ID),
+    (0x1f3c7, 0x1f3c7, LineBreak::EB),
+    (0x1f3c8, 0x1f3c9, LineBreak::ID),
+    (0x1f3ca, 0x1f3cc, LineBreak::EB),
+    (0x1f3cd, 0x1f3fa, LineBreak::ID),
+    (0x1f3fb, 0x1f3ff, LineBreak::EM),
+    (0x1f400, 0x1f441, LineBreak::ID),
+    (0x1f442, 0x1f443, LineBreak::EB),
+    (0x1f444, 0x1f445, LineBreak::ID),
+    (0x1f446, 0x1f450, LineBreak::EB),
+    (0x1f451, 0x1f465, LineBreak::ID),
+    (0x1f466, 0x1f478, LineBreak::EB),
+    (0x1f479, 0x1f47b, LineBreak::ID),
+    (0x1f47c, 0x1f47c, LineBreak::EB),
+    (0x1f47d, 0x1f480, LineBreak::ID),
+    (0x1f481, 0x1f483, LineBreak::EB),
+    (0x1f484, 0x1f484, LineBreak::ID),
+    (0x1f485, 0x1f487, LineBreak::EB),
+    (0x1f488, 0x1f48e, LineBreak::ID),
+    (0x1f48f, 0x1f48f, LineBreak::EB),
+    (0x1f490, 0x1f490, LineBreak::ID),
+    (0x1f491, 0x1f491, LineBreak::EB),
+    (0x1f492, 0x1f49f, LineBreak::ID),
+    (0x1f4a0, 0x1f4a0, LineBreak::AL),
+    (0x1f4a1, 0x1f4a1, LineBreak::ID),
+    (0x1f4a2, 0x1f4a2, LineBreak::AL),
+    (0x1f4a3, 0x1f4a3, LineBreak::ID),
+    (0x1f4a4, 0x1f4a4, LineBreak::AL),
+    (0x1f4a5, 0x1f4a9, LineBreak::ID),
+    (0x1f4aa, 0x1f4aa, LineBreak::EB),
+    (0x1f4ab, 0x1f4ae, LineBreak::ID),
+    (0x1f4af, 0x1f4af, LineBreak::AL),
+    (0x1f4b0, 0x1f4b0, LineBreak::ID),
+    (0x1f4b1, 0x1f4b2, LineBreak::AL),
+    (0x1f4b3, 0x1f4ff, LineBreak::ID),
+    (0x1f500, 0x1f506, LineBreak::AL),
+    (0x1f507, 0x1f516, LineBreak::ID),
+    (0x1f517, 0x1f524, LineBreak::AL),
+    (0x1f525, 0x1f531, LineBreak::ID),
+    (0x1f532, 0x1f549, LineBreak::AL),
+    (0x1f54a, 0x1f573, LineBreak::ID),
+    (0x1f574, 0x1f575, LineBreak::EB),
+    (0x1f576, 0x1f579, LineBreak::ID),
+    (0x1f57a, 0x1f57a, LineBreak::EB),
+    (0x1f57b, 0x1f58f, LineBreak::ID),
+    (0x1f590, 0x1f590, LineBreak::EB),
+    (0x1f591, 0x1f594, LineBreak::ID),
+    (0x1f595, 0x1f596, LineBreak::EB),
+    (0x1f597, 0x1f5d3, LineBreak::ID),
+    (0x1f5d4, 0x1f5db, LineBreak::AL),
+    (0x1f5dc, 0x1f5f3, LineBreak::ID),
+    (0x1f5f4, 0x1f5f9, LineBreak::AL),
+    (0x1f5fa, 0x1f644, LineBreak::ID),
+    (0x1f645, 0x1f647, LineBreak::EB),
+    (0x1f648, 0x1f64a, LineBreak::ID),
+    (0x1f64b, 0x1f64f, LineBreak::EB),
+    (0x1f650, 0x1f675, LineBreak::AL),
+    (0x1f676, 0x1f678, LineBreak::QU),
+    (0x1f679, 0x1f67b, LineBreak::NS),
+    (0x1f67c, 0x1f67f, LineBreak::AL),
+    (0x1f680, 0x1f6a2, LineBreak::ID),
+    (0x1f6a3, 0x1f6a3, LineBreak::EB),
+    (0x1f6a4, 0x1f6b3, LineBreak::ID),
+    (0x1f6b4, 0x1f6b6, LineBreak::EB),
+    (0x1f6b7, 0x1f6bf, LineBreak::ID),
+    (0x1f6c0, 0x1f6c0, LineBreak::EB),
+    (0x1f6c1, 0x1f6cb, LineBreak::ID),
+    (0x1f6cc, 0x1f6cc, LineBreak::EB),
+    (0x1f6cd, 0x1f6ff, LineBreak::ID),
+    (0x1f700, 0x1f773, LineBreak::AL),
+    (0x1f774, 0x1f776, LineBreak::ID),
+    (0x1f777, 0x1f77a, LineBreak::AL),
+    (0x1f77b, 0x1f77f, LineBreak::ID),
+    (0x1f780, 0x1f7d4, LineBreak::AL),
+    (0x1f7d5, 0x1f7ff, LineBreak::ID),
+    (0x1f800, 0x1f80b, LineBreak::AL),
+    (0x1f810, 0x1f847, LineBreak::AL),
+    (0x1f850, 0x1f859, LineBreak::AL),
+    (0x1f860, 0x1f887, LineBreak::AL),
+    (0x1f890, 0x1f8ad, LineBreak::AL),
+    (0x1f8b0, 0x1f8bb, LineBreak::AL),
+    (0x1f8c0, 0x1f8c1, LineBreak::AL),
+    (0x1f8d0, 0x1f8d8, LineBreak::AL),
+    (0x1f900, 0x1f90b, LineBreak::AL),
+    (0x1f90c, 0x1f90c, LineBreak::EB),
+    (0x1f90d, 0x1f90e, LineBreak::ID),
+    (0x1f90f, 0x1f90f, LineBreak::EB),
+    (0x1f910, 0x1f917, LineBreak::ID),
+    (0x1f918, 0x1f91f, LineBreak::EB),
+    (0x1f920, 0x1f925, LineBreak::ID),
+    (0x1f926, 0x1f926, LineBreak::EB),
+    (0x1f927, 0x1f92f, LineBreak::ID),
+    (0x1f930, 0x1f939, LineBreak::EB),
+    (0x1f93a, 0x1f93b, LineBreak::ID),
+    (0x1f93c, 0x1f93e, LineBreak::EB),
+    (0x1f93f, 0x1f976, LineBreak::ID),
+    (0x1f977, 0x1f977, LineBreak::EB),
+    (0x1f978, 0x1f9b4, LineBreak::ID),
+    (0x1f9b5, 0x1f9b6, LineBreak::EB),
+    (0x1f9b7, 0x1f9b7, LineBreak::ID),
+    (0x1f9b8, 0x1f9b9, LineBreak::EB),
+    (0x1f9ba, 0x1f9ba, LineBreak::ID),
+    (0x1f9bb, 0x1f9bb, LineBreak::EB),
+    (0x1f9bc, 0x1f9cc, LineBreak::ID),
+    (0x1f9cd, 0x1f9cf, LineBreak::EB),
+    (0x1f9d0, 0x1f9d0, LineBreak::ID),
+    (0x1f9d1, 0x1f9dd, LineBreak::EB),
+    (0x1f9de, 0x1f9ff, LineBreak::ID),
+    (0x1fa00, 0x1fa57, LineBreak::AL),
+    (0x1fa58, 0x1fac2, LineBreak::ID),
+    (0x1fac3, 0x1fac5, LineBreak::EB),
+    (0x1fac6, 0x1faef, LineBreak::ID),
+    (0x1faf0, 0x1faf8, LineBreak::EB),
+    (0x1faf9, 0x1faff, LineBreak::ID),
+    (0x1fb00, 0x1fb92, LineBreak::AL),
+    (0x1fb94, 0x1fbef, LineBreak::AL),
+    (0x1fbf0, 0x1fbf9, LineBreak::NU),
+    (0x1fbfa, 0x1fbfa, LineBreak::AL),
+    (0x1fc00, 0x1fffd, LineBreak::ID),
+    (0x20000, 0x2fffd, LineBreak::ID),
+    (0x30000, 0x3fffd, LineBreak::ID),
+    (0xe0001, 0xe0001, LineBreak::CM),
+    (0xe0020, 0xe007f, LineBreak::CM),
+    (0xe0100, 0xe01ef, LineBreak::CM),
+];
+
+pub(crate) static GENERAL_CATEGORY: &[(u32, u32, GeneralCategory)] = &[
+    (0x0000, 0x001f, GeneralCategory::Cc),
+    (0x0020, 0x0020, GeneralCategory::Zs),
+    (0x0021, 0x0023, GeneralCategory::Po),
+    (0x0024, 0x0024, GeneralCategory::Sc),
+    (0x0025, 0x0027, GeneralCategory::Po),
+    (0x0028, 0x0028, GeneralCategory::Ps),
+    (0x0029, 0x0029, GeneralCategory::Pe),
+    (0x002a, 0x002a, GeneralCategory::Po),
+    (0x002b, 0x002b, GeneralCategory::Sm),
+    (0x002c, 0x002c, GeneralCategory::Po),
+    (0x002d, 0x002d, GeneralCategory::Pd),
+    (0x002e, 0x002f, GeneralCategory::Po),
+    (0x0030, 0x0039, GeneralCategory::Nd),
+    (0x003a, 0x003b, GeneralCategory::Po),
+    (0x003c, 0x003e, GeneralCategory::Sm),
+    (0x003f, 0x0040, GeneralCategory::Po),
+    (0x0041, 0x005a, GeneralCategory::Lu),
+    (0x005b, 0x005b, GeneralCategory::Ps),
+    (0x005c, 0x005c, GeneralCategory::Po),
+    (0x005d, 0x005d, GeneralCategory::Pe),
+    (0x005e, 0x005e, GeneralCategory::Sk),
+    (0x005f, 0x005f, GeneralCategory::Pc),
+    (0x0060, 0x0060, GeneralCategory::Sk),
+    (0x0061, 0x007a, GeneralCategory::Ll),
+    (0x007b, 0x007b, GeneralCategory::Ps),
+    (0x007c, 0x007c, GeneralCategory::Sm),
+    (0x007d, 0x007d, GeneralCategory::Pe),
+    (0x007e, 0x007e, GeneralCategory::Sm),
+    (0x007f, 0x009f, GeneralCategory::Cc),
+    (0x00a0, 0x00a0, GeneralCategory::Zs),
+    (0x00a1, 0x00a1, GeneralCategory::Po),
+    (0x00a2, 0x00a5, GeneralCategory::Sc),
+    (0x00a6, 0x00a6, GeneralCategory::So),
+    (0x00a7, 0x00a7, GeneralCategory::Po),
+    (0x00a8, 0x00a8, GeneralCategory::Sk),
+    (0x00a9, 0x00a9, GeneralCategory::So),
+    (0x00aa, 0x00aa, GeneralCategory::Lo),
+    (0x00ab, 0x00ab, GeneralCategory::Pi),
+    (0x00ac, 0x00ac, GeneralCategory::Sm),
+    (0x00ad, 0x00ad, GeneralCategory::Cf),
+    (0x00ae, 0x00ae, GeneralCategory::So),
+    (0x00af, 0x00af, GeneralCategory::Sk),
+    (0x00b0, 0x00b0, GeneralCategory::So),
+    (0x00b1, 0x00b1, GeneralCategory::Sm),
+    (0x00b2, 0x00b3, GeneralCategory::No),
+    (0x00b4, 0x00b4, GeneralCategory::Sk),
+    (0x00b5, 0x00b5, GeneralCategory::Ll),
+    (0x00b6, 0x00b7, GeneralCategory::Po),
+    (0x00b8, 0x00b8, GeneralCategory::Sk),
+    (0x00b9, 0x00b9, GeneralCategory::No),
+    (0x00ba, 0x00ba, GeneralCategory::Lo),
+    (0x00bb, 0x00bb, GeneralCategory::Pf),
+    (0x00bc, 0x00be, GeneralCategory::No),
+    (0x00bf, 0x00bf, GeneralCategory::Po),
+    (0x00c0, 0x00d6, GeneralCategory::Lu),
+    (0x00d7, 0x00d7, GeneralCategory::Sm),
+    (0x00d8, 0x00de, GeneralCategory::Lu),
+    (0x00df, 0x00f6, GeneralCategory::Ll),
+    (0x00f7, 0x00f7, GeneralCategory::Sm),
+    (0x00f8, 0x00ff, GeneralCategory::Ll),
+    (0x0100, 0x0100, GeneralCategory::Lu),
+    (0x0101, 0x0101, GeneralCategory::Ll),
+    (0x0102, 0x0102, GeneralCategory::Lu),
+    (0x0103, 0x0103, GeneralCategory::Ll),
+    (0x0104, 0x0104, GeneralCategory::Lu),
+    (0x0105, 0x0105, GeneralCategory::Ll),
+    (0x0106, 0x0106, GeneralCategory::Lu),
+    (0x0107, 0x0107, GeneralCategory::Ll),
+    (0x0108, 0x0108, GeneralCategory::Lu),
+    (0x0109, 0x0109, GeneralCategory::Ll),
+    (0x010a, 0x010a, GeneralCategory::Lu),
+    (0x010b, 0x010b, GeneralCategory::Ll),
+    (0x010c, 0x010c, GeneralCategory::Lu),
+    (0x010d, 0x010d, GeneralCategory::Ll),
+    (0x010e, 0x010e, GeneralCategory::Lu),
+    (0x010f, 0x010f, GeneralCategory::Ll),
+    (0x0110, 0x0110, GeneralCategory::Lu),
+    (0x0111, 0x0111, GeneralCategory::Ll),
+    (0x0112, 0x0112, GeneralCategory::Lu),
+    (0x0113, 0x0113, GeneralCategory::Ll),
+    (0x0114, 0x0114, GeneralCategory::Lu),
+    (0x0115, 0x0115, GeneralCategory::Ll),
+    (0x0116, 0x0116, GeneralCategory::Lu),
+    (0x0117, 0x0117, GeneralCategory::Ll),
+    (0x0118, 0x0118, GeneralCategory::Lu),
+    (0x0119, 0x0119, GeneralCategory::Ll),
+    (0x011a, 0x011a, GeneralCategory::Lu),
+    (0x011b, 0x011b, GeneralCategory::Ll),
+    (0x011c, 0x011c, GeneralCategory::Lu),
+    (0x011d, 0x011d, GeneralCategory::Ll),
+    (0x011e, 0x011e, GeneralCategory::Lu),
+    (0x011f, 0x011f, GeneralCategory::Ll),
+    (0x0120, 0x0120, GeneralCategory::Lu),
+    (0x0121, 0x0121, GeneralCategory::Ll),
+    (0x0122, 0x0122, GeneralCategory::Lu),
+    (0x0123, 0x0123, GeneralCategory::Ll),
+    (0x0124, 0x0124, GeneralCategory::Lu),
+    (0x0125, 0x0125, GeneralCategory::Ll),
+    (0x0126, 0x0126, GeneralCategory::Lu),
+    (0x0127, 0x0127, GeneralCategory::Ll),
+    (0x0128, 0x0128, GeneralCategory::Lu),
+    (0x0129, 0x0129, GeneralCategory::Ll),
+    (0x012a, 0x012a, GeneralCategory::Lu),
+    (0x012b, 0x012b, GeneralCategory::Ll),
+    (0x012c, 0x012c, GeneralCategory::Lu),
+    (0x012d, 0x012d, GeneralCategory::Ll),
+    (0x012e, 0x012e, GeneralCategory::Lu),
+    (0x012f, 0x012f, GeneralCategory::Ll),
+    (0x0130, 0x0130, GeneralCategory::Lu),
+    (0x0131, 0x0131, GeneralCategory::Ll),
+    (0x0132, 0x0132, GeneralCategory::Lu),
+    (0x0133, 0x0133, GeneralCategory::Ll),
+    (0x0134, 0x0134, GeneralCategory::Lu),
+    (0x0135, 0x0135, GeneralCategory::Ll),
+    (0x0136, 0x0136, GeneralCategory::Lu),
+    (0x0137, 0x0138, GeneralCategory::Ll),
+    (0x0139, 0x0139, GeneralCategory::Lu),
+    (0x013a, 0x013a, GeneralCategory::Ll),
+    (0x013b, 0x013b, GeneralCategory::Lu),
+    (0x013c, 0x013c, GeneralCategory::Ll),
+    (0x013d, 0x013d, GeneralCategory::Lu),
+    (0x013e, 0x013e, GeneralCategory::Ll),
+    (0x013f, 0x013f, GeneralCategory::Lu),
+    (0x0140, 0x0140, GeneralCategory::Ll),
+    (0x0141, 0x0141, GeneralCategory::Lu),
+    (0x0142, 0x0142, GeneralCategory::Ll),
+    (0x0143, 0x0143, GeneralCategory::Lu),
+    (0x0144, 0x0144, GeneralCategory::Ll),
+    (0x0145, 0x0145, GeneralCategory::Lu),
+    (0x0146, 0x0146, GeneralCategory::Ll),
+    (0x0147, 0x0147, GeneralCategory::Lu),
+    (0x0148, 0x0149, GeneralCategory::Ll),
+    (0x014a, 0x014a, GeneralCategory::Lu),
+    (0x014b, 0x014b, GeneralCategory::Ll),
+    (0x014c, 0x014c, GeneralCategory::Lu),
+    (0x014d, 0x014d, GeneralCategory::Ll),
+    (0x014e, 0x014e, GeneralCategory::Lu),
+    (0x014f, 0x014f, GeneralCategory::Ll),
+    (0x0150, 0x0150, GeneralCategory::Lu),
+    (0x0151, 0x0151, GeneralCategory::Ll),
+    (0x0152, 0x0152, GeneralCategory::Lu),
+    (0x0153, 0x0153, GeneralCategory::Ll),
+    (0x0154, 0x0154, GeneralCategory::Lu),
+    (0x0155, 0x0155, GeneralCategory::Ll),
+    (0x0156, 0x0156, GeneralCategory::Lu),
+    (0x0157, 0x0157, GeneralCategory::Ll),
+    (0x0158, 0x0158, GeneralCategory::Lu),
+    (0x0159, 0x0159, GeneralCategory::Ll),
+    (0x015a, 0x015a, GeneralCategory::Lu),
+    (0x015b, 0x015b, GeneralCategory::Ll),
+    (0x015c, 0x015c, GeneralCategory::Lu),
+    (0x015d, 0x015d, GeneralCategory::Ll),
+    (0x015e, 0x015e, GeneralCategory::Lu),
+    (0x015f, 0x015f, GeneralCategory::Ll),
+    (0x0160, 0x0160, GeneralCategory::Lu),
+    (0x0161, 0x0161, GeneralCategory::Ll),
+    (0x0162, 0x0162, GeneralCategory::Lu),
+    (0x0163, 0x0163, GeneralCategory::Ll),
+    (0x0164, 0x0164, GeneralCategory::Lu),
+    (0x0165, 0x0165, GeneralCategory::Ll),
+    (0x0166, 0x0166, GeneralCategory::Lu),
+    (0x0167, 0x0167, GeneralCategory::Ll),
+    (0x0168, 0x0168, GeneralCategory::Lu),
+    (0x0169, 0x0169, GeneralCategory::Ll),
+    (0x016a, 0x016a, GeneralCategory::Lu),
+    (0x016b, 0x016b, GeneralCategory::Ll),
+    (0x016c, 0x016c, GeneralCategory::Lu),
+    (0x016d, 0x016d, GeneralCategory::Ll),
+    (0x016e, 0x016e, GeneralCategory::Lu),
+    (0x016f, 0x016f, GeneralCategory::Ll),
+    (0x0170, 0x0170, GeneralCategory::Lu),
+    (0x0171, 0x0171, GeneralCategory::Ll),
+    (0x0172, 0x0172, GeneralCategory::Lu),
+    (0x0173, 0x0173, GeneralCategory::Ll),
+    (0x0174, 0x0174, GeneralCategory::Lu),
+    (0x0175, 0x0175, GeneralCategory::Ll),
+    (0x0176, 0x0176, GeneralCategory::Lu),
+    (0x0177, 0x0177, GeneralCategory::Ll),
+    (0x0178, 0x0179, GeneralCategory::Lu),
+    (0x017a, 0x017a, GeneralCategory::Ll),
+    (0x017b, 0x017b, GeneralCategory::Lu),
+    (0x017c, 0x017c, GeneralCategory::Ll),
+    (0x017d, 0x017d, GeneralCategory::Lu),
+    (0x017e, 0x0180, GeneralCategory::Ll),
+    (0x0181, 0x0182, GeneralCategory::Lu),
+    (0x0183, 0x0183, GeneralCategory::Ll),
+    (0x0184, 0x0184, GeneralCategory::Lu),
+    (0x0185, 0x0185, GeneralCategory::Ll),
+    (0x0186, 0x0187, GeneralCategory::Lu),
+    (0x0188, 0x0188, GeneralCategory::Ll),
+    (0x0189, 0x018b, GeneralCategory::Lu),
+    (0x018c, 0x018d, GeneralCategory::Ll),
+    (0x018e, 0x0191, GeneralCategory::Lu),
+    (0x0192, 0x0192, GeneralCategory::Ll),
+    (0x0193, 0x0194, GeneralCategory::Lu),
+    (0x0195, 0x0195, GeneralCategory::Ll),
+    (0x0196, 0x0198, GeneralCategory::Lu),
+    (0x0199, 0x019b, GeneralCategory::Ll),
+    (0x019c, 0x019d, GeneralCategory::Lu),
+    (0x019e, 0x019e, GeneralCategory::Ll),
+    (0x019f, 0x01a0, GeneralCategory::Lu),
+    (0x01a1, 0x01a1, GeneralCategory::Ll),
+    (0x01a2, 0x01a2, GeneralCategory::Lu),
+    (0x01a3, 0x01a3, GeneralCategory::Ll),
+    (0x01a4, 0x01a4, GeneralCategory::Lu),
+    (0x01a5, 0x01a5, GeneralCategory::Ll),
+    (0x01a6, 0x01a7, GeneralCategory::Lu),
+    (0x01a8, 0x01a8, GeneralCategory::Ll),
+    (0x01a9, 0x01a9, GeneralCategory::Lu),
+    (0x01aa, 0x01ab, GeneralCategory::Ll),
+    (0x01ac, 0x01ac, GeneralCategory::Lu),
+    (0x01ad, 0x01ad, GeneralCategory::Ll),
+    (0x01ae, 0x01af, GeneralCategory::Lu),
+    (0x01b0, 0x01b0, GeneralCategory::Ll),
+    (0x01b1, 0x01b3, GeneralCategory::Lu),
+    (0x01b4, 0x01b4, GeneralCategory::Ll),
+    (0x01b5, 0x01b5, GeneralCategory::Lu),
+    (0x01b6, 0x01b6, GeneralCategory::Ll),
+    (0x01b7, 0x01b8, GeneralCategory::Lu),
+    (0x01b9, 0x01ba, GeneralCategory::Ll),
+    (0x01bb, 0x01bb, GeneralCategory::Lo),
+    (0x01bc, 0x01bc, GeneralCategory::Lu),
+    (0x01bd, 0x01bf, GeneralCategory::Ll),
+    (0x01c0, 0x01c3, GeneralCategory::Lo),
+    (0x01c4, 0x01c4, GeneralCategory::Lu),
+    (0x01c5, 0x01c5, GeneralCategory::Lt),
+    (0x01c6, 0x01c6, GeneralCategory::Ll),
+    (0x01c7, 0x01c7, GeneralCategory::Lu),
+    (0x01c8, 0x01c8, GeneralCategory::Lt),
+    (0x01c9, 0x01c9, GeneralCategory::Ll),
+    (0x01ca, 0x01ca, GeneralCategory::Lu),
+    (0x01cb, 0x01cb, GeneralCategory::Lt),
+    (0x01cc, 0x01cc, GeneralCategory::Ll),
+    (0x01cd, 0x01cd, GeneralCategory::Lu),
+    (0x01ce, 0x01ce, GeneralCategory::Ll),
+    (0x01cf, 0x01cf, GeneralCategory::Lu),
+    (0x01d0, 0x01d0, GeneralCategory::Ll),
+    (0x01d1, 0x01d1, GeneralCategory::Lu),
+    (0x01d2, 0x01d2, GeneralCategory::Ll),
+    (0x01d3, 0x01d3, GeneralCategory::Lu),
+    (0x01d4, 0x01d4, GeneralCategory::Ll),
+    (0x01d5, 0x01d5, GeneralCategory::Lu),
+    (0x01d6, 0x01d6, GeneralCategory::Ll),
+    (0x01d7, 0x01d7, GeneralCategory::Lu),
+    (0x01d8, 0x01d8, GeneralCategory::Ll),
+    (0x01d9, 0x01d9, GeneralCategory::Lu),
+    (0x01da, 0x01da, GeneralCategory::Ll),
+    (0x01db, 0x01db, GeneralCategory::Lu),
+    (0x01dc, 0x01dd, GeneralCategory::Ll),
+    (0x01de, 0x01de, GeneralCategory::Lu),
+    (0x01df, 0x01df, GeneralCategory::Ll),
+    (0x01e0, 0x01e0, GeneralCategory::Lu),
+    (0x01e1, 0x01e1, GeneralCategory::Ll),
+    (0x01e2, 0x01e2, GeneralCategory::Lu),
+    (0x01e3, 0x01e3, GeneralCategory::Ll),
+    (0x01e4, 0x01e4, GeneralCategory::Lu),
+    (0x01e5, 0x01e5, GeneralCategory::Ll),
+    (0x01e6, 0x01e6, GeneralCategory::Lu),
+    (0x01e7, 0x01e7, GeneralCategory::Ll),
+    (0x01e8, 0x01e8, GeneralCategory::Lu),
+    (0x01e9, 0x01e9, GeneralCategory::Ll),
+    (0x01ea, 0x01ea, GeneralCategory::Lu),
+    (0x01eb, 0x01eb, GeneralCategory::Ll),
+    (0x01ec, 0x01ec, GeneralCategory::Lu),
+    (0x01ed, 0x01ed, GeneralCategory::Ll),
+    (0x01ee, 0x01ee, GeneralCategory::Lu),
+    (0x01ef, 0x01f0, GeneralCategory::Ll),
+    (0x01f1, 0x01f1, GeneralCategory::Lu),
+    (0x01f2, 0x01f2, GeneralCategory::Lt),
+    (0x01f3, 0x01f3, GeneralCategory::Ll),
+    (0x01f4, 0x01f4, GeneralCategory::Lu),
+    (0x01f5, 0x01f5, GeneralCategory::Ll),
+    (0x01f6, 0x01f8, GeneralCategory::Lu),
+    (0x01f9, 0x01f9, GeneralCategory::Ll),
+    (0x01fa, 0x01fa, GeneralCategory::Lu),
+    (0x01fb, 0x01fb, GeneralCategory::Ll),
+    (0x01fc, 0x01fc, GeneralCategory::Lu),
+    (0x01fd, 0x01fd, GeneralCategory::Ll),
+    (0x01fe, 0x01fe, GeneralCategory::Lu),
+    (0x01ff, 0x01ff, GeneralCategory::Ll),
+    (0x0200, 0x0200, GeneralCategory::Lu),
+    (0x0201, 0x0201, GeneralCategory::Ll),
+    (0x0202, 0x0202, GeneralCategory::Lu),
+    (0x0203, 0x0203, GeneralCategory::Ll),
+    (0x0204, 0x0204, GeneralCategory::Lu),
+    (0x0205, 0x0205, GeneralCategory::Ll),
+    (0x0206, 0x0206, GeneralCategory::Lu),
+    (0x0207, 0x0207, GeneralCategory::Ll),
+    (0x0208, 0x0208, GeneralCategory::Lu),
+    (0x0209, 0x0209, GeneralCategory::Ll),
+    (0x020a, 0x020a, GeneralCategory::Lu),
+    (0x020b, 0x020b, GeneralCategory::Ll),
+    (0x020c, 0x020c, GeneralCategory::Lu),
+    (0x020d, 0x020d, GeneralCategory::Ll),
+    (0x020e, 0x020e, GeneralCategory::Lu),
+    (0x020f, 0x020f, GeneralCategory::Ll),
+    (0x0210, 0x0210, GeneralCategory::Lu),
+    (0x0211, 0x0211, GeneralCategory::Ll),
+    (0x0212, 0x0212, GeneralCategory::Lu),
+    (0x0213, 0x0213, GeneralCategory::Ll),
+    (0x0214, 0x0214, GeneralCategory::Lu),
+    (0x0215, 0x0215, GeneralCategory::Ll),
+    (0x0216, 0x0216, GeneralCategory::Lu),
+    (0x0217, 0x0217, GeneralCategory::Ll),
+    (0x0218, 0x0218, GeneralCategory::Lu),
+    (0x0219, 0x0219, GeneralCategory::Ll),
+    (0x021a, 0x021a, GeneralCategory::Lu),
+    (0x021b, 0x021b, GeneralCategory::Ll),
+    (0x021c, 0x021c, GeneralCategory::Lu),
+    (0x021d, 0x021d, GeneralCategory::Ll),
+    (0x021e, 0x021e, GeneralCategory::Lu),
+    (0x021f, 0x021f, GeneralCategory::Ll),
+    (0x0220, 0x0220, GeneralCategory::Lu),
+    (0x0221, 0x0221, GeneralCategory::Ll),
+    (0x0222, 0x0222, GeneralCategory::Lu),
+    (0x0223, 0x0223, GeneralCategory::Ll),
+    (0x0224, 0x0224, GeneralCategory::Lu),
+    (0x0225, 0x0225, GeneralCategory::Ll),
+    (0x0226, 0x0226, GeneralCategory::Lu),
+    (0x0227, 0x0227, GeneralCategory::Ll),
+    (0x0228, 0x0228, GeneralCategory::Lu),
+    (0x0229, 0x0229, GeneralCategory::Ll),
+    (0x022a, 0x022a, GeneralCategory::Lu),
+    (0x022b, 0x022b, GeneralCategory::Ll),
+    (0x022c, 0x022c, GeneralCategory::Lu),
+    (0x022d, 0x022d, GeneralCategory::Ll),
+    (0x022e, 0x022e, GeneralCategory::Lu),
+    (0x022f, 0x022f, GeneralCategory::Ll),
+    (0x0230, 0x0230, GeneralCategory::Lu),
+    (0x0231, 0x0231, GeneralCategory::Ll),
+    (0x0232, 0x0232, GeneralCategory::Lu),
+    (0x0233, 0x0239, GeneralCategory::Ll),
+    (0x023a, 0x023b, GeneralCategory::Lu),
+    (0x023c, 0x023c, GeneralCategory::Ll),
+    (0x023d, 0x023e, GeneralCategory::Lu),
+    (0x023f, 0x0240, GeneralCategory::Ll),
+    (0x0241, 0x0241, GeneralCategory::Lu),
+    (0x0242, 0x0242, GeneralCategory::Ll),
+    (0x0243, 0x0246, GeneralCategory::Lu),
+    (0x0247, 0x0247, GeneralCategory::Ll),
+    (0x0248, 0x0248, GeneralCategory::Lu),
+    (0x0249, 0x0249, GeneralCategory::Ll),
+    (0x024a, 0x024a, GeneralCategory::Lu),
+    (0x024b, 0x024b, GeneralCategory::Ll),
+    (0x024c, 0x024c, GeneralCategory::Lu),
+    (0x024d, 0x024d, GeneralCategory::Ll),
+    (0x024e, 0x024e, GeneralCategory::Lu),
+    (0x024f, 0x0293, GeneralCategory::Ll),
+    (0x0294, 0x0295, GeneralCategory::Lo),
+    (0x0296, 0x02af, GeneralCategory::Ll),
+    (0x02b0, 0x02c1, GeneralCategory::Lm),
+    (0x02c2, 0x02c5, GeneralCategory::Sk),
+    (0x02c6, 0x02d1, GeneralCategory::Lm),
+    (0x02d2, 0x02df, GeneralCategory::Sk),
+    (0x02e0, 0x02e4, GeneralCategory::Lm),
+    (0x02e5, 0x02eb, GeneralCategory::Sk),
+    (0x02ec, 0x02ec, GeneralCategory::Lm),
+    (0x02ed, 0x02ed, GeneralCategory::Sk),
+    (0x02ee, 0x02ee, GeneralCategory::Lm),
+    (0x02ef, 0x02ff, GeneralCategory::Sk),
+    (0x0300, 0x036f, GeneralCategory::Mn),
+    (0x0370, 0x0370, GeneralCategory::Lu),
+    (0x0371, 0x0371, GeneralCategory::Ll),
+    (0x0372, 0x0372, GeneralCategory::Lu),
+    (0x0373, 0x0373, GeneralCategory::Ll),
+    (0x0374, 0x0374, GeneralCategory::Lm),
+    (0x0375, 0x0375, GeneralCategory::Sk),
+    (0x0376, 0x0376, GeneralCategory::Lu),
+    (0x0377, 0x0377, GeneralCategory::Ll),
+    (0x037a, 0x037a, GeneralCategory::Lm),
+    (0x037b, 0x037d, GeneralCategory::Ll),
+    (0x037e, 0x037e, GeneralCategory::Po),
+    (0x037f, 0x037f, GeneralCategory::Lu),
+    (0x0384, 0x0385, GeneralCategory::Sk),
+    (0x0386, 0x0386, GeneralCategory::Lu),
+    (0x0387, 0x0387, GeneralCategory::Po),
+    (0x0388, 0x038a, GeneralCategory::Lu),
+    (0x038c, 0x038c, GeneralCategory::Lu),
+    (0x038e, 0x038f, GeneralCategory::Lu),
+    (0x0390, 0x0390, GeneralCategory::Ll),
+    (0x0391, 0x03a1, GeneralCategory::Lu),
+    (0x03a3, 0x03ab, GeneralCategory::Lu),
+    (0x03ac, 0x03ce, GeneralCategory::Ll),
+    (0x03cf, 0x03cf, GeneralCategory::Lu),
+    (0x03d0, 0x03d1, GeneralCategory::Ll),
+    (0x03d2, 0x03d4, GeneralCategory::Lu),
+    (0x03d5, 0x03d7, GeneralCategory::Ll),
+    (0x03d8, 0x03d8, GeneralCategory::Lu),
+    (0x03d9, 0x03d9, GeneralCategory::Ll),
+    (0x03da, 0x03da, GeneralCategory::Lu),
+    (0x03db, 0x03db, GeneralCategory::Ll),
+    (0x03dc, 0x03dc, GeneralCategory::Lu),
+    (0x03dd, 0x03dd, GeneralCategory::Ll),
+    (0x03de, 0x03de, GeneralCategory::Lu),
+    (0x03df, 0x03df, GeneralCategory::Ll),
+    (0x03e0, 0x03e0, GeneralCategory::Lu),
+    (0x03e1, 0x03e1, GeneralCategory::Ll),
+    (0x03e2, 0x03e2, GeneralCategory::Lu),
+    (0x03e3, 0x03e3, GeneralCategory::Ll),
+    (0x03e4, 0x03e4, GeneralCategory::Lu),
+    (0x03e5, 0x03e5, GeneralCategory::Ll),
+    (0x03e6, 0x03e6, GeneralCategory::Lu),
+    (0x03e7, 0x03e7, GeneralCategory::Ll),
+    (0x03e8, 0x03e8, GeneralCategory::Lu),
+    (0x03e9, 0x03e9, GeneralCategory::Ll),
+    (0x03ea, 0x03ea, GeneralCategory::Lu),
+    (0x03eb, 0x03eb, GeneralCategory::Ll),
+    (0x03ec, 0x03ec, GeneralCategory::Lu),
+    (0x03ed, 0x03ed, GeneralCategory::Ll),
+    (0x03ee, 0x03ee, GeneralCategory::Lu),
+    (0x03ef, 0x03f3, GeneralCategory::Ll),
+    (0x03f4, 0x03f4, GeneralCategory::Lu),
+    (0x03f5, 0x03f5, GeneralCategory::Ll),
+    (0x03f6, 0x03f6, GeneralCategory::Sm),
+    (0x03f7, 0x03f7, GeneralCategory::Lu),
+    (0x03f8, 0x03f8, GeneralCategory::Ll),
+    (0x03f9, 0x03fa, GeneralCategory::Lu),
+    (0x03fb, 0x03fc, GeneralCategory::Ll),
+    (0x03fd, 0x042f, GeneralCategory::Lu),
+    (0x0430, 0x045f, GeneralCategory::Ll),
+    (0x0460, 0x0460, GeneralCategory::Lu),
+    (0x0461, 0x0461, GeneralCategory::Ll),
+    (0x0462, 0x0462, GeneralCategory::Lu),
+    (0x0463, 0x0463, GeneralCategory::Ll),
+    (0x0464, 0x0464, GeneralCategory::Lu),
+    (0x0465, 0x0465, GeneralCategory::Ll),
+    (0x0466, 0x0466, GeneralCategory::Lu),
+    (0x0467, 0x0467, GeneralCategory::Ll),
+    (0x0468, 0x0468, GeneralCategory::Lu),
+    (0x0469, 0x0469, GeneralCategory::Ll),
+    (0x046a, 0x046a, GeneralCategory::Lu),
+    (0x046b, 0x046b, GeneralCategory::Ll),
+    (0x046c, 0x046c, GeneralCategory::Lu),
+    (0x046d, 0x046d, GeneralCategory::Ll),
+    (0x046e, 0x046e, GeneralCategory::Lu),
+    (0x046f, 0x046f, GeneralCategory::Ll),
+    (0x0470, 0x0470, GeneralCategory::Lu),
+    (0x0471, 0x0471, GeneralCategory::Ll),
+    (0x0472, 0x0472, GeneralCategory::Lu),
+    (0x0473, 0x0473, GeneralCategory::Ll),
+    (0x0474, 0x0474, GeneralCategory::Lu),
+    (0x0475, 0x0475, GeneralCategory::Ll),
+    (0x0476, 0x0476, GeneralCategory::Lu),
+    (0x0477, 0x0477, GeneralCategory::Ll),
+    (0x0478, 0x0478, GeneralCategory::Lu),
+    (0x0479, 0x0479, GeneralCategory::Ll),
+    (0x047a, 0x047a, GeneralCategory::Lu),
+    (0x047b, 0x047b, GeneralCategory::Ll),
+    (0x047c, 0x047c, GeneralCategory::Lu),
+    (0x047d, 0x047d, GeneralCategory::Ll),
+    (0x047e, 0x047e, GeneralCategory::Lu),
+    (0x047f, 0x047f, GeneralCategory::Ll),
+    (0x0480, 0x0480, GeneralCategory::Lu),
+    (0x0481, 0x0481, GeneralCategory::Ll),
+    (0x0482, 0x0482, GeneralCategory::So),
+    (0x0483, 0x0487, GeneralCategory::Mn),
+    (0x0488, 0x0489, GeneralCategory::Me),
+    (0x048a, 0x048a, GeneralCategory::Lu),
+    (0x048b, 0x048b, GeneralCategory::Ll),
+    (0x048c, 0x048c, GeneralCategory::Lu),
+    (0x048d, 0x048d, GeneralCategory::Ll),
+    (0x048e, 0x048e, GeneralCategory::Lu),
+    (0x048f, 0x048f, GeneralCategory::Ll),
+    (0x0490, 0x0490, GeneralCategory::Lu),
+    (0x0491, 0x0491, GeneralCategory::Ll),
+    (0x0492, 0x0492, GeneralCategory::Lu),
+    (0x0493, 0x0493, GeneralCategory::Ll),
+    (0x0494, 0x0494, GeneralCategory::Lu),
+    (0x0495, 0x0495, GeneralCategory::Ll),
+    (0x0496, 0x0496, GeneralCategory::Lu),
+    (0x0497, 0x0497, GeneralCategory::Ll),
+    (0x0498, 0x0498, GeneralCategory::Lu),
+    (0x0499, 0x0499, GeneralCategory::Ll),
+    (0x049a, 0x049a, GeneralCategory::Lu),
+    (0x049b, 0x049b, GeneralCategory::Ll),
+    (0x049c, 0x049c, GeneralCategory::Lu),
+    (0x049d, 0x049d, GeneralCategory::Ll),
+    (0x049e, 0x049e, GeneralCategory::Lu),
+    (0x049f, 0x049f, GeneralCategory::Ll),
+    (0x04a0, 0x04a0, GeneralCategory::Lu),
+    (0x04a1, 0x04a1, GeneralCategory::Ll),
+    (0x04a2, 0x04a2, GeneralCategory::Lu),
+    (0x04a3, 0x04a3, GeneralCategory::Ll),
+    (0x04a4, 0x04a4, GeneralCategory::Lu),
+    (0x04a5, 0x04a5, GeneralCategory::Ll),
+    (0x04a6, 0x04a6, GeneralCategory::Lu),
+    (0x04a7, 0x04a7, GeneralCategory::Ll),
+    (0x04a8, 0x04a8, GeneralCategory::Lu),
+    (0x04a9, 0x04a9, GeneralCategory::Ll),
+    (0x04aa, 0x04aa, GeneralCategory::Lu),
+    (0x04ab, 0x04ab, GeneralCategory::Ll),
+    (0x04ac, 0x04ac, GeneralCategory::Lu),
+    (0x04ad, 0x04ad, GeneralCategory::Ll),
+    (0x04ae, 0x04ae, GeneralCategory::Lu),
+    (0x04af, 0x04af, GeneralCategory::Ll),
+    (0x04b0, 0x04b0, GeneralCategory::Lu),
+    (0x04b1, 0x04b1, GeneralCategory::Ll),
+    (0x04b2, 0x04b2, GeneralCategory::Lu),
+    (0x04b3, 0x04b3, GeneralCategory::Ll),
+    (0x04b4, 0x04b4, GeneralCategory::Lu),
+    (0x04b5, 0x04b5, GeneralCategory::Ll),
+    (0x04b6, 0x04b6, GeneralCategory::Lu),
+    (0x04b7, 0x04b7, GeneralCategory::Ll),
+    (0x04b8, 0x04b8, GeneralCategory::Lu),
+    (0x04b9, 0x04b9, GeneralCategory::Ll),
+    (0x04ba, 0x04ba, GeneralCategory::Lu),
+    (0x04bb, 0x04bb, GeneralCategory::Ll),
+    (0x04bc, 0x04bc, GeneralCategory::Lu),
+    (0x04bd, 0x04bd, GeneralCategory::Ll),
+    (0x04be, 0x04be, GeneralCategory::Lu),
+    (0x04bf, 0x04bf, GeneralCategory::Ll),
+    (0x04c0, 0x04c1, GeneralCategory::Lu),
+    (0x04c2, 0x04c2, GeneralCategory::Ll),
+    (0x04c3, 0x04c3, GeneralCategory::Lu),
+    (0x04c4, 0x04c4, GeneralCategory::Ll),
+    (0x04c5, 0x04c5, GeneralCategory::Lu),
+    (0x04c6, 0x04c6, GeneralCategory::Ll),
+    (0x04c7, 0x04c7, GeneralCategory::Lu),
+    (0x04c8, 0x04c8, GeneralCategory::Ll),
+    (0x04c9, 0x04c9, GeneralCategory::Lu),
+    (0x04ca, 0x04ca, GeneralCategory::Ll),
+    (0x04cb, 0x04cb, GeneralCategory::Lu),
+    (0x04cc, 0x04cc, GeneralCategory::Ll),
+    (0x04cd, 0x04cd, GeneralCategory::Lu),
+    (0x04ce, 0x04cf, GeneralCategory::Ll),
+    (0x04d0, 0x04d0, GeneralCategory::Lu),
+    (0x04d1, 0x04d1, GeneralCategory::Ll),
+    (0x04d2, 0x04d2, GeneralCategory::Lu),
+    (0x04d3, 0x04d3, GeneralCategory::Ll),
+    (0x04d4, 0x04d4, GeneralCategory::Lu),
+    (0x04d5, 0x04d5, GeneralCategory::Ll),
+    (0x04d6, 0x04d6, GeneralCategory::Lu),
+    (0x04d7, 0x04d7, GeneralCategory::Ll),
+    (0x04d8, 0x04d8, GeneralCategory::Lu),
+    (0x04d9, 0x04d9, GeneralCategory::Ll),
+    (0x04da, 0x04da, GeneralCategory::Lu),
+    (0x04db, 0x04db, GeneralCategory::Ll),
+    (0x04dc, 0x04dc, GeneralCategory::Lu),
+    (0x04dd, 0x04dd, GeneralCategory::Ll),
+    (0x04de, 0x04de, GeneralCategory::Lu),
+    (0x04df, 0x04df, GeneralCategory::Ll),
+    (0x04e0, 0x04e0, GeneralCategory::Lu),
+    (0x04e1, 0x04e1, GeneralCategory::Ll),
+    (0x04e2, 0x04e2, GeneralCategory::Lu),
+    (0x04e3, 0x04e3, GeneralCategory::Ll),
+    (0x04e4, 0x04e4, GeneralCategory::Lu),
+    (0x04e5, 0x04e5, GeneralCategory::Ll),
+    (0x04e6, 0x04e6, GeneralCategory::Lu),
+    (0x04e7, 0x04e7, GeneralCategory::Ll),
+    (0x04e8, 0x04e8, GeneralCategory::Lu),
+    (0x04e9, 0x04e9, GeneralCategory::Ll),
+    (0x04ea, 0x04ea, GeneralCategory::Lu),
+    (0x04eb, 0x04eb, GeneralCategory::Ll),
+    (0x04ec, 0x04ec, GeneralCategory::Lu),
+    (0x04ed, 0x04ed, GeneralCategory::Ll),
+    (0x04ee, 0x04ee, GeneralCategory::Lu),
+    (0x04ef, 0x04ef, GeneralCategory::Ll),
+    (0x04f0, 0x04f0, GeneralCategory::Lu),
+    (0x04f1, 0x04f1, GeneralCategory::Ll),
+    (0x04f2, 0x04f2, GeneralCategory::Lu),
+    (0x04f3, 0x04f3, GeneralCategory::Ll),
+    (0x04f4, 0x04f4, GeneralCategory::Lu),
+    (0x04f5, 0x04f5, GeneralCategory::Ll),
+    (0x04f6, 0x04f6, GeneralCategory::Lu),
+    (0x04f7, 0x04f7, GeneralCategory::Ll),
+    (0x04f8, 0x04f8, GeneralCategory::Lu),
+    (0x04f9, 0x04f9, GeneralCategory::Ll),
+    (0x04fa, 0x04fa, GeneralCategory::Lu),
+    (0x04fb, 0x04fb, GeneralCategory::Ll),
+    (0x04fc, 0x04fc, GeneralCategory::Lu),
+    (0x04fd, 0x04fd, GeneralCategory::Ll),
+    (0x04fe, 0x04fe, GeneralCategory::Lu),
+    (0x04ff, 0x04ff, GeneralCategory::Ll),
+    (0x0500, 0x0500, GeneralCategory::Lu),
+    (0x0501, 0x0501, GeneralCategory::Ll),
+    (0x0502, 0x0502, GeneralCategory::Lu),
+    (0x0503, 0x0503, GeneralCategory::Ll),
+    (0x0504, 0x0504, GeneralCategory::Lu),
+    (0x0505, 0x0505, GeneralCategory::Ll),
+    (0x0506, 0x0506, GeneralCategory::Lu),
+    (0x0507, 0x0507, GeneralCategory::Ll),
+    (0x0508, 0x0508, GeneralCategory::Lu),
+    (0x0509, 0x0509, GeneralCategory::Ll),
+    (0x050a, 0x050a, GeneralCategory::Lu),
+    (0x050b, 0x050b, GeneralCategory::Ll),
+    (0x050c, 0x050c, GeneralCategory::Lu),
+    (0x050d, 0x050d, GeneralCategory::Ll),
+    (0x050e, 0x050e, GeneralCategory::Lu),
+    (0x050f, 0x050f, GeneralCategory::Ll),
+    (0x0510, 0x0510, GeneralCategory::Lu),
+    (0x0511, 0x0511, GeneralCategory::Ll),
+    (0x0512, 0x0512, GeneralCategory::Lu),
+    (0x0513, 0x0513, GeneralCategory::Ll),
+    (0x0514, 0x0514, GeneralCategory::Lu),
+    (0x0515, 0x0515, GeneralCategory::Ll),
+    (0x0516, 0x0516, GeneralCategory::Lu),
+    (0x0517, 0x0517, GeneralCategory::Ll),
+    (0x0518, 0x0518, GeneralCategory::Lu),
+    (0x0519, 0x0519, GeneralCategory::Ll),
+    (0x051a, 0x051a, GeneralCategory::Lu),
+    (0x051b, 0x051b, GeneralCategory::Ll),
+    (0x051c, 0x051c, GeneralCategory::Lu),
+    (0x051d, 0x051d, GeneralCategory::Ll),
+    (0x051e, 0x051e, GeneralCategory::Lu),
+    (0x051f, 0x051f, GeneralCategory::Ll),
+    (0x0520, 0x0520, GeneralCategory::Lu),
+    (0x0521, 0x0521, GeneralCategory::Ll),
+    (0x0522, 0x0522, GeneralCategory::Lu),
+    (0x0523, 0x0523, GeneralCategory::Ll),
+    (0x0524, 0x0524, GeneralCategory::Lu),
+    (0x0525, 0x0525, GeneralCategory::Ll),
+    (0x0526, 0x0526, GeneralCategory::Lu),
+    (0x0527, 0x0527, GeneralCategory::Ll),
+    (0x0528, 0x0528, GeneralCategory::Lu),
+    (0x0529, 0x0529, GeneralCategory::Ll),
+    (0x052a, 0x052a, GeneralCategory::Lu),
+    (0x052b, 0x052b, GeneralCategory::Ll),
+    (0x052c, 0x052c, GeneralCategory::Lu),
+    (0x052d, 0x052d, GeneralCategory::Ll),
+    (0x052e, 0x052e, GeneralCategory::Lu),
+    (0x052f, 0x052f, GeneralCategory::Ll),
+    (0x0531, 0x0556, GeneralCategory::Lu),
+    (0x0559, 0x0559, GeneralCategory::Lm),
+    (0x055a, 0x055f, GeneralCategory::Po),
+    (0x0560, 0x0588, GeneralCategory::Ll),
+    (0x0589, 0x0589, GeneralCategory::Po),
+    (0x058a, 0x058a, GeneralCategory::Pd),
+    (0x058d, 0x058e, GeneralCategory::So),
+    (0x058f, 0x058f, GeneralCategory::Sc),
+    (0x0591, 0x05bd, GeneralCategory::Mn),
+    (0x05be, 0x05be, GeneralCategory::Pd),
+    (0x05bf, 0x05bf, GeneralCategory::Mn),
+    (0x05c0, 0x05c0, GeneralCategory::Po),
+    (0x05c1, 0x05c2, GeneralCategory::Mn),
+    (0x05c3, 0x05c3, GeneralCategory::Po),
+    (0x05c4, 0x05c5, GeneralCategory::Mn),
+    (0x05c6, 0x05c6, GeneralCategory::Po),
+    (0x05c7, 0x05c7, GeneralCategory::Mn),
+    (0x05d0, 0x05ea, GeneralCategory::Lo),
+    (0x05ef, 0x05f2, GeneralCategory::Lo),
+    (0x05f3, 0x05f4, GeneralCategory::Po),
+    (0x0600, 0x0605, GeneralCategory::Cf),
+    (0x0606, 0x0608, GeneralCategory::Sm),
+    (0x0609, 0x060a, GeneralCategory::Po),
+    (0x060b, 0x060b, GeneralCategory::Sc),
+    (0x060c, 0x060d, GeneralCategory::Po),
+    (0x060e, 0x060f, GeneralCategory::So),
+    (0x0610, 0x061a, GeneralCategory::Mn),
+    (0x061b, 0x061b, GeneralCategory::Po),
+    (0x061c, 0x061c, GeneralCategory::Cf),
+    (0x061d, 0x061f, GeneralCategory::Po),
+    (0x0620, 0x063f, GeneralCategory::Lo),
+    (0x0640, 0x0640, GeneralCategory::Lm),
+    (0x0641, 0x064a, GeneralCategory::Lo),
+    (0x064b, 0x065f, GeneralCategory::Mn),
+    (0x0660, 0x0669, GeneralCategory::Nd),
+    (0x066a, 0x066d, GeneralCategory::Po),
+    (0x066e, 0x066f, GeneralCategory::Lo),
+    (0x0670, 0x0670, GeneralCategory::Mn),
+    (0x0671, 0x06d3, GeneralCategory::Lo),
+    (0x06d4, 0x06d4, GeneralCategory::Po),
+    (0x06d5, 0x06d5, GeneralCategory::Lo),
+    (0x06d6, 0x06dc, GeneralCategory::Mn),
+    (0x06dd, 0x06dd, GeneralCategory::Cf),
+    (0x06de, 0x06de, GeneralCategory::So),
+    (0x06df, 0x06e4, GeneralCategory::Mn),
+    (0x06e5, 0x06e6, GeneralCategory::Lm),
+    (0x06e7, 0x06e8, GeneralCategory::Mn),
+    (0x06e9, 0x06e9, GeneralCategory::So),
+    (0x06ea, 0x06ed, GeneralCategory::Mn),
+    (0x06ee, 0x06ef, GeneralCategory::Lo),
+    (0x06f0, 0x06f9, GeneralCategory::Nd),
+    (0x06fa, 0x06fc, GeneralCategory::Lo),
+    (0x06fd, 0x06fe, GeneralCategory::So),
+    (0x06ff, 0x06ff, GeneralCategory::Lo),
+    (0x0700, 0x070d, GeneralCategory::Po),
+    (0x070f, 0x070f, GeneralCategory::Cf),
+    (0x0710, 0x0710, GeneralCategory::Lo),
+    (0x0711, 0x0711, GeneralCategory::Mn),
+    (0x0712, 0x072f, GeneralCategory::Lo),
+    (0x0730, 0x074a, GeneralCategory::Mn),
+    (0x074d, 0x07a5, GeneralCategory::Lo),
+    (0x07a6, 0x07b0, GeneralCategory::Mn),
+    (0x07b1, 0x07b1, GeneralCategory::Lo),
+    (0x07c0, 0x07c9, GeneralCategory::Nd),
+    (0x07ca, 0x07ea, GeneralCategory::Lo),
+    (0x07eb, 0x07f3, GeneralCategory::Mn),
+    (0x07f4, 0x07f5, GeneralCategory::Lm),
+    (0x07f6, 0x07f6, GeneralCategory::So),
+    (0x07f7, 0x07f9, GeneralCategory::Po),
+    (0x07fa, 0x07fa, GeneralCategory::Lm),
+    (0x07fd, 0x07fd, GeneralCategory::Mn),
+    (0x07fe, 0x07ff, GeneralCategory::Sc),
+    (0x0800, 0x0815, GeneralCategory::Lo),
+    (0x0816, 0x0819, GeneralCategory::Mn),
+    (0x081a, 0x081a, GeneralCategory::Lm),
+    (0x081b, 0x0823, GeneralCategory::Mn),
+    (0x0824, 0x0824, GeneralCategory::Lm),
+    (0x0825, 0x0827, GeneralCategory::Mn),
+    (0x0828, 0x0828, GeneralCategory::Lm),
+    (0x0829, 0x082d, GeneralCategory::Mn),
+    (0x0830, 0x083e, GeneralCategory::Po),
+    (0x0840, 0x0858, GeneralCategory::Lo),
+    (0x0859, 0x085b, GeneralCategory::Mn),
+    (0x085e, 0x085e, GeneralCategory::Po),
+    (0x0860, 0x086a, GeneralCategory::Lo),
+    (0x0870, 0x0887, GeneralCategory::Lo),
+    (0x0888, 0x0888, GeneralCategory::Sk),
+    (0x0889, 0x088f, GeneralCategory::Lo),
+    (0x0890, 0x0891, GeneralCategory::Cf),
+    (0x0897, 0x089f, GeneralCategory::Mn),
+    (0x08a0, 0x08c8, GeneralCategory::Lo),
+    (0x08c9, 0x08c9, GeneralCategory::Lm),
+    (0x08ca, 0x08e1, GeneralCategory::Mn),
+    (0x08e2, 0x08e2, GeneralCategory::Cf),
+    (0x08e3, 0x0902, GeneralCategory::Mn),
+    (0x0903, 0x0903, GeneralCategory::Mc),
+    (0x0904, 0x0939, GeneralCategory::Lo),
+    (0x093a, 0x093a, GeneralCategory::Mn),
+    (0x093b, 0x093b, GeneralCategory::Mc),
+    (0x093c, 0x093c, GeneralCategory::Mn),
+    (0x093d, 0x093d, GeneralCategory::Lo),
+    (0x093e, 0x0940, GeneralCategory::Mc),
+    (0x0941, 0x0948, GeneralCategory::Mn),
+    (0x0949, 0x094c, GeneralCategory::Mc),
+    (0x094d, 0x094d, GeneralCategory::Mn),
+    (0x094e, 0x094f, GeneralCategory::Mc),
+    (0x0950, 0x0950, GeneralCategory::Lo),
+    (0x0951, 0x0957, GeneralCategory::Mn),
+    (0x0958, 0x0961, GeneralCategory::Lo),
+    (0x0962, 0x0963, GeneralCategory::Mn),
+    (0x0964, 0x0965, GeneralCategory::Po),
+    (0x0966, 0x096f, GeneralCategory::Nd),
+    (0x0970, 0x0970, GeneralCategory::Po),
+    (0x0971, 0x0971, GeneralCategory::Lm),
+    (0x0972, 0x0980, GeneralCategory::Lo),
+    (0x0981, 0x0981, GeneralCategory::Mn),
+    (0x0982, 0x0983, GeneralCategory::Mc),
+    (0x0985, 0x098c, GeneralCategory::Lo),
+    (0x098f, 0x0990, GeneralCategory::Lo),
+    (0x0993, 0x09a8, GeneralCategory::Lo),
+    (0x09aa, 0x09b0, GeneralCategory::Lo),
+    (0x09b2, 0x09b2, GeneralCategory::Lo),
+    (0x09b6, 0x09b9, GeneralCategory::Lo),
+    (0x09bc, 0x09bc, GeneralCategory::Mn),
+    (0x09bd, 0x09bd, GeneralCategory::Lo),
+    (0x09be, 0x09c0, GeneralCategory::Mc),
+    (0x09c1, 0x09c4, GeneralCategory::Mn),
+    (0x09c7, 0x09c8, GeneralCategory::Mc),
+    (0x09cb, 0x09cc, GeneralCategory::Mc),
+    (0x09cd, 0x09cd, GeneralCategory::Mn),
+    (0x09ce, 0x09ce, GeneralCategory::Lo),
+    (0x09d7, 0x09d7, GeneralCategory::Mc),
+    (0x09dc, 0x09dd, GeneralCategory::Lo),
+    (0x09df, 0x09e1, GeneralCategory::Lo),
+    (0x09e2, 0x09e3, GeneralCategory::Mn),
+    (0x09e6, 0x09ef, GeneralCategory::Nd),
+    (0x09f0, 0x09f1, GeneralCategory::Lo),
+    (0x09f2, 0x09f3, GeneralCategory::Sc),
+    (0x09f4, 0x09f9, GeneralCategory::No),
+    (0x09fa, 0x09fa, GeneralCategory::So),
+    (0x09fb, 0x09fb, GeneralCategory::Sc),
+    (0x09fc, 0x09fc, GeneralCategory::Lo),
+    (0x09fd, 0x09fd, GeneralCategory::Po),
+    (0x09fe, 0x09fe, GeneralCategory::Mn),
+    (0x0a01, 0x0a02, GeneralCategory::Mn),
+    (0x0a03, 0x0a03, GeneralCategory::Mc),
+    (0x0a05, 0x0a0a, GeneralCategory::Lo),
+    (0x0a0f, 0x0a10, GeneralCategory::Lo),
+    (0x0a13, 0x0a28, GeneralCategory::Lo),
+    (0x0a2a, 0x0a30, GeneralCategory::Lo),
+    (0x0a32, 0x0a33, GeneralCategory::Lo),
+    (0x0a35, 0x0a36, GeneralCategory::Lo),
+    (0x0a38, 0x0a39, GeneralCategory::Lo),
+    (0x0a3c, 0x0a3c, GeneralCategory::Mn),
+    (0x0a3e, 0x0a40, GeneralCategory::Mc),
+    (0x0a41, 0x0a42, GeneralCategory::Mn),
+    (0x0a47, 0x0a48, GeneralCategory::Mn),
+    (0x0a4b, 0x0a4d, GeneralCategory::Mn),
+    (0x0a51, 0x0a51, GeneralCategory::Mn),
+    (0x0a59, 0x0a5c, GeneralCategory::Lo),
+    (0x0a5e, 0x0a5e, GeneralCategory::Lo),
+    (0x0a66, 0x0a6f, GeneralCategory::Nd),
+    (0x0a70, 0x0a71, GeneralCategory::Mn),
+    (0x0a72, 0x0a74, GeneralCategory::Lo),
+    (0x0a75, 0x0a75, GeneralCategory::Mn),
+    (0x0a76, 0x0a76, GeneralCategory::Po),
+    (0x0a81, 0x0a82, GeneralCategory::Mn),
+    (0x0a83, 0x0a83, GeneralCategory::Mc),
+    (0x0a85, 0x0a8d, GeneralCategory::Lo),
+    (0x0a8f, 0x0a91, GeneralCategory::Lo),
+    (0x0a93, 0x0aa8, GeneralCategory::Lo),
+    (0x0aaa, 0x0ab0, GeneralCategory::Lo),
+    (0x0ab2, 0x0ab3, GeneralCategory::Lo),
+    (0x0ab5, 0x0ab9, GeneralCategory::Lo),
+    (0x0abc, 0x0abc, GeneralCategory::Mn),
+    (0x0abd, 0x0abd, GeneralCategory::Lo),
+    (0x0abe, 0x0ac0, GeneralCategory::Mc),
+    (0x0ac1, 0x0ac5, GeneralCategory::Mn),
+    (0x0ac7, 0x0ac8, GeneralCategory::Mn),
+    (0x0ac9, 0x0ac9, GeneralCategory::Mc),
+    (0x0acb, 0x0acc, GeneralCategory::Mc),
+    (0x0acd, 0x0acd, GeneralCategory::Mn),
+    (0x0ad0, 0x0ad0, GeneralCategory::Lo),
+    (0x0ae0, 0x0ae1, GeneralCategory::Lo),
+    (0x0ae2, 0x0ae3, GeneralCategory::Mn),
+    (0x0ae6, 0x0aef, GeneralCategory::Nd),
+    (0x0af0, 0x0af0, GeneralCategory::Po),
+    (0x0af1, 0x0af1, GeneralCategory::Sc),
+    (0x0af9, 0x0af9, GeneralCategory::Lo),
+    (0x0afa, 0x0aff, GeneralCategory::Mn),
+    (0x0b01, 0x0b01, GeneralCategory::Mn),
+    (0x0b02, 0x0b03, GeneralCategory::Mc),
+    (0x0b05, 0x0b0c, GeneralCategory::Lo),
+    (0x0b0f, 0x0b10, GeneralCategory::Lo),
+    (0x0b13, 0x0b28, GeneralCategory::Lo),
+    (0x0b2a, 0x0b30, GeneralCategory::Lo),
+    (0x0b32, 0x0b33, GeneralCategory::Lo),
+    (0x0b35, 0x0b39, GeneralCategory::Lo),
+    (0x0b3c, 0x0b3c, GeneralCategory::Mn),
+    (0x0b3d, 0x0b3d, GeneralCategory::Lo),
+    (0x0b3e, 0x0b3e, GeneralCategory::Mc),
+    (0x0b3f, 0x0b3f, GeneralCategory::Mn),
+    (0x0b40, 0x0b40, GeneralCategory::Mc),
+    (0x0b41, 0x0b44, GeneralCategory::Mn),
+    (0x0b47, 0x0b48, GeneralCategory::Mc),
+    (0x0b4b, 0x0b4c, GeneralCategory::Mc),
+    (0x0b4d, 0x0b4d, GeneralCategory::Mn),
+    (0x0b55, 0x0b56, GeneralCategory::Mn),
+    (0x0b57, 0x0b57, GeneralCategory::Mc),
+    (0x0b5c, 0x0b5d, GeneralCategory::Lo),
+    (0x0b5f, 0x0b61, GeneralCategory::Lo),
+    (0x0b62, 0x0b63, GeneralCategory::Mn),
+    (0x0b66, 0x0b6f, GeneralCategory::Nd),
+    (0x0b70, 0x0b70, GeneralCategory::So),
+    (0x0b71, 0x0b71, GeneralCategory::Lo),
+    (0x0b72, 0x0b77, GeneralCategory::No),
+    (0x0b82, 0x0b82, GeneralCategory::Mn),
+    (0x0b83, 0x0b83, GeneralCategory::Lo),
+    (0x0b85, 0x0b8a, GeneralCategory::Lo),
+    (0x0b8e, 0x0b90, GeneralCategory::Lo),
+    (0x0b92, 0x0b95, GeneralCategory::Lo),
+    (0x0b99, 0x0b9a, GeneralCategory::Lo),
+    (0x0b9c, 0x0b9c, GeneralCategory::Lo),
+    (0x0b9e, 0x0b9f, GeneralCategory::Lo),
+    (0x0ba3, 0x0ba4, GeneralCategory::Lo),
+    (0x0ba8, 0x0baa, GeneralCategory::Lo),
+    (0x0bae, 0x0bb9, GeneralCategory::Lo),
+    (0x0bbe, 0x0bbf, GeneralCategory::Mc),
+    (0x0bc0, 0x0bc0, GeneralCategory::Mn),
+    (0x0bc1, 0x0bc2, GeneralCategory::Mc),
+    (0x0bc6, 0x0bc8, GeneralCategory::Mc),
+    (0x0bca, 0x0bcc, GeneralCategory::Mc),
+    (0x0bcd, 0x0bcd, GeneralCategory::Mn),
+    (0x0bd0, 0x0bd0, GeneralCategory::Lo),
+    (0x0bd7, 0x0bd7, GeneralCategory::Mc),
+    (0x0be6, 0x0bef, GeneralCategory::Nd),
+    (0x0bf0, 0x0bf2, GeneralCategory::No),
+    (0x0bf3, 0x0bf8, GeneralCategory::So),
+    (0x0bf9, 0x0bf9, GeneralCategory::Sc),
+    (0x0bfa, 0x0bfa, GeneralCategory::So),
+    (0x0c00, 0x0c00, GeneralCategory::Mn),
+    (0x0c01, 0x0c03, GeneralCategory::Mc),
+    (0x0c04, 0x0c04, GeneralCategory::Mn),
+    (0x0c05, 0x0c0c, GeneralCategory::Lo),
+    (0x0c0e, 0x0c10, GeneralCategory::Lo),
+    (0x0c12, 0x0c28, GeneralCategory::Lo),
+    (0x0c2a, 0x0c39, GeneralCategory::Lo),
+    (0x0c3c, 0x0c3c, GeneralCategory::Mn),
+    (0x0c3d, 0x0c3d, GeneralCategory::Lo),
+    (0x0c3e, 0x0c40, GeneralCategory::Mn),
+    (0x0c41, 0x0c44, GeneralCategory::Mc),
+    (0x0c46, 0x0c48, GeneralCategory::Mn),
+    (0x0c4a, 0x0c4d, GeneralCategory::Mn),
+    (0x0c55, 0x0c56, GeneralCategory::Mn),
+    (0x0c58, 0x0c5a, GeneralCategory::Lo),
+    (0x0c5c, 0x0c5d, GeneralCategory::Lo),
+    (0x0c60, 0x0c61, GeneralCategory::Lo),
+    (0x0c62, 0x0c63, GeneralCategory::Mn),
+    (0x0c66, 0x0c6f, GeneralCategory::Nd),
+    (0x0c77, 0x0c77, GeneralCategory::Po),
+    (0x0c78, 0x0c7e, GeneralCategory::No),
+    (0x0c7f, 0x0c7f, GeneralCategory::So),
+    (0x0c80, 0x0c80, GeneralCategory::Lo),
+    (0x0c81, 0x0c81, GeneralCategory::Mn),
+    (0x0c82, 0x0c83, GeneralCategory::Mc),
+    (0x0c84, 0x0c84, GeneralCategory::Po),
+    (0x0c85, 0x0c8c, GeneralCategory::Lo),
+    (0x0c8e, 0x0c90, GeneralCategory::Lo),
+    (0x0c92, 0x0ca8, GeneralCategory::Lo),
+    (0x0caa, 0x0cb3, GeneralCategory::Lo),
+    (0x0cb5, 0x0cb9, GeneralCategory::Lo),
+    (0x0cbc, 0x0cbc, GeneralCategory::Mn),
+    (0x0cbd, 0x0cbd, GeneralCategory::Lo),
+    (0x0cbe, 0x0cbe, GeneralCategory::Mc),
+    (0x0cbf, 0x0cbf, GeneralCategory::Mn),
+    (0x0cc0, 0x0cc4, GeneralCategory::Mc),
+    (0x0cc6, 0x0cc6, GeneralCategory::Mn),
+    (0x0cc7, 0x0cc8, GeneralCategory::Mc),
+    (0x0cca, 0x0ccb, GeneralCategory::Mc),
+    (0x0ccc, 0x0ccd, GeneralCategory::Mn),
+    (0x0cd5, 0x0cd6, GeneralCategory::Mc),
+    (0x0cdc, 0x0cde, GeneralCategory::Lo),
+    (0x0ce0, 0x0ce1, GeneralCategory::Lo),
+    (0x0ce2, 0x0ce3, GeneralCategory::Mn),
+    (0x0ce6, 0x0cef, GeneralCategory::Nd),
+    (0x0cf1, 0x0cf2, GeneralCategory::Lo),
+    (0x0cf3, 0x0cf3, GeneralCategory::Mc),
+    (0x0d00, 0x0d01, GeneralCategory::Mn),
+    (0x0d02, 0x0d03, GeneralCategory::Mc),
+    (0x0d04, 0x0d0c, GeneralCategory::Lo),
+    (0x0d0e, 0x0d10, GeneralCategory::Lo),
+    (0x0d12, 0x0d3a, GeneralCategory::Lo),
+    (0x0d3b, 0x0d3c, GeneralCategory::Mn),
+    (0x0d3d, 0x0d3d, GeneralCategory::Lo),
+    (0x0d3e, 0x0d40, GeneralCategory::Mc),
+    (0x0d41, 0x0d44, GeneralCategory::Mn),
+    (0x0d46, 0x0d48, GeneralCategory::Mc),
+    (0x0d4a, 0x0d4c, GeneralCategory::Mc),
+    (0x0d4d, 0x0d4d, GeneralCategory::Mn),
+    (0x0d4e, 0x0d4e, GeneralCategory::Lo),
+    (0x0d4f, 0x0d4f, GeneralCategory::So),
+    (0x0d54, 0x0d56, GeneralCategory::Lo),
+    (0x0d57, 0x0d57, GeneralCategory::Mc),
+    (0x0d58, 0x0d5e, GeneralCategory::No),
+    (0x0d5f, 0x0d61, GeneralCategory::Lo),
+    (0x0d62, 0x0d63, GeneralCategory::Mn),
+    (0x0d66, 0x0d6f, GeneralCategory::Nd),
+    (0x0d70, 0x0d78, GeneralCategory::No),
+    (0x0d79, 0x0d79, GeneralCategory::So),
+    (0x0d7a, 0x0d7f, GeneralCategory::Lo),
+    (0x0d81, 0x0d81, GeneralCategory::Mn),
+    (0x0d82, 0x0d83, GeneralCategory::Mc),
+    (0x0d85, 0x0d96, GeneralCategory::Lo),
+    (0x0d9a, 0x0db1, GeneralCategory::Lo),
+    (0x0db3, 0x0dbb, GeneralCategory::Lo),
+    (0x0dbd, 0x0dbd, GeneralCategory::Lo),
+    (0x0dc0, 0x0dc6, GeneralCategory::Lo),
+    (0x0dca, 0x0dca, GeneralCategory::Mn),
+    (0x0dcf, 0x0dd1, GeneralCategory::Mc),
+    (0x0dd2, 0x0dd4, GeneralCategory::Mn),
+    (0x0dd6, 0x0dd6, GeneralCategory::Mn),
+    (0x0dd8, 0x0ddf, GeneralCategory::Mc),
+    (0x0de6, 0x0def, GeneralCategory::Nd),
+    (0x0df2, 0x0df3, GeneralCategory::Mc),
+    (0x0df4, 0x0df4, GeneralCategory::Po),
+    (0x0e01, 0x0e30, GeneralCategory::Lo),
+    (0x0e31, 0x0e31, GeneralCategory::Mn),
+    (0x0e32, 0x0e33, GeneralCategory::Lo),
+    (0x0e34, 0x0e3a, GeneralCategory::Mn),
+    (0x0e3f, 0x0e3f, GeneralCategory::Sc),
+    (0x0e40, 0x0e45, GeneralCategory::Lo),
+    (0x0e46, 0x0e46, GeneralCategory::Lm),
+    (0x0e47, 0x0e4e, GeneralCategory::Mn),
+    (0x0e4f, 0x0e4f, GeneralCategory::Po),
+    (0x0e50, 0x0e59, GeneralCategory::Nd),
+    (0x0e5a, 0x0e5b, GeneralCategory::Po),
+    (0x0e81, 0x0e82, GeneralCategory::Lo),
+    (0x0e84, 0x0e84, GeneralCategory::Lo),
+    (0x0e86, 0x0e8a, GeneralCategory::Lo),
+    (0x0e8c, 0x0ea3, GeneralCategory::Lo),
+    (0x0ea5, 0x0ea5, GeneralCategory::Lo),
+    (0x0ea7, 0x0eb0, GeneralCategory::Lo),
+    (0x0eb1, 0x0eb1, GeneralCategory::Mn),
+    (0x0eb2, 0x0eb3, GeneralCategory::Lo),
+    (0x0eb4, 0x0ebc, GeneralCategory::Mn),
+    (0x0ebd, 0x0ebd, GeneralCategory::Lo),
+    (0x0ec0, 0x0ec4, GeneralCategory::Lo),
+    (0x0ec6, 0x0ec6, GeneralCategory::Lm),
+    (0x0ec8, 0x0ece, GeneralCategory::Mn),
+    (0x0ed0, 0x0ed9, GeneralCategory::Nd),
+    (0x0edc, 0x0edf, GeneralCategory::Lo),
+    (0x0f00, 0x0f00, GeneralCategory::Lo),
+    (0x0f01, 0x0f03, GeneralCategory::So),
+    (0x0f04, 0x0f12, GeneralCategory::Po),
+    (0x0f13, 0x0f13, GeneralCategory::So),
+    (0x0f14, 0x0f14, GeneralCategory::Po),
+    (0x0f15, 0x0f17, GeneralCategory::So),
+    (0x0f18, 0x0f19, GeneralCategory::Mn),
+    (0x0f1a, 0x0f1f, GeneralCategory::So),
+    (0x0f20, 0x0f29, GeneralCategory::Nd),
+    (0x0f2a, 0x0f33, GeneralCategory::No),
+    (0x0f34, 0x0f34, GeneralCategory::So),
+    (0x0f35, 0x0f35, GeneralCategory::Mn),
+    (0x0f36, 0x0f36, GeneralCategory::So),
+    (0x0f37, 0x0f37, GeneralCategory::Mn),
+    (0x0f38, 0x0f38, GeneralCategory::So),
+    (0x0f39, 0x0f39, GeneralCategory::Mn),
+    (0x0f3a, 0x0f3a, GeneralCategory::Ps),
+    (0x0f3b, 0x0f3b, GeneralCategory::Pe),
+    (0x0f3c, 0x0f3c, GeneralCategory::Ps),
+    (0x0f3d, 0x0f3d, GeneralCategory::Pe),
+    (0x0f3e, 0x0f3f, GeneralCategory::Mc),
+    (0x0f40, 0x0f47, GeneralCategory::Lo),
+    (0x0f49, 0x0f6c, GeneralCategory::Lo),
+    (0x0f71, 0x0f7e, GeneralCategory::Mn),
+    (0x0f7f, 0x0f7f, GeneralCategory::Mc),
+    (0x0f80, 0x0f84, GeneralCategory::Mn),
+    (0x0f85, 0x0f85, GeneralCategory::Po),
+    (0x0f86, 0x0f87, GeneralCategory::Mn),
+    (0x0f88, 0x0f8c, GeneralCategory::Lo),
+    (0x0f8d, 0x0f97, GeneralCategory::Mn),
+    (0x0f99, 0x0fbc, GeneralCategory::Mn),
+    (0x0fbe, 0x0fc5, GeneralCategory::So),
+    (0x0fc6, 0x0fc6, GeneralCategory::Mn),
+    (0x0fc7, 0x0fcc, GeneralCategory::So),
+    (0x0fce, 0x0fcf, GeneralCategory::So),
+    (0x0fd0, 0x0fd4, GeneralCategory::Po),
+    (0x0fd5, 0x0fd8, GeneralCategory::So),
+    (0x0fd9, 0x0fda, GeneralCategory::Po),
+    (0x1000, 0x102a, GeneralCategory::Lo),
+    (0x102b, 0x102c, GeneralCategory::Mc),
+    (0x102d, 0x1030, GeneralCategory::Mn),
+    (0x1031, 0x1031, GeneralCategory::Mc),
+    (0x1032, 0x1037, GeneralCategory::Mn),
+    (0x1038, 0x1038, GeneralCategory::Mc),
+    (0x1039, 0x103a, GeneralCategory::Mn),
+    (0x103b, 0x103c, GeneralCategory::Mc),
+    (0x103d, 0x103e, GeneralCategory::Mn),
+    (0x103f, 0x103f, GeneralCategory::Lo),
+    (0x1040, 0x1049, GeneralCategory::Nd),
+    (0x104a, 0x104f, GeneralCategory::Po),
+    (0x1050, 0x1055, GeneralCategory::Lo),
+    (0x1056, 0x1057, GeneralCategory::Mc),
+    (0x1058, 0x1059, GeneralCategory::Mn),
+    (0x105a, 0x105d, GeneralCategory::Lo),
+    (0x105e, 0x1060, GeneralCategory::Mn),
+    (0x1061, 0x1061, GeneralCategory::Lo),
+    (0x1062, 0x1064, GeneralCategory::Mc),
+    (0x1065, 0x1066, GeneralCategory::Lo),
+    (0x1067, 0x106d, GeneralCategory::Mc),
+    (0x106e, 0x1070, GeneralCategory::Lo),
+    (0x1071, 0x1074, GeneralCategory::Mn),
+    (0x1075, 0x1081, GeneralCategory::Lo),
+    (0x1082, 0x1082, GeneralCategory::Mn),
+    (0x1083, 0x1084, GeneralCategory::Mc),
+    (0x1085, 0x1086, GeneralCategory::Mn),
+    (0x1087, 0x108c, GeneralCategory::Mc),
+    (0x108d, 0x108d, GeneralCategory::Mn),
+    (0x108e, 0x108e, GeneralCategory::Lo),
+    (0x108f, 0x108f, GeneralCategory::Mc),
+    (0x1090, 0x1099, GeneralCategory::Nd),
+    (0x109a, 0x109c, GeneralCategory::Mc),
+    (0x109d, 0x109d, GeneralCategory::Mn),
+    (0x109e, 0x109f, GeneralCategory::So),
+    (0x10a0, 0x10c5, GeneralCategory::Lu),
+    (0x10c7, 0x10c7, GeneralCategory::Lu),
+    (0x10cd, 0x10cd, GeneralCategory::Lu),
+    (0x10d0, 0x10fa, GeneralCategory::Ll),
+    (0x10fb, 0x10fb, GeneralCategory::Po),
+    (0x10fc, 0x10fc, GeneralCategory::Lm),
+    (0x10fd, 0x10ff, GeneralCategory::Ll),
+    (0x1100, 0x1248, GeneralCategory::Lo),
+    (0x124a, 0x124d, GeneralCategory::Lo),
+    (0x1250, 0x1256, GeneralCategory::Lo),
+    (0x1258, 0x1258, GeneralCategory::Lo),
+    (0x125a, 0x125d, GeneralCategory::Lo),
+    (0x1260, 0x1288, GeneralCategory::Lo),
+    (0x128a, 0x128d, GeneralCategory::Lo),
+    (0x1290, 0x12b0, GeneralCategory::Lo),
+    (0x12b2, 0x12b5, GeneralCategory::Lo),
+    (0x12b8, 0x12be, GeneralCategory::Lo),
+    (0x12c0, 0x12c0, GeneralCategory::Lo),
+    (0x12c2, 0x12c5, GeneralCategory::Lo),
+    (0x12c8, 0x12d6, GeneralCategory::Lo),
+    (0x12d8, 0x1310, GeneralCategory::Lo),
+    (0x1312, 0x1315, GeneralCategory::Lo),
+    (0x1318, 0x135a, GeneralCategory::Lo),
+    (0x135d, 0x135f, GeneralCategory::Mn),
+    (0x1360, 0x1368, GeneralCategory::Po),
+    (0x1369, 0x137c, GeneralCategory::No),
+    (0x1380, 0x138f, GeneralCategory::Lo),
+    (0x1390, 0x1399, GeneralCategory::So),
+    (0x13a0, 0x13f5, GeneralCategory::Lu),
+    (0x13f8, 0x13fd, GeneralCategory::Ll),
+    (0x1400, 0x1400, GeneralCategory::Pd),
+    (0x1401, 0x166c, GeneralCategory::Lo),
+    (0x166d, 0x166d, GeneralCategory::So),
+    (0x166e, 0x166e, GeneralCategory::Po),
+    (0x166f, 0x167f, GeneralCategory::Lo),
+    (0x1680, 0x1680, GeneralCategory::Zs),
+    (0x1681, 0x169a, GeneralCategory::Lo),
+    (0x169b, 0x169b, GeneralCategory::Ps),
+    (0x169c, 0x169c, GeneralCategory::Pe),
+    (0x16a0, 0x16ea, GeneralCategory::Lo),
+    (0x16eb, 0x16ed, GeneralCategory::Po),
+    (0x16ee, 0x16f0, GeneralCategory::Nl),
+    (0x16f1, 0x16f8, GeneralCategory::Lo),
+    (0x1700, 0x1711, GeneralCategory::Lo),
+    (0x1712, 0x1714, GeneralCategory::Mn),
+    (0x1715, 0x1715, GeneralCategory::Mc),
+    (0x171f, 0x1731, GeneralCategory::Lo),
+    (0x1732, 0x1733, GeneralCategory::Mn),
+    (0x1734, 0x1734, GeneralCategory::Mc),
+    (0x1735, 0x1736, GeneralCategory::Po),
+    (0x1740, 0x1751, GeneralCategory::Lo),
+    (0x1752, 0x1753, GeneralCategory::Mn),
+    (0x1760, 0x176c, GeneralCategory::Lo),
+    (0x176e, 0x1770, GeneralCategory::Lo),
+    (0x1772, 0x1773, GeneralCategory::Mn),
+    (0x1780, 0x17b3, GeneralCategory::Lo),
+    (0x17b4, 0x17b5, GeneralCategory::Mn),
+    (0x17b6, 0x17b6, GeneralCategory::Mc),
+    (0x17b7, 0x17bd, GeneralCategory::Mn),
+    (0x17be, 0x17c5, GeneralCategory::Mc),
+    (0x17c6, 0x17c6, GeneralCategory::Mn),
+    (0x17c7, 0x17c8, GeneralCategory::Mc),
+    (0x17c9, 0x17d3, GeneralCategory::Mn),
+    (0x17d4, 0x17d6, GeneralCategory::Po),
+    (0x17d7, 0x17d7, GeneralCategory::Lm),
+    (0x17d8, 0x17da, GeneralCategory::Po),
+    (0x17db, 0x17db, GeneralCategory::Sc),
+    (0x17dc, 0x17dc, GeneralCategory::Lo),
+    (0x17dd, 0x17dd, GeneralCategory::Mn),
+    (0x17e0, 0x17e9, GeneralCategory::Nd),
+    (0x17f0, 0x17f9, GeneralCategory::No),
+    (0x1800, 0x1805, GeneralCategory::Po),
+    (0x1806, 0x1806, GeneralCategory::Pd),
+    (0x1807, 0x180a, GeneralCategory::Po),
+    (0x180b, 0x180d, GeneralCategory::Mn),
+    (0x180e, 0x180e, GeneralCategory::Cf),
+    (0x180f, 0x180f, GeneralCategory::Mn),
+    (0x1810, 0x1819, GeneralCategory::Nd),
+    (0x1820, 0x1842, GeneralCategory::Lo),
+    (0x1843, 0x1843, GeneralCategory::Lm),
+    (0x1844, 0x1878, GeneralCategory::Lo),
+    (0x1880, 0x1884, GeneralCategory::Lo),
+    (0x1885, 0x1886, GeneralCategory::Mn),
+    (0x1887, 0x18a8, GeneralCategory::Lo),
+    (0x18a9, 0x18a9, GeneralCategory::Mn),
+    (0x18aa, 0x18aa, GeneralCategory::Lo),
+    (0x18b0, 0x18f5, GeneralCategory::Lo),
+    (0x1900, 0x191e, GeneralCategory::Lo),
+    (0x1920, 0x1922, GeneralCategory::Mn),
+    (0x1923, 0x1926, GeneralCategory::Mc),
+    (0x1927, 0x1928, GeneralCategory::Mn),
+    (0x1929, 0x192b, GeneralCategory::Mc),
+    (0x1930, 0x1931, GeneralCategory::Mc),
+    (0x1932, 0x1932, GeneralCategory::Mn),
+    (0x1933, 0x1938, GeneralCategory::Mc),
+    (0x1939, 0x193b, GeneralCategory::Mn),
+    (0x1940, 0x1940, GeneralCategory::So),
+    (0x1944, 0x1945, GeneralCategory::Po),
+    (0x1946, 0x194f, GeneralCategory::Nd),
+    (0x1950, 0x196d, GeneralCategory::Lo),
+    (0x1970, 0x1974, GeneralCategory::Lo),
+    (0x1980, 0x19ab, GeneralCategory::Lo),
+    (0x19b0, 0x19c9, GeneralCategory::Lo),
+    (0x19d0, 0x19d9, GeneralCategory::Nd),
+    (0x19da, 0x19da, GeneralCategory::No),
+    (0x19de, 0x19ff, GeneralCategory::So),
+    (0x1a00, 0x1a16, GeneralCategory::Lo),
+    (0x1a17, 0x1a18, GeneralCategory::Mn),
+    (0x1a19, 0x1a1a, GeneralCategory::Mc),
+    (0x1a1b, 0x1a1b, GeneralCategory::Mn),
+    (0x1a1e, 0x1a1f, GeneralCategory::Po),
+    (0x1a20, 0x1a54, GeneralCategory::Lo),
+    (0x1a55, 0x1a55, GeneralCategory::Mc),
+    (0x1a56, 0x1a56, GeneralCategory::Mn),
+    (0x1a57, 0x1a57, GeneralCategory::Mc),
+    (0x1a58, 0x1a5e, GeneralCategory::Mn),
+    (0x1a60, 0x1a60, GeneralCategory::Mn),
+    (0x1a61, 0x1a61, GeneralCategory::Mc),
+    (0x1a62, 0x1a62, GeneralCategory::Mn),
+    (0x1a63, 0x1a64, GeneralCategory::Mc),
+    (0x1a65, 0x1a6c, GeneralCategory::Mn),
+    (0x1a6d, 0x1a72, GeneralCategory::Mc),
+    (0x1a73, 0x1a7c, GeneralCategory::Mn),
+    (0x1a7f, 0x1a7f, GeneralCategory::Mn),
+    (0x1a80, 0x1a89, GeneralCategory::Nd),
+    (0x1a90, 0x1a99, GeneralCategory::Nd),
+    (0x1aa0, 0x1aa6, GeneralCategory::Po),
+    (0x1aa7, 0x1aa7, GeneralCategory::Lm),
+    (0x1aa8, 0x1aad, GeneralCategory::Po),
+    (0x1ab0, 0x1abd, GeneralCategory::Mn),
+    (0x1abe, 0x1abe, GeneralCategory::Me),
+    (0x1abf, 0x1add, GeneralCategory::Mn),
+    (0x1ae0, 0x1aeb, GeneralCategory::Mn),
+    (0x1b00, 0x1b03, GeneralCategory::Mn),
+    (0x1b04, 0x1b04, GeneralCategory::Mc),
+    (0x1b05, 0x1b33, GeneralCategory::Lo),
+    (0x1b34, 0x1b34, GeneralCategory::Mn),
+    (0x1b35, 0x1b35, GeneralCategory::Mc),
+    (0x1b36, 0x1b3a, GeneralCategory::Mn),
+    (0x1b3b, 0x1b3b, GeneralCategory::Mc),
+    (0x1b3c, 0x1b3c, GeneralCategory::Mn),
+    (0x1b3d, 0x1b41, GeneralCategory::Mc),
+    (0x1b42, 0x1b42, GeneralCategory::Mn),
+    (0x1b43, 0x1b44, GeneralCategory::Mc),
+    (0x1b45, 0x1b4c, GeneralCategory::Lo),
+    (0x1b4e, 0x1b4f, GeneralCategory::Po),
+    (0x1b50, 0x1b59, GeneralCategory::Nd),
+    (0x1b5a, 0x1b60, GeneralCategory::Po),
+    (0x1b61, 0x1b6a, GeneralCategory::So),
+    (0x1b6b, 0x1b73, GeneralCategory::Mn),
+    (0x1b74, 0x1b7c, GeneralCategory::So),
+    (0x1b7d, 0x1b7f, GeneralCategory::Po),
+    (0x1b80, 0x1b81, GeneralCategory::Mn),
+    (0x1b82, 0x1b82, GeneralCategory::Mc),
+    (0x1b83, 0x1ba0, GeneralCategory::Lo),
+    (0x1ba1, 0x1ba1, GeneralCategory::Mc),
+    (0x1ba2, 0x1ba5, GeneralCategory::Mn),
+    (0x1ba6, 0x1ba7, GeneralCategory::Mc),
+    (0x1ba8, 0x1ba9, GeneralCategory::Mn),
+    (0x1baa, 0x1baa, GeneralCategory::Mc),
+    (0x1bab, 0x1bad, GeneralCategory::Mn),
+    (0x1bae, 0x1baf, GeneralCategory::Lo),
+    (0x1bb0, 0x1bb9, GeneralCategory::Nd),
+    (0x1bba, 0x1be5, GeneralCategory::Lo),
+    (0x1be6, 0x1be6, GeneralCategory::Mn),
+    (0x1be7, 0x1be7, GeneralCategory::Mc),
+    (0x1be8, 0x1be9, GeneralCategory::Mn),
+    (0x1bea, 0x1bec, GeneralCategory::Mc),
+    (0x1bed, 0x1bed, GeneralCategory::Mn),
+    (0x1bee, 0x1bee, GeneralCategory::Mc),
+    (0x1bef, 0x1bf1, GeneralCategory::Mn),
+    (0x1bf2, 0x1bf3, GeneralCategory::Mc),
+    (0x1bfc, 0x1bff, GeneralCategory::Po),
+    (0x1c00, 0x1c23, GeneralCategory::Lo),
+    (0x1c24, 0x1c2b, GeneralCategory::Mc),
+    (0x1c2c, 0x1c33, GeneralCategory::Mn),
+    (0x1c34, 0x1c35, GeneralCategory::Mc),
+    (0x1c36, 0x1c37, GeneralCategory::Mn),
+    (0x1c3b, 0x1c3f, GeneralCategory::Po),
+    (0x1c40, 0x1c49, GeneralCategory::Nd),
+    (0x1c4d, 0x1c4f, GeneralCategory::Lo),
+    (0x1c50, 0x1c59, GeneralCategory::Nd),
+    (0x1c5a, 0x1c77, GeneralCategory::Lo),
+    (0x1c78, 0x1c7d, GeneralCategory::Lm),
+    (0x1c7e, 0x1c7f, GeneralCategory::Po),
+    (0x1c80, 0x1c88, GeneralCategory::Ll),
+    (0x1c89, 0x1c89, GeneralCategory::Lu),
+    (0x1c8a, 0x1c8a, GeneralCategory::Ll),
+    (0x1c90, 0x1cba, GeneralCategory::Lu),
+    (0x1cbd, 0x1cbf, GeneralCategory::Lu),
+    (0x1cc0, 0x1cc7, GeneralCategory::Po),
+    (0x1cd0, 0x1cd2, GeneralCategory::Mn),
+    (0x1cd3, 0x1cd3, GeneralCategory::Po),
+    (0x1cd4, 0x1ce0, GeneralCategory::Mn),
+    (0x1ce1, 0x1ce1, GeneralCategory::Mc),
+    (0x1ce2, 0x1ce8, GeneralCategory::Mn),
+    (0x1ce9, 0x1cec, GeneralCategory::Lo),
+    (0x1ced, 0x1ced, GeneralCategory::Mn),
+    (0x1cee, 0x1cf3, GeneralCategory::Lo),
+    (0x1cf4, 0x1cf4, GeneralCategory::Mn),
+    (0x1cf5, 0x1cf6, GeneralCategory::Lo),
+    (0x1cf7, 0x1cf7, GeneralCategory::Mc),
+    (0x1cf8, 0x1cf9, GeneralCategory::Mn),
+    (0x1cfa, 0x1cfa, GeneralCategory::Lo),
+    (0x1d00, 0x1d2b, GeneralCategory::Ll),
+    (0x1d2c, 0x1d6a, GeneralCategory::Lm),
+    (0x1d6b, 0x1d77, GeneralCategory::Ll),
+    (0x1d78, 0x1d78, GeneralCategory::Lm),
+    (0x1d79, 0x1d9a, GeneralCategory::Ll),
+    (0x1d9b, 0x1dbf, GeneralCategory::Lm),
+    (0x1dc0, 0x1dff, GeneralCategory::Mn),
+    (0x1e00, 0x1e00, GeneralCategory::Lu),
+    (0x1e01, 0x1e01, GeneralCategory::Ll),
+    (0x1e02, 0x1e02, GeneralCategory::Lu),
+    (0x1e03, 0x1e03, GeneralCategory::Ll),
+    (0x1e04, 0x1e04, GeneralCategory::Lu),
+    (0x1e05, 0x1e05, GeneralCategory::Ll),
+    (0x1e06, 0x1e06, GeneralCategory::Lu),
+    (0x1e07, 0x1e07, GeneralCategory::Ll),
+    (0x1e08, 0x1e08, GeneralCategory::Lu),
+    (0x1e09, 0x1e09, GeneralCategory::Ll),
+    (0x1e0a, 0x1e0a, GeneralCategory::Lu),
+    (0x1e0b, 0x1e0b, GeneralCategory::Ll),
+    (0x1e0c, 0x1e0c, GeneralCategory::Lu),
+    (0x1e0d, 0x1e0d, GeneralCategory::Ll),
+    (0x1e0e, 0x1e0e, GeneralCategory::Lu),
+    (0x1e0f, 0x1e0f, GeneralCategory::Ll),
+    (0x1e10, 0x1e10, GeneralCategory::Lu),
+    (0x1e11, 0x1e11, GeneralCategory::Ll),
+    (0x1e12, 0x1e12, GeneralCategory::Lu),
+    (0x1e13, 0x1e13, GeneralCategory::Ll),
+    (0x1e14, 0x1e14, GeneralCategory::Lu),
+    (0x1e15, 0x1e15, GeneralCategory::Ll),
+    (0x1e16, 0x1e16, GeneralCategory::Lu),
+    (0x1e17, 0x1e17, GeneralCategory::Ll),
+    (0x1e18, 0x1e18, GeneralCategory::Lu),
+    (0x1e19, 0x1e19, GeneralCategory::Ll),
+    (0x1e1a, 0x1e1a, GeneralCategory::Lu),
+    (0x1e1b, 0x1e1b, GeneralCategory::Ll),
+    (0x1e1c, 0x1e1c, GeneralCategory::Lu),
+    (0x1e1d, 0x1e1d, GeneralCategory::Ll),
+    (0x1e1e, 0x1e1e, GeneralCategory::Lu),
+    (0x1e1f, 0x1e1f, GeneralCategory::Ll),
+    (0x1e20, 0x1e20, GeneralCategory::Lu),
+    (0x1e21, 0x1e21, GeneralCategory::Ll),
+    (0x1e22, 0x1e22, GeneralCategory::Lu),
+    (0x1e23, 0x1e23, GeneralCategory::Ll),
+    (0x1e24, 0x1e24, GeneralCategory::Lu),
+    (0x1e25, 0x1e25, GeneralCategory::Ll),
+    (0x1e26, 0x1e26, GeneralCategory::Lu),
+    (0x1e27, 0x1e27, GeneralCategory::Ll),
+    (0x1e28, 0x1e28, GeneralCategory::Lu),
+    (0x1e29, 0x1e29, GeneralCategory::Ll),
+    (0x1e2a, 0x1e2a, GeneralCategory::Lu),
+    (0x1e2b, 0x1e2b, GeneralCategory::Ll),
+    (0x1e2c, 0x1e2c, GeneralCategory::Lu),
+    (0x1e2d, 0x1e2d, GeneralCategory::Ll),
+    (0x1e2e, 0x1e2e, GeneralCategory::Lu),
+    (0x1e2f, 0x1e2f, GeneralCategory::Ll),
+    (0x1e30, 0x1e30, GeneralCategory::Lu),
+    (0x1e31, 0x1e31, GeneralCategory::Ll),
+    (0x1e32, 0x1e32, GeneralCategory::Lu),
+    (0x1e33, 0x1e33, GeneralCategory::Ll),
+    (0x1e34, 0x1e34, GeneralCategory::Lu),
+    (0x1e35, 0x1e35, GeneralCategory::Ll),
+    (0x1e36, 0x1e36, GeneralCategory::Lu),
+    (0x1e37, 0x1e37, GeneralCategory::Ll),
+    (0x1e38, 0x1e38, GeneralCategory::Lu),
+    (0x1e39, 0x1e39, GeneralCategory::Ll),
+    (0x1e3a, 0x1e3a, GeneralCategory::Lu),
+    (0x1e3b, 0x1e3b, GeneralCategory::Ll),
+    (0x1e3c, 0x1e3c, GeneralCategory::Lu),
+    (0x1e3d, 0x1e3d, GeneralCategory::Ll),
+    (0x1e3e, 0x1e3e, GeneralCategory::Lu),
+    (0x1e3f, 0x1e3f, GeneralCategory::Ll),
+    (0x1e40, 0x1e40, GeneralCategory::Lu),
+    (0x1e41, 0x1e41, GeneralCategory::Ll),
+    (0x1e42, 0x1e42, GeneralCategory::Lu),
+    (0x1e43, 0x1e43, GeneralCategory::Ll),
+    (0x1e44, 0x1e44, GeneralCategory::Lu),
+    (0x1e45, 0x1e45, GeneralCategory::Ll),
+    (0x1e46, 0x1e46, GeneralCategory::Lu),
+    (0x1e47, 0x1e47, GeneralCategory::Ll),
+    (0x1e48, 0x1e48, GeneralCategory::Lu),
+    (0x1e49, 0x1e49, GeneralCategory::Ll),
+    (0x1e4a, 0x1e4a, GeneralCategory::Lu),
+    (0x1e4b, 0x1e4b, GeneralCategory::Ll),
+    (0x1e4c, 0x1e4c, GeneralCategory::Lu),
+    (0x1e4d, 0x1e4d, GeneralCategory::Ll),
+    (0x1e4e, 0x1e4e, GeneralCategory::Lu),
+    (0x1e4f, 0x1e4f, GeneralCategory::Ll),
+    (0x1e50, 0x1e50, GeneralCategory::Lu),
+    (0x1e51, 0x1e51, GeneralCategory::Ll),
+    (0x1e52, 0x1e52, GeneralCategory::Lu),
+    (0x1e53, 0x1e53, GeneralCategory::Ll),
+    (0x1e54, 0x1e54, GeneralCategory::Lu),
+    (0x1e55, 0x1e55, GeneralCategory::Ll),
+    (0x1e56, 0x1e56, GeneralCategory::Lu),
+    (0x1e57, 0x1e57, GeneralCategory::Ll),
+    (0x1e58, 0x1e58, GeneralCategory::Lu),
+    (0x1e59, 0x1e59, GeneralCategory::Ll),
+    (0x1e5a, 0x1e5a, GeneralCategory::Lu),
+    (0x1e5b, 0x1e5b, GeneralCategory::Ll),
+    (0x1e5c, 0x1e5c, GeneralCategory::Lu),
+    (0x1e5d, 0x1e5d, GeneralCategory::Ll),
+    (0x1e5e, 0x1e5e, GeneralCategory::Lu),
+    (0x1e5f, 0x1e5f, GeneralCategory::Ll),
+    (0x1e60, 0x1e60, GeneralCategory::Lu),
+    (0x1e61, 0x1e61, GeneralCategory::Ll),
+    (0x1e62, 0x1e62, GeneralCategory::Lu),
+    (0x1e63, 0x1e63, GeneralCategory::Ll),
+    (0x1e64, 0x1e64, GeneralCategory::Lu),
+    (0x1e65, 0x1e65, GeneralCategory::Ll),
+    (0x1e66, 0x1e66, GeneralCategory::Lu),
+    (0x1e67, 0x1e67, GeneralCategory::Ll),
+    (0x1e68, 0x1e68, GeneralCategory::Lu),
+    (0x1e69, 0x1e69, GeneralCategory::Ll),
+    (0x1e6a, 0x1e6a, GeneralCategory::Lu),
+    (0x1e6b, 0x1e6b, GeneralCategory::Ll),
+    (0x1e6c, 0x1e6c, GeneralCategory::Lu),
+    (0x1e6d, 0x1e6d, GeneralCategory::Ll),
+    (0x1e6e, 0x1e6e, GeneralCategory::Lu),
+    (0x1e6f, 0x1e6f, GeneralCategory::Ll),
+    (0x1e70, 0x1e70, GeneralCategory::Lu),
+    (0x1e71, 0x1e71, GeneralCategory::Ll),
+    (0x1e72, 0x1e72, GeneralCategory::Lu),
+    (0x1e73, 0x1e73, GeneralCategory::Ll),
+    (0x1e74, 0x1e74, GeneralCategory::Lu),
+    (0x1e75, 0x1e75, GeneralCategory::Ll),
+    (0x1e76, 0x1e76, GeneralCategory::Lu),
+    (0x1e77, 0x1e77, GeneralCategory::Ll),
+    (0x1e78, 0x1e78, GeneralCategory::Lu),
+    (0x1e79, 0x1e79, GeneralCategory::Ll),
+    (0x1e7a, 0x1e7a, GeneralCategory::Lu),
+    (0x1e7b, 0x1e7b, GeneralCategory::Ll),
+    (0x1e7c, 0x1e7c, GeneralCategory::Lu),
+    (0x1e7d, 0x1e7d, GeneralCategory::Ll),
+    (0x1e7e, 0x1e7e, GeneralCategory::Lu),
+    (0x1e7f, 0x1e7f, GeneralCategory::Ll),
+    (0x1e80, 0x1e80, GeneralCategory::Lu),
+    (0x1e81, 0x1e81, GeneralCategory::Ll),
+    (0x1e82, 0x1e82, GeneralCategory::Lu),
+    (0x1e83, 0x1e83, GeneralCategory::Ll),
+    (0x1e84, 0x1e84, GeneralCategory::Lu),
+    (0x1e85, 0x1e85, GeneralCategory::Ll),
+    (0x1e86, 0x1e86, GeneralCategory::Lu),
+    (0x1e87, 0x1e87, GeneralCategory::Ll),
+    (0x1e88, 0x1e88, GeneralCategory::Lu),
+    (0x1e89, 0x1e89, GeneralCategory::Ll),
+    (0x1e8a, 0x1e8a, GeneralCategory::Lu),
+    (0x1e8b, 0x1e8b, GeneralCategory::Ll),
+    (0x1e8c, 0x1e8c, GeneralCategory::Lu),
+    (0x1e8d, 0x1e8d, GeneralCategory::Ll),
+    (0x1e8e, 0x1e8e, GeneralCategory::Lu),
+    (0x1e8f, 0x1e8f, GeneralCategory::Ll),
+    (0x1e90, 0x1e90, GeneralCategory::Lu),
+    (0x1e91, 0x1e91, GeneralCategory::Ll),
+    (0x1e92, 0x1e92, GeneralCategory::Lu),
+    (0x1e93, 0x1e93, GeneralCategory::Ll),
+    (0x1e94, 0x1e94, GeneralCategory::Lu),
+    (0x1e95, 0x1e9d, GeneralCategory::Ll),
+    (0x1e9e, 0x1e9e, GeneralCategory::Lu),
+    (0x1e9f, 0x1e9f, GeneralCategory::Ll),
+    (0x1ea0, 0x1ea0, GeneralCategory::Lu),
+    (0x1ea1, 0x1ea1, GeneralCategory::Ll),
+    (0x1ea2, 0x1ea2, GeneralCategory::Lu),
+    (0x1ea3, 0x1ea3, GeneralCategory::Ll),
+    (0x1ea4, 0x1ea4, GeneralCategory::Lu),
+    (0x1ea5, 0x1ea5, GeneralCategory::Ll),
+    (0x1ea6, 0x1ea6, GeneralCategory::Lu),
+    (0x1ea7, 0x1ea7, GeneralCategory::Ll),
+    (0x1ea8, 0x1ea8, GeneralCategory::Lu),
+    (0x1ea9, 0x1ea9, GeneralCategory::Ll),
+    (0x1eaa, 0x1eaa, GeneralCategory::Lu),
+    (0x1eab, 0x1eab, GeneralCategory::Ll),
+    (0x1eac, 0x1eac, GeneralCategory::Lu),
+    (0x1ead, 0x1ead, GeneralCategory::Ll),
+    (0x1eae, 0x1eae, GeneralCategory::Lu),
+    (0x1eaf, 0x1eaf, GeneralCategory::Ll),
+    (0x1eb0, 0x1eb0, GeneralCategory::Lu),
+    (0x1eb1, 0x1eb1, GeneralCategory::Ll),
+    (0x1eb2, 0x1eb2, GeneralCategory::Lu),
+    (0x1eb3, 0x1eb3, GeneralCategory::Ll),
+    (0x1eb4, 0x1eb4, GeneralCategory::Lu),
+    (0x1eb5, 0x1eb5, GeneralCategory::Ll),
+    (0x1eb6, 0x1eb6, GeneralCategory::Lu),
+    (0x1eb7, 0x1eb7, GeneralCategory::Ll),
+    (0x1eb8, 0x1eb8, GeneralCategory::Lu),
+    (0x1eb9, 0x1eb9, GeneralCategory::Ll),
+    (0x1eba, 0x1eba, GeneralCategory::Lu),
+    (0x1ebb, 0x1ebb, GeneralCategory::Ll),
+    (0x1ebc, 0x1ebc, GeneralCategory::Lu),
+    (0x1ebd, 0x1ebd, GeneralCategory::Ll),
+    (0x1ebe, 0x1ebe, GeneralCategory::Lu),
+    (0x1ebf, 0x1ebf, GeneralCategory::Ll),
+    (0x1ec0, 0x1ec0, GeneralCategory::Lu),
+    (0x1ec1, 0x1ec1, GeneralCategory::Ll),
+    (0x1ec2, 0x1ec2, GeneralCategory::Lu),
+    (0x1ec3, 0x1ec3, GeneralCategory::Ll),
+    (0x1ec4, 0x1ec4, GeneralCategory::Lu),
+    (0x1ec5, 0x1ec5, GeneralCategory::Ll),
+    (0x1ec6, 0x1ec6, GeneralCategory::Lu),
+    (0x1ec7, 0x1ec7, GeneralCategory::Ll),
+    (0x1ec8, 0x1ec8, GeneralCategory::Lu),
+    (0x1ec9, 0x1ec9, GeneralCategory::Ll),
+    (0x1eca, 0x1eca, GeneralCategory::Lu),
+    (0x1ecb, 0x1ecb, GeneralCategory::Ll),
+    (0x1ecc, 0x1ecc, GeneralCategory::Lu),
+    (0x1ecd, 0x1ecd, GeneralCategory::Ll),
+    (0x1ece, 0x1ece, GeneralCategory::Lu),
+    (0x1ecf, 0x1ecf, GeneralCategory::Ll),
+    (0x1ed0, 0x1ed0, GeneralCategory::Lu),
+    (0x1ed1, 0x1ed1, GeneralCategory::Ll),
+    (0x1ed2, 0x1ed2, GeneralCategory::Lu),
+    (0x1ed3, 0x1ed3, GeneralCategory::Ll),
+    (0x1ed4, 0x1ed4, GeneralCategory::Lu),
+    (0x1ed5, 0x1ed5, GeneralCategory::Ll),
+    (0x1ed6, 0x1ed6, GeneralCategory::Lu),
+    (0x1ed7, 0x1ed7, GeneralCategory::Ll),
+    (0x1ed8, 0x1ed8, GeneralCategory::Lu),
+    (0x1ed9, 0x1ed9, GeneralCategory::Ll),
+    (0x1eda, 0x1eda, GeneralCategory::Lu),
+    (0x1edb, 0x1edb, GeneralCategory::Ll),
+    (0x1edc, 0x1edc, GeneralCategory::Lu),
+    (0x1edd, 0x1edd, GeneralCategory::Ll),
+    (0x1ede, 0x1ede, GeneralCategory::Lu),
+    (0x1edf, 0x1edf, GeneralCategory::Ll),
+    (0x1ee0, 0x1ee0, GeneralCategory::Lu),
+    (0x1ee1, 0x1ee1, GeneralCategory::Ll),
+    (0x1ee2, 0x1ee2, GeneralCategory::Lu),
+    (0x1ee3, 0x1ee3, GeneralCategory::Ll),
+    (0x1ee4, 0x1ee4, GeneralCategory::Lu),
+    (0x1ee5, 0x1ee5, GeneralCategory::Ll),
+    (0x1ee6, 0x1ee6, GeneralCategory::Lu),
+    (0x1ee7, 0x1ee7, GeneralCategory::Ll),
+    (0x1ee8, 0x1ee8, GeneralCategory::Lu),
+    (0x1ee9, 0x1ee9, GeneralCategory::Ll),
+    (0x1eea, 0x1eea, GeneralCategory::Lu),
+    (0x1eeb, 0x1eeb, GeneralCategory::Ll),
+    (0x1eec, 0x1eec, GeneralCategory::Lu),
+    (0x1eed, 0x1eed, GeneralCategory::Ll),
+    (0x1eee, 0x1eee, GeneralCategory::Lu),
+    (0x1eef, 0x1eef, GeneralCategory::Ll),
+    (0x1ef0, 0x1ef0, GeneralCategory::Lu),
+    (0x1ef1, 0x1ef1, GeneralCategory::Ll),
+    (0x1ef2, 0x1ef2, GeneralCategory::Lu),
+    (0x1ef3, 0x1ef3, GeneralCategory::Ll),
+    (0x1ef4, 0x1ef4, GeneralCategory::Lu),
+    (0x1ef5, 0x1ef5, GeneralCategory::Ll),
+    (0x1ef6, 0x1ef6, GeneralCategory::Lu),
+    (0x1ef7, 0x1ef7, GeneralCategory::Ll),
+    (0x1ef8, 0x1ef8, GeneralCategory::Lu),
+    (0x1ef9, 0x1ef9, GeneralCategory::Ll),
+    (0x1efa, 0x1efa, GeneralCategory::Lu),
+    (0x1efb, 0x1efb, GeneralCategory::Ll),
+    (0x1efc, 0x1efc, GeneralCategory::Lu),
+    (0x1efd, 0x1efd, GeneralCategory::Ll),
+    (0x1efe, 0x1efe, GeneralCategory::Lu),
+    (0x1eff, 0x1f07, GeneralCategory::Ll),
+    (0x1f08, 0x1f0f, GeneralCategory::Lu),
+    (0x1f10, 0x1f15, GeneralCategory::Ll),
+    (0x1f18, 0x1f1d, GeneralCategory::Lu),
+    (0x1f20, 0x1f27, GeneralCategory::Ll),
+    (0x1f28, 0x1f2f, GeneralCategory::Lu),
+    (0x1f30, 0x1f37, GeneralCategory::Ll),
+    (0x1f38, 0x1f3f, GeneralCategory::Lu),
+    (0x1f40, 0x1f45, GeneralCategory::Ll),
+    (0x1f48, 0x1f4d, GeneralCategory::Lu),
+    (0x1f50, 0x1f57, GeneralCategory::Ll),
+    (0x1f59, 0x1f59, GeneralCategory::Lu),
+    (0x1f5b, 0x1f5b, GeneralCategory::Lu),
+    (0x1f5d, 0x1f5d, GeneralCategory::Lu),
+    (0x1f5f, 0x1f5f, GeneralCategory::Lu),
+    (0x1f60, 0x1f67, GeneralCategory::Ll),
+    (0x1f68, 0x1f6f, GeneralCategory::Lu),
+    (0x1f70, 0x1f7d, GeneralCategory::Ll),
+    (0x1f80, 0x1f87, GeneralCategory::Ll),
+    (0x1f88, 0x1f8f, GeneralCategory::Lt),
+    (0x1f90, 0x1f97, GeneralCategory::Ll),
+    (0x1f98, 0x1f9f, GeneralCategory::Lt),
+    (0x1fa0, 0x1fa7, GeneralCategory::Ll),
+    (0x1fa8, 0x1faf, GeneralCategory::Lt),
+    (0x1fb0, 0x1fb4, GeneralCategory::Ll),
+    (0x1fb6, 0x1fb7, GeneralCategory::Ll),
+    (0x1fb8, 0x1fbb, GeneralCategory::Lu),
+    (0x1fbc, 0x1fbc, GeneralCategory::Lt),
+    (0x1fbd, 0x1fbd, GeneralCategory::Sk),
+    (0x1fbe, 0x1fbe, GeneralCategory::Ll),
+    (0x1fbf, 0x1fc1, GeneralCategory::Sk),
+    (0x1fc2, 0x1fc4, GeneralCategory::Ll),
+    (0x1fc6, 0x1fc7, GeneralCategory::Ll),
+    (0x1fc8, 0x1fcb, GeneralCategory::Lu),
+    (0x1fcc, 0x1fcc, GeneralCategory::Lt),
+    (0x1fcd, 0x1fcf, GeneralCategory::Sk),
+    (0x1fd0, 0x1fd3, GeneralCategory::Ll),
+    (0x1fd6, 0x1fd7, GeneralCategory::Ll),
+    (0x1fd8, 0x1fdb, GeneralCategory::Lu),
+    (0x1fdd, 0x1fdf, GeneralCategory::Sk),
+    (0x1fe0, 0x1fe7, GeneralCategory::Ll),
+    (0x1fe8, 0x1fec, GeneralCategory::Lu),
+    (0x1fed, 0x1fef, GeneralCategory::Sk),
+    (0x1ff2, 0x1ff4, GeneralCategory::Ll),
+    (0x1ff6, 0x1ff7, GeneralCategory::Ll),
+    (0x1ff8, 0x1ffb, GeneralCategory::Lu),
+    (0x1ffc, 0x1ffc, GeneralCategory::Lt),
+    (0x1ffd, 0x1ffe, GeneralCategory::Sk),
+    (0x2000, 0x200a, GeneralCategory::Zs),
+    (0x200b, 0x200f, GeneralCategory::Cf),
+    (0x2010, 0x2015, GeneralCategory::Pd),
+    (0x2016, 0x2017, GeneralCategory::Po),
+    (0x2018, 0x2018, GeneralCategory::Pi),
+    (0x2019, 0x2019, GeneralCategory::Pf),
+    (0x201a, 0x201a, GeneralCategory::Ps),
+    (0x201b, 0x201c, GeneralCategory::Pi),
+    (0x201d, 0x201d, GeneralCategory::Pf),
+    (0x201e, 0x201e, GeneralCategory::Ps),
+    (0x201f, 0x201f, GeneralCategory::Pi),
+    (0x2020, 0x2027, GeneralCategory::Po),
+    (0x2028, 0x2028, GeneralCategory::Zl),
+    (0x2029, 0x2029, GeneralCategory::Zp),
+    (0x202a, 0x202e, GeneralCategory::Cf),
+    (0x202f, 0x202f, GeneralCategory::Zs),
+    (0x2030, 0x2038, GeneralCategory::Po),
+    (0x2039, 0x2039, GeneralCategory::Pi),
+    (0x203a, 0x203a, GeneralCategory::Pf),
+    (0x203b, 0x203e, GeneralCategory::Po),
+    (0x203f, 0x2040, GeneralCategory::Pc),
+    (0x2041, 0x2043, GeneralCategory::Po),
+    (0x2044, 0x2044, GeneralCategory::Sm),
+    (0x2045, 0x2045, GeneralCategory::Ps),
+    (0x2046, 0x2046, GeneralCategory::Pe),
+    (0x2047, 0x2051, GeneralCategory::Po),
+    (0x2052, 0x2052, GeneralCategory::Sm),
+    (0x2053, 0x2053, GeneralCategory::Po),
+    (0x2054, 0x2054, GeneralCategory::Pc),
+    (0x2055, 0x205e, GeneralCategory::Po),
+    (0x205f, 0x205f, GeneralCategory::Zs),
+    (0x2060, 0x2064, GeneralCategory::Cf),
+    (0x2066, 0x206f, GeneralCategory::Cf),
+    (0x2070, 0x2070, GeneralCategory::No),
+    (0x2071, 0x2071, GeneralCategory::Lm),
+    (0x2074, 0x2079, GeneralCategory::No),
+    (0x207a, 0x207c, GeneralCategory::Sm),
+    (0x207d, 0x207d, GeneralCategory::Ps),
+    (0x207e, 0x207e, GeneralCategory::Pe),
+    (0x207f, 0x207f, GeneralCategory::Lm),
+    (0x2080, 0x2089, GeneralCategory::No),
+    (0x208a, 0x208c, GeneralCategory::Sm),
+    (0x208d, 0x208d, GeneralCategory::Ps),
+    (0x208e, 0x208e, GeneralCategory::Pe),
+    (0x2090, 0x209c, GeneralCategory::Lm),
+    (0x20a0, 0x20c1, GeneralCategory::Sc),
+    (0x20d0, 0x20dc, GeneralCategory::Mn),
+    (0x20dd, 0x20e0, GeneralCategory::Me),
+    (0x20e1, 0x20e1, GeneralCategory::Mn),
+    (0x20e2, 0x20e4, GeneralCategory::Me),
+    (0x20e5, 0x20f0, GeneralCategory::Mn),
+    (0x2100, 0x2101, GeneralCategory::So),
+    (0x2102, 0x2102, GeneralCategory::Lu),
+    (0x2103, 0x2106, GeneralCategory::So),
+    (0x2107, 0x2107, GeneralCategory::Lu),
+    (0x2108, 0x2109, GeneralCategory::So),
+    (0x210a, 0x210a, GeneralCategory::Ll),
+    (0x210b, 0x210d, GeneralCategory::Lu),
+    (0x210e, 0x210f, GeneralCategory::Ll),
+    (0x2110, 0x2112, GeneralCategory::Lu),
+    (0x2113, 0x2113, GeneralCategory::Ll),
+    (0x2114, 0x2114, GeneralCategory::So),
+    (0x2115, 0x2115, GeneralCategory::Lu),
+    (0x2116, 0x2117, GeneralCategory::So),
+    (0x2118, 0x2118, GeneralCategory::Sm),
+    (0x2119, 0x211d, GeneralCategory::Lu),
+    (0x211e, 0x2123, GeneralCategory::So),
+    (0x2124, 0x2124, GeneralCategory::Lu),
+    (0x2125, 0x2125, GeneralCategory::So),
+    (0x2126, 0x2126, GeneralCategory::Lu),
+    (0x2127, 0x2127, GeneralCategory::So),
+    (0x2128, 0x2128, GeneralCategory::Lu),
+    (0x2129, 0x2129, GeneralCategory::So),
+    (0x212a, 0x212d, GeneralCategory::Lu),
+    (0x212e, 0x212e, GeneralCategory::So),
+    (0x212f, 0x212f, GeneralCategory::Ll),
+    (0x2130, 0x2133, GeneralCategory::Lu),
+    (0x2134, 0x2134, GeneralCategory::Ll),
+    (0x2135, 0x2138, GeneralCategory::Lo),
+    (0x2139, 0x2139, GeneralCategory::Ll),
+    (0x213a, 0x213b, GeneralCategory::So),
+    (0x213c, 0x213d, GeneralCategory::Ll),
+    (0x213e, 0x213f, GeneralCategory::Lu),
+    (0x2140, 0x2144, GeneralCategory::Sm),
+    (0x2145, 0x2145, GeneralCategory::Lu),
+    (0x2146, 0x2149, GeneralCategory::Ll),
+    (0x214a, 0x214a, GeneralCategory::So),
+    (0x214b, 0x214b, GeneralCategory::Sm),
+    (0x214c, 0x214d, GeneralCategory::So),
+    (0x214e, 0x214e, GeneralCategory::Ll),
+    (0x214f, 0x214f, GeneralCategory::So),
+    (0x2150, 0x215f, GeneralCategory::No),
+    (0x2160, 0x2182, GeneralCategory::Nl),
+    (0x2183, 0x2183, GeneralCategory::Lu),
+    (0x2184, 0x2184, GeneralCategory::Ll),
+    (0x2185, 0x2188, GeneralCategory::Nl),
+    (0x2189, 0x2189, GeneralCategory::No),
+    (0x218a, 0x218b, GeneralCategory::So),
+    (0x2190, 0x2194, GeneralCategory::Sm),
+    (0x2195, 0x2199, GeneralCategory::So),
+    (0x219a, 0x219b, GeneralCategory::Sm),
+    (0x219c, 0x219f, GeneralCategory::So),
+    (0x21a0, 0x21a0, GeneralCategory::Sm),
+    (0x21a1, 0x21a2, GeneralCategory::So),
+    (0x21a3, 0x21a3, GeneralCategory::Sm),
+    (0x21a4, 0x21a5, GeneralCategory::So),
+    (0x21a6, 0x21a6, GeneralCategory::Sm),
+    (0x21a7, 0x21ad, GeneralCategory::So),
+    (0x21ae, 0x21ae, GeneralCategory::Sm),
+    (0x21af, 0x21cd, GeneralCategory::So),
+    (0x21ce, 0x21cf, GeneralCategory::Sm),
+    (0x21d0, 0x21d1, GeneralCategory::So),
+    (0x21d2, 0x21d2, GeneralCategory::Sm),
+    (0x21d3, 0x21d3, GeneralCategory::So),
+    (0x21d4, 0x21d4, GeneralCategory::Sm),
+    (0x21d5, 0x21f3, GeneralCategory::So),
+    (0x21f4, 0x22ff, GeneralCategory::Sm),
+    (0x2300, 0x2307, GeneralCategory::So),
+    (0x2308, 0x2308, GeneralCategory::Ps),
+    (0x2309, 0x2309, GeneralCategory::Pe),
+    (0x230a, 0x230a, GeneralCategory::Ps),
+    (0x230b, 0x230b, GeneralCategory::Pe),
+    (0x230c, 0x231f, GeneralCategory::So),
+    (0x2320, 0x2321, GeneralCategory::Sm),
+    (0x2322, 0x2328, GeneralCategory::So),
+    (0x2329, 0x2329, GeneralCategory::Ps),
+    (0x232a, 0x232a, GeneralCategory::Pe),
+    (0x232b, 0x237b, GeneralCategory::So),
+    (0x237c, 0x237c, GeneralCategory::Sm),
+    (0x237d, 0x239a, GeneralCategory::So),
+    (0x239b, 0x23b3, GeneralCategory::Sm),
+    (0x23b4, 0x23db, GeneralCategory::So),
+    (0x23dc, 0x23e1, GeneralCategory::Sm),
+    (0x23e2, 0x2429, GeneralCategory::So),
+    (0x2440, 0x244a, GeneralCategory::So),
+    (0x2460, 0x249b, GeneralCategory::No),
+    (0x249c, 0x24e9, GeneralCategory::So),
+    (0x24ea, 0x24ff, GeneralCategory::No),
+    (0x2500, 0x25b6, GeneralCategory::So),
+    (0x25b7, 0x25b7, GeneralCategory::Sm),
+    (0x25b8, 0x25c0, GeneralCategory::So),
+    (0x25c1, 0x25c1, GeneralCategory::Sm),
+    (0x25c2, 0x25f7, GeneralCategory::So),
+    (0x25f8, 0x25ff, GeneralCategory::Sm),
+    (0x2600, 0x266e, GeneralCategory::So),
+    (0x266f, 0x266f, GeneralCategory::Sm),
+    (0x2670, 0x2767, GeneralCategory::So),
+    (0x2768, 0x2768, GeneralCategory::Ps),
+    (0x2769, 0x2769, GeneralCategory::Pe),
+    (0x276a, 0x276a, GeneralCategory::Ps),
+    (0x276b, 0x276b, GeneralCategory::Pe),
+    (0x276c, 0x276c, GeneralCategory::Ps),
+    (0x276d, 0x276d, GeneralCategory::Pe),
+    (0x276e, 0x276e, GeneralCategory::Ps),
+    (0x276f, 0x276f, GeneralCategory::Pe),
+    (0x2770, 0x2770, GeneralCategory::Ps),
+    (0x2771, 0x2771, GeneralCategory::Pe),
+    (0x2772, 0x2772, GeneralCategory::Ps),
+    (0x2773, 0x2773, GeneralCategory::Pe),
+    (0x2774, 0x2774, GeneralCategory::Ps),
+    (0x2775, 0x2775, GeneralCategory::Pe),
+    (0x2776, 0x2793, GeneralCategory::No),
+    (0x2794, 0x27bf, GeneralCategory::So),
+    (0x27c0, 0x27c4, GeneralCategory::Sm),
+    (0x27c5, 0x27c5, GeneralCategory::Ps),
+    (0x27c6, 0x27c6, GeneralCategory::Pe),
+    (0x27c7, 0x27e5, GeneralCategory::Sm),
+    (0x27e6, 0x27e6, GeneralCategory::Ps),
+    (0x27e7, 0x27e7, GeneralCategory::Pe),
+    (0x27e8, 0x27e8, GeneralCategory::Ps),
+    (0x27e9, 0x27e9, GeneralCategory::Pe),
+    (0x27ea, 0x27ea, GeneralCategory::Ps),
+    (0x27eb, 0x27eb, GeneralCategory::Pe),
+    (0x27ec, 0x27ec, GeneralCategory::Ps),
+    (0x27ed, 0x27ed, GeneralCategory::Pe),
+    (0x27ee, 0x27ee, GeneralCategory::Ps),
+    (0x27ef, 0x27ef, GeneralCategory::Pe),
+    (0x27f0, 0x27ff, GeneralCategory::Sm),
+    (0x2800, 0x28ff, GeneralCategory::So),
+    (0x2900, 0x2982, GeneralCategory::Sm),
+    (0x2983, 0x2983, GeneralCategory::Ps),
+    (0x2984, 0x2984, GeneralCategory::Pe),
+    (0x2985, 0x2985, GeneralCategory::Ps),
+    (0x2986, 0x2986, GeneralCategory::Pe),
+    (0x2987, 0x2987, GeneralCategory::Ps),
+    (0x2988, 0x2988, GeneralCategory::Pe),
+    (0x2989, 0x2989, GeneralCategory::Ps),
+    (0x298a, 0x298a, GeneralCategory::Pe),
+    (0x298b, 0x298b, GeneralCategory::Ps),
+    (0x298c, 0x298c, GeneralCategory::Pe),
+    (0x298d, 0x298d, GeneralCategory::Ps),
+    (0x298e, 0x298e, GeneralCategory::Pe),
+    (0x298f, 0x298f, GeneralCategory::Ps),
+    (0x2990, 0x2990, GeneralCategory::Pe),
+    (0x2991, 0x2991, GeneralCategory::Ps),
+    (0x2992, 0x2992, GeneralCategory::Pe),
+    (0x2993, 0x2993, GeneralCategory::Ps),
+    (0x2994, 0x2994, GeneralCategory::Pe),
+    (0x2995, 0x2995, GeneralCategory::Ps),
+    (0x2996, 0x2996, GeneralCategory::Pe),
+    (0x2997, 0x2997, GeneralCategory::Ps),
+    (0x2998, 0x2998, GeneralCategory::Pe),
+    (0x2999, 0x29d7, GeneralCategory::Sm),
+    (0x29d8, 0x29d8, GeneralCategory::Ps),
+    (0x29d9, 0x29d9, GeneralCategory::Pe),
+    (0x29da, 0x29da, GeneralCategory::Ps),
+    (0x29db, 0x29db, GeneralCategory::Pe),
+    (0x29dc, 0x29fb, GeneralCategory::Sm),
+    (0x29fc, 0x29fc, GeneralCategory::Ps),
+    (0x29fd, 0x29fd, GeneralCategory::Pe),
+    (0x29fe, 0x2aff, GeneralCategory::Sm),
+    (0x2b00, 0x2b2f, GeneralCategory::So),
+    (0x2b30, 0x2b44, GeneralCategory::Sm),
+    (0x2b45, 0x2b46, GeneralCategory::So),
+    (0x2b47, 0x2b4c, GeneralCategory::Sm),
+    (0x2b4d, 0x2b73, GeneralCategory::So),
+    (0x2b76, 0x2bff, GeneralCategory::So),
+    (0x2c00, 0x2c2f, GeneralCategory::Lu),
+    (0x2c30, 0x2c5f, GeneralCategory::Ll),
+    (0x2c60, 0x2c60, GeneralCategory::Lu),
+    (0x2c61, 0x2c61, GeneralCategory::Ll),
+    (0x2c62, 0x2c64, GeneralCategory::Lu),
+    (0x2c65, 0x2c66, GeneralCategory::Ll),
+    (0x2c67, 0x2c67, GeneralCategory::Lu),
+    (0x2c68, 0x2c68, GeneralCategory::Ll),
+    (0x2c69, 0x2c69, GeneralCategory::Lu),
+    (0x2c6a, 0x2c6a, GeneralCategory::Ll),
+    (0x2c6b, 0x2c6b, GeneralCategory::Lu),
+    (0x2c6c, 0x2c6c, GeneralCategory::Ll),
+    (0x2c6d, 0x2c70, GeneralCategory::Lu),
+    (0x2c71, 0x2c71, GeneralCategory::Ll),
+    (0x2c72, 0x2c72, GeneralCategory::Lu),
+    (0x2c73, 0x2c74, GeneralCategory::Ll),
+    (0x2c75, 0x2c75, GeneralCategory::Lu),
+    (0x2c76, 0x2c7b, GeneralCategory::Ll),
+    (0x2c7c, 0x2c7d, GeneralCategory::Lm),
+    (0x2c7e, 0x2c80, GeneralCategory::Lu),
+    (0x2c81, 0x2c81, GeneralCategory::Ll),
+    (0x2c82, 0x2c82, GeneralCategory::Lu),
+    (0x2c83, 0x2c83, GeneralCategory::Ll),
+    (0x2c84, 0x2c84, GeneralCategory::Lu),
+    (0x2c85, 0x2c85, GeneralCategory::Ll),
+    (0x2c86, 0x2c86, GeneralCategory::Lu),
+    (0x2c87, 0x2c87, GeneralCategory::Ll),
+    (0x2c88, 0x2c88, GeneralCategory::Lu),
+    (0x2c89, 0x2c89, GeneralCategory::Ll),
+    (0x2c8a, 0x2c8a, GeneralCategory::Lu),
+    (0x2c8b, 0x2c8b, GeneralCategory::Ll),
+    (0x2c8c, 0x2c8c, GeneralCategory::Lu),
+    (0x2c8d, 0x2c8d, GeneralCategory::Ll),
+    (0x2c8e, 0x2c8e, GeneralCategory::Lu),
+    (0x2c8f, 0x2c8f, GeneralCategory::Ll),
+    (0x2c90, 0x2c90, GeneralCategory::Lu),
+    (0x2c91, 0x2c91, GeneralCategory::Ll),
+    (0x2c92, 0x2c92, GeneralCategory::Lu),
+    (0x2c93, 0x2c93, GeneralCategory::Ll),
+    (0x2c94, 0x2c94, GeneralCategory::Lu),
+    (0x2c95, 0x2c95, GeneralCategory::Ll),
+    (0x2c96, 0x2c96, GeneralCategory::Lu),
+    (0x2c97, 0x2c97, GeneralCategory::Ll),
+    (0x2c98, 0x2c98, GeneralCategory::Lu),
+    (0x2c99, 0x2c99, GeneralCategory::Ll),
+    (0x2c9a, 0x2c9a, GeneralCategory::Lu),
+    (0x2c9b, 0x2c9b, GeneralCategory::Ll),
+    (0x2c9c, 0x2c9c, GeneralCategory::Lu),
+    (0x2c9d, 0x2c9d, GeneralCategory::Ll),
+    (0x2c9e, 0x2c9e, GeneralCategory::Lu),
+    (0x2c9f, 0x2c9f, GeneralCategory::Ll),
+    (0x2ca0, 0x2ca0, GeneralCategory::Lu),
+    (0x2ca1, 0x2ca1, GeneralCategory::Ll),
+    (0x2ca2, 0x2ca2, GeneralCategory::Lu),
+    (0x2ca3, 0x2ca3, GeneralCategory::Ll),
+    (0x2ca4, 0x2ca4, GeneralCategory::Lu),
+    (0x2ca5, 0x2ca5, GeneralCategory::Ll),
+    (0x2ca6, 0x2ca6, GeneralCategory::Lu),
+    (0x2ca7, 0x2ca7, GeneralCategory::Ll),
+    (0x2ca8, 0x2ca8, GeneralCategory::Lu),
+    (0x2ca9, 0x2ca9, GeneralCategory::Ll),
+    (0x2caa, 0x2caa, GeneralCategory::Lu),
+    (0x2cab, 0x2cab, GeneralCategory::Ll),
+    (0x2cac, 0x2cac, GeneralCategory::Lu),
+    (0x2cad, 0x2cad, GeneralCategory::Ll),
+    (0x2cae, 0x2cae, GeneralCategory::Lu),
+    (0x2caf, 0x2caf, GeneralCategory::Ll),
+    (0x2cb0, 0x2cb0, GeneralCategory::Lu),
+    (0x2cb1, 0x2cb1, GeneralCategory::Ll),
+    (0x2cb2, 0x2cb2, GeneralCategory::Lu),
+    (0x2cb3, 0x2cb3, GeneralCategory::Ll),
+    (0x2cb4, 0x2cb4, GeneralCategory::Lu),
+    (0x2cb5, 0x2cb5, GeneralCategory::Ll),
+    (0x2cb6, 0x2cb6, GeneralCategory::Lu),
+    (0x2cb7, 0x2cb7, GeneralCategory::Ll),
+    (0x2cb8, 0x2cb8, GeneralCategory::Lu),
+    (0x2cb9, 0x2cb9, GeneralCategory::Ll),
+    (0x2cba, 0x2cba, GeneralCategory::Lu),
+    (0x2cbb, 0x2cbb, GeneralCategory::Ll),
+    (0x2cbc, 0x2cbc, GeneralCategory::Lu),
+    (0x2cbd, 0x2cbd, GeneralCategory::Ll),
+    (0x2cbe, 0x2cbe, GeneralCategory::Lu),
+    (0x2cbf, 0x2cbf, GeneralCategory::Ll),
+    (0x2cc0, 0x2cc0, GeneralCategory::Lu),
+    (0x2cc1, 0x2cc1, GeneralCategory::Ll),
+    (0x2cc2, 0x2cc2, GeneralCategory::Lu),
+    (0x2cc3, 0x2cc3, GeneralCategory::Ll),
+    (0x2cc4, 0x2cc4, GeneralCategory::Lu),
+    (0x2cc5, 0x2cc5, GeneralCategory::Ll),
+    (0x2cc6, 0x2cc6, GeneralCategory::Lu),
+    (0x2cc7, 0x2cc7, GeneralCategory::Ll),
+    (0x2cc8, 0x2cc8, GeneralCategory::Lu),
+    (0x2cc9, 0x2cc9, GeneralCategory::Ll),
+    (0x2cca, 0x2cca, GeneralCategory::Lu),
+    (0x2ccb, 0x2ccb, GeneralCategory::Ll),
+    (0x2ccc, 0x2ccc, GeneralCategory::Lu),
+    (0x2ccd, 0x2ccd, GeneralCategory::Ll),
+    (0x2cce, 0x2cce, GeneralCategory::Lu),
+    (0x2ccf, 0x2ccf, GeneralCategory::Ll),
+    (0x2cd0, 0x2cd0, GeneralCategory::Lu),
+    (0x2cd1, 0x2cd1, GeneralCategory::Ll),
+    (0x2cd2, 0x2cd2, GeneralCategory::Lu),
+    (0x2cd3, 0x2cd3, GeneralCategory::Ll),
+    (0x2cd4, 0x2cd4, GeneralCategory::Lu),
+    (0x2cd5, 0x2cd5, GeneralCategory::Ll),
+    (0x2cd6, 0x2cd6, GeneralCategory::Lu),
+    (0x2cd7, 0x2cd7, GeneralCategory::Ll),
+    (0x2cd8, 0x2cd8, GeneralCategory::Lu),
+    (0x2cd9, 0x2cd9, GeneralCategory::Ll),
+    (0x2cda, 0x2cda, GeneralCategory::Lu),
+    (0x2cdb, 0x2cdb, GeneralCategory::Ll),
+    (0x2cdc, 0x2cdc, GeneralCategory::Lu),
+    (0x2cdd, 0x2cdd, GeneralCategory::Ll),
+    (0x2cde, 0x2cde, GeneralCategory::Lu),
+    (0x2cdf, 0x2cdf, GeneralCategory::Ll),
+    (0x2ce0, 0x2ce0, GeneralCategory::Lu),
+    (0x2ce1, 0x2ce1, GeneralCategory::Ll),
+    (0x2ce2, 0x2ce2, GeneralCategory::Lu),
+    (0x2ce3, 0x2ce4, GeneralCategory::Ll),
+    (0x2ce5, 0x2cea, GeneralCategory::So),
+    (0x2ceb, 0x2ceb, GeneralCategory::Lu),
+    (0x2cec, 0x2cec, GeneralCategory::Ll),
+    (0x2ced, 0x2ced, GeneralCategory::Lu),
+    (0x2cee, 0x2cee, GeneralCategory::Ll),
+    (0x2cef, 0x2cf1, GeneralCategory::Mn),
+    (0x2cf2, 0x2cf2, GeneralCategory::Lu),
+    (0x2cf3, 0x2cf3, GeneralCategory::Ll),
+    (0x2cf9, 0x2cfc, GeneralCategory::Po),
+    (0x2cfd, 0x2cfd, GeneralCategory::No),
+    (0x2cfe, 0x2cff, GeneralCategory::Po),
+    (0x2d00, 0x2d25, GeneralCategory::Ll),
+    (0x2d27, 0x2d27, GeneralCategory::Ll),
+    (0x2d2d, 0x2d2d, GeneralCategory::Ll),
+    (0x2d30, 0x2d67, GeneralCategory::Lo),
+    (0x2d6f, 0x2d6f, GeneralCategory::Lm),
+    (0x2d70, 0x2d70, GeneralCategory::Po),
+    (0x2d7f, 0x2d7f, GeneralCategory::Mn),
+    (0x2d80, 0x2d96, GeneralCategory::Lo),
+    (0x2da0, 0x2da6, GeneralCategory::Lo),
+    (0x2da8, 0x2dae, GeneralCategory::Lo),
+    (0x2db0, 0x2db6, GeneralCategory::Lo),
+    (0x2db8, 0x2dbe, GeneralCategory::Lo),
+    (0x2dc0, 0x2dc6, GeneralCategory::Lo),
+    (0x2dc8, 0x2dce, GeneralCategory::Lo),
+    (0x2dd0, 0x2dd6, GeneralCategory::Lo),
+    (0x2dd8, 0x2dde, GeneralCategory::Lo),
+    (0x2de0, 0x2dff, GeneralCategory::Mn),
+    (0x2e00, 0x2e01, GeneralCategory::Po),
+    (0x2e02, 0x2e02, GeneralCategory::Pi),
+    (0x2e03, 0x2e03, GeneralCategory::Pf),
+    (0x2e04, 0x2e04, GeneralCategory::Pi),
+    (0x2e05, 0x2e05, GeneralCategory::Pf),
+    (0x2e06, 0x2e08, GeneralCategory::Po),
+    (0x2e09, 0x2e09, GeneralCategory::Pi),
+    (0x2e0a, 0x2e0a, GeneralCategory::Pf),
+    (0x2e0b, 0x2e0b, GeneralCategory::Po),
+    (0x2e0c, 0x2e0c, GeneralCategory::Pi),
+    (0x2e0d, 0x2e0d, GeneralCategory::Pf),
+    (0x2e0e, 0x2e16, GeneralCategory::Po),
+    (0x2e17, 0x2e17, GeneralCategory::Pd),
+    (0x2e18, 0x2e19, GeneralCategory::Po),
+    (0x2e1a, 0x2e1a, GeneralCategory::Pd),
+    (0x2e1b, 0x2e1b, GeneralCategory::Po),
+    (0x2e1c, 0x2e1c, GeneralCategory::Pi),
+    (0x2e1d, 0x2e1d, GeneralCategory::Pf),
+    (0x2e1e, 0x2e1f, GeneralCategory::Po),
+    (0x2e20, 0x2e20, GeneralCategory::Pi),
+    (0x2e21, 0x2e21, GeneralCategory::Pf),
+    (0x2e22, 0x2e22, GeneralCategory::Ps),
+    (0x2e23, 0x2e23, GeneralCategory::Pe),
+    (0x2e24, 0x2e24, GeneralCategory::Ps),
+    (0x2e25, 0x2e25, GeneralCategory::Pe),
+    (0x2e26, 0x2e26, GeneralCategory::Ps),
+    (0x2e27, 0x2e27, GeneralCategory::Pe),
+    (0x2e28, 0x2e28, GeneralCategory::Ps),
+    (0x2e29, 0x2e29, GeneralCategory::Pe),
+    (0x2e2a, 0x2e2e, GeneralCategory::Po),
+    (0x2e2f, 0x2e2f, GeneralCategory::Lm),
+    (0x2e30, 0x2e39, GeneralCategory::Po),
+    (0x2e3a, 0x2e3b, GeneralCategory::Pd),
+    (0x2e3c, 0x2e3f, GeneralCategory::Po),
+    (0x2e40, 0x2e40, GeneralCategory::Pd),
+    (0x2e41, 0x2e41, GeneralCategory::Po),
+    (0x2e42, 0x2e42, GeneralCategory::Ps),
+    (0x2e43, 0x2e4f, GeneralCategory::Po),
+    (0x2e50, 0x2e51, GeneralCategory::So),
+    (0x2e52, 0x2e54, GeneralCategory::Po),
+    (0x2e55, 0x2e55, GeneralCategory::Ps),
+    (0x2e56, 0x2e56, GeneralCategory::Pe),
+    (0x2e57, 0x2e57, GeneralCategory::Ps),
+    (0x2e58, 0x2e58, GeneralCategory::Pe),
+    (0x2e59, 0x2e59, GeneralCategory::Ps),
+    (0x2e5a, 0x2e5a, GeneralCategory::Pe),
+    (0x2e5b, 0x2e5b, GeneralCategory::Ps),
+    (0x2e5c, 0x2e5c, GeneralCategory::Pe),
+    (0x2e5d, 0x2e5d, GeneralCategory::Pd),
+    (0x2e80, 0x2e99, GeneralCategory::So),
+    (0x2e9b, 0x2ef3, GeneralCategory::So),
+    (0x2f00, 0x2fd5, GeneralCategory::So),
+    (0x2ff0, 0x2fff, GeneralCategory::So),
+    (0x3000, 0x3000, GeneralCategory::Zs),
+    (0x3001, 0x3003, GeneralCategory::Po),
+    (0x3004, 0x3004, GeneralCategory::So),
+    (0x3005, 0x3005, GeneralCategory::Lm),
+    (0x3006, 0x3006, GeneralCategory::Lo),
+    (0x3007, 0x3007, GeneralCategory::Nl),
+    (0x3008, 0x3008, GeneralCategory::Ps),
+    (0x3009, 0x3009, GeneralCategory::Pe),
+    (0x300a, 0x300a, GeneralCategory::Ps),
+    (0x300b, 0x300b, GeneralCategory::Pe),
+    (0x300c, 0x300c, GeneralCategory::Ps),
+    (0x300d, 0x300d, GeneralCategory::Pe),
+    (0x300e, 0x300e, GeneralCategory::Ps),
+    (0x300f, 0x300f, GeneralCategory::Pe),
+    (0x3010, 0x3010, GeneralCategory::Ps),
+    (0x3011, 0x3011, GeneralCategory::Pe),
+    (0x3012, 0x3013, GeneralCategory::So),
+    (0x3014, 0x3014, GeneralCategory::Ps),
+    (0x3015, 0x3015, GeneralCategory::Pe),
+    (0x3016, 0x3016, GeneralCategory::Ps),
+    (0x3017, 0x3017, GeneralCategory::Pe),
+    (0x3018, 0x3018, GeneralCategory::Ps),
+    (0x3019, 0x3019, GeneralCategory::Pe),
+    (0x301a, 0x301a, GeneralCategory::Ps),
+    (0x301b, 0x301b, GeneralCategory::Pe),
+    (0x301c, 0x301c, GeneralCategory::Pd),
+    (0x301d, 0x301d, GeneralCategory::Ps),
+    (0x301e, 0x301f, GeneralCategory::Pe),
+    (0x3020, 0x3020, GeneralCategory::So),
+    (0x3021, 0x3029, GeneralCategory::Nl),
+    (0x302a, 0x302d, GeneralCategory::Mn),
+    (0x302e, 0x302f, GeneralCategory::Mc),
+    (0x3030, 0x3030, GeneralCategory::Pd),
+    (0x3031, 0x3035, GeneralCategory::Lm),
+    (0x3036, 0x3037, GeneralCategory::So),
+    (0x3038, 0x303a, GeneralCategory::Nl),
+    (0x303b, 0x303b, GeneralCategory::Lm),
+    (0x303c, 0x303c, GeneralCategory::Lo),
+    (0x303d, 0x303d, GeneralCategory::Po),
+    (0x303e, 0x303f, GeneralCategory::So),
+    (0x3041, 0x3096, GeneralCategory::Lo),
+    (0x3099, 0x309a, GeneralCategory::Mn),
+    (0x309b, 0x309c, GeneralCategory::Sk),
+    (0x309d, 0x309e, GeneralCategory::Lm),
+    (0x309f, 0x309f, GeneralCategory::Lo),
+    (0x30a0, 0x30a0, GeneralCategory::Pd),
+    (0x30a1, 0x30fa, GeneralCategory::Lo),
+    (0x30fb, 0x30fb, GeneralCategory::Po),
+    (0x30fc, 0x30fe, GeneralCategory::Lm),
+    (0x30ff, 0x30ff, GeneralCategory::Lo),
+    (0x3105, 0x312f, GeneralCategory::Lo),
+    (0x3131, 0x318e, GeneralCategory::Lo),
+    (0x3190, 0x3191, GeneralCategory::So),
+    (0x3192, 0x3195, GeneralCategory::No),
+    (0x3196, 0x319f, GeneralCategory::So),
+    (0x31a0, 0x31bf, GeneralCategory::Lo),
+    (0x31c0, 0x31e5, GeneralCategory::So),
+    (0x31ef, 0x31ef, GeneralCategory::So),
+    (0x31f0, 0x31ff, GeneralCategory::Lo),
+    (0x3200, 0x321e, GeneralCategory::So),
+    (0x3220, 0x3229, GeneralCategory::No),
+    (0x322a, 0x3247, GeneralCategory::So),
+    (0x3248, 0x324f, GeneralCategory::No),
+    (0x3250, 0x3250, GeneralCategory::So),
+    (0x3251, 0x325f, GeneralCategory::No),
+    (0x3260, 0x327f, GeneralCategory::So),
+    (0x3280, 0x3289, GeneralCategory::No),
+    (0x328a, 0x32b0, GeneralCategory::So),
+    (0x32b1, 0x32bf, GeneralCategory::No),
+    (0x32c0, 0x33ff, GeneralCategory::So),
+    (0x3400, 0x4dbf, GeneralCategory::Lo),
+    (0x4dc0, 0x4dff, GeneralCategory::So),
+    (0x4e00, 0xa014, GeneralCategory::Lo),
+    (0xa015, 0xa015, GeneralCategory::Lm),
+    (0xa016, 0xa48c, GeneralCategory::Lo),
+    (0xa490, 0xa4c6, GeneralCategory::So),
+    (0xa4d0, 0xa4f7, GeneralCategory::Lo),
+    (0xa4f8, 0xa4fd, GeneralCategory::Lm),
+    (0xa4fe, 0xa4ff, GeneralCategory::Po),
+    (0xa500, 0xa60b, GeneralCategory::Lo),
+    (0xa60c, 0xa60c, GeneralCategory::Lm),
+    (0xa60d, 0xa60f, GeneralCategory::Po),
+    (0xa610, 0xa61f, GeneralCategory::Lo),
+    (0xa620, 0xa629, GeneralCategory::Nd),
+    (0xa62a, 0xa62b, GeneralCategory::Lo),
+    (0xa640, 0xa640, GeneralCategory::Lu),
+    (0xa641, 0xa641, GeneralCategory::Ll),
+    (0xa642, 0xa642, GeneralCategory::Lu),
+    (0xa643, 0xa643, GeneralCategory::Ll),
+    (0xa644, 0xa644, GeneralCategory::Lu),
+    (0xa645, 0xa645, GeneralCategory::Ll),
+    (0xa646, 0xa646, GeneralCategory::Lu),
+    (0xa647, 0xa647, GeneralCategory::Ll),
+    (0xa648, 0xa648, GeneralCategory::Lu),
+    (0xa649, 0xa649, GeneralCategory::Ll),
+    (0xa64a, 0xa64a, GeneralCategory::Lu),
+    (0xa64b, 0xa64b, GeneralCategory::Ll),
+    (0xa64c, 0xa64c, GeneralCategory::Lu),
+    (0xa64d, 0xa64d, GeneralCategory::Ll),
+    (0xa64e, 0xa64e, GeneralCategory::Lu),
+    (0xa64f, 0xa64f, GeneralCategory::Ll),
+    (0xa650, 0xa650, GeneralCategory::Lu),
+    (0xa651, 0xa651, GeneralCategory::Ll),
+    (0xa652, 0xa652, GeneralCategory::Lu),
+    (0xa653, 0xa653, GeneralCategory::Ll),
+    (0xa654, 0xa654, GeneralCategory::Lu),
+    (0xa655, 0xa655, GeneralCategory::Ll),
+    (0xa656, 0xa656, GeneralCategory::Lu),
+    (0xa657, 0xa657, GeneralCategory::Ll),
+    (0xa658, 0xa658, GeneralCategory::Lu),
+    (0xa659, 0xa659, GeneralCategory::Ll),
+    (0xa65a, 0xa65a, GeneralCategory::Lu),
+    (0xa65b, 0xa65b, GeneralCategory::Ll),
+    (0xa65c, 0xa65c, GeneralCategory::Lu),
+    (0xa65d, 0xa65d, GeneralCategory::Ll),
+    (0xa65e, 0xa65e, GeneralCategory::Lu),
+    (0xa65f, 0xa65f, GeneralCategory::Ll),
+    (0xa660, 0xa660, GeneralCategory::Lu),
+    (0xa661, 0xa661, GeneralCategory::Ll),
+    (0xa662, 0xa662, GeneralCategory::Lu),
+    (0xa663, 0xa663, GeneralCategory::Ll),
+    (0xa664, 0xa664, GeneralCategory::Lu),
+    (0xa665, 0xa665, GeneralCategory::Ll),
+    (0xa666, 0xa666, GeneralCategory::Lu),
+    (0xa667, 0xa667, GeneralCategory::Ll),
+    (0xa668, 0xa668, GeneralCategory::Lu),
+    (0xa669, 0xa669, GeneralCategory::Ll),
+    (0xa66a, 0xa66a, GeneralCategory::Lu),
+    (0xa66b, 0xa66b, GeneralCategory::Ll),
+    (0xa66c, 0xa66c, GeneralCategory::Lu),
+    (0xa66d, 0xa66d, GeneralCategory::Ll),
+    (0xa66e, 0xa66e, GeneralCategory::Lo),
+    (0xa66f, 0xa66f, GeneralCategory::Mn),
+    (0xa670, 0xa672, GeneralCategory::Me),
+    (0xa673, 0xa673, GeneralCategory::Po),
+    (0xa674, 0xa67d, GeneralCategory::Mn),
+    (0xa67e, 0xa67e, GeneralCategory::Po),
+    (0xa67f, 0xa67f, GeneralCategory::Lm),
+    (0xa680, 0xa680, GeneralCategory::Lu),
+    (0xa681, 0xa681, GeneralCategory::Ll),
+    (0xa682, 0xa682, GeneralCategory::Lu),
+    (0xa683, 0xa683, GeneralCategory::Ll),
+    (0xa684, 0xa684, GeneralCategory::Lu),
+    (0xa685, 0xa685, GeneralCategory::Ll),
+    (0xa686, 0xa686, GeneralCategory::Lu),
+    (0xa687, 0xa687, GeneralCategory::Ll),
+    (0xa688, 0xa688, GeneralCategory::Lu),
+    (0xa689, 0xa689, GeneralCategory::Ll),
+    (0xa68a, 0xa68a, GeneralCategory::Lu),
+    (0xa68b, 0xa68b, GeneralCategory::Ll),
+    (0xa68c, 0xa68c, GeneralCategory::Lu),
+    (0xa68d, 0xa68d, GeneralCategory::Ll),
+    (0xa68e, 0xa68e, GeneralCategory::Lu),
+    (0xa68f, 0xa68f, GeneralCategory::Ll),
+    (0xa690, 0xa690, GeneralCategory::Lu),
+    (0xa691, 0xa691, GeneralCategory::Ll),
+    (0xa692, 0xa692, GeneralCategory::Lu),
+    (0xa693, 0xa693, GeneralCategory::Ll),
+    (0xa694, 0xa694, GeneralCategory::Lu),
+    (0xa695, 0xa695, GeneralCategory::Ll),
+    (0xa696, 0xa696, GeneralCategory::Lu),
+    (0xa697, 0xa697, GeneralCategory::Ll),
+    (0xa698, 0xa698, GeneralCategory::Lu),
+    (0xa699, 0xa699, GeneralCategory::Ll),
+    (0xa69a, 0xa69a, GeneralCategory::Lu),
+    (0xa69b, 0xa69b, GeneralCategory::Ll),
+    (0xa69c, 0xa69d, GeneralCategory::Lm),
+    (0xa69e, 0xa69f, GeneralCategory::Mn),
+    (0xa6a0, 0xa6e5, GeneralCategory::Lo),
+    (0xa6e6, 0xa6ef, GeneralCategory::Nl),
+    (0xa6f0, 0xa6f1, GeneralCategory::Mn),
+    (0xa6f2, 0xa6f7, GeneralCategory::Po),
+    (0xa700, 0xa716, GeneralCategory::Sk),
+    (0xa717, 0xa71f, GeneralCategory::Lm),
+    (0xa720, 0xa721, GeneralCategory::Sk),
+    (0xa722, 0xa722, GeneralCategory::Lu),
+    (0xa723, 0xa723, GeneralCategory::Ll),
+    (0xa724, 0xa724, GeneralCategory::Lu),
+    (0xa725, 0xa725, GeneralCategory::Ll),
+    (0xa726, 0xa726, GeneralCategory::Lu),
+    (0xa727, 0xa727, GeneralCategory::Ll),
+    (0xa728, 0xa728, GeneralCategory::Lu),
+    (0xa729, 0xa729, GeneralCategory::Ll),
+    (0xa72a, 0xa72a, GeneralCategory::Lu),
+    (0xa72b, 0xa72b, GeneralCategory::Ll),
+    (0xa72c, 0xa72c, GeneralCategory::Lu),
+    (0xa72d, 0xa72d, GeneralCategory::Ll),
+    (0xa72e, 0xa72e, GeneralCategory::Lu),
+    (0xa72f, 0xa731, GeneralCategory::Ll),
+    (0xa732, 0xa732, GeneralCategory::Lu),
+    (0xa733, 0xa733, GeneralCategory::Ll),
+    (0xa734, 0xa734, GeneralCategory::Lu),
+    (0xa735, 0xa735, GeneralCategory::Ll),
+    (0xa736, 0xa736, GeneralCategory::Lu),
+    (0xa737, 0xa737, GeneralCategory::Ll),
+    (0xa738, 0xa738, GeneralCategory::Lu),
+    (0xa739, 0xa739, GeneralCategory::Ll),
+    (0xa73a, 0xa73a, GeneralCategory::Lu),
+    (0xa73b, 0xa73b, GeneralCategory::Ll),
+    (0xa73c, 0xa73c, GeneralCategory::Lu),
+    (0xa73d, 0xa73d, GeneralCategory::Ll),
+    (0xa73e, 0xa73e, GeneralCategory::Lu),
+    (0xa73f, 0xa73f, GeneralCategory::Ll),
+    (0xa740, 0xa740, GeneralCategory::Lu),
+    (0xa741, 0xa741, GeneralCategory::Ll),
+    (0xa742, 0xa742, GeneralCategory::Lu),
+    (0xa743, 0xa743, GeneralCategory::Ll),
+    (0xa744, 0xa744, GeneralCategory::Lu),
+    (0xa745, 0xa745, GeneralCategory::Ll),
+    (0xa746, 0xa746, GeneralCategory::Lu),
+    (0xa747, 0xa747, GeneralCategory::Ll),
+    (0xa748, 0xa748, GeneralCategory::Lu),
+    (0xa749, 0xa749, GeneralCategory::Ll),
+    (0xa74a, 0xa74a, GeneralCategory::Lu),
+    (0xa74b, 0xa74b, GeneralCategory::Ll),
+    (0xa74c, 0xa74c, GeneralCategory::Lu),
+    (0xa74d, 0xa74d, GeneralCategory::Ll),
+    (0xa74e, 0xa74e, GeneralCategory::Lu),
+    (0xa74f, 0xa74f, GeneralCategory::Ll),
+    (0xa750, 0xa750, GeneralCategory::Lu),
+    (0xa751, 0xa751, GeneralCategory::Ll),
+    (0xa752, 0xa752, GeneralCategory::Lu),
+    (0xa753, 0xa753, GeneralCategory::Ll),
+    (0xa754, 0xa754, GeneralCategory::Lu),
+    (0xa755, 0xa755, GeneralCategory::Ll),
+    (0xa756, 0xa756, GeneralCategory::Lu),
+    (0xa757, 0xa757, GeneralCategory::Ll),
+    (0xa758, 0xa758, GeneralCategory::Lu),
+    (0xa759, 0xa759, GeneralCategory::Ll),
+    (0xa75a, 0xa75a, GeneralCategory::Lu),
+    (0xa75b, 0xa75b, GeneralCategory::Ll),
+    (0xa75c, 0xa75c, GeneralCategory::Lu),
+    (0xa75d, 0xa75d, GeneralCategory::Ll),
+    (0xa75e, 0xa75e, GeneralCategory::Lu),
+    (0xa75f, 0xa75f, GeneralCategory::Ll),
+    (0xa760, 0xa760, GeneralCategory::Lu),
+    (0xa761, 0xa761, GeneralCategory::Ll),
+    (0xa762, 0xa762, GeneralCategory::Lu),
+    (0xa763, 0xa763, GeneralCategory::Ll),
+    (0xa764, 0xa764, GeneralCategory::Lu),
+    (0xa765, 0xa765, GeneralCategory::Ll),
+    (0xa766, 0xa766, GeneralCategory::Lu),
+    (0xa767, 0xa767, GeneralCategory::Ll),
+    (0xa768, 0xa768, GeneralCategory::Lu),
+    (0xa769, 0xa769, GeneralCategory::Ll),
+    (0xa76a, 0xa76a, GeneralCategory::Lu),
+    (0xa76b, 0xa76b, GeneralCategory::Ll),
+    (0xa76c, 0xa76c, GeneralCategory::Lu),
+    (0xa76d, 0xa76d, GeneralCategory::Ll),
+    (0xa76e, 0xa76e, GeneralCategory::Lu),
+    (0xa76f, 0xa76f, GeneralCategory::Ll),
+    (0xa770, 0xa770, GeneralCategory::Lm),
+    (0xa771, 0xa778, GeneralCategory::Ll),
+    (0xa779, 0xa779, GeneralCategory::Lu),
+    (0xa77a, 0xa77a, GeneralCategory::Ll),
+    (0xa77b, 0xa77b, GeneralCategory::Lu),
+    (0xa77c, 0xa77c, GeneralCategory::Ll),
+    (0xa77d, 0xa77e, GeneralCategory::Lu),
+    (0xa77f, 0xa77f, GeneralCategory::Ll),
+    (0xa780, 0xa780, GeneralCategory::Lu),
+    (0xa781, 0xa781, GeneralCategory::Ll),
+    (0xa782, 0xa782, GeneralCategory::Lu),
+    (0xa783, 0xa783, GeneralCategory::Ll),
+    (0xa784, 0xa784, GeneralCategory::Lu),
+    (0xa785, 0xa785, GeneralCategory::Ll),
+    (0xa786, 0xa786, GeneralCategory::Lu),
+    (0xa787, 0xa787, GeneralCategory::Ll),
+    (0xa788, 0xa788, GeneralCategory::Lm),
+    (0xa789, 0xa78a, GeneralCategory::Sk),
+    (0xa78b, 0xa78b, GeneralCategory::Lu),
+    (0xa78c, 0xa78c, GeneralCategory::Ll),
+    (0xa78d, 0xa78d, GeneralCategory::Lu),
+    (0xa78e, 0xa78e, GeneralCategory::Ll),
+    (0xa78f, 0xa78f, GeneralCategory::Lo),
+    (0xa790, 0xa790, GeneralCategory::Lu),
+    (0xa791, 0xa791, GeneralCategory::Ll),
+    (0xa792, 0xa792, GeneralCategory::Lu),
+    (0xa793, 0xa795, GeneralCategory::Ll),
+    (0xa796, 0xa796, GeneralCategory::Lu),
+    (0xa797, 0xa797, GeneralCategory::Ll),
+    (0xa798, 0xa798, GeneralCategory::Lu),
+    (0xa799, 0xa799, GeneralCategory::Ll),
+    (0xa79a, 0xa79a, GeneralCategory::Lu),
+    (0xa79b, 0xa79b, GeneralCategory::Ll),
+    (0xa79c, 0xa79c, GeneralCategory::Lu),
+    (0xa79d, 0xa79d, GeneralCategory::Ll),
+    (0xa79e, 0xa79e, GeneralCategory::Lu),
+    (0xa79f, 0xa79f, GeneralCategory::Ll),
+    (0xa7a0, 0xa7a0, GeneralCategory::Lu),
+    (0xa7a1, 0xa7a1, GeneralCategory::Ll),
+    (0xa7a2, 0xa7a2, GeneralCategory::Lu),
+    (0xa7a3, 0xa7a3, GeneralCategory::Ll),
+    (0xa7a4, 0xa7a4, GeneralCategory::Lu),
+    (0xa7a5, 0xa7a5, GeneralCategory::Ll),
+    (0xa7a6, 0xa7a6, GeneralCategory::Lu),
+    (0xa7a7, 0xa7a7, GeneralCategory::Ll),
+    (0xa7a8, 0xa7a8, GeneralCategory::Lu),
+    (0xa7a9, 0xa7a9, GeneralCategory::Ll),
+    (0xa7aa, 0xa7ae, GeneralCategory::Lu),
+    (0xa7af, 0xa7af, GeneralCategory::Ll),
+    (0xa7b0, 0xa7b4, GeneralCategory::Lu),
+    (0xa7b5, 0xa7b5, GeneralCategory::Ll),
+    (0xa7b6, 0xa7b6, GeneralCategory::Lu),
+    (0xa7b7, 0xa7b7, GeneralCategory::Ll),
+    (0xa7b8, 0xa7b8, GeneralCategory::Lu),
+    (0xa7b9, 0xa7b9, GeneralCategory::Ll),
+    (0xa7ba, 0xa7ba, GeneralCategory::Lu),
+    (0xa7bb, 0xa7bb, GeneralCategory::Ll),
+    (0xa7bc, 0xa7bc, GeneralCategory::Lu),
+    (0xa7bd, 0xa7bd, GeneralCategory::Ll),
+    (0xa7be, 0xa7be, GeneralCategory::Lu),
+    (0xa7bf, 0xa7bf, GeneralCategory::Ll),
+    (0xa7c0, 0xa7c0, GeneralCategory::Lu),
+    (0xa7c1, 0xa7c1, GeneralCategory::Ll),
+    (0xa7c2, 0xa7c2, GeneralCategory::Lu),
+    (0xa7c3, 0xa7c3, GeneralCategory::Ll),
+    (0xa7c4, 0xa7c7, GeneralCategory::Lu),
+    (0xa7c8, 0xa7c8, GeneralCategory::Ll),
+    (0xa7c9, 0xa7c9, GeneralCategory::Lu),
+    (0xa7ca, 0xa7ca, GeneralCategory::Ll),
+    (0xa7cb, 0xa7cc, GeneralCategory::Lu),
+    (0xa7cd, 0xa7cd, GeneralCategory::Ll),
+    (0xa7ce, 0xa7ce, GeneralCategory::Lu),
+    (0xa7cf, 0xa7cf, GeneralCategory::Ll),
+    (0xa7d0, 0xa7d0, GeneralCategory::Lu),
+    (0xa7d1, 0xa7d1, GeneralCategory::Ll),
+    (0xa7d2, 0xa7d2, GeneralCategory::Lu),
+    (0xa7d3, 0xa7d3, GeneralCategory::Ll),
+    (0xa7d4, 0xa7d4, GeneralCategory::Lu),
+    (0xa7d5, 0xa7d5, GeneralCategory::Ll),
+    (0xa7d6, 0xa7d6, GeneralCategory::Lu),
+    (0xa7d7, 0xa7d7, GeneralCategory::Ll),
+    (0xa7d8, 0xa7d8, GeneralCategory::Lu),
+    (0xa7d9, 0xa7d9, GeneralCategory::Ll),
+    (0xa7da, 0xa7da, GeneralCategory::Lu),
+    (0xa7db, 0xa7db, GeneralCategory::Ll),
+    (0xa7dc, 0xa7dc, GeneralCategory::Lu),
+    (0xa7f1, 0xa7f4, GeneralCategory::Lm),
+    (0xa7f5, 0xa7f5, GeneralCategory::Lu),
+    (0xa7f6, 0xa7f6, GeneralCategory::Ll),
+    (0xa7f7, 0xa7f7, GeneralCategory::Lo),
+    (0xa7f8, 0xa7f9, GeneralCategory::Lm),
+    (0xa7fa, 0xa7fa, GeneralCategory::Ll),
+    (0xa7fb, 0xa801, GeneralCategory::Lo),
+    (0xa802, 0xa802, GeneralCategory::Mn),
+    (0xa803, 0xa805, GeneralCategory::Lo),
+    (0xa806, 0xa806, GeneralCategory::Mn),
+    (0xa807, 0xa80a, GeneralCategory::Lo),
+    (0xa80b, 0xa80b, GeneralCategory::Mn),
+    (0xa80c, 0xa822, GeneralCategory::Lo),
+    (0xa823, 0xa824, GeneralCategory::Mc),
+    (0xa825, 0xa826, GeneralCategory::Mn),
+    (0xa827, 0xa827, GeneralCategory::Mc),
+    (0xa828, 0xa82b, GeneralCategory::So),
+    (0xa82c, 0xa82c, GeneralCategory::Mn),
+    (0xa830, 0xa835, GeneralCategory::No),
+    (0xa836, 0xa837, GeneralCategory::So),
+    (0xa838, 0xa838, GeneralCategory::Sc),
+    (0xa839, 0xa839, GeneralCategory::So),
+    (0xa840, 0xa873, GeneralCategory::Lo),
+    (0xa874, 0xa877, GeneralCategory::Po),
+    (0xa880, 0xa881, GeneralCategory::Mc),
+    (0xa882, 0xa8b3, GeneralCategory::Lo),
+    (0xa8b4, 0xa8c3, GeneralCategory::Mc),
+    (0xa8c4, 0xa8c5, GeneralCategory::Mn),
+    (0xa8ce, 0xa8cf, GeneralCategory::Po),
+    (0xa8d0, 0xa8d9, GeneralCategory::Nd),
+    (0xa8e0, 0xa8f1, GeneralCategory::Mn),
+    (0xa8f2, 0xa8f7, GeneralCategory::Lo),
+    (0xa8f8, 0xa8fa, GeneralCategory::Po),
+    (0xa8fb, 0xa8fb, GeneralCategory::Lo),
+    (0xa8fc, 0xa8fc, GeneralCategory::Po),
+    (0xa8fd, 0xa8fe, GeneralCategory::Lo),
+    (0xa8ff, 0xa8ff, GeneralCategory::Mn),
+    (0xa900, 0xa909, GeneralCategory::Nd),
+    (0xa90a, 0xa925, GeneralCategory::Lo),
+    (0xa926, 0xa92d, GeneralCategory::Mn),
+    (0xa92e, 0xa92f, GeneralCategory::Po),
+    (0xa930, 0xa946, GeneralCategory::Lo),
+    (0xa947, 0xa951, GeneralCategory::Mn),
+    (0xa952, 0xa953, GeneralCategory::Mc),
+    (0xa95f, 0xa95f, GeneralCategory::Po),
+    (0xa960, 0xa97c, GeneralCategory::Lo),
+    (0xa980, 0xa982, GeneralCategory::Mn),
+    (0xa983, 0xa983, GeneralCategory::Mc),
+    (0xa984, 0xa9b2, GeneralCategory::Lo),
+    (0xa9b3, 0xa9b3, GeneralCategory::Mn),
+    (0xa9b4, 0xa9b5, GeneralCategory::Mc),
+    (0xa9b6, 0xa9b9, GeneralCategory::Mn),
+    (0xa9ba, 0xa9bb, GeneralCategory::Mc),
+    (0xa9bc, 0xa9bd, GeneralCategory::Mn),
+    (0xa9be, 0xa9c0, GeneralCategory::Mc),
+    (0xa9c1, 0xa9cd, GeneralCategory::Po),
+    (0xa9cf, 0xa9cf, GeneralCategory::Lm),
+    (0xa9d0, 0xa9d9, GeneralCategory::Nd),
+    (0xa9de, 0xa9df, GeneralCategory::Po),
+    (0xa9e0, 0xa9e4, GeneralCategory::Lo),
+    (0xa9e5, 0xa9e5, GeneralCategory::Mn),
+    (0xa9e6, 0xa9e6, GeneralCategory::Lm),
+    (0xa9e7, 0xa9ef, GeneralCategory::Lo),
+    (0xa9f0, 0xa9f9, GeneralCategory::Nd),
+    (0xa9fa, 0xa9fe, GeneralCategory::Lo),
+    (0xaa00, 0xaa28, GeneralCategory::Lo),
+    (0xaa29, 0xaa2e, GeneralCategory::Mn),
+    (0xaa2f, 0xaa30, GeneralCategory::Mc),
+    (0xaa31, 0xaa32, GeneralCategory::Mn),
+    (0xaa33, 0xaa34, GeneralCategory::Mc),
+    (0xaa35, 0xaa36, GeneralCategory::Mn),
+    (0xaa40, 0xaa42, GeneralCategory::Lo),
+    (0xaa43, 0xaa43, GeneralCategory::Mn),
+    (0xaa44, 0xaa4b, GeneralCategory::Lo),
+    (0xaa4c, 0xaa4c, GeneralCategory::Mn),
+    (0xaa4d, 0xaa4d, GeneralCategory::Mc),
+    (0xaa50, 0xaa59, GeneralCategory::Nd),
+    (0xaa5c, 0xaa5f, GeneralCategory::Po),
+    (0xaa60, 0xaa6f, GeneralCategory::Lo),
+    (0xaa70, 0xaa70, GeneralCategory::Lm),
+    (0xaa71, 0xaa76, GeneralCategory::Lo),
+    (0xaa77, 0xaa79, GeneralCategory::So),
+    (0xaa7a, 0xaa7a, GeneralCategory::Lo),
+    (0xaa7b, 0xaa7b, GeneralCategory::Mc),
+    (0xaa7c, 0xaa7c, GeneralCategory::Mn),
+    (0xaa7d, 0xaa7d, GeneralCategory::Mc),
+    (0xaa7e, 0xaaaf, GeneralCategory::Lo),
+    (0xaab0, 0xaab0, GeneralCategory::Mn),
+    (0xaab1, 0xaab1, GeneralCategory::Lo),
+    (0xaab2, 0xaab4, GeneralCategory::Mn),
+    (0xaab5, 0xaab6, GeneralCategory::Lo),
+    (0xaab7, 0xaab8, GeneralCategory::Mn),
+    (0xaab9, 0xaabd, GeneralCategory::Lo),
+    (0xaabe, 0xaabf, GeneralCategory::Mn),
+    (0xaac0, 0xaac0, GeneralCategory::Lo),
+    (0xaac1, 0xaac1, GeneralCategory::Mn),
+    (0xaac2, 0xaac2, GeneralCategory::Lo),
+    (0xaadb, 0xaadc, GeneralCategory::Lo),
+    (0xaadd, 0xaadd, GeneralCategory::Lm),
+    (0xaade, 0xaadf, GeneralCategory::Po),
+    (0xaae0, 0xaaea, GeneralCategory::Lo),
+    (0xaaeb, 0xaaeb, GeneralCategory::Mc),
+    (0xaaec, 0xaaed, GeneralCategory::Mn),
+    (0xaaee, 0xaaef, GeneralCategory::Mc),
+    (0xaaf0, 0xaaf1, GeneralCategory::Po),
+    (0xaaf2, 0xaaf2, GeneralCategory::Lo),
+    (0xaaf3, 0xaaf4, GeneralCategory::Lm),
+    (0xaaf5, 0xaaf5, GeneralCategory::Mc),
+    (0xaaf6, 0xaaf6, GeneralCategory::Mn),
+    (0xab01, 0xab06, GeneralCategory::Lo),
+    (0xab09, 0xab0e, GeneralCategory::Lo),
+    (0xab11, 0xab16, GeneralCategory::Lo),
+    (0xab20, 0xab26, GeneralCategory::Lo),
+    (0xab28, 0xab2e, GeneralCategory::Lo),
+    (0xab30, 0xab5a, GeneralCategory::Ll),
+    (0xab5b, 0xab5b, GeneralCategory::Sk),
+    (0xab5c, 0xab5f, GeneralCategory::Lm),
+    (0xab60, 0xab68, GeneralCategory::Ll),
+    (0xab69, 0xab69, GeneralCategory::Lm),
+    (0xab6a, 0xab6b, GeneralCategory::Sk),
+    (0xab70, 0xabbf, GeneralCategory::Ll),
+    (0xabc0, 0xabe2, GeneralCategory::Lo),
+    (0xabe3, 0xabe4, GeneralCategory::Mc),
+    (0xabe5, 0xabe5, GeneralCategory::Mn),
+    (0xabe6, 0xabe7, GeneralCategory::Mc),
+    (0xabe8, 0xabe8, GeneralCategory::Mn),
+    (0xabe9, 0xabea, GeneralCategory::Mc),
+    (0xabeb, 0xabeb, GeneralCategory::Po),
+    (0xabec, 0xabec, GeneralCategory::Mc),
+    (0xabed, 0xabed, GeneralCategory::Mn),
+    (0xabf0, 0xabf9, GeneralCategory::Nd),
+    (0xac00, 0xd7a3, GeneralCategory::Lo),
+    (0xd7b0, 0xd7c6, GeneralCategory::Lo),
+    (0xd7cb, 0xd7fb, GeneralCategory::Lo),
+    (0xd800, 0xdfff, GeneralCategory::Cs),
+    (0xe000, 0xf8ff, GeneralCategory::Co),
+    (0xf900, 0xfa6d, GeneralCategory::Lo),
+    (0xfa70, 0xfad9, GeneralCategory::Lo),
+    (0xfb00, 0xfb06, GeneralCategory::Ll),
+    (0xfb13, 0xfb17, GeneralCategory::Ll),
+    (0xfb1d, 0xfb1d, GeneralCategory::Lo),
+    (0xfb1e, 0xfb1e, GeneralCategory::Mn),
+    (0xfb1f, 0xfb28, GeneralCategory::Lo),
+    (0xfb29, 0xfb29, GeneralCategory::Sm),
+    (0xfb2a, 0xfb36, GeneralCategory::Lo),
+    (0xfb38, 0xfb3c, GeneralCategory::Lo),
+    (0xfb3e, 0xfb3e, GeneralCategory::Lo),
+    (0xfb40, 0xfb41, GeneralCategory::Lo),
+    (0xfb43, 0xfb44, GeneralCategory::Lo),
+    (0xfb46, 0xfbb1, GeneralCategory::Lo),
+    (0xfbb2, 0xfbc2, GeneralCategory::Sk),
+    (0xfbc3, 0xfbd2, GeneralCategory::So),
+    (0xfbd3, 0xfd3d, GeneralCategory::Lo),
+    (0xfd3e, 0xfd3e, GeneralCategory::Pe),
+    (0xfd3f, 0xfd3f, GeneralCategory::Ps),
+    (0xfd40, 0xfd4f, GeneralCategory::So),
+    (0xfd50, 0xfd8f, GeneralCategory::Lo),
+    (0xfd90, 0xfd91, GeneralCategory::So),
+    (0xfd92, 0xfdc7, GeneralCategory::Lo),
+    (0xfdc8, 0xfdcf, GeneralCategory::So),
+    (0xfdf0, 0xfdfb, GeneralCategory::Lo),
+    (0xfdfc, 0xfdfc, GeneralCategory::Sc),
+    (0xfdfd, 0xfdff, GeneralCategory::So),
+    (0xfe00, 0xfe0f, GeneralCategory::Mn),
+    (0xfe10, 0xfe16, GeneralCategory::Po),
+    (0xfe17, 0xfe17, GeneralCategory::Ps),
+    (0xfe18, 0xfe18, GeneralCategory::Pe),
+    (0xfe19, 0xfe19, GeneralCategory::Po),
+    (0xfe20, 0xfe2f, GeneralCategory::Mn),
+    (0xfe30, 0xfe30, GeneralCategory::Po),
+    (0xfe31, 0xfe32, GeneralCategory::Pd),
+    (0xfe33, 0xfe34, GeneralCategory::Pc),
+    (0xfe35, 0xfe35, GeneralCategory::Ps),
+    (0xfe36, 0xfe36, GeneralCategory::Pe),
+    (0xfe37, 0xfe37, GeneralCategory::Ps),
+    (0xfe38, 0xfe38, GeneralCategory::Pe),
+    (0xfe39, 0xfe39, GeneralCategory::Ps),
+    (0xfe3a, 0xfe3a, GeneralCategory::Pe),
+    (0xfe3b, 0xfe3b, GeneralCategory::Ps),
+    (0xfe3c, 0xfe3c, GeneralCategory::Pe),
+    (0xfe3d, 0xfe3d, GeneralCategory::Ps),
+    (0xfe3e, 0xfe3e, GeneralCategory::Pe),
+    (0xfe3f, 0xfe3f, GeneralCategory::Ps),
+    (0xfe40, 0xfe40, GeneralCategory::Pe),
+    (0xfe41, 0xfe41, GeneralCategory::Ps),
+    (0xfe42, 0xfe42, GeneralCategory::Pe),
+    (0xfe43, 0xfe43, GeneralCategory::Ps),
+    (0xfe44, 0xfe44, GeneralCategory::Pe),
+    (0xfe45, 0xfe46, GeneralCategory::Po),
+    (0xfe47, 0xfe47, GeneralCategory::Ps),
+    (0xfe48, 0xfe48, GeneralCategory::Pe),
+    (0xfe49, 0xfe4c, GeneralCategory::Po),
+    (0xfe4d, 0xfe4f, GeneralCategory::Pc),
+    (0xfe50, 0xfe52, GeneralCategory::Po),
+    (0xfe54, 0xfe57, GeneralCategory::Po),
+    (0xfe58, 0xfe58, GeneralCategory::Pd),
+    (0xfe59, 0xfe59, GeneralCategory::Ps),
+    (0xfe5a, 0xfe5a, GeneralCategory::Pe),
+    (0xfe5b, 0xfe5b, GeneralCategory::Ps),
+    (0xfe5c, 0xfe5c, GeneralCategory::Pe),
+    (0xfe5d, 0xfe5d, GeneralCategory::Ps),
+    (0xfe5e, 0xfe5e, GeneralCategory::Pe),
+    (0xfe5f, 0xfe61, GeneralCategory::Po),
+    (0xfe62, 0xfe62, GeneralCategory::Sm),
+    (0xfe63, 0xfe63, GeneralCategory::Pd),
+    (0xfe64, 0xfe66, GeneralCategory::Sm),
+    (0xfe68, 0xfe68, GeneralCategory::Po),
+    (0xfe69, 0xfe69, GeneralCategory::Sc),
+    (0xfe6a, 0xfe6b, GeneralCategory::Po),
+    (0xfe70, 0xfe74, GeneralCategory::Lo),
+    (0xfe76, 0xfefc, GeneralCategory::Lo),
+    (0xfeff, 0xfeff, GeneralCategory::Cf),
+    (0xff01, 0xff03, GeneralCategory::Po),
+    (0xff04, 0xff04, GeneralCategory::Sc),
+    (0xff05, 0xff07, GeneralCategory::Po),
+    (0xff08, 0xff08, GeneralCategory::Ps),
+    (0xff09, 0xff09, GeneralCategory::Pe),
+    (0xff0a, 0xff0a, GeneralCategory::Po),
+    (0xff0b, 0xff0b, GeneralCategory::Sm),
+    (0xff0c, 0xff0c, GeneralCategory::Po),
+    (0xff0d, 0xff0d, GeneralCategory::Pd),
+    (0xff0e, 0xff0f, GeneralCategory::Po),
+    (0xff10, 0xff19, GeneralCategory::Nd),
+    (0xff1a, 0xff1b, GeneralCategory::Po),
+    (0xff1c, 0xff1e, GeneralCategory::Sm),
+    (0xff1f, 0xff20, GeneralCategory::Po),
+    (0xff21, 0xff3a, GeneralCategory::Lu),
+    (0xff3b, 0xff3b, GeneralCategory::Ps),
+    (0xff3c, 0xff3c, GeneralCategory::Po),
+    (0xff3d, 0xff3d, GeneralCategory::Pe),
+    (0xff3e, 0xff3e, GeneralCategory::Sk),
+    (0xff3f, 0xff3f, GeneralCategory::Pc),
+    (0xff40, 0xff40, GeneralCategory::Sk),
+    (0xff41, 0xff5a, GeneralCategory::Ll),
+    (0xff5b, 0xff5b, GeneralCategory::Ps),
+    (0xff5c, 0xff5c, GeneralCategory::Sm),
+    (0xff5d, 0xff5d, GeneralCategory::Pe),
+    (0xff5e, 0xff5e, GeneralCategory::Sm),
+    (0xff5f, 0xff5f, GeneralCategory::Ps),
+    (0xff60, 0xff60, GeneralCategory::Pe),
+    (0xff61, 0xff61, GeneralCategory::Po),
+    (0xff62, 0xff62, GeneralCategory::Ps),
+    (0xff63, 0xff63, GeneralCategory::Pe),
+    (0xff64, 0xff65, GeneralCategory::Po),
+    (0xff66, 0xff6f, GeneralCategory::Lo),
+    (0xff70, 0xff70, GeneralCategory::Lm),
+    (0xff71, 0xff9d, GeneralCategory::Lo),
+    (0xff9e, 0xff9f, GeneralCategory::Lm),
+    (0xffa0, 0xffbe, GeneralCategory::Lo),
+    (0xffc2, 0xffc7, GeneralCategory::Lo),
+    (0xffca, 0xffcf, GeneralCategory::Lo),
+    (0xffd2, 0xffd7, GeneralCategory::Lo),
+    (0xffda, 0xffdc, GeneralCategory::Lo),
+    (0xffe0, 0xffe1, GeneralCategory::Sc),
+    (0xffe2, 0xffe2, GeneralCategory::Sm),
+    (0xffe3, 0xffe3, GeneralCategory::Sk),
+    (0xffe4, 0xffe4, GeneralCategory::So),
+    (0xffe5, 0xffe6, GeneralCategory::Sc),
+    (0xffe8, 0xffe8, GeneralCategory::So),
+    (0xffe9, 0xffec, GeneralCategory::Sm),
+    (0xffed, 0xffee, GeneralCategory::So),
+    (0xfff9, 0xfffb, GeneralCategory::Cf),
+    (0xfffc, 0xfffd, GeneralCategory::So),
+    (0x10000, 0x1000b, GeneralCategory::Lo),
+    (0x1000d, 0x10026, GeneralCategory::Lo),
+    (0x10028, 0x1003a, GeneralCategory::Lo),
+    (0x1003c, 0x1003d, GeneralCategory::Lo),
+    (0x1003f, 0x1004d, GeneralCategory::Lo),
+    (0x10050, 0x1005d, GeneralCategory::Lo),
+    (0x10080, 0x100fa, GeneralCategory::Lo),
+    (0x10100, 0x10102, GeneralCategory::Po),
+    (0x10107, 0x10133, GeneralCategory::No),
+    (0x10137, 0x1013f, GeneralCategory::So),
+    (0x10140, 0x10174, GeneralCategory::Nl),
+    (0x10175, 0x10178, GeneralCategory::No),
+    (0x10179, 0x10189, GeneralCategory::So),
+    (0x1018a, 0x1018b, GeneralCategory::No),
+    (0x1018c, 0x1018e, GeneralCategory::So),
+    (0x10190, 0x1019c, GeneralCategory::So),
+    (0x101a0, 0x101a0, GeneralCategory::So),
+    (0x101d0, 0x101fc, GeneralCategory::So),
+    (0x101fd, 0x101fd, GeneralCategory::Mn),
+    (0x10280, 0x1029c, GeneralCategory::Lo),
+    (0x102a0, 0x102d0, GeneralCategory::Lo),
+    (0x102e0, 0x102e0, GeneralCategory::Mn),
+    (0x102e1, 0x102fb, GeneralCategory::No),
+    (0x10300, 0x1031f, GeneralCategory::Lo),
+    (0x10320, 0x10323, GeneralCategory::No),
+    (0x1032d, 0x10340, GeneralCategory::Lo),
+    (0x10341, 0x10341, GeneralCategory::Nl),
+    (0x10342, 0x10349, GeneralCategory::Lo),
+    (0x1034a, 0x1034a, GeneralCategory::Nl),
+    (0x10350, 0x10375, GeneralCategory::Lo),
+    (0x10376, 0x1037a, GeneralCategory::Mn),
+    (0x10380, 0x1039d, GeneralCategory::Lo),
+    (0x1039f, 0x1039f, GeneralCategory::Po),
+    (0x103a0, 0x103c3, GeneralCategory::Lo),
+    (0x103c8, 0x103cf, GeneralCategory::Lo),
+    (0x103d0, 0x103d0, GeneralCategory::Po),
+    (0x103d1, 0x103d5, GeneralCategory::Nl),
+    (0x10400, 0x10427, GeneralCategory::Lu),
+    (0x10428, 0x1044f, GeneralCategory::Ll),
+    (0x10450, 0x1049d, GeneralCategory::Lo),
+    (0x104a0, 0x104a9, GeneralCategory::Nd),
+    (0x104b0, 0x104d3, GeneralCategory::Lu),
+    (0x104d8, 0x104fb, GeneralCategory::Ll),
+    (0x10500, 0x10527, GeneralCategory::Lo),
+    (0x10530, 0x10563, GeneralCategory::Lo),
+    (0x1056f, 0x1056f, GeneralCategory::Po),
+    (0x10570, 0x1057a, GeneralCategory::Lu),
+    (0x1057c, 0x1058a, GeneralCategory::Lu),
+    (0x1058c, 0x10592, GeneralCategory::Lu),
+    (0x10594, 0x10595, GeneralCategory::Lu),
+    (0x10597, 0x105a1, GeneralCategory::Ll),
+    (0x105a3, 0x105b1, GeneralCategory::Ll),
+    (0x105b3, 0x105b9, GeneralCategory::Ll),
+    (0x105bb, 0x105bc, GeneralCategory::Ll),
+    (0x105c0, 0x105f3, GeneralCategory::Lo),
+    (0x10600, 0x10736, GeneralCategory::Lo),
+    (0x10740, 0x10755, GeneralCategory::Lo),
+    (0x10760, 0x10767, GeneralCategory::Lo),
+    (0x10780, 0x10785, GeneralCategory::Lm),
+    (0x10787, 0x107b0, GeneralCategory::Lm),
+    (0x107b2, 0x107ba, GeneralCategory::Lm),
+    (0x10800, 0x10805, GeneralCategory::Lo),
+    (0x10808, 0x10808, GeneralCategory::Lo),
+    (0x1080a, 0x10835, GeneralCategory::Lo),
+    (0x10837, 0x10838, GeneralCategory::Lo),
+    (0x1083c, 0x1083c, GeneralCategory::Lo),
+    (0x1083f, 0x10855, GeneralCategory::Lo),
+    (0x10857, 0x10857, GeneralCategory::Po),
+    (0x10858, 0x1085f, GeneralCategory::No),
+    (0x10860, 0x10876, GeneralCategory::Lo),
+    (0x10877, 0x10878, GeneralCategory::So),
+    (0x10879, 0x1087f, GeneralCategory::No),
+    (0x10880, 0x1089e, GeneralCategory::Lo),
+    (0x108a7, 0x108af, GeneralCategory::No),
+    (0x108e0, 0x108f2, GeneralCategory::Lo),
+    (0x108f4, 0x108f5, GeneralCategory::Lo),
+    (0x108fb, 0x108ff, GeneralCategory::No),
+    (0x10900, 0x10915, GeneralCategory::Lo),
+    (0x10916, 0x1091b, GeneralCategory::No),
+    (0x1091f, 0x1091f, GeneralCategory::Po),
+    (0x10920, 0x10939, GeneralCategory::Lo),
+    (0x1093f, 0x1093f, GeneralCategory::Po),
+    (0x10940, 0x10959, GeneralCategory::Lo),
+    (0x10980, 0x109b7, GeneralCategory::Lo),
+    (0x109bc, 0x109bd, GeneralCategory::No),
+    (0x109be, 0x109bf, GeneralCategory::Lo),
+    (0x109c0, 0x109cf, GeneralCategory::No),
+    (0x109d2, 0x109ff, GeneralCategory::No),
+    (0x10a00, 0x10a00, GeneralCategory::Lo),
+    (0x10a01, 0x10a03, GeneralCategory::Mn),
+    (0x10a05, 0x10a06, GeneralCategory::Mn),
+    (0x10a0c, 0x10a0f, GeneralCategory::Mn),
+    (0x10a10, 0x10a13, GeneralCategory::Lo),
+    (0x10a15, 0x10a17, GeneralCategory::Lo),
+    (0x10a19, 0x10a35, GeneralCategory::Lo),
+    (0x10a38, 0x10a3a, GeneralCategory::Mn),
+    (0x10a3f, 0x10a3f, GeneralCategory::Mn),
+    (0x10a40, 0x10a48, GeneralCategory::No),
+    (0x10a50, 0x10a58, GeneralCategory::Po),
+    (0x10a60, 0x10a7c, GeneralCategory::Lo),
+    (0x10a7d, 0x10a7e, GeneralCategory::No),
+    (0x10a7f, 0x10a7f, GeneralCategory::Po),
+    (0x10a80, 0x10a9c, GeneralCategory::Lo),
+    (0x10a9d, 0x10a9f, GeneralCategory::No),
+    (0x10ac0, 0x10ac7, GeneralCategory::Lo),
+    (0x10ac8, 0x10ac8, GeneralCategory::So),
+    (0x10ac9, 0x10ae4, GeneralCategory::Lo),
+    (0x10ae5, 0x10ae6, GeneralCategory::Mn),
+    (0x10aeb, 0x10aef, GeneralCategory::No),
+    (0x10af0, 0x10af6, GeneralCategory::Po),
+    (0x10b00, 0x10b35, GeneralCategory::Lo),
+    (0x10b39, 0x10b3f, GeneralCategory::Po),
+    (0x10b40, 0x10b55, GeneralCategory::Lo),
+    (0x10b58, 0x10b5f, GeneralCategory::No),
+    (0x10b60, 0x10b72, GeneralCategory::Lo),
+    (0x10b78, 0x10b7f, GeneralCategory::No),
+    (0x10b80, 0x10b91, GeneralCategory::Lo),
+    (0x10b99, 0x10b9c, GeneralCategory::Po),
+    (0x10ba9, 0x10baf, GeneralCategory::No),
+    (0x10c00, 0x10c48, GeneralCategory::Lo),
+    (0x10c80, 0x10cb2, GeneralCategory::Lu),
+    (0x10cc0, 0x10cf2, GeneralCategory::Ll),
+    (0x10cfa, 0x10cff, GeneralCategory::No),
+    (0x10d00, 0x10d23, GeneralCategory::Lo),
+    (0x10d24, 0x10d27, GeneralCategory::Mn),
+    (0x10d30, 0x10d39, GeneralCategory::Nd),
+    (0x10d40, 0x10d49, GeneralCategory::Nd),
+    (0x10d4a, 0x10d4d, GeneralCategory::Lo),
+    (0x10d4e, 0x10d4e, GeneralCategory::Lm),
+    (0x10d4f, 0x10d4f, GeneralCategory::Lo),
+    (0x10d50, 0x10d65, GeneralCategory::Lu),
+    (0x10d69, 0x10d6d, GeneralCategory::Mn),
+    (0x10d6e, 0x10d6e, GeneralCategory::Pd),
+    (0x10d6f, 0x10d6f, GeneralCategory::Lm),
+    (0x10d70, 0x10d85, GeneralCategory::Ll),
+    (0x10d8e, 0x10d8f, GeneralCategory::Sm),
+    (0x10e60, 0x10e7e, GeneralCategory::No),
+    (0x10e80, 0x10ea9, GeneralCategory::Lo),
+    (0x10eab, 0x10eac, GeneralCategory::Mn),
+    (0x10ead, 0x10ead, GeneralCategory::Pd),
+    (0x10eb0, 0x10eb1, GeneralCategory::Lo),
+    (0x10ec2, 0x10ec4, GeneralCategory::Lo),
+    (0x10ec5, 0x10ec5, GeneralCategory::Lm),
+    (0x10ec6, 0x10ec7, GeneralCategory::Lo),
+    (0x10ed0, 0x10ed0, GeneralCategory::Po),
+    (0x10ed1, 0x10ed8, GeneralCategory::So),
+    (0x10efa, 0x10eff, GeneralCategory::Mn),
+    (0x10f00, 0x10f1c, GeneralCategory::Lo),
+    (0x10f1d, 0x10f26, GeneralCategory::No),
+    (0x10f27, 0x10f27, GeneralCategory::Lo),
+    (0x10f30, 0x10f45, GeneralCategory::Lo),
+    (0x10f46, 0x10f50, GeneralCategory::Mn),
+    (0x10f51, 0x10f54, GeneralCategory::No),
+    (0x10f55, 0x10f59, GeneralCategory::Po),
+    (0x10f70, 0x10f81, GeneralCategory::Lo),
+    (0x10f82, 0x10f85, GeneralCategory::Mn),
+    (0x10f86, 0x10f89, GeneralCategory::Po),
+    (0x10fb0, 0x10fc4, GeneralCategory::Lo),
+    (0x10fc5, 0x10fcb, GeneralCategory::No),
+    (0x10fe0, 0x10ff6, GeneralCategory::Lo),
+    (0x11000, 0x11000, GeneralCategory::Mc),
+    (0x11001, 0x11001, GeneralCategory::Mn),
+    (0x11002, 0x11002, GeneralCategory::Mc),
+    (0x11003, 0x11037, GeneralCategory::Lo),
+    (0x11038, 0x11046, GeneralCategory::Mn),
+    (0x11047, 0x1104d, GeneralCategory::Po),
+    (0x11052, 0x11065, GeneralCategory::No),
+    (0x11066, 0x1106f, GeneralCategory::Nd),
+    (0x11070, 0x11070, GeneralCategory::Mn),
+    (0x11071, 0x11072, GeneralCategory::Lo),
+    (0x11073, 0x11074, GeneralCategory::Mn),
+    (0x11075, 0x11075, GeneralCategory::Lo),
+    (0x1107f, 0x11081, GeneralCategory::Mn),
+    (0x11082, 0x11082, GeneralCategory::Mc),
+    (0x11083, 0x110af, GeneralCategory::Lo),
+    (0x110b0, 0x110b2, GeneralCategory::Mc),
+    (0x110b3, 0x110b6, GeneralCategory::Mn),
+    (0x110b7, 0x110b8, GeneralCategory::Mc),
+    (0x110b9, 0x110ba, GeneralCategory::Mn),
+    (0x110bb, 0x110bc, GeneralCategory::Po),
+    (0x110bd, 0x110bd, GeneralCategory::Cf),
+    (0x110be, 0x110c1, GeneralCategory::Po),
+    (0x110c2, 0x110c2, GeneralCategory::Mn),
+    (0x110cd, 0x110cd, GeneralCategory::Cf),
+    (0x110d0, 0x110e8, GeneralCategory::Lo),
+    (0x110f0, 0x110f9, GeneralCategory::Nd),
+    (0x11100, 0x11102, GeneralCategory::Mn),
+    (0x11103, 0x11126, GeneralCategory::Lo),
+    (0x11127, 0x1112b, GeneralCategory::Mn),
+    (0x1112c, 0x1112c, GeneralCategory::Mc),
+    (0x1112d, 0x11134, GeneralCategory::Mn),
+    (0x11136, 0x1113f, GeneralCategory::Nd),
+    (0x11140, 0x11143, GeneralCategory::Po),
+    (0x11144, 0x11144, GeneralCategory::Lo),
+    (0x11145, 0x11146, GeneralCategory::Mc),
+    (0x11147, 0x11147, GeneralCategory::Lo),
+    (0x11150, 0x11172, GeneralCategory::Lo),
+    (0x11173, 0x11173, GeneralCategory::Mn),
+    (0x11174, 0x11175, GeneralCategory::Po),
+    (0x11176, 0x11176, GeneralCategory::Lo),
+    (0x11180, 0x11181, GeneralCategory::Mn),
+    (0x11182, 0x11182, GeneralCategory::Mc),
+    (0x11183, 0x111b2, GeneralCategory::Lo),
+    (0x111b3, 0x111b5, GeneralCategory::Mc),
+    (0x111b6, 0x111be, GeneralCategory::Mn),
+    (0x111bf, 0x111c0, GeneralCategory::Mc),
+    (0x111c1, 0x111c4, GeneralCategory::Lo),
+    (0x111c5, 0x111c8, GeneralCategory::Po),
+    (0x111c9, 0x111cc, GeneralCategory::Mn),
+    (0x111cd, 0x111cd, GeneralCategory::Po),
+    (0x111ce, 0x111ce, GeneralCategory::Mc),
+    (0x111cf, 0x111cf, GeneralCategory::Mn),
+    (0x111d0, 0x111d9, GeneralCategory::Nd),
+    (0x111da, 0x111da, GeneralCategory::Lo),
+    (0x111db, 0x111db, GeneralCategory::Po),
+    (0x111dc, 0x111dc, GeneralCategory::Lo),
+    (0x111dd, 0x111df, GeneralCategory::Po),
+    (0x111e1, 0x111f4, GeneralCategory::No),
+    (0x11200, 0x11211, GeneralCategory::Lo),
+    (0x11213, 0x1122b, GeneralCategory::Lo),
+    (0x1122c, 0x1122e, GeneralCategory::Mc),
+    (0x1122f, 0x11231, GeneralCategory::Mn),
+    (0x11232, 0x11233, GeneralCategory::Mc),
+    (0x11234, 0x11234, GeneralCategory::Mn),
+    (0x11235, 0x11235, GeneralCategory::Mc),
+    (0x11236, 0x11237, GeneralCategory::Mn),
+    (0x11238, 0x1123d, GeneralCategory::Po),
+    (0x1123e, 0x1123e, GeneralCategory::Mn),
+    (0x1123f, 0x11240, GeneralCategory::Lo),
+    (0x11241, 0x11241, GeneralCategory::Mn),
+    (0x11280, 0x11286, GeneralCategory::Lo),
+    (0x11288, 0x11288, GeneralCategory::Lo),
+    (0x1128a, 0x1128d, GeneralCategory::Lo),
+    (0x1128f, 0x1129d, GeneralCategory::Lo),
+    (0x1129f, 0x112a8, GeneralCategory::Lo),
+    (0x112a9, 0x112a9, GeneralCategory::Po),
+    (0x112b0, 0x112de, GeneralCategory::Lo),
+    (0x112df, 0x112df, GeneralCategory::Mn),
+    (0x112e0, 0x112e2, GeneralCategory::Mc),
+    (0x112e3, 0x112ea, GeneralCategory::Mn),
+    (0x112f0, 0x112f9, GeneralCategory::Nd),
+    (0x11300, 0x11301, GeneralCategory::Mn),
+    (0x11302, 0x11303, GeneralCategory::Mc),
+    (0x11305, 0x1130c, GeneralCategory::Lo),
+    (0x1130f, 0x11310, GeneralCategory::Lo),
+    (0x11313, 0x11328, GeneralCategory::Lo),
+    (0x1132a, 0x11330, GeneralCategory::Lo),
+    (0x11332, 0x11333, GeneralCategory::Lo),
+    (0x11335, 0x11339, GeneralCategory::Lo),
+    (0x1133b, 0x1133c, GeneralCategory::Mn),
+    (0x1133d, 0x1133d, GeneralCategory::Lo),
+    (0x1133e, 0x1133f, GeneralCategory::Mc),
+    (0x11340, 0x11340, GeneralCategory::Mn),
+    (0x11341, 0x11344, GeneralCategory::Mc),
+    (0x11347, 0x11348, GeneralCategory::Mc),
+    (0x1134b, 0x1134d, GeneralCategory::Mc),
+    (0x11350, 0x11350, GeneralCategory::Lo),
+    (0x11357, 0x11357, GeneralCategory::Mc),
+    (0x1135d, 0x11361, GeneralCategory::Lo),
+    (0x11362, 0x11363, GeneralCategory::Mc),
+    (0x11366, 0x1136c, GeneralCategory::Mn),
+    (0x11370, 0x11374, GeneralCategory::Mn),
+    (0x11380, 0x11389, GeneralCategory::Lo),
+    (0x1138b, 0x1138b, GeneralCategory::Lo),
+    (0x1138e, 0x1138e, GeneralCategory::Lo),
+    (0x11390, 0x113b5, GeneralCategory::Lo),
+    (0x113b7, 0x113b7, GeneralCategory::Lo),
+    (0x113b8, 0x113ba, GeneralCategory::Mc),
+    (0x113bb, 0x113c0, GeneralCategory::Mn),
+    (0x113c2, 0x113c2, GeneralCategory::Mc),
+    (0x113c5, 0x113c5, GeneralCategory::Mc),
+    (0x113c7, 0x113ca, GeneralCategory::Mc),
+    (0x113cc, 0x113cd, GeneralCategory::Mc),
+    (0x113ce, 0x113ce, GeneralCategory::Mn),
+    (0x113cf, 0x113cf, GeneralCategory::Mc),
+    (0x113d0, 0x113d0, GeneralCategory::Mn),
+    (0x113d1, 0x113d1, GeneralCategory::Lo),
+    (0x113d2, 0x113d2, GeneralCategory::Mn),
+    (0x113d3, 0x113d3, GeneralCategory::Lo),
+    (0x113d4, 0x113d5, GeneralCategory::Po),
+    (0x113d7, 0x113d8, GeneralCategory::Po),
+    (0x113e1, 0x113e2, GeneralCategory::Mn),
+    (0x11400, 0x11434, GeneralCategory::Lo),
+    (0x11435, 0x11437, GeneralCategory::Mc),
+    (0x11438, 0x1143f, GeneralCategory::Mn),
+    (0x11440, 0x11441, GeneralCategory::Mc),
+    (0x11442, 0x11444, GeneralCategory::Mn),
+    (0x11445, 0x11445, GeneralCategory::Mc),
+    (0x11446, 0x11446, GeneralCategory::Mn),
+    (0x11447, 0x1144a, GeneralCategory::Lo),
+    (0x1144b, 0x1144f, GeneralCategory::Po),
+    (0x11450, 0x11459, GeneralCategory::Nd),
+    (0x1145a, 0x1145b, GeneralCategory::Po),
+    (0x1145d, 0x1145d, GeneralCategory::Po),
+    (0x1145e, 0x1145e, GeneralCategory::Mn),
+    (0x1145f, 0x11461, GeneralCategory::Lo),
+    (0x11480, 0x114af, GeneralCategory::Lo),
+    (0x114b0, 0x114b2, GeneralCategory::Mc),
+    (0x114b3, 0x114b8, GeneralCategory::Mn),
+    (0x114b9, 0x114b9, GeneralCategory::Mc),
+    (0x114ba, 0x114ba, GeneralCategory::Mn),
+    (0x114bb, 0x114be, GeneralCategory::Mc),
+    (0x114bf, 0x114c0, GeneralCategory::Mn),
+    (0x114c1, 0x114c1, GeneralCategory::Mc),
+    (0x114c2, 0x114c3, GeneralCategory::Mn),
+    (0x114c4, 0x114c5, GeneralCategory::Lo),
+    (0x114c6, 0x114c6, GeneralCategory::Po),
+    (0x114c7, 0x114c7, GeneralCategory::Lo),
+    (0x114d0, 0x114d9, GeneralCategory::Nd),
+    (0x11580, 0x115ae, GeneralCategory::Lo),
+    (0x115af, 0x115b1, GeneralCategory::Mc),
+    (0x115b2, 0x115b5, GeneralCategory::Mn),
+    (0x115b8, 0x115bb, GeneralCategory::Mc),
+    (0x115bc, 0x115bd, GeneralCategory::Mn),
+    (0x115be, 0x115be, GeneralCategory::Mc),
+    (0x115bf, 0x115c0, GeneralCategory::Mn),
+    (0x115c1, 0x115d7, GeneralCategory::Po),
+    (0x115d8, 0x115db, GeneralCategory::Lo),
+    (0x115dc, 0x115dd, GeneralCategory::Mn),
+    (0x11600, 0x1162f, GeneralCategory::Lo),
+    (0x11630, 0x11632, GeneralCategory::Mc),
+    (0x11633, 0x1163a, GeneralCategory::Mn),
+    (0x1163b, 0x1163c, GeneralCategory::Mc),
+    (0x1163d, 0x1163d, GeneralCategory::Mn),
+    (0x1163e, 0x1163e, GeneralCategory::Mc),
+    (0x1163f, 0x11640, GeneralCategory::Mn),
+    (0x11641, 0x11643, GeneralCategory::Po),
+    (0x11644, 0x11644, GeneralCategory::Lo),
+    (0x11650, 0x11659, GeneralCategory::Nd),
+    (0x11660, 0x1166c, GeneralCategory::Po),
+    (0x11680, 0x116aa, GeneralCategory::Lo),
+    (0x116ab, 0x116ab, GeneralCategory::Mn),
+    (0x116ac, 0x116ac, GeneralCategory::Mc),
+    (0x116ad, 0x116ad, GeneralCategory::Mn),
+    (0x116ae, 0x116af, GeneralCategory::Mc),
+    (0x116b0, 0x116b5, GeneralCategory::Mn),
+    (0x116b6, 0x116b6, GeneralCategory::Mc),
+    (0x116b7, 0x116b7, GeneralCategory::Mn),
+    (0x116b8, 0x116b8, GeneralCategory::Lo),
+    (0x116b9, 0x116b9, GeneralCategory::Po),
+    (0x116c0, 0x116c9, GeneralCategory::Nd),
+    (0x116d0, 0x116e3, GeneralCategory::Nd),
+    (0x11700, 0x1171a, GeneralCategory::Lo),
+    (0x1171d, 0x1171d, GeneralCategory::Mn),
+    (0x1171e, 0x1171e, GeneralCategory::Mc),
+    (0x1171f, 0x1171f, GeneralCategory::Mn),
+    (0x11720, 0x11721, GeneralCategory::Mc),
+    (0x11722, 0x11725, GeneralCategory::Mn),
+    (0x11726, 0x11726, GeneralCategory::Mc),
+    (0x11727, 0x1172b, GeneralCategory::Mn),
+    (0x11730, 0x11739, GeneralCategory::Nd),
+    (0x1173a, 0x1173b, GeneralCategory::No),
+    (0x1173c, 0x1173e, GeneralCategory::Po),
+    (0x1173f, 0x1173f, GeneralCategory::So),
+    (0x11740, 0x11746, GeneralCategory::Lo),
+    (0x11800, 0x1182b, GeneralCategory::Lo),
+    (0x1182c, 0x1182e, GeneralCategory::Mc),
+    (0x1182f, 0x11837, GeneralCategory::Mn),
+    (0x11838, 0x11838, GeneralCategory::Mc),
+    (0x11839, 0x1183a, GeneralCategory::Mn),
+    (0x1183b, 0x1183b, GeneralCategory::Po),
+    (0x118a0, 0x118bf, GeneralCategory::Lu),
+    (0x118c0, 0x118df, GeneralCategory::Ll),
+    (0x118e0, 0x118e9, GeneralCategory::Nd),
+    (0x118ea, 0x118f2, GeneralCategory::No),
+    (0x118ff, 0x11906, GeneralCategory::Lo),
+    (0x11909, 0x11909, GeneralCategory::Lo),
+    (0x1190c, 0x11913, GeneralCategory::Lo),
+    (0x11915, 0x11916, GeneralCategory::Lo),
+    (0x11918, 0x1192f, GeneralCategory::Lo),
+    (0x11930, 0x11935, GeneralCategory::Mc),
+    (0x11937, 0x11938, GeneralCategory::Mc),
+    (0x1193b, 0x1193c, GeneralCategory::Mn),
+    (0x1193d, 0x1193d, GeneralCategory::Mc),
+    (0x1193e, 0x1193e, GeneralCategory::Mn),
+    (0x1193f, 0x1193f, GeneralCategory::Lo),
+    (0x11940, 0x11940, GeneralCategory::Mc),
+    (0x11941, 0x11941, GeneralCategory::Lo),
+    (0x11942, 0x11942, GeneralCategory::Mc),
+    (0x11943, 0x11943, GeneralCategory::Mn),
+    (0x11944, 0x11946, GeneralCategory::Po),
+    (0x11950, 0x11959, GeneralCategory::Nd),
+    (0x119a0, 0x119a7, GeneralCategory::Lo),
+    (0x119aa, 0x119d0, GeneralCategory::Lo),
+    (0x119d1, 0x119d3, GeneralCategory::Mc),
+    (0x119d4, 0x119d7, GeneralCategory::Mn),
+    (0x119da, 0x119db, GeneralCategory::Mn),
+    (0x119dc, 0x119df, GeneralCategory::Mc),
+    (0x119e0, 0x119e0, GeneralCategory::Mn),
+    (0x119e1, 0x119e1, GeneralCategory::Lo),
+    (0x119e2, 0x119e2, GeneralCategory::Po),
+    (0x119e3, 0x119e3, GeneralCategory::Lo),
+    (0x119e4, 0x119e4, GeneralCategory::Mc),
+    (0x11a00, 0x11a00, GeneralCategory::Lo),
+    (0x11a01, 0x11a0a, GeneralCategory::Mn),
+    (0x11a0b, 0x11a32, GeneralCategory::Lo),
+    (0x11a33, 0x11a38, GeneralCategory::Mn),
+    (0x11a39, 0x11a39, GeneralCategory::Mc),
+    (0x11a3a, 0x11a3a, GeneralCategory::Lo),
+    (0x11a3b, 0x11a3e, GeneralCategory::Mn),
+    (0x11a3f, 0x11a46, GeneralCategory::Po),
+    (0x11a47, 0x11a47, GeneralCategory::Mn),
+    (0x11a50, 0x11a50, GeneralCategory::Lo),
+    (0x11a51, 0x11a56, GeneralCategory::Mn),
+    (0x11a57, 0x11a58, GeneralCategory::Mc),
+    (0x11a59, 0x11a5b, GeneralCategory::Mn),
+    (0x11a5c, 0x11a89, GeneralCategory::Lo),
+    (0x11a8a, 0x11a96, GeneralCategory::Mn),
+    (0x11a97, 0x11a97, GeneralCategory::Mc),
+    (0x11a98, 0x11a99, GeneralCategory::Mn),
+    (0x11a9a, 0x11a9c, GeneralCategory::Po),
+    (0x11a9d, 0x11a9d, GeneralCategory::Lo),
+    (0x11a9e, 0x11aa2, GeneralCategory::Po),
+    (0x11ab0, 0x11af8, GeneralCategory::Lo),
+    (0x11b00, 0x11b09, GeneralCategory::Po),
+    (0x11b60, 0x11b60, GeneralCategory::Mn),
+    (0x11b61, 0x11b61, GeneralCategory::Mc),
+    (0x11b62, 0x11b64, GeneralCategory::Mn),
+    (0x11b65, 0x11b65, GeneralCategory::Mc),
+    (0x11b66, 0x11b66, GeneralCategory::Mn),
+    (0x11b67, 0x11b67, GeneralCategory::Mc),
+    (0x11bc0, 0x11be0, GeneralCategory::Lo),
+    (0x11be1, 0x11be1, GeneralCategory::Po),
+    (0x11bf0, 0x11bf9, GeneralCategory::Nd),
+    (0x11c00, 0x11c08, GeneralCategory::Lo),
+    (0x11c0a, 0x11c2e, GeneralCategory::Lo),
+    (0x11c2f, 0x11c2f, GeneralCategory::Mc),
+    (0x11c30, 0x11c36, GeneralCategory::Mn),
+    (0x11c38, 0x11c3d, GeneralCategory::Mn),
+    (0x11c3e, 0x11c3e, GeneralCategory::Mc),
+    (0x11c3f, 0x11c3f, GeneralCategory::Mn),
+    (0x11c40, 0x11c40, GeneralCategory::Lo),
+    (0x11c41, 0x11c45, GeneralCategory::Po),
+    (0x11c50, 0x11c59, GeneralCategory::Nd),
+    (0x11c5a, 0x11c6c, GeneralCategory::No),
+    (0x11c70, 0x11c71, GeneralCategory::Po),
+    (0x11c72, 0x11c8f, GeneralCategory::Lo),
+    (0x11c92, 0x11ca7, GeneralCategory::Mn),
+    (0x11ca9, 0x11ca9, GeneralCategory::Mc),
+    (0x11caa, 0x11cb0, GeneralCategory::Mn),
+    (0x11cb1, 0x11cb1, GeneralCategory::Mc),
+    (0x11cb2, 0x11cb3, GeneralCategory::Mn),
+    (0x11cb4, 0x11cb4, GeneralCategory::Mc),
+    (0x11cb5, 0x11cb6, GeneralCategory::Mn),
+    (0x11d00, 0x11d06, GeneralCategory::Lo),
+    (0x11d08, 0x11d09, GeneralCategory::Lo),
+    (0x11d0b, 0x11d30, GeneralCategory::Lo),
+    (0x11d31, 0x11d36, GeneralCategory::Mn),
+    (0x11d3a, 0x11d3a, GeneralCategory::Mn),
+    (0x11d3c, 0x11d3d, GeneralCategory::Mn),
+    (0x11d3f, 0x11d45, GeneralCategory::Mn),
+    (0x11d46, 0x11d46, GeneralCategory::Lo),
+    (0x11d47, 0x11d47, GeneralCategory::Mn),
+    (0x11d50, 0x11d59, GeneralCategory::Nd),
+    (0x11d60, 0x11d65, GeneralCategory::Lo),
+    (0x11d67, 0x11d68, GeneralCategory::Lo),
+    (0x11d6a, 0x11d89, GeneralCategory::Lo),
+    (0x11d8a, 0x11d8e, GeneralCategory::Mc),
+    (0x11d90, 0x11d91, GeneralCategory::Mn),
+    (0x11d93, 0x11d94, GeneralCategory::Mc),
+    (0x11d95, 0x11d95, GeneralCategory::Mn),
+    (0x11d96, 0x11d96, GeneralCategory::Mc),
+    (0x11d97, 0x11d97, GeneralCategory::Mn),
+    (0x11d98, 0x11d98, GeneralCategory::Lo),
+    (0x11da0, 0x11da9, GeneralCategory::Nd),
+    (0x11db0, 0x11dd8, GeneralCategory::Lo),
+    (0x11dd9, 0x11dd9, GeneralCategory::Lm),
+    (0x11dda, 0x11ddb, GeneralCategory::Lo),
+    (0x11de0, 0x11de9, GeneralCategory::Nd),
+    (0x11ee0, 0x11ef2, GeneralCategory::Lo),
+    (0x11ef3, 0x11ef4, GeneralCategory::Mn),
+    (0x11ef5, 0x11ef6, GeneralCategory::Mc),
+    (0x11ef7, 0x11ef8, GeneralCategory::Po),
+    (0x11f00, 0x11f01, GeneralCategory::Mn),
+    (0x11f02, 0x11f02, GeneralCategory::Lo),
+    (0x11f03, 0x11f03, GeneralCategory::Mc),
+    (0x11f04, 0x11f10, GeneralCategory::Lo),
+    (0x11f12, 0x11f33, GeneralCategory::Lo),
+    (0x11f34, 0x11f35, GeneralCategory::Mc),
+    (0x11f36, 0x11f3a, GeneralCategory::Mn),
+    (0x11f3e, 0x11f3f, GeneralCategory::Mc),
+    (0x11f40, 0x11f40, GeneralCategory::Mn),
+    (0x11f41, 0x11f41, GeneralCategory::Mc),
+    (0x11f42, 0x11f42, GeneralCategory::Mn),
+    (0x11f43, 0x11f4f, GeneralCategory::Po),
+    (0x11f50, 0x11f59, GeneralCategory::Nd),
+    (0x11f5a, 0x11f5a, GeneralCategory::Mn),
+    (0x11fb0, 0x11fb0, GeneralCategory::Lo),
+    (0x11fc0, 0x11fd4, GeneralCategory::No),
+    (0x11fd5, 0x11fdc, GeneralCategory::So),
+    (0x11fdd, 0x11fe0, GeneralCategory::Sc),
+    (0x11fe1, 0x11ff1, GeneralCategory::So),
+    (0x11fff, 0x11fff, GeneralCategory::Po),
+    (0x12000, 0x12399, GeneralCategory::Lo),
+    (0x12400, 0x1246e, GeneralCategory::Nl),
+    (0x12470, 0x12474, GeneralCategory::Po),
+    (0x12480, 0x12543, GeneralCategory::Lo),
+    (0x12f90, 0x12ff0, GeneralCategory::Lo),
+    (0x12ff1, 0x12ff2, GeneralCategory::Po),
+    (0x13000, 0x1342f, GeneralCategory::Lo),
+    (0x13430, 0x1343f, GeneralCategory::Cf),
+    (0x13440, 0x13440, GeneralCategory::Mn),
+    (0x13441, 0x13446, GeneralCategory::Lo),
+    (0x13447, 0x13455, GeneralCategory::Mn),
+    (0x13460, 0x143fa, GeneralCategory::Lo),
+    (0x14400, 0x14646, GeneralCategory::Lo),
+    (0x16100, 0x1611d, GeneralCategory::Lo),
+    (0x1611e, 0x16129, GeneralCategory::Mn),
+    (0x1612a, 0x1612c, GeneralCategory::Mc),
+    (0x1612d, 0x1612f, GeneralCategory::Mn),
+    (0x16130, 0x16139, GeneralCategory::Nd),
+    (0x16800, 0x16a38, GeneralCategory::Lo),
+    (0x16a40, 0x16a5e, GeneralCategory::Lo),
+    (0x16a60, 0x16a69, GeneralCategory::Nd),
+    (0x16a6e, 0x16a6f, GeneralCategory::Po),
+    (0x16a70, 0x16abe, GeneralCategory::Lo),
+    (0x16ac0, 0x16ac9, GeneralCategory::Nd),
+    (0x16ad0, 0x16aed, GeneralCategory::Lo),
+    (0x16af0, 0x16af4, GeneralCategory::Mn),
+    (0x16af5, 0x16af5, GeneralCategory::Po),
+    (0x16b00, 0x16b2f, GeneralCategory::Lo),
+    (0x16b30, 0x16b36, GeneralCategory::Mn),
+    (0x16b37, 0x16b3b, GeneralCategory::Po),
+    (0x16b3c, 0x16b3f, GeneralCategory::So),
+    (0x16b40, 0x16b43, GeneralCategory::Lm),
+    (0x16b44, 0x16b44, GeneralCategory::Po),
+    (0x16b45, 0x16b45, GeneralCategory::So),
+    (0x16b50, 0x16b59, GeneralCategory::Nd),
+    (0x16b5b, 0x16b61, GeneralCategory::No),
+    (0x16b63, 0x16b77, GeneralCategory::Lo),
+    (0x16b7d, 0x16b8f, GeneralCategory::Lo),
+    (0x16d40, 0x16d42, GeneralCategory::Lm),
+    (0x16d43, 0x16d6a, GeneralCategory::Lo),
+    (0x16d6b, 0x16d6c, GeneralCategory::Lm),
+    (0x16d6d, 0x16d6f, GeneralCategory::Po),
+    (0x16d70, 0x16d79, GeneralCategory::Nd),
+    (0x16e40, 0x16e5f, GeneralCategory::Lu),
+    (0x16e60, 0x16e7f, GeneralCategory::Ll),
+    (0x16e80, 0x16e96, GeneralCategory::No),
+    (0x16e97, 0x16e9a, GeneralCategory::Po),
+    (0x16ea0, 0x16eb8, GeneralCategory::Lu),
+    (0x16ebb, 0x16ed3, GeneralCategory::Ll),
+    (0x16f00, 0x16f4a, GeneralCategory::Lo),
+    (0x16f4f, 0x16f4f, GeneralCategory::Mn),
+    (0x16f50, 0x16f50, GeneralCategory::Lo),
+    (0x16f51, 0x16f87, GeneralCategory::Mc),
+    (0x16f8f, 0x16f92, GeneralCategory::Mn),
+    (0x16f93, 0x16f9f, GeneralCategory::Lm),
+    (0x16fe0, 0x16fe1, GeneralCategory::Lm),
+    (0x16fe2, 0x16fe2, GeneralCategory::Po),
+    (0x16fe3, 0x16fe3, GeneralCategory::Lm),
+    (0x16fe4, 0x16fe4, GeneralCategory::Mn),
+    (0x16ff0, 0x16ff1, GeneralCategory::Mc),
+    (0x16ff2, 0x16ff3, GeneralCategory::Lm),
+    (0x16ff4, 0x16ff6, GeneralCategory::Nl),
+    (0x17000, 0x18cd5, GeneralCategory::Lo),
+    (0x18cff, 0x18d1e, GeneralCategory::Lo),
+    (0x18d80, 0x18df2, GeneralCategory::Lo),
+    (0x1aff0, 0x1aff3, GeneralCategory::Lm),
+    (0x1aff5, 0x1affb, GeneralCategory::Lm),
+    (0x1affd, 0x1affe, GeneralCategory::Lm),
+    (0x1b000, 0x1b122, GeneralCategory::Lo),
+    (0x1b132, 0x1b132, GeneralCategory::Lo),
+    (0x1b150, 0x1b152, GeneralCategory::Lo),
+    (0x1b155, 0x1b155, GeneralCategory::Lo),
+    (0x1b164, 0x1b167, GeneralCategory::Lo),
+    (0x1b170, 0x1b2fb, GeneralCategory::Lo),
+    (0x1bc00, 0x1bc6a, GeneralCategory::Lo),
+    (0x1bc70, 0x1bc7c, GeneralCategory::Lo),
+    (0x1bc80, 0x1bc88, GeneralCategory::Lo),
+    (0x1bc90, 0x1bc99, GeneralCategory::Lo),
+    (0x1bc9c, 0x1bc9c, GeneralCategory::So),
+    (0x1bc9d, 0x1bc9e, GeneralCategory::Mn),
+    (0x1bc9f, 0x1bc9f, GeneralCategory::Po),
+    (0x1bca0, 0x1bca3, GeneralCategory::Cf),
+    (0x1cc00, 0x1ccef, GeneralCategory::So),
+    (0x1ccf0, 0x1ccf9, GeneralCategory::Nd),
+    (0x1ccfa, 0x1ccfc, GeneralCategory::So),
+    (0x1cd00, 0x1ceb3, GeneralCategory::So),
+    (0x1ceba, 0x1ced0, GeneralCategory::So),
+    (0x1cee0, 0x1ceef, GeneralCategory::So),
+    (0x1cef0, 0x1cef0, GeneralCategory::Sm),
+    (0x1cf00, 0x1cf2d, GeneralCategory::Mn),
+    (0x1cf30, 0x1cf46, GeneralCategory::Mn),
+    (0x1cf50, 0x1cfc3, GeneralCategory::So),
+    (0x1d000, 0x1d0f5, GeneralCategory::So),
+    (0x1d100, 0x1d126, GeneralCategory::So),
+    (0x1d129, 0x1d164, GeneralCategory::So),
+    (0x1d165, 0x1d166, GeneralCategory::Mc),
+    (0x1d167, 0x1d169, GeneralCategory::Mn),
+    (0x1d16a, 0x1d16c, GeneralCategory::So),
+    (0x1d16d, 0x1d172, GeneralCategory::Mc),
+    (0x1d173, 0x1d17a, GeneralCategory::Cf),
+    (0x1d17b, 0x1d182, GeneralCategory::Mn),
+    (0x1d183, 0x1d184, GeneralCategory::So),
+    (0x1d185, 0x1d18b, GeneralCategory::Mn),
+    (0x1d18c, 0x1d1a9, GeneralCategory::So),
+    (0x1d1aa, 0x1d1ad, GeneralCategory::Mn),
+    (0x1d1ae, 0x1d1ea, GeneralCategory::So),
+    (0x1d200, 0x1d241, GeneralCategory::So),
+    (0x1d242, 0x1d244, GeneralCategory::Mn),
+    (0x1d245, 0x1d245, GeneralCategory::So),
+    (0x1d2c0, 0x1d2d3, GeneralCategory::No),
+    (0x1d2e0, 0x1d2f3, GeneralCategory::No),
+    (0x1d300, 0x1d356, GeneralCategory::So),
+    (0x1d360, 0x1d378, GeneralCategory::No),
+    (0x1d400, 0x1d419, GeneralCategory::Lu),
+    (0x1d41a, 0x1d433, GeneralCategory::Ll),
+    (0x1d434, 0x1d44d, GeneralCategory::Lu),
+    (0x1d44e, 0x1d454, GeneralCategory::Ll),
+    (0x1d456, 0x1d467, GeneralCategory::Ll),
+    (0x1d468, 0x1d481, GeneralCategory::Lu),
+    (0x1d482, 0x1d49b, GeneralCategory::Ll),
+    (0x1d49c, 0x1d49c, GeneralCategory::Lu),
+    (0x1d49e, 0x1d49f, GeneralCategory::Lu),
+    (0x1d4a2, 0x1d4a2, GeneralCategory::Lu),
+    (0x1d4a5, 0x1d4a6, GeneralCategory::Lu),
+    (0x1d4a9, 0x1d4ac, GeneralCategory::Lu),
+    (0x1d4ae, 0x1d4b5, GeneralCategory::Lu),
+    (0x1d4b6, 0x1d4b9, GeneralCategory::Ll),
+    (0x1d4bb, 0x1d4bb, GeneralCategory::Ll),
+    (0x1d4bd, 0x1d4c3, GeneralCategory::Ll),
+    (0x1d4c5, 0x1d4cf, GeneralCategory::Ll),
+    (0x1d4d0, 0x1d4e9, GeneralCategory::Lu),
+    (0x1d4ea, 0x1d503, GeneralCategory::Ll),
+    (0x1d504, 0x1d505, GeneralCategory::Lu),
+    (0x1d507, 0x1d50a, GeneralCategory::Lu),
+    (0x1d50d, 0x1d514, GeneralCategory::Lu),
+    (0x1d516, 0x1d51c, GeneralCategory::Lu),
+    (0x1d51e, 0x1d537, GeneralCategory::Ll),
+    (0x1d538, 0x1d539, GeneralCategory::Lu),
+    (0x1d53b, 0x1d53e, GeneralCategory::Lu),
+    (0x1d540, 0x1d544, GeneralCategory::Lu),
+    (0x1d546, 0x1d546, GeneralCategory::Lu),
+    (0x1d54a, 0x1d550, GeneralCategory::Lu),
+    (0x1d552, 0x1d56b, GeneralCategory::Ll),
+    (0x1d56c, 0x1d585, GeneralCategory::Lu),
+    (0x1d586, 0x1d59f, GeneralCategory::Ll),
+    (0x1d5a0, 0x1d5b9, GeneralCategory::Lu),
+    (0x1d5ba, 0x1d5d3, GeneralCategory::Ll),
+    (0x1d5d4, 0x1d5ed, GeneralCategory::Lu),
+    (0x1d5ee, 0x1d607, GeneralCategory::Ll),
+    (0x1d608, 0x1d621, GeneralCategory::Lu),
+    (0x1d622, 0x1d63b, GeneralCategory::Ll),
+    (0x1d63c, 0x1d655, GeneralCategory::Lu),
+    (0x1d656, 0x1d66f, GeneralCategory::Ll),
+    (0x1d670, 0x1d689, GeneralCategory::Lu),
+    (0x1d68a, 0x1d6a5, GeneralCategory::Ll),
+    (0x1d6a8, 0x1d6c0, GeneralCategory::Lu),
+    (0x1d6c1, 0x1d6c1, GeneralCategory::Sm),
+    (0x1d6c2, 0x1d6da, GeneralCategory::Ll),
+    (0x1d6db, 0x1d6db, GeneralCategory::Sm),
+    (0x1d6dc, 0x1d6e1, GeneralCategory::Ll),
+    (0x1d6e2, 0x1d6fa, GeneralCategory::Lu),
+    (0x1d6fb, 0x1d6fb, GeneralCategory::Sm),
+    (0x1d6fc, 0x1d714, GeneralCategory::Ll),
+    (0x1d715, 0x1d715, GeneralCategory::Sm),
+    (0x1d716, 0x1d71b, GeneralCategory::Ll),
+    (0x1d71c, 0x1d734, GeneralCategory::Lu),
+    (0x1d735, 0x1d735, GeneralCategory::Sm),
+    (0x1d736, 0x1d74e, GeneralCategory::Ll),
+    (0x1d74f, 0x1d74f, GeneralCategory::Sm),
+    (0x1d750, 0x1d755, GeneralCategory::Ll),
+    (0x1d756, 0x1d76e, GeneralCategory::Lu),
+    (0x1d76f, 0x1d76f, GeneralCategory::Sm),
+    (0x1d770, 0x1d788, GeneralCategory::Ll),
+    (0x1d789, 0x1d789, GeneralCategory::Sm),
+    (0x1d78a, 0x1d78f, GeneralCategory::Ll),
+    (0x1d790, 0x1d7a8, GeneralCategory::Lu),
+    (0x1d7a9, 0x1d7a9, GeneralCategory::Sm),
+    (0x1d7aa, 0x1d7c2, GeneralCategory::Ll),
+    (0x1d7c3, 0x1d7c3, GeneralCategory::Sm),
+    (0x1d7c4, 0x1d7c9, GeneralCategory::Ll),
+    (0x1d7ca, 0x1d7ca, GeneralCategory::Lu),
+    (0x1d7cb, 0x1d7cb, GeneralCategory::Ll),
+    (0x1d7ce, 0x1d7ff, GeneralCategory::Nd),
+    (0x1d800, 0x1d9ff, GeneralCategory::So),
+    (0x1da00, 0x1da36, GeneralCategory::Mn),
+    (0x1da37, 0x1da3a, GeneralCategory::So),
+    (0x1da3b, 0x1da6c, GeneralCategory::Mn),
+    (0x1da6d, 0x1da74, GeneralCategory::So),
+    (0x1da75, 0x1da75, GeneralCategory::Mn),
+    (0x1da76, 0x1da83, GeneralCategory::So),
+    (0x1da84, 0x1da84, GeneralCategory::Mn),
+    (0x1da85, 0x1da86, GeneralCategory::So),
+    (0x1da87, 0x1da8b, GeneralCategory::Po),
+    (0x1da9b, 0x1da9f, GeneralCategory::Mn),
+    (0x1daa1, 0x1daaf, GeneralCategory::Mn),
+    (0x1df00, 0x1df09, GeneralCategory::Ll),
+    (0x1df0a, 0x1df0a, GeneralCategory::Lo),
+    (0x1df0b, 0x1df1e, GeneralCategory::Ll),
+    (0x1df25, 0x1df2a, GeneralCategory::Ll),
+    (0x1e000, 0x1e006, GeneralCategory::Mn),
+    (0x1e008, 0x1e018, GeneralCategory::Mn),
+    (0x1e01b, 0x1e021, GeneralCategory::Mn),
+    (0x1e023, 0x1e024, GeneralCategory::Mn),
+    (0x1e026, 0x1e02a, GeneralCategory::Mn),
+    (0x1e030, 0x1e06d, GeneralCategory::Lm),
+    (0x1e08f, 0x1e08f, GeneralCategory::Mn),
+    (0x1e100, 0x1e12c, GeneralCategory::Lo),
+    (0x1e130, 0x1e136, GeneralCategory::Mn),
+    (0x1e137, 0x1e13d, GeneralCategory::Lm),
+    (0x1e140, 0x1e149, GeneralCategory::Nd),
+    (0x1e14e, 0x1e14e, GeneralCategory::Lo),
+    (0x1e14f, 0x1e14f, GeneralCategory::So),
+    (0x1e290, 0x1e2ad, GeneralCategory::Lo),
+    (0x1e2ae, 0x1e2ae, GeneralCategory::Mn),
+    (0x1e2c0, 0x1e2eb, GeneralCategory::Lo),
+    (0x1e2ec, 0x1e2ef, GeneralCategory::Mn),
+    (0x1e2f0, 0x1e2f9, GeneralCategory::Nd),
+    (0x1e2ff, 0x1e2ff, GeneralCategory::Sc),
+    (0x1e4d0, 0x1e4ea, GeneralCategory::Lo),
+    (0x1e4eb, 0x1e4eb, GeneralCategory::Lm),
+    (0x1e4ec, 0x1e4ef, GeneralCategory::Mn),
+    (0x1e4f0, 0x1e4f9, GeneralCategory::Nd),
+    (0x1e5d0, 0x1e5ed, GeneralCategory::Lo),
+    (0x1e5ee, 0x1e5ef, GeneralCategory::Mn),
+    (0x1e5f0, 0x1e5f0, GeneralCategory::Lo),
+    (0x1e5f1, 0x1e5fa, GeneralCategory::Nd),
+    (0x1e5ff, 0x1e5ff, GeneralCategory::Po),
+    (0x1e6c0, 0x1e6de, GeneralCategory::Lo),
+    (0x1e6e0, 0x1e6e2, GeneralCategory::Lo),
+    (0x1e6e3, 0x1e6e3, GeneralCategory::Mn),
+    (0x1e6e4, 0x1e6e5, GeneralCategory::Lo),
+    (0x1e6e6, 0x1e6e6, GeneralCategory::Mn),
+    (0x1e6e7, 0x1e6ed, GeneralCategory::Lo),
+    (0x1e6ee, 0x1e6ef, GeneralCategory::Mn),
+    (0x1e6f0, 0x1e6f4, GeneralCategory::Lo),
+    (0x1e6f5, 0x1e6f5, GeneralCategory::Mn),
+    (0x1e6fe, 0x1e6fe, GeneralCategory::Lo),
+    (0x1e6ff, 0x1e6ff, GeneralCategory::Lm),
+    (0x1e7e0, 0x1e7e6, GeneralCategory::Lo),
+    (0x1e7e8, 0x1e7eb, GeneralCategory::Lo),
+    (0x1e7ed, 0x1e7ee, GeneralCategory::Lo),
+    (0x1e7f0, 0x1e7fe, GeneralCategory::Lo),
+    (0x1e800, 0x1e8c4, GeneralCategory::Lo),
+    (0x1e8c7, 0x1e8cf, GeneralCategory::No),
+    (0x1e8d0, 0x1e8d6, GeneralCategory::Mn),
+    (0x1e900, 0x1e921, GeneralCategory::Lu),
+    (0x1e922, 0x1e943, GeneralCategory::Ll),
+    (0x1e944, 0x1e94a, GeneralCategory::Mn),
+    (0x1e94b, 0x1e94b, GeneralCategory::Lm),
+    (0x1e950, 0x1e959, GeneralCategory::Nd),
+    (0x1e95e, 0x1e95f, GeneralCategory::Po),
+    (0x1ec71, 0x1ecab, GeneralCategory::No),
+    (0x1ecac, 0x1ecac, GeneralCategory::So),
+    (0x1ecad, 0x1ecaf, GeneralCategory::No),
+    (0x1ecb0, 0x1ecb0, GeneralCategory::Sc),
+    (0x1ecb1, 0x1ecb4, GeneralCategory::No),
+    (0x1ed01, 0x1ed2d, GeneralCategory::No),
+    (0x1ed2e, 0x1ed2e, GeneralCategory::So),
+    (0x1ed2f, 0x1ed3d, GeneralCategory::No),
+    (0x1ee00, 0x1ee03, GeneralCategory::Lo),
+    (0x1ee05, 0x1ee1f, GeneralCategory::Lo),
+    (0x1ee21, 0x1ee22, GeneralCategory::Lo),
+    (0x1ee24, 0x1ee24, GeneralCategory::Lo),
+    (0x1ee27, 0x1ee27, GeneralCategory::Lo),
+    (0x1ee29, 0x1ee32, GeneralCategory::Lo),
+    (0x1ee34, 0x1ee37, GeneralCategory::Lo),
+    (0x1ee39, 0x1ee39, GeneralCategory::Lo),
+    (0x1ee3b, 0x1ee3b, GeneralCategory::Lo),
+    (0x1ee42, 0x1ee42, GeneralCategory::Lo),
+    (0x1ee47, 0x1ee47, GeneralCategory::Lo),
+    (0x1ee49, 0x1ee49, GeneralCategory::Lo),
+    (0x1ee4b, 0x1ee4b, GeneralCategory::Lo),
+    (0x1ee4d, 0x1ee4f, GeneralCategory::Lo),
+    (0x1ee51, 0x1ee52, GeneralCategory::Lo),
+    (0x1ee54, 0x1ee54, GeneralCategory::Lo),
+    (0x1ee57, 0x1ee57, GeneralCategory::Lo),
+    (0x1ee59, 0x1ee59, GeneralCategory::Lo),
+    (0x1ee5b, 0x1ee5b, GeneralCategory::Lo),
+    (0x1ee5d, 0x1ee5d, GeneralCategory::Lo),
+    (0x1ee5f, 0x1ee5f, GeneralCategory::Lo),
+    (0x1ee61, 0x1ee62, GeneralCategory::Lo),
+    (0x1ee64, 0x1ee64, GeneralCategory::Lo),
+    (0x1ee67, 0x1ee6a, GeneralCategory::Lo),
+    (0x1ee6c, 0x1ee72, GeneralCategory::Lo),
+    (0x1ee74, 0x1ee77, GeneralCategory::Lo),
+    (0x1ee79, 0x1ee7c, GeneralCategory::Lo),
+    (0x1ee7e, 0x1ee7e, GeneralCategory::Lo),
+    (0x1ee80, 0x1ee89, GeneralCategory::Lo),
+    (0x1ee8b, 0x1ee9b, GeneralCategory::Lo),
+    (0x1eea1, 0x1eea3, GeneralCategory::Lo),
+    (0x1eea5, 0x1eea9, GeneralCategory::Lo),
+    (0x1eeab, 0x1eebb, GeneralCategory::Lo),
+    (0x1eef0, 0x1eef1, GeneralCategory::Sm),
+    (0x1f000, 0x1f02b, GeneralCategory::So),
+    (0x1f030, 0x1f093, GeneralCategory::So),
+    (0x1f0a0, 0x1f0ae, GeneralCategory::So),
+    (0x1f0b1, 0x1f0bf, GeneralCategory::So),
+    (0x1f0c1, 0x1f0cf, GeneralCategory::So),
+    (0x1f0d1, 0x1f0f5, GeneralCategory::So),
+    (0x1f100, 0x1f10c, GeneralCategory::No),
+    (0x1f10d, 0x1f1ad, GeneralCategory::So),
+    (0x1f1e6, 0x1f202, GeneralCategory::So),
+    (0x1f210, 0x1f23b, GeneralCategory::So),
+    (0x1f240, 0x1f248, GeneralCategory::So),
+    (0x1f250, 0x1f251, GeneralCategory::So),
+    (0x1f260, 0x1f265, GeneralCategory::So),
+    (0x1f300, 0x1f3fa, GeneralCategory::So),
+    (0x1f3fb, 0x1f3ff, GeneralCategory::Sk),
+    (0x1f400, 0x1f6d8, GeneralCategory::So),
+    (0x1f6dc, 0x1f6ec, GeneralCategory::So),
+    (0x1f6f0, 0x1f6fc, GeneralCategory::So),
+    (0x1f700, 0x1f7d9, GeneralCategory::So),
+    (0x1f7e0, 0x1f7eb, GeneralCategory::So),
+    (0x1f7f0, 0x1f7f0, GeneralCategory::So),
+    (0x1f800, 0x1f80b, GeneralCategory::So),
+    (0x1f810, 0x1f847, GeneralCategory::So),
+    (0x1f850, 0x1f859, GeneralCategory::So),
+    (0x1f860, 0x1f887, GeneralCategory::So),
+    (0x1f890, 0x1f8ad, GeneralCategory::So),
+    (0x1f8b0, 0x1f8bb, GeneralCategory::So),
+    (0x1f8c0, 0x1f8c1, GeneralCategory::So),
+    (0x1f8d0, 0x1f8d8, GeneralCategory::Sm),
+    (0x1f900, 0x1fa57, GeneralCategory::So),
+    (0x1fa60, 0x1fa6d, GeneralCategory::So),
+    (0x1fa70, 0x1fa7c, GeneralCategory::So),
+    (0x1fa80, 0x1fa8a, GeneralCategory::So),
+    (0x1fa8e, 0x1fac6, GeneralCategory::So),
+    (0x1fac8, 0x1fac8, GeneralCategory::So),
+    (0x1facd, 0x1fadc, GeneralCategory::So),
+    (0x1fadf, 0x1faea, GeneralCategory::So),
+    (0x1faef, 0x1faf8, GeneralCategory::So),
+    (0x1fb00, 0x1fb92, GeneralCategory::So),
+    (0x1fb94, 0x1fbef, GeneralCategory::So),
+    (0x1fbf0, 0x1fbf9, GeneralCategory::Nd),
+    (0x1fbfa, 0x1fbfa, GeneralCategory::So),
+    (0x20000, 0x2a6df, GeneralCategory::Lo),
+    (0x2a700, 0x2b81d, GeneralCategory::Lo),
+    (0x2b820, 0x2cead, GeneralCategory::Lo),
+    (0x2ceb0, 0x2ebe0, GeneralCategory::Lo),
+    (0x2ebf0, 0x2ee5d, GeneralCategory::Lo),
+    (0x2f800, 0x2fa1d, GeneralCategory::Lo),
+    (0x30000, 0x3134a, GeneralCategory::Lo),
+    (0x31350, 0x33479, GeneralCategory::Lo),
+    (0xe0001, 0xe0001, GeneralCategory::Cf),
+    (0xe0020, 0xe007f, GeneralCategory::Cf),
+    (0xe0100, 0xe01ef, GeneralCategory::Mn),
+    (0xf0000, 0xffffd, GeneralCategory::Co),
+    (0x100000, 0x10fffd, GeneralCategory::Co),
+];
+
+// East_Asian_Width F, W & H.
+pub(crate) static EAST_ASIAN_WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115f),
+    (0x20a9, 0x20a9),
+    (0x231a, 0x231b),
+    (0x2329, 0x232a),
+    (0x23e9, 0x23ec),
+    (0x23f0, 0x23f0),
+    (0x23f3, 0x23f3),
+    (0x25fd, 0x25fe),
+    (0x2614, 0x2615),
+    (0x2630, 0x2637),
+    (0x2648, 0x2653),
+    (0x267f, 0x267f),
+    (0x268a, 0x268f),
+    (0x2693, 0x2693),
+    (0x26a1, 0x26a1),
+    (0x26aa, 0x26ab),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26ce, 0x26ce),
+    (0x26d4, 0x26d4),
+    (0x26ea, 0x26ea),
+    (0x26f2, 0x26f3),
+    (0x26f5, 0x26f5),
+    (0x26fa, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2705, 0x2705),
+    (0x270a, 0x270b),
+    (0x2728, 0x2728),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x2e80, 0x2e99),
+    (0x2e9b, 0x2ef3),
+    (0x2f00, 0x2fd5),
+    (0x2ff0, 0x303e),
+    (0x3041, 0x3096),
+    (0x3099, 0x30ff),
+    (0x3105, 0x312f),
+    (0x3131, 0x318e),
+    (0x3190, 0x31e5),
+    (0x31ef, 0x321e),
+    (0x3220, 0x3247),
+    (0x3250, 0xa48c),
+    (0xa490, 0xa4c6),
+    (0xa960, 0xa97c),
+    (0xac00, 0xd7a3),
+    (0xf900, 0xfaff),
+    (0xfe10, 0xfe19),
+    (0xfe30, 0xfe52),
+    (0xfe54, 0xfe66),
+    (0xfe68, 0xfe6b),
+    (0xff01, 0xffbe),
+    (0xffc2, 0xffc7),
+    (0xffca, 0xffcf),
+    (0xffd2, 0xffd7),
+    (0xffda, 0xffdc),
+    (0xffe0, 0xffe6),
+    (0xffe8, 0xffee),
+    (0x16fe0, 0x16fe4),
+    (0x16ff0, 0x16ff6),
+    (0x17000, 0x18cd5),
+    (0x18cff, 0x18d1e),
+    (0x18d80, 0x18df2),
+    (0x1aff0, 0x1aff3),
+    (0x1aff5, 0x1affb),
+    (0x1affd, 0x1affe),
+    (0x1b000, 0x1b122),
+    (0x1b132, 0x1b132),
+    (0x1b150, 0x1b152),
+    (0x1b155, 0x1b155),
+    (0x1b164, 0x1b167),
+    (0x1b170, 0x1b2fb),
+    (0x1d300, 0x1d356),
+    (0x1d360, 0x1d376),
+    (0x1f004, 0x1f004),
+    (0x1f0cf, 0x1f0cf),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f200, 0x1f202),
+    (0x1f210, 0x1f23b),
+    (0x1f240, 0x1f248),
+    (0x1f250, 0x1f251),
+    (0x1f260, 0x1f265),
+    (0x1f300, 0x1f320),
+    (0x1f32d, 0x1f335),
+    (0x1f337, 0x1f37c),
+    (0x1f37e, 0x1f393),
+    (0x1f3a0, 0x1f3ca),
+    (0x1f3cf, 0x1f3d3),
+    (0x1f3e0, 0x1f3f0),
+    (0x1f3f4, 0x1f3f4),
+    (0x1f3f8, 0x1f43e),
+    (0x1f440, 0x1f440),
+    (0x1f442, 0x1f4fc),
+    (0x1f4ff, 0x1f53d),
+    (0x1f54b, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f57a, 0x1f57a),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a4),
+    (0x1f5fb, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cc, 0x1f6cc),
+    (0x1f6d0, 0x1f6d2),
+    (0x1f6d5, 0x1f6d8),
+    (0x1f6dc, 0x1f6df),
+    (0x1f6eb, 0x1f6ec),
+    (0x1f6f4, 0x1f6fc),
+    (0x1f7e0, 0x1f7eb),
+    (0x1f7f0, 0x1f7f0),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa70, 0x1fa7c),
+    (0x1fa80, 0x1fa8a),
+    (0x1fa8e, 0x1fac6),
+    (0x1fac8, 0x1fac8),
+    (0x1facd, 0x1fadc),
+    (0x1fadf, 0x1faea),
+    (0x1faef, 0x1faf8),
+    (0x20000, 0x2fffd),
+    (0x30000, 0x3fffd),
+];
+
+pub(crate) static EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00a9, 0x00a9),
+    (0x00ae, 0x00ae),
+    (0x203c, 0x203c),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21a9, 0x21aa),
+    (0x231a, 0x231b),
+    (0x2328, 0x2328),
+    (0x23cf, 0x23cf),
+    (0x23e9, 0x23f3),
+    (0x23f8, 0x23fa),
+    (0x24c2, 0x24c2),
+    (0x25aa, 0x25ab),
+    (0x25b6, 0x25b6),
+    (0x25c0, 0x25c0),
+    (0x25fb, 0x25fe),
+    (0x2600, 0x2604),
+    (0x260e, 0x260e),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261d, 0x261d),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262a, 0x262a),
+    (0x262e, 0x262f),
+    (0x2638, 0x263a),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265f, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267b, 0x267b),
+    (0x267e, 0x267f),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269b, 0x269c),
+    (0x26a0, 0x26a1),
+    (0x26a7, 0x26a7),
+    (0x26aa, 0x26ab),
+    (0x26b0, 0x26b1),
+    (0x26bd, 0x26be),
+    (0x26c4, 0x26c5),
+    (0x26c8, 0x26c8),
+    (0x26ce, 0x26cf),
+    (0x26d1, 0x26d1),
+    (0x26d3, 0x26d4),
+    (0x26e9, 0x26ea),
+    (0x26f0, 0x26f5),
+    (0x26f7, 0x26fa),
+    (0x26fd, 0x26fd),
+    (0x2702, 0x2702),
+    (0x2705, 0x2705),
+    (0x2708, 0x270d),
+    (0x270f, 0x270f),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271d, 0x271d),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274c, 0x274c),
+    (0x274e, 0x274e),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x2795, 0x2797),
+    (0x27a1, 0x27a1),
+    (0x27b0, 0x27b0),
+    (0x27bf, 0x27bf),
+    (0x2934, 0x2935),
+    (0x2b05, 0x2b07),
+    (0x2b1b, 0x2b1c),
+    (0x2b50, 0x2b50),
+    (0x2b55, 0x2b55),
+    (0x3030, 0x3030),
+    (0x303d, 0x303d),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1f004, 0x1f004),
+    (0x1f02c, 0x1f02f),
+    (0x1f094, 0x1f09f),
+    (0x1f0af, 0x1f0b0),
+    (0x1f0c0, 0x1f0c0),
+    (0x1f0cf, 0x1f0d0),
+    (0x1f0f6, 0x1f0ff),
+    (0x1f170, 0x1f171),
+    (0x1f17e, 0x1f17f),
+    (0x1f18e, 0x1f18e),
+    (0x1f191, 0x1f19a),
+    (0x1f1ae, 0x1f1e5),
+    (0x1f201, 0x1f20f),
+    (0x1f21a, 0x1f21a),
+    (0x1f22f, 0x1f22f),
+    (0x1f232, 0x1f23a),
+    (0x1f23c, 0x1f23f),
+    (0x1f249, 0x1f25f),
+    (0x1f266, 0x1f321),
+    (0x1f324, 0x1f393),
+    (0x1f396, 0x1f397),
+    (0x1f399, 0x1f39b),
+    (0x1f39e, 0x1f3f0),
+    (0x1f3f3, 0x1f3f5),
+    (0x1f3f7, 0x1f3fa),
+    (0x1f400, 0x1f4fd),
+    (0x1f4ff, 0x1f53d),
+    (0x1f549, 0x1f54e),
+    (0x1f550, 0x1f567),
+    (0x1f56f, 0x1f570),
+    (0x1f573, 0x1f57a),
+    (0x1f587, 0x1f587),
+    (0x1f58a, 0x1f58d),
+    (0x1f590, 0x1f590),
+    (0x1f595, 0x1f596),
+    (0x1f5a4, 0x1f5a5),
+    (0x1f5a8, 0x1f5a8),
+    (0x1f5b1, 0x1f5b2),
+    (0x1f5bc, 0x1f5bc),
+    (0x1f5c2, 0x1f5c4),
+    (0x1f5d1, 0x1f5d3),
+    (0x1f5dc, 0x1f5de),
+    (0x1f5e1, 0x1f5e1),
+    (0x1f5e3, 0x1f5e3),
+    (0x1f5e8, 0x1f5e8),
+    (0x1f5ef, 0x1f5ef),
+    (0x1f5f3, 0x1f5f3),
+    (0x1f5fa, 0x1f64f),
+    (0x1f680, 0x1f6c5),
+    (0x1f6cb, 0x1f6d2),
+    (0x1f6d5, 0x1f6e5),
+    (0x1f6e9, 0x1f6e9),
+    (0x1f6eb, 0x1f6f0),
+    (0x1f6f3, 0x1f6ff),
+    (0x1f7da, 0x1f7ff),
+    (0x1f80c, 0x1f80f),
+    (0x1f848, 0x1f84f),
+    (0x1f85a, 0x1f85f),
+    (0x1f888, 0x1f88f),
+    (0x1f8ae, 0x1f8af),
+    (0x1f8bc, 0x1f8bf),
+    (0x1f8c2, 0x1f8cf),
+    (0x1f8d9, 0x1f8ff),
+    (0x1f90c, 0x1f93a),
+    (0x1f93c, 0x1f945),
+    (0x1f947, 0x1f9ff),
+    (0x1fa58, 0x1fa5f),
+    (0x1fa6e, 0x1faff),
+    (0x1fc00, 0x1fffd),
+];
+
+pub(crate) static BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+    (0x0000, 0x0008, BidiClass::BN),
+    (0x0009, 0x0009, BidiClass::S),
+    (0x000a, 0x000a, BidiClass::B),
+    (0x000b, 0x000b, BidiClass::S),
+    (0x000c, 0x000c, BidiClass::WS),
+    (0x000d, 0x000d, BidiClass::B),
+    (0x000e, 0x001b, BidiClass::BN),
+    (0x001c, 0x001e, BidiClass::B),
+    (0x001f, 0x001f, BidiClass::S),
+    (0x0020, 0x0020, BidiClass::WS),
+    (0x0021, 0x0022, BidiClass::ON),
+    (0x0023, 0x0025, BidiClass::ET),
+    (0x0026, 0x002a, BidiClass::ON),
+    (0x002b, 0x002b, BidiClass::ES),
+    (0x002c, 0x002c, BidiClass::CS),
+    (0x002d, 0x002d, BidiClass::ES),
+    (0x002e, 0x002f, BidiClass::CS),
+    (0x0030, 0x0039, BidiClass::EN),
+    (0x003a, 0x003a, BidiClass::CS),
+    (0x003b, 0x0040, BidiClass::ON),
+    (0x005b, 0x0060, BidiClass::ON),
+    (0x007b, 0x007e, BidiClass::ON),
+    (0x007f, 0x0084, BidiClass::BN),
+    (0x0085, 0x0085, BidiClass::B),
+    (0x0086, 0x009f, BidiClass::BN),
+    (0x00a0, 0x00a0, BidiClass::CS),
+    (0x00a1, 0x00a1, BidiClass::ON),
+    (0x00a2, 0x00a5, BidiClass::ET),
+    (0x00a6, 0x00a9, BidiClass::ON),
+    (0x00ab, 0x00ac, BidiClass::ON),
+    (0x00ad, 0x00ad, BidiClass::BN),
+    (0x00ae, 0x00af, BidiClass::ON),
+    (0x00b0, 0x00b1, BidiClass::ET),
+    (0x00b2, 0x00b3, BidiClass::EN),
+    (0x00b4, 0x00b4, BidiClass::ON),
+    (0x00b6, 0x00b8, BidiClass::ON),
+    (0x00b9, 0x00b9, BidiClass::EN),
+    (0x00bb, 0x00bf, BidiClass::ON),
+    (0x00d7, 0x00d7, BidiClass::ON),
+    (0x00f7, 0x00f7, BidiClass::ON),
+    (0x02b9, 0x02ba, BidiClass::ON),
+    (0x02c2, 0x02cf, BidiClass::ON),
+    (0x02d2, 0x02df, BidiClass::ON),
+    (0x02e5, 0x02ed, BidiClass::ON),
+    (0x02ef, 0x02ff, BidiClass::ON),
+    (0x0300, 0x036f, BidiClass::NSM),
+    (0x0374, 0x0375, BidiClass::ON),
+    (0x037e, 0x037e, BidiClass::ON),
+    (0x0384, 0x0385, BidiClass::ON),
+    (0x0387, 0x0387, BidiClass::ON),
+    (0x03f6, 0x03f6, BidiClass::ON),
+    (0x0483, 0x0489, BidiClass::NSM),
+    (0x058a, 0x058a, BidiClass::ON),
+    (0x058d, 0x058e, BidiClass::ON),
+    (0x058f, 0x058f, BidiClass::ET),
+    (0x0590, 0x0590, BidiClass::R),
+    (0x0591, 0x05bd, BidiClass::NSM),
+    (0x05be, 0x05be, BidiClass::R),
+    (0x05bf, 0x05bf, BidiClass::NSM),
+    (0x05c0, 0x05c0, BidiClass::R),
+    (0x05c1, 0x05c2, BidiClass::NSM),
+    (0x05c3, 0x05c3, BidiClass::R),
+    (0x05c4, 0x05c5, BidiClass::NSM),
+    (0x05c6, 0x05c6, BidiClass::R),
+    (0x05c7, 0x05c7, BidiClass::NSM),
+    (0x05c8, 0x05ff, BidiClass::R),
+    (0x0600, 0x0605, BidiClass::AN),
+    (0x0606, 0x0607, BidiClass::ON),
+    (0x0608, 0x0608, BidiClass::AL),
+    (0x0609, 0x060a, BidiClass::ET),
+    (0x060b, 0x060b, BidiClass::AL),
+    (0x060c, 0x060c, BidiClass::CS),
+    (0x060d, 0x060d, BidiClass::AL),
+    (0x060e, 0x060f, BidiClass::ON),
+    (0x0610, 0x061a, BidiClass::NSM),
+    (0x061b, 0x064a, BidiClass::AL),
+    (0x064b, 0x065f, BidiClass::NSM),
+    (0x0660, 0x0669, BidiClass::AN),
+    (0x066a, 0x066a, BidiClass::ET),
+    (0x066b, 0x066c, BidiClass::AN),
+    (0x066d, 0x066f, BidiClass::AL),
+    (0x0670, 0x0670, BidiClass::NSM),
+    (0x0671, 0x06d5, BidiClass::AL),
+    (0x06d6, 0x06dc, BidiClass::NSM),
+    (0x06dd, 0x06dd, BidiClass::AN),
+    (0x06de, 0x06de, BidiClass::ON),
+    (0x06df, 0x06e4, BidiClass::NSM),
+    (0x06e5, 0x06e6, BidiClass::AL),
+    (0x06e7, 0x06e8, BidiClass::NSM),
+    (0x06e9, 0x06e9, BidiClass::ON),
+    (0x06ea, 0x06ed, BidiClass::NSM),
+    (0x06ee, 0x06ef, BidiClass::AL),
+    (0x06f0, 0x06f9, BidiClass::EN),
+    (0x06fa, 0x0710, BidiClass::AL),
+    (0x0711, 0x0711, BidiClass::NSM),
+    (0x0712, 0x072f, BidiClass::AL),
+    (0x0730, 0x074a, BidiClass::NSM),
+    (0x074b, 0x07a5, BidiClass::AL),
+    (0x07a6, 0x07b0, BidiClass::NSM),
+    (0x07b1, 0x07bf, BidiClass::AL),
+    (0x07c0, 0x07ea, BidiClass::R),
+    (0x07eb, 0x07f3, BidiClass::NSM),
+    (0x07f4, 0x07f5, BidiClass::R),
+    (0x07f6, 0x07f9, BidiClass::ON),
+    (0x07fa, 0x07fc, BidiClass::R),
+    (0x07fd, 0x07fd, BidiClass::NSM),
+    (0x07fe, 0x0815, BidiClass::R),
+    (0x0816, 0x0819, BidiClass::NSM),
+    (0x081a, 0x081a, BidiClass::R),
+    (0x081b, 0x0823, BidiClass::NSM),
+    (0x0824, 0x0824, BidiClass::R),
+    (0x0825, 0x0827, BidiClass::NSM),
+    (0x0828, 0x0828, BidiClass::R),
+    (0x0829, 0x082d, BidiClass::NSM),
+    (0x082e, 0x0858, BidiClass::R),
+    (0x0859, 0x085b, BidiClass::NSM),
+    (0x085c, 0x085f, BidiClass::R),
+    (0x0860, 0x088f, BidiClass::AL),
+    (0x0890, 0x0891, BidiClass::AN),
+    (0x0892, 0x0896, BidiClass::AL),
+    (0x0897, 0x089f, BidiClass::NSM),
+    (0x08a0, 0x08c9, BidiClass::AL),
+    (0x08ca, 0x08e1, BidiClass::NSM),
+    (0x08e2, 0x08e2, BidiClass::AN),
+    (0x08e3, 0x0902, BidiClass::NSM),
+    (0x093a, 0x093a, BidiClass::NSM),
+    (0x093c, 0x093c, BidiClass::NSM),
+    (0x0941, 0x0948, BidiClass::NSM),
+    (0x094d, 0x094d, BidiClass::NSM),
+    (0x0951, 0x0957, BidiClass::NSM),
+    (0x0962, 0x0963, BidiClass::NSM),
+    (0x0981, 0x0981, BidiClass::NSM),
+    (0x09bc, 0x09bc, BidiClass::NSM),
+    (0x09c1, 0x09c4, BidiClass::NSM),
+    (0x09cd, 0x09cd, BidiClass::NSM),
+    (0x09e2, 0x09e3, BidiClass::NSM),
+    (0x09f2, 0x09f3, BidiClass::ET),
+    (0x09fb, 0x09fb, BidiClass::ET),
+    (0x09fe, 0x09fe, BidiClass::NSM),
+    (0x0a01, 0x0a02, BidiClass::NSM),
+    (0x0a3c, 0x0a3c, BidiClass::NSM),
+    (0x0a41, 0x0a42, BidiClass::NSM),
+    (0x0a47, 0x0a48, BidiClass::NSM),
+    (0x0a4b, 0x0a4d, BidiClass::NSM),
+    (0x0a51, 0x0a51, BidiClass::NSM),
+    (0x0a70, 0x0a71, BidiClass::NSM),
+    (0x0a75, 0x0a75, BidiClass::NSM),
+    (0x0a81, 0x0a82, BidiClass::NSM),
+    (0x0abc, 0x0abc, BidiClass::NSM),
+    (0x0ac1, 0x0ac5, BidiClass::NSM),
+    (0x0ac7, 0x0ac8, BidiClass::NSM),
+    (0x0acd, 0x0acd, BidiClass::NSM),
+    (0x0ae2, 0x0ae3, BidiClass::NSM),
+    (0x0af1, 0x0af1, BidiClass::ET),
+    (0x0afa, 0x0aff, BidiClass::NSM),
+    (0x0b01, 0x0b01, BidiClass::NSM),
+    (0x0b3c, 0x0b3c, BidiClass::NSM),
+    (0x0b3f, 0x0b3f, BidiClass::NSM),
+    (0x0b41, 0x0b44, BidiClass::NSM),
+    (0x0b4d, 0x0b4d, BidiClass::NSM),
+    (0x0b55, 0x0b56, BidiClass::NSM),
+    (0x0b62, 0x0b63, BidiClass::NSM),
+    (0x0b82, 0x0b82, BidiClass::NSM),
+    (0x0bc0, 0x0bc0, BidiClass::NSM),
+    (0x0bcd, 0x0bcd, BidiClass::NSM),
+    (0x0bf3, 0x0bf8, BidiClass::ON),
+    (0x0bf9, 0x0bf9, BidiClass::ET),
+    (0x0bfa, 0x0bfa, BidiClass::ON),
+    (0x0c00, 0x0c00, BidiClass::NSM),
+    (0x0c04, 0x0c04, BidiClass::NSM),
+    (0x0c3c, 0x0c3c, BidiClass::NSM),
+    (0x0c3e, 0x0c40, BidiClass::NSM),
+    (0x0c46, 0x0c48, BidiClass::NSM),
+    (0x0c4a, 0x0c4d, BidiClass::NSM),
+    (0x0c55, 0x0c56, BidiClass::NSM),
+    (0x0c62, 0x0c63, BidiClass::NSM),
+    (0x0c78, 0x0c7e, BidiClass::ON),
+    (0x0c81, 0x0c81, BidiClass::NSM),
+    (0x0cbc, 0x0cbc, BidiClass::NSM),
+    (0x0ccc, 0x0ccd, BidiClass::NSM),
+    (0x0ce2, 0x0ce3, BidiClass::NSM),
+    (0x0d00, 0x0d01, BidiClass::NSM),
+    (0x0d3b, 0x0d3c, BidiClass::NSM),
+    (0x0d41, 0x0d44, BidiClass::NSM),
+    (0x0d4d, 0x0d4d, BidiClass::NSM),
+    (0x0d62, 0x0d63, BidiClass::NSM),
+    (0x0d81, 0x0d81, BidiClass::NSM),
+    (0x0dca, 0x0dca, BidiClass::NSM),
+    (0x0dd2, 0x0dd4, BidiClass::NSM),
+    (0x0dd6, 0x0dd6, BidiClass::NSM),
+    (0x0e31, 0x0e31, BidiClass::NSM),
+    (0x0e34, 0x0e3a, BidiClass::NSM),
+    (0x0e3f, 0x0e3f, BidiClass::ET),
+    (0x0e47, 0x0e4e, BidiClass::NSM),
+    (0x0eb1, 0x0eb1, BidiClass::NSM),
+    (0x0eb4, 0x0ebc, BidiClass::NSM),
+    (0x0ec8, 0x0ece, BidiClass::NSM),
+    (0x0f18, 0x0f19, BidiClass::NSM),
+    (0x0f35, 0x0f35, BidiClass::NSM),
+    (0x0f37, 0x0f37, BidiClass::NSM),
+    (0x0f39, 0x0f39, BidiClass::NSM),
+    (0x0f3a, 0x0f3d, BidiClass::ON),
+    (0x0f71, 0x0f7e, BidiClass::NSM),
+    (0x0f80, 0x0f84, BidiClass::NSM),
+    (0x0f86, 0x0f87, BidiClass::NSM),
+    (0x0f8d, 0x0f97, BidiClass::NSM),
+    (0x0f99, 0x0fbc, BidiClass::NSM),
+    (0x0fc6, 0x0fc6, BidiClass::NSM),
+    (0x102d, 0x1030, BidiClass::NSM),
+    (0x1032, 0x1037, BidiClass::NSM),
+    (0x1039, 0x103a, BidiClass::NSM),
+    (0x103d, 0x103e, BidiClass::NSM),
+    (0x1058, 0x1059, BidiClass::NSM),
+    (0x105e, 0x1060, BidiClass::NSM),
+    (0x1071, 0x1074, BidiClass::NSM),
+    (0x1082, 0x1082, BidiClass::NSM),
+    (0x1085, 0x1086, BidiClass::NSM),
+    (0x108d, 0x108d, BidiClass::NSM),
+    (0x109d, 0x109d, BidiClass::NSM),
+    (0x135d, 0x135f, BidiClass::NSM),
+    (0x1390, 0x1399, BidiClass::ON),
+    (0x1400, 0x1400, BidiClass::ON),
+    (0x1680, 0x1680, BidiClass::WS),
+    (0x169b, 0x169c, BidiClass::ON),
+    (0x1712, 0x1714, BidiClass::NSM),
+    (0x1732, 0x1733, BidiClass::NSM),
+    (0x1752, 0x1753, BidiClass::NSM),
+    (0x1772, 0x1773, BidiClass::NSM),
+    (0x17b4, 0x17b5, BidiClass::NSM),
+    (0x17b7, 0x17bd, BidiClass::NSM),
+    (0x17c6, 0x17c6, BidiClass::NSM),
+    (0x17c9, 0x17d3, BidiClass::NSM),
+    (0x17db, 0x17db, BidiClass::ET),
+    (0x17dd, 0x17dd, BidiClass::NSM),
+    (0x17f0, 0x17f9, BidiClass::ON),
+    (0x1800, 0x180a, BidiClass::ON),
+    (0x180b, 0x180d, BidiClass::NSM),
+    (0x180e, 0x180e, BidiClass::BN),
+    (0x180f, 0x180f, BidiClass::NSM),
+    (0x1885, 0x1886, BidiClass::NSM),
+    (0x18a9, 0x18a9, BidiClass::NSM),
+    (0x1920, 0x1922, BidiClass::NSM),
+    (0x1927, 0x1928, BidiClass::NSM),
+    (0x1932, 0x1932, BidiClass::NSM),
+    (0x1939, 0x193b, BidiClass::NSM),
+    (0x1940, 0x1940, BidiClass::ON),
+    (0x1944, 0x1945, BidiClass::ON),
+    (0x19de, 0x19ff, BidiClass::ON),
+    (0x1a17, 0x1a18, BidiClass::NSM),
+    (0x1a1b, 0x1a1b, BidiClass::NSM),
+    (0x1a56, 0x1a56, BidiClass::NSM),
+    (0x1a58, 0x1a5e, BidiClass::NSM),
+    (0x1a60, 0x1a60, BidiClass::NSM),
+    (0x1a62, 0x1a62, BidiClass::NSM),
+    (0x1a65, 0x1a6c, BidiClass::NSM),
+    (0x1a73, 0x1a7c, BidiClass::NSM),
+    (0x1a7f, 0x1a7f, BidiClass::NSM),
+    (0x1ab0, 0x1add, BidiClass::NSM),
+    (0x1ae0, 0x1aeb, BidiClass::NSM),
+    (0x1b00, 0x1b03, BidiClass::NSM),
+    (0x1b34, 0x1b34, BidiClass::NSM),
+    (0x1b36, 0x1b3a, BidiClass::NSM),
+    (0x1b3c, 0x1b3c, BidiClass::NSM),
+    (0x1b42, 0x1b42, BidiClass::NSM),
+    (0x1b6b, 0x1b73, BidiClass::NSM),
+    (0x1b80, 0x1b81, BidiClass::NSM),
+    (0x1ba2, 0x1ba5, BidiClass::NSM),
+    (0x1ba8, 0x1ba9, BidiClass::NSM),
+    (0x1bab, 0x1bad, BidiClass::NSM),
+    (0x1be6, 0x1be6, BidiClass::NSM),
+    (0x1be8, 0x1be9, BidiClass::NSM),
+    (0x1bed, 0x1bed, BidiClass::NSM),
+    (0x1bef, 0x1bf1, BidiClass::NSM),
+    (0x1c2c, 0x1c33, BidiClass::NSM),
+    (0x1c36, 0x1c37, BidiClass::NSM),
+    (0x1cd0, 0x1cd2, BidiClass::NSM),
+    (0x1cd4, 0x1ce0, BidiClass::NSM),
+    (0x1ce2, 0x1ce8, BidiClass::NSM),
+    (0x1ced, 0x1ced, BidiClass::NSM),
+    (0x1cf4, 0x1cf4, BidiClass::NSM),
+    (0x1cf8, 0x1cf9, BidiClass::NSM),
+    (0x1dc0, 0x1dff, BidiClass::NSM),
+    (0x1fbd, 0x1fbd, BidiClass::ON),
+    (0x1fbf, 0x1fc1, BidiClass::ON),
+    (0x1fcd, 0x1fcf, BidiClass::ON),
+    (0x1fdd, 0x1fdf, BidiClass::ON),
+    (0x1fed, 0x1fef, BidiClass::ON),
+    (0x1ffd, 0x1ffe, BidiClass::ON),
+    (0x2000, 0x200a, BidiClass::WS),
+    (0x200b, 0x200d, BidiClass::BN),
+    (0x200f, 0x200f, BidiClass::R),
+    (0x2010, 0x2027, BidiClass::ON),
+    (0x2028, 0x2028, BidiClass::WS),
+    (0x2029, 0x2029, BidiClass::B),
+    (0x202a, 0x202a, BidiClass::LRE),
+    (0x202b, 0x202b, BidiClass::RLE),
+    (0x202c, 0x202c, BidiClass::PDF),
+    (0x202d, 0x202d, BidiClass::LRO),
+    (0x202e, 0x202e, BidiClass::RLO),
+    (0x202f, 0x202f, BidiClass::CS),
+    (0x2030, 0x2034, BidiClass::ET),
+    (0x2035, 0x2043, BidiClass::ON),
+    (0x2044, 0x2044, BidiClass::CS),
+    (0x2045, 0x205e, BidiClass::ON),
+    (0x205f, 0x205f, BidiClass::WS),
+    (0x2060, 0x2065, BidiClass::BN),
+    (0x2066, 0x2066, BidiClass::LRI),
+    (0x2067, 0x2067, BidiClass::RLI),
+    (0x2068, 0x2068, BidiClass::FSI),
+    (0x2069, 0x2069, BidiClass::PDI),
+    (0x206a, 0x206f, BidiClass::BN),
+    (0x2070, 0x2070, BidiClass::EN),
+    (0x2074, 0x2079, BidiClass::EN),
+    (0x207a, 0x207b, BidiClass::ES),
+    (0x207c, 0x207e, BidiClass::ON),
+    (0x2080, 0x2089, BidiClass::EN),
+    (0x208a, 0x208b, BidiClass::ES),
+    (0x208c, 0x208e, BidiClass::ON),
+    (0x20a0, 0x20cf, BidiClass::ET),
+    (0x20d0, 0x20f0, BidiClass::NSM),
+    (0x2100, 0x2101, BidiClass::ON),
+    (0x2103, 0x2106, BidiClass::ON),
+    (0x2108, 0x2109, BidiClass::ON),
+    (0x2114, 0x2114, BidiClass::ON),
+    (0x2116, 0x2118, BidiClass::ON),
+    (0x211e, 0x2123, BidiClass::ON),
+    (0x2125, 0x2125, BidiClass::ON),
+    (0x2127, 0x2127, BidiClass::ON),
+    (0x2129, 0x2129, BidiClass::ON),
+    (0x212e, 0x212e, BidiClass::ET),
+    (0x213a, 0x213b, BidiClass::ON),
+    (0x2140, 0x2144, BidiClass::ON),
+    (0x214a, 0x214d, BidiClass::ON),
+    (0x2150, 0x215f, BidiClass::ON),
+    (0x2189, 0x218b, BidiClass::ON),
+    (0x2190, 0x2211, BidiClass::ON),
+    (0x2212, 0x2212, BidiClass::ES),
+    (0x2213, 0x2213, BidiClass::ET),
+    (0x2214, 0x2335, BidiClass::ON),
+    (0x237b, 0x2394, BidiClass::ON),
+    (0x2396, 0x2429, BidiClass::ON),
+    (0x2440, 0x244a, BidiClass::ON),
+    (0x2460, 0x2487, BidiClass::ON),
+    (0x2488, 0x249b, BidiClass::EN),
+    (0x24ea, 0x26ab, BidiClass::ON),
+    (0x26ad, 0x27ff, BidiClass::ON),
+    (0x2900, 0x2b73, BidiClass::ON),
+    (0x2b76, 0x2bff, BidiClass::ON),
+    (0x2ce5, 0x2cea, BidiClass::ON),
+    (0x2cef, 0x2cf1, BidiClass::NSM),
+    (0x2cf9, 0x2cff, BidiClass::ON),
+    (0x2d7f, 0x2d7f, BidiClass::NSM),
+    (0x2de0, 0x2dff, BidiClass::NSM),
+    (0x2e00, 0x2e5d, BidiClass::ON),
+    (0x2e80, 0x2e99, BidiClass::ON),
+    (0x2e9b, 0x2ef3, BidiClass::ON),
+    (0x2f00, 0x2fd5, BidiClass::ON),
+    (0x2ff0, 0x2fff, BidiClass::ON),
+    (0x3000, 0x3000, BidiClass::WS),
+    (0x3001, 0x3004, BidiClass::ON),
+    (0x3008, 0x3020, BidiClass::ON),
+    (0x302a, 0x302d, BidiClass::NSM),
+    (0x3030, 0x3030, BidiClass::ON),
+    (0x3036, 0x3037, BidiClass::ON),
+    (0x303d, 0x303f, BidiClass::ON),
+    (0x3099, 0x309a, BidiClass::NSM),
+    (0x309b, 0x309c, BidiClass::ON),
+    (0x30a0, 0x30a0, BidiClass::ON),
+    (0x30fb, 0x30fb, BidiClass::ON),
+    (0x31c0, 0x31e5, BidiClass::ON),
+    (0x31ef, 0x31ef, BidiClass::ON),
+    (0x321d, 0x321e, BidiClass::ON),
+    (0x3250, 0x325f, BidiClass::ON),
+    (0x327c, 0x327e, BidiClass::ON),
+    (0x32b1, 0x32bf, BidiClass::ON),
+    (0x32cc, 0x32cf, BidiClass::ON),
+    (0x3377, 0x337a, BidiClass::ON),
+    (0x33de, 0x33df, BidiClass::ON),
+    (0x33ff, 0x33ff, BidiClass::ON),
+    (0x4dc0, 0x4dff, BidiClass::ON),
+    (0xa490, 0xa4c6, BidiClass::ON),
+    (0xa60d, 0xa60f, BidiClass::ON),
+    (0xa66f, 0xa672, BidiClass::NSM),
+    (0xa673, 0xa673, BidiClass::ON),
+    (0xa674, 0xa67d, BidiClass::NSM),
+    (0xa67e, 0xa67f, BidiClass::ON),
+    (0xa69e, 0xa69f, BidiClass::NSM),
+    (0xa6f0, 0xa6f1, BidiClass::NSM),
+    (0xa700, 0xa721, BidiClass::ON),
+    (0xa788, 0xa788, BidiClass::ON),
+    (0xa802, 0xa802, BidiClass::NSM),
+    (0xa806, 0xa806, BidiClass::NSM),
+    (0xa80b, 0xa80b, BidiClass::NSM),
+    (0xa825, 0xa826, BidiClass::NSM),
+    (0xa828, 0xa82b, BidiClass::ON),
+    (0xa82c, 0xa82c, BidiClass::NSM),
+    (0xa838, 0xa839, BidiClass::ET),
+    (0xa874, 0xa877, BidiClass::ON),
+    (0xa8c4, 0xa8c5, BidiClass::NSM),
+    (0xa8e0, 0xa8f1, BidiClass::NSM),
+    (0xa8ff, 0xa8ff, BidiClass::NSM),
+    (0xa926, 0xa92d, BidiClass::NSM),
+    (0xa947, 0xa951, BidiClass::NSM),
+    (0xa980, 0xa982, BidiClass::NSM),
+    (0xa9b3, 0xa9b3, BidiClass::NSM),
+    (0xa9b6, 0xa9b9, BidiClass::NSM),
+    (0xa9bc, 0xa9bd, BidiClass::NSM),
+    (0xa9e5, 0xa9e5, BidiClass::NSM),
+    (0xaa29, 0xaa2e, BidiClass::NSM),
+    (0xaa31, 0xaa32, BidiClass::NSM),
+    (0xaa35, 0xaa36, BidiClass::NSM),
+    (0xaa43, 0xaa43, BidiClass::NSM),
+    (0xaa4c, 0xaa4c, BidiClass::NSM),
+    (0xaa7c, 0xaa7c, BidiClass::NSM),
+    (0xaab0, 0xaab0, BidiClass::NSM),
+    (0xaab2, 0xaab4, BidiClass::NSM),
+    (0xaab7, 0xaab8, BidiClass::NSM),
+    (0xaabe, 0xaabf, BidiClass::NSM),
+    (0xaac1, 0xaac1, BidiClass::NSM),
+    (0xaaec, 0xaaed, BidiClass::NSM),
+    (0xaaf6, 0xaaf6, BidiClass::NSM),
+    (0xab6a, 0xab6b, BidiClass::ON),
+    (0xabe5, 0xabe5, BidiClass::NSM),
+    (0xabe8, 0xabe8, BidiClass::NSM),
+    (0xabed, 0xabed, BidiClass::NSM),
+    (0xfb1d, 0xfb1d, BidiClass::R),
+    (0xfb1e, 0xfb1e, BidiClass::NSM),
+    (0xfb1f, 0xfb28, BidiClass::R),
+    (0xfb29, 0xfb29, BidiClass::ES),
+    (0xfb2a, 0xfb4f, BidiClass::R),
+    (0xfb50, 0xfbc2, BidiClass::AL),
+    (0xfbc3, 0xfbd2, BidiClass::ON),
+    (0xfbd3, 0xfd3d, BidiClass::AL),
+    (0xfd3e, 0xfd4f, BidiClass::ON),
+    (0xfd50, 0xfd8f, BidiClass::AL),
+    (0xfd90, 0xfd91, BidiClass::ON),
+    (0xfd92, 0xfdc7, BidiClass::AL),
+    (0xfdc8, 0xfdcf, BidiClass::ON),
+    (0xfdd0, 0xfdef, BidiClass::BN),
+    (0xfdf0, 0xfdfc, BidiClass::AL),
+    (0xfdfd, 0xfdff, BidiClass::ON),
+    (0xfe00, 0xfe0f, BidiClass::NSM),
+    (0xfe10, 0xfe19, BidiClass::ON),
+    (0xfe20, 0xfe2f, BidiClass::NSM),
+    (0xfe30, 0xfe4f, BidiClass::ON),
+    (0xfe50, 0xfe50, BidiClass::CS),
+    (0xfe51, 0xfe51, BidiClass::ON),
+    (0xfe52, 0xfe52, BidiClass::CS),
+    (0xfe54, 0xfe54, BidiClass::ON),
+    (0xfe55, 0xfe55, BidiClass::CS),
+    (0xfe56, 0xfe5e, BidiClass::ON),
+    (0xfe5f, 0xfe5f, BidiClass::ET),
+    (0xfe60, 0xfe61, BidiClass::ON),
+    (0xfe62, 0xfe63, BidiClass::ES),
+    (0xfe64, 0xfe66, BidiClass::ON),
+    (0xfe68, 0xfe68, BidiClass::ON),
+    (0xfe69, 0xfe6a, BidiClass::ET),
+    (0xfe6b, 0xfe6b, BidiClass::ON),
+    (0xfe70, 0xfefe, BidiClass::AL),
+    (0xfeff, 0xfeff, BidiClass::BN),
+    (0xff01, 0xff02, BidiClass::ON),
+    (0xff03, 0xff05, BidiClass::ET),
+    (0xff06, 0xff0a, BidiClass::ON),
+    (0xff0b, 0xff0b, BidiClass::ES),
+    (0xff0c, 0xff0c, BidiClass::CS),
+    (0xff0d, 0xff0d, BidiClass::ES),
+    (0xff0e, 0xff0f, BidiClass::CS),
+    (0xff10, 0xff19, BidiClass::EN),
+    (0xff1a, 0xff1a, BidiClass::CS),
+    (0xff1b, 0xff20, BidiClass::ON),
+    (0xff3b, 0xff40, BidiClass::ON),
+    (0xff5b, 0xff65, BidiClass::ON),
+    (0xffe0, 0xffe1, BidiClass::ET),
+    (0xffe2, 0xffe4, BidiClass::ON),
+    (0xffe5, 0xffe6, BidiClass::ET),
+    (0xffe8, 0xffee, BidiClass::ON),
+    (0xfff0, 0xfff8, BidiClass::BN),
+    (0xfff9, 0xfffd, BidiClass::ON),
+    (0xfffe, 0xffff, BidiClass::BN),
+    (0x10101, 0x10101, BidiClass::ON),
+    (0x10140, 0x1018c, BidiClass::ON),
+    (0x10190, 0x1019c, BidiClass::ON),
+    (0x101a0, 0x101a0, BidiClass::ON),
+    (0x101fd, 0x101fd, BidiClass::NSM),
+    (0x102e0, 0x102e0, BidiClass::NSM),
+    (0x102e1, 0x102fb, BidiClass::EN),
+    (0x10376, 0x1037a, BidiClass::NSM),
+    (0x10800, 0x1091e, BidiClass::R),
+    (0x1091f, 0x1091f, BidiClass::ON),
+    (0x10920, 0x10a00, BidiClass::R),
+    (0x10a01, 0x10a03, BidiClass::NSM),
+    (0x10a04, 0x10a04, BidiClass::R),
+    (0x10a05, 0x10a06, BidiClass::NSM),
+    (0x10a07, 0x10a0b, BidiClass::R),
+    (0x10a0c, 0x10a0f, BidiClass::NSM),
+    (0x10a10, 0x10a37, BidiClass::R),
+    (0x10a38, 0x10a3a, BidiClass::NSM),
+    (0x10a3b, 0x10a3e, BidiClass::R),
+    (0x10a3f, 0x10a3f, BidiClass::NSM),
+    (0x10a40, 0x10ae4, BidiClass::R),
+    (0x10ae5, 0x10ae6, BidiClass::NSM),
+    (0x10ae7, 0x10b38, BidiClass::R),
+    (0x10b39, 0x10b3f, BidiClass::ON),
+    (0x10b40, 0x10cff, BidiClass::R),
+    (0x10d00, 0x10d23, BidiClass::AL),
+    (0x10d24, 0x10d27, BidiClass::NSM),
+    (0x10d28, 0x10d2f, BidiClass::AL),
+    (0x10d30, 0x10d39, BidiClass::AN),
+    (0x10d3a, 0x10d3f, BidiClass::AL),
+    (0x10d40, 0x10d49, BidiClass::AN),
+    (0x10d4a, 0x10d68, BidiClass::R),
+    (0x10d69, 0x10d6d, BidiClass::NSM),
+    (0x10d6e, 0x10d6e, BidiClass::ON),
+    (0x10d6f, 0x10e5f, BidiClass::R),
+    (0x10e60, 0x10e7e, BidiClass::AN),
+    (0x10e7f, 0x10eaa, BidiClass::R),
+    (0x10eab, 0x10eac, BidiClass::NSM),
+    (0x10ead, 0x10ebf, BidiClass::R),
+    (0x10ec0, 0x10ecf, BidiClass::AL),
+    (0x10ed0, 0x10ed8, BidiClass::ON),
+    (0x10ed9, 0x10ef9, BidiClass::AL),
+    (0x10efa, 0x10eff, BidiClass::NSM),
+    (0x10f00, 0x10f2f, BidiClass::R),
+    (0x10f30, 0x10f45, BidiClass::AL),
+    (0x10f46, 0x10f50, BidiClass::NSM),
+    (0x10f51, 0x10f6f, BidiClass::AL),
+    (0x10f70, 0x10f81, BidiClass::R),
+    (0x10f82, 0x10f85, BidiClass::NSM),
+    (0x10f86, 0x10fff, BidiClass::R),
+    (0x11001, 0x11001, BidiClass::NSM),
+    (0x11038, 0x11046, BidiClass::NSM),
+    (0x11052, 0x11065, BidiClass::ON),
+    (0x11070, 0x11070, BidiClass::NSM),
+    (0x11073, 0x11074, BidiClass::NSM),
+    (0x1107f, 0x11081, BidiClass::NSM),
+    (0x110b3, 0x110b6, BidiClass::NSM),
+    (0x110b9, 0x110ba, BidiClass::NSM),
+    (0x110c2, 0x110c2, BidiClass::NSM),
+    (0x11100, 0x11102, BidiClass::NSM),
+    (0x11127, 0x1112b, BidiClass::NSM),
+    (0x1112d, 0x11134, BidiClass::NSM),
+    (0x11173, 0x11173, BidiClass::NSM),
+    (0x11180, 0x11181, BidiClass::NSM),
+    (0x111b6, 0x111be, BidiClass::NSM),
+    (0x111c9, 0x111cc, BidiClass::NSM),
+    (0x111cf, 0x111cf, BidiClass::NSM),
+    (0x1122f, 0x11231, BidiClass::NSM),
+    (0x11234, 0x11234, BidiClass::NSM),
+    (0x11236, 0x11237, BidiClass::NSM),
+    (0x1123e, 0x1123e, BidiClass::NSM),
+    (0x11241, 0x11241, BidiClass::NSM),
+    (0x112df, 0x112df, BidiClass::NSM),
+    (0x112e3, 0x112ea, BidiClass::NSM),
+    (0x11300, 0x11301, BidiClass::NSM),
+    (0x1133b, 0x1133c, BidiClass::NSM),
+    (0x11340, 0x11340, BidiClass::NSM),
+    (0x11366, 0x1136c, BidiClass::NSM),
+    (0x11370, 0x11374, BidiClass::NSM),
+    (0x113bb, 0x113c0, BidiClass::NSM),
+    (0x113ce, 0x113ce, BidiClass::NSM),
+    (0x113d0, 0x113d0, BidiClass::NSM),
+    (0x113d2, 0x113d2, BidiClass::NSM),
+    (0x113e1, 0x113e2, BidiClass::NSM),
+    (0x11438, 0x1143f, BidiClass::NSM),
+    (0x11442, 0x11444, BidiClass::NSM),
+    (0x11446, 0x11446, BidiClass::NSM),
+    (0x1145e, 0x1145e, BidiClass::NSM),
+    (0x114b3, 0x114b8, BidiClass::NSM),
+    (0x114ba, 0x114ba, BidiClass::NSM),
+    (0x114bf, 0x114c0, BidiClass::NSM),
+    (0x114c2, 0x114c3, BidiClass::NSM),
+    (0x115b2, 0x115b5, BidiClass::NSM),
+    (0x115bc, 0x115bd, BidiClass::NSM),
+    (0x115bf, 0x115c0, BidiClass::NSM),
+    (0x115dc, 0x115dd, BidiClass::NSM),
+    (0x11633, 0x1163a, BidiClass::NSM),
+    (0x1163d, 0x1163d, BidiClass::NSM),
+    (0x1163f, 0x11640, BidiClass::NSM),
+    (0x11660, 0x1166c, BidiClass::ON),
+    (0x116ab, 0x116ab, BidiClass::NSM),
+    (0x116ad, 0x116ad, BidiClass::NSM),
+    (0x116b0, 0x116b5, BidiClass::NSM),
+    (0x116b7, 0x116b7, BidiClass::NSM),
+    (0x1171d, 0x1171d, BidiClass::NSM),
+    (0x1171f, 0x1171f, BidiClass::NSM),
+    (0x11722, 0x11725, BidiClass::NSM),
+    (0x11727, 0x1172b, BidiClass::NSM),
+    (0x1182f, 0x11837, BidiClass::NSM),
+    (0x11839, 0x1183a, BidiClass::NSM),
+    (0x1193b, 0x1193c, BidiClass::NSM),
+    (0x1193e, 0x1193e, BidiClass::NSM),
+    (0x11943, 0x11943, BidiClass::NSM),
+    (0x119d4, 0x119d7, BidiClass::NSM),
+    (0x119da, 0x119db, BidiClass::NSM),
+    (0x119e0, 0x119e0, BidiClass::NSM),
+    (0x11a01, 0x11a06, BidiClass::NSM),
+    (0x11a09, 0x11a0a, BidiClass::NSM),
+    (0x11a33, 0x11a38, BidiClass::NSM),
+    (0x11a3b, 0x11a3e, BidiClass::NSM),
+    (0x11a47, 0x11a47, BidiClass::NSM),
+    (0x11a51, 0x11a56, BidiClass::NSM),
+    (0x11a59, 0x11a5b, BidiClass::NSM),
+    (0x11a8a, 0x11a96, BidiClass::NSM),
+    (0x11a98, 0x11a99, BidiClass::NSM),
+    (0x11b60, 0x11b60, BidiClass::NSM),
+    (0x11b62, 0x11b64, BidiClass::NSM),
+    (0x11b66, 0x11b66, BidiClass::NSM),
+    (0x11c30, 0x11c36, BidiClass::NSM),
+    (0x11c38, 0x11c3d, BidiClass::NSM),
+    (0x11c92, 0x11ca7, BidiClass::NSM),
+    (0x11caa, 0x11cb0, BidiClass::NSM),
+    (0x11cb2, 0x11cb3, BidiClass::NSM),
+    (0x11cb5, 0x11cb6, BidiClass::NSM),
+    (0x11d31, 0x11d36, BidiClass::NSM),
+    (0x11d3a, 0x11d3a, BidiClass::NSM),
+    (0x11d3c, 0x11d3d, BidiClass::NSM),
+    (0x11d3f, 0x11d45, BidiClass::NSM),
+    (0x11d47, 0x11d47, BidiClass::NSM),
+    (0x11d90, 0x11d91, BidiClass::NSM),
+    (0x11d95, 0x11d95, BidiClass::NSM),
+    (0x11d97, 0x11d97, BidiClass::NSM),
+    (0x11ef3, 0x11ef4, BidiClass::NSM),
+    (0x11f00, 0x11f01, BidiClass::NSM),
+    (0x11f36, 0x11f3a, BidiClass::NSM),
+    (0x11f40, 0x11f40, BidiClass::NSM),
+    (0x11f42, 0x11f42, BidiClass::NSM),
+    (0x11f5a, 0x11f5a, BidiClass::NSM),
+    (0x11fd5, 0x11fdc, BidiClass::ON),
+    (0x11fdd, 0x11fe0, BidiClass::ET),
+    (0x11fe1, 0x11ff1, BidiClass::ON),
+    (0x13440, 0x13440, BidiClass::NSM),
+    (0x13447, 0x13455, BidiClass::NSM),
+    (0x1611e, 0x16129, BidiClass::NSM),
+    (0x1612d, 0x1612f, BidiClass::NSM),
+    (0x16af0, 0x16af4, BidiClass::NSM),
+    (0x16b30, 0x16b36, BidiClass::NSM),
+    (0x16f4f, 0x16f4f, BidiClass::NSM),
+    (0x16f8f, 0x16f92, BidiClass::NSM),
+    (0x16fe2, 0x16fe2, BidiClass::ON),
+    (0x16fe4, 0x16fe4, BidiClass::NSM),
+    (0x1bc9d, 0x1bc9e, BidiClass::NSM),
+    (0x1bca0, 0x1bca3, BidiClass::BN),
+    (0x1cc00, 0x1ccd5, BidiClass::ON),
+    (0x1ccf0, 0x1ccf9, BidiClass::EN),
+    (0x1ccfa, 0x1ccfc, BidiClass::ON),
+    (0x1cd00, 0x1ceb3, BidiClass::ON),
+    (0x1ceba, 0x1ced0, BidiClass::ON),
+    (0x1cee0, 0x1cef0, BidiClass::ON),
+    (0x1cf00, 0x1cf2d, BidiClass::NSM),
+    (0x1cf30, 0x1cf46, BidiClass::NSM),
+    (0x1d167, 0x1d169, BidiClass::NSM),
+    (0x1d173, 0x1d17a, BidiClass::BN),
+    (0x1d17b, 0x1d182, BidiClass::NSM),
+    (0x1d185, 0x1d18b, BidiClass::NSM),
+    (0x1d1aa, 0x1d1ad, BidiClass::NSM),
+    (0x1d1e9, 0x1d1ea, BidiClass::ON),
+    (0x1d200, 0x1d241, BidiClass::ON),
+    (0x1d242, 0x1d244, BidiClass::NSM),
+    (0x1d245, 0x1d245, BidiClass::ON),
+    (0x1d300, 0x1d356, BidiClass::ON),
+    (0x1d6c1, 0x1d6c1, BidiClass::ON),
+    (0x1d6db, 0x1d6db, BidiClass::ON),
+    (0x1d6fb, 0x1d6fb, BidiClass::ON),
+    (0x1d715, 0x1d715, BidiClass::ON),
+    (0x1d735, 0x1d735, BidiClass::ON),
+    (0x1d74f, 0x1d74f, BidiClass::ON),
+    (0x1d76f, 0x1d76f, BidiClass::ON),
+    (0x1d789, 0x1d789, BidiClass::ON),
+    (0x1d7a9, 0x1d7a9, BidiClass::ON),
+    (0x1d7c3, 0x1d7c3, BidiClass::ON),
+    (0x1d7ce, 0x1d7ff, BidiClass::EN),
+    (0x1da00, 0x1da36, BidiClass::NSM),
+    (0x1da3b, 0x1da6c, BidiClass::NSM),
+    (0x1da75, 0x1da75, BidiClass::NSM),
+    (0x1da84, 0x1da84, BidiClass::NSM),
+    (0x1da9b, 0x1da9f, BidiClass::NSM),
+    (0x1daa1, 0x1daaf, BidiClass::NSM),
+    (0x1e000, 0x1e006, BidiClass::NSM),
+    (0x1e008, 0x1e018, BidiClass::NSM),
+    (0x1e01b, 0x1e021, BidiClass::NSM),
+    (0x1e023, 0x1e024, BidiClass::NSM),
+    (0x1e026, 0x1e02a, BidiClass::NSM),
+    (0x1e08f, 0x1e08f, BidiClass::NSM),
+    (0x1e130, 0x1e136, BidiClass::NSM),
+    (0x1e2ae, 0x1e2ae, BidiClass::NSM),
+    (0x1e2ec, 0x1e2ef, BidiClass::NSM),
+    (0x1e2ff, 0x1e2ff, BidiClass::ET),
+    (0x1e4ec, 0x1e4ef, BidiClass::NSM),
+    (0x1e5ee, 0x1e5ef, BidiClass::NSM),
+    (0x1e6e3, 0x1e6e3, BidiClass::NSM),
+    (0x1e6e6, 0x1e6e6, BidiClass::NSM),
+    (0x1e6ee, 0x1e6ef, BidiClass::NSM),
+    (0x1e6f5, 0x1e6f5, BidiClass::NSM),
+    (0x1e800, 0x1e8cf, BidiClass::R),
+    (0x1e8d0, 0x1e8d6, BidiClass::NSM),
+    (0x1e8d7, 0x1e943, BidiClass::R),
+    (0x1e944, 0x1e94a, BidiClass::NSM),
+    (0x1e94b, 0x1ec6f, BidiClass::R),
+    (0x1ec70, 0x1ecbf, BidiClass::AL),
+    (0x1ecc0, 0x1ecff, BidiClass::R),
+    (0x1ed00, 0x1ed4f, BidiClass::AL),
+    (0x1ed50, 0x1edff, BidiClass::R),
+    (0x1ee00, 0x1eeef, BidiClass::AL),
+    (0x1eef0, 0x1eef1, BidiClass::ON),
+    (0x1eef2, 0x1eeff, BidiClass::AL),
+    (0x1ef00, 0x1efff, BidiClass::R),
+    (0x1f000, 0x1f02b, BidiClass::ON),
+    (0x1f030, 0x1f093, BidiClass::ON),
+    (0x1f0a0, 0x1f0ae, BidiClass::ON),
+    (0x1f0b1, 0x1f0bf, BidiClass::ON),
+    (0x1f0c1, 0x1f0cf, BidiClass::ON),
+    (0x1f0d1, 0x1f0f5, BidiClass::ON),
+    (0x1f100, 0x1f10a, BidiClass::EN),
+    (0x1f10b, 0x1f10f, BidiClass::ON),
+    (0x1f12f, 0x1f12f, BidiClass::ON),
+    (0x1f16a, 0x1f16f, BidiClass::ON),
+    (0x1f1ad, 0x1f1ad, BidiClass::ON),
+    (0x1f260, 0x1f265, BidiClass::ON),
+    (0x1f300, 0x1f6d8, BidiClass::ON),
+    (0x1f6dc, 0x1f6ec, BidiClass::ON),
+    (0x1f6f0, 0x1f6fc, BidiClass::ON),
+    (0x1f700, 0x1f7d9, BidiClass::ON),
+    (0x1f7e0, 0x1f7eb, BidiClass::ON),
+    (0x1f7f0, 0x1f7f0, BidiClass::ON),
+    (0x1f800, 0x1f80b, BidiClass::ON),
+    (0x1f810, 0x1f847, BidiClass::ON),
+    (0x1f850, 0x1f859, BidiClass::ON),
+    (0x1f860, 0x1f887, BidiClass::ON),
+    (0x1f890, 0x1f8ad, BidiClass::ON),
+    (0x1f8b0, 0x1f8bb, BidiClass::ON),
+    (0x1f8c0, 0x1f8c1, BidiClass::ON),
+    (0x1f8d0, 0x1f8d8, BidiClass::ON),
+    (0x1f900, 0x1fa57, BidiClass::ON),
+    (0x1fa60, 0x1fa6d, BidiClass::ON),
+    (0x1fa70, 0x1fa7c, BidiClass::ON),
+    (0x1fa80, 0x1fa8a, BidiClass::ON),
+    (0x1fa8e, 0x1fac6, BidiClass::ON),
+    (0x1fac8, 0x1fac8, BidiClass::ON),
+    (0x1facd, 0x1fadc, BidiClass::ON),
+    (0x1fadf, 0x1faea, BidiClass::ON),
+    (0x1faef, 0x1faf8, BidiClass::ON),
+    (0x1fb00, 0x1fb92, BidiClass::ON),
+    (0x1fb94, 0x1fbef, BidiClass::ON),
+    (0x1fbf0, 0x1fbf9, BidiClass::EN),
+    (0x1fbfa, 0x1fbfa, BidiClass::ON),
+    (0x1fffe, 0x1ffff, BidiClass::BN),
+    (0x2fffe, 0x2ffff, BidiClass::BN),
+    (0x3fffe, 0x3ffff, BidiClass::BN),
+    (0x4fffe, 0x4ffff, BidiClass::BN),
+    (0x5fffe, 0x5ffff, BidiClass::BN),
+    (0x6fffe, 0x6ffff, BidiClass::BN),
+    (0x7fffe, 0x7ffff, BidiClass::BN),
+    (0x8fffe, 0x8ffff, BidiClass::BN),
+    (0x9fffe, 0x9ffff, BidiClass::BN),
+    (0xafffe, 0xaffff, BidiClass::BN),
+    (0xbfffe, 0xbffff, BidiClass::BN),
+    (0xcfffe, 0xcffff, BidiClass::BN),
+    (0xdfffe, 0xe00ff, BidiClass::BN),
+    (0xe0100, 0xe01ef, BidiClass::NSM),
+    (0xe01f0, 0xe0fff, BidiClass::BN),
+    (0xefffe, 0xeffff, BidiClass::BN),
+    (0xffffe, 0xfffff, BidiClass::BN),
+    (0x10fffe, 0x10ffff, BidiClass::BN),
+];
+
+// (char, mirrored char).
+pub(crate) static BIDI_MIRRORING: &[(u32, u32)] = &[
+    (0x0028, 0x0029),
+    (0x0029, 0x0028),
+    (0x003c, 0x003e),
+    (0x003e, 0x003c),
+    (0x005b, 0x005d),
+    (0x005d, 0x005b),
+    (0x007b, 0x007d),
+    (0x007d, 0x007b),
+    (0x00ab, 0x00bb),
+    (0x00bb, 0x00ab),
+    (0x0f3a, 0x0f3b),
+    (0x0f3b, 0x0f3a),
+    (0x0f3c, 0x0f3d),
+    (0x0f3d, 0x0f3c),
+    (0x169b, 0x169c),
+    (0x169c, 0x169b),
+    (0x2039, 0x203a),
+    (0x203a, 0x2039),
+    (0x2045, 0x2046),
+    (0x2046, 0x2045),
+    (0x207d, 0x207e),
+    (0x207e, 0x207d),
+    (0x208d, 0x208e),
+    (0x208e, 0x208d),
+    (0x2208, 0x220b),
+    (0x2209, 0x220c),
+    (0x220a, 0x220d),
+    (0x220b, 0x2208),
+    (0x220c, 0x2209),
+    (0x220d, 0x220a),
+    (0x2215, 0x29f5),
+    (0x221f, 0x2bfe),
+    (0x2220, 0x29a3),
+    (0x2221, 0x299b),
+    (0x2222, 0x29a0),
+    (0x2224, 0x2aee),
+    (0x223c, 0x223d),
+    (0x223d, 0x223c),
+    (0x2243, 0x22cd),
+    (0x2245, 0x224c),
+    (0x224c, 0x2245),
+    (0x2252, 0x2253),
+    (0x2253, 0x2252),
+    (0x2254, 0x2255),
+    (0x2255, 0x2254),
+    (0x2264, 0x2265),
+    (0x2265, 0x2264),
+    (0x2266, 0x2267),
+    (0x2267, 0x2266),
+    (0x2268, 0x2269),
+    (0x2269, 0x2268),
+    (0x226a, 0x226b),
+    (0x226b, 0x226a),
+    (0x226e, 0x226f),
+    (0x226f, 0x226e),
+    (0x2270, 0x2271),
+    (0x2271, 0x2270),
+    (0x2272, 0x2273),
+    (0x2273, 0x2272),
+    (0x2274, 0x2275),
+    (0x2275, 0x2274),
+    (0x2276, 0x2277),
+    (0x2277, 0x2276),
+    (0x2278, 0x2279),
+    (0x2279, 0x2278),
+    (0x227a, 0x227b),
+    (0x227b, 0x227a),
+    (0x227c, 0x227d),
+    (0x227d, 0x227c),
+    (0x227e, 0x227f),
+    (0x227f, 0x227e),
+    (0x2280, 0x2281),
+    (0x2281, 0x2280),
+    (0x2282, 0x2283),
+    (0x2283, 0x2282),
+    (0x2284, 0x2285),
+    (0x2285, 0x2284),
+    (0x2286, 0x2287),
+    (0x2287, 0x2286),
+    (0x2288, 0x2289),
+    (0x2289, 0x2288),
+    (0x228a, 0x228b),
+    (0x228b, 0x228a),
+    (0x228f, 0x2290),
+    (0x2290, 0x228f),
+    (0x2291, 0x2292),
+    (0x2292, 0x2291),
+    (0x2298, 0x29b8),
+    (0x22a2, 0x22a3),
+    (0x22a3, 0x22a2),
+    (0x22a6, 0x2ade),
+    (0x22a8, 0x2ae4),
+    (0x22a9, 0x2ae3),
+    (0x22ab, 0x2ae5),
+    (0x22b0, 0x22b1),
+    (0x22b1, 0x22b0),
+    (0x22b2, 0x22b3),
+    (0x22b3, 0x22b2),
+    (0x22b4, 0x22b5),
+    (0x22b5, 0x22b4),
+    (0x22b6, 0x22b7),
+    (0x22b7, 0x22b6),
+    (0x22b8, 0x27dc),
+    (0x22c9, 0x22ca),
+    (0x22ca, 0x22c9),
+    (0x22cb, 0x22cc),
+    (0x22cc, 0x22cb),
+    (0x22cd, 0x2243),
+    (0x22d0, 0x22d1),
+    (0x22d1, 0x22d0),
+    (0x22d6, 0x22d7),
+    (0x22d7, 0x22d6),
+    (0x22d8, 0x22d9),
+    (0x22d9, 0x22d8),
+    (0x22da, 0x22db),
+    (0x22db, 0x22da),
+    (0x22dc, 0x22dd),
+    (0x22dd, 0x22dc),
+    (0x22de, 0x22df),
+    (0x22df, 0x22de),
+    (0x22e0, 0x22e1),
+    (0x22e1, 0x22e0),
+    (0x22e2, 0x22e3),
+    (0x22e3, 0x22e2),
+    (0x22e4, 0x22e5),
+    (0x22e5, 0x22e4),
+    (0x22e6, 0x22e7),
+    (0x22e7, 0x22e6),
+    (0x22e8, 0x22e9),
+    (0x22e9, 0x22e8),
+    (0x22ea, 0x22eb),
+    (0x22eb, 0x22ea),
+    (0x22ec, 0x22ed),
+    (0x22ed, 0x22ec),
+    (0x22f0, 0x22f1),
+    (0x22f1, 0x22f0),
+    (0x22f2, 0x22fa),
+    (0x22f3, 0x22fb),
+    (0x22f4, 0x22fc),
+    (0x22f6, 0x22fd),
+    (0x22f7, 0x22fe),
+    (0x22fa, 0x22f2),
+    (0x22fb, 0x22f3),
+    (0x22fc, 0x22f4),
+    (0x22fd, 0x22f6),
+    (0x22fe, 0x22f7),
+    (0x2308, 0x2309),
+    (0x2309, 0x2308),
+    (0x230a, 0x230b),
+    (0x230b, 0x230a),
+    (0x2329, 0x232a),
+    (0x232a, 0x2329),
+    (0x2768, 0x2769),
+    (0x2769, 0x2768),
+    (0x276a, 0x276b),
+    (0x276b, 0x276a),
+    (0x276c, 0x276d),
+    (0x276d, 0x276c),
+    (0x276e, 0x276f),
+    (0x276f, 0x276e),
+    (0x2770, 0x2771),
+    (0x2771, 0x2770),
+    (0x2772, 0x2773),
+    (0x2773, 0x2772),
+    (0x2774, 0x2775),
+    (0x2775, 0x2774),
+    (0x27c3, 0x27c4),
+    (0x27c4, 0x27c3),
+    (0x27c5, 0x27c6),
+    (0x27c6, 0x27c5),
+    (0x27c8, 0x27c9),
+    (0x27c9, 0x27c8),
+    (0x27cb, 0x27cd),
+    (0x27cd, 0x27cb),
+    (0x27d5, 0x27d6),
+    (0x27d6, 0x27d5),
+    (0x27dc, 0x22b8),
+    (0x27dd, 0x27de),
+    (0x27de, 0x27dd),
+    (0x27e2, 0x27e3),
+    (0x27e3, 0x27e2),
+    (0x27e4, 0x27e5),
+    (0x27e5, 0x27e4),
+    (0x27e6, 0x27e7),
+    (0x27e7, 0x27e6),
+    (0x27e8, 0x27e9),
+    (0x27e9, 0x27e8),
+    (0x27ea, 0x27eb),
+    (0x27eb, 0x27ea),
+    (0x27ec, 0x27ed),
+    (0x27ed, 0x27ec),
+    (0x27ee, 0x27ef),
+    (0x27ef, 0x27ee),
+    (0x2983, 0x2984),
+    (0x2984, 0x2983),
+    (0x2985, 0x2986),
+    (0x2986, 0x2985),
+    (0x2987, 0x2988),
+    (0x2988, 0x2987),
+    (0x2989, 0x298a),
+    (0x298a, 0x2989),
+    (0x298b, 0x298c),
+    (0x298c, 0x298b),
+    (0x298d, 0x2990),
+    (0x298e, 0x298f),
+    (0x298f, 0x298e),
+    (0x2990, 0x298d),
+    (0x2991, 0x2992),
+    (0x2992, 0x2991),
+    (0x2993, 0x2994),
+    (0x2994, 0x2993),
+    (0x2995, 0x2996),
+    (0x2996, 0x2995),
+    (0x2997, 0x2998),
+    (0x2998, 0x2997),
+    (0x299b, 0x2221),
+    (0x29a0, 0x2222),
+    (0x29a3, 0x2220),
+    (0x29a4, 0x29a5),
+    (0x29a5, 0x29a4),
+    (0x29a8, 0x29a9),
+    (0x29a9, 0x29a8),
+    (0x29aa, 0x29ab),
+    (0x29ab, 0x29aa),
+    (0x29ac, 0x29ad),
+    (0x29ad, 0x29ac),
+    (0x29ae, 0x29af),
+    (0x29af, 0x29ae),
+    (0x29b8, 0x2298),
+    (0x29c0, 0x29c1),
+    (0x29c1, 0x29c0),
+    (0x29c4, 0x29c5),
+    (0x29c5, 0x29c4),
+    (0x29cf, 0x29d0),
+    (0x29d0, 0x29cf),
+    (0x29d1, 0x29d2),
+    (0x29d2, 0x29d1),
+    (0x29d4, 0x29d5),
+    (0x29d5, 0x29d4),
+    (0x29d8, 0x29d9),
+    (0x29d9, 0x29d8),
+    (0x29da, 0x29db),
+    (0x29db, 0x29da),
+    (0x29e8, 0x29e9),
+    (0x29e9, 0x29e8),
+    (0x29f5, 0x2215),
+    (0x29f8, 0x29f9),
+    (0x29f9, 0x29f8),
+    (0x29fc, 0x29fd),
+    (0x29fd, 0x29fc),
+    (0x2a2b, 0x2a2c),
+    (0x2a2c, 0x2a2b),
+    (0x2a2d, 0x2a2e),
+    (0x2a2e, 0x2a2d),
+    (0x2a34, 0x2a35),
+    (0x2a35, 0x2a34),
+    (0x2a3c, 0x2a3d),
+    (0x2a3d, 0x2a3c),
+    (0x2a64, 0x2a65),
+    (0x2a65, 0x2a64),
+    (0x2a79, 0x2a7a),
+    (0x2a7a, 0x2a79),
+    (0x2a7b, 0x2a7c),
+    (0x2a7c, 0x2a7b),
+    (0x2a7d, 0x2a7e),
+    (0x2a7e, 0x2a7d),
+    (0x2a7f, 0x2a80),
+    (0x2a80, 0x2a7f),
+    (0x2a81, 0x2a82),
+    (0x2a82, 0x2a81),
+    (0x2a83, 0x2a84),
+    (0x2a84, 0x2a83),
+    (0x2a85, 0x2a86),
+    (0x2a86, 0x2a85),
+    (0x2a87, 0x2a88),
+    (0x2a88, 0x2a87),
+    (0x2a89, 0x2a8a),
+    (0x2a8a, 0x2a89),
+    (0x2a8b, 0x2a8c),
+    (0x2a8c, 0x2a8b),
+    (0x2a8d, 0x2a8e),
+    (0x2a8e, 0x2a8d),
+    (0x2a8f, 0x2a90),
+    (0x2a90, 0x2a8f),
+    (0x2a91, 0x2a92),
+    (0x2a92, 0x2a91),
+    (0x2a93, 0x2a94),
+    (0x2a94, 0x2a93),
+    (0x2a95, 0x2a96),
+    (0x2a96, 0x2a95),
+    (0x2a97, 0x2a98),
+    (0x2a98, 0x2a97),
+    (0x2a99, 0x2a9a),
+    (0x2a9a, 0x2a99),
+    (0x2a9b, 0x2a9c),
+    (0x2a9c, 0x2a9b),
+    (0x2a9d, 0x2a9e),
+    (0x2a9e, 0x2a9d),
+    (0x2a9f, 0x2aa0),
+    (0x2aa0, 0x2a9f),
+    (0x2aa1, 0x2aa2),
+    (0x2aa2, 0x2aa1),
+    (0x2aa6, 0x2aa7),
+    (0x2aa7, 0x2aa6),
+    (0x2aa8, 0x2aa9),
+    (0x2aa9, 0x2aa8),
+    (0x2aaa, 0x2aab),
+    (0x2aab, 0x2aaa),
+    (0x2aac, 0x2aad),
+    (0x2aad, 0x2aac),
+    (0x2aaf, 0x2ab0),
+    (0x2ab0, 0x2aaf),
+    (0x2ab1, 0x2ab2),
+    (0x2ab2, 0x2ab1),
+    (0x2ab3, 0x2ab4),
+    (0x2ab4, 0x2ab3),
+    (0x2ab5, 0x2ab6),
+    (0x2ab6, 0x2ab5),
+    (0x2ab7, 0x2ab8),
+    (0x2ab8, 0x2ab7),
+    (0x2ab9, 0x2aba),
+    (0x2aba, 0x2ab9),
+    (0x2abb, 0x2abc),
+    (0x2abc, 0x2abb),
+    (0x2abd, 0x2abe),
+    (0x2abe, 0x2abd),
+    (0x2abf, 0x2ac0),
+    (0x2ac0, 0x2abf),
+    (0x2ac1, 0x2ac2),
+    (0x2ac2, 0x2ac1),
+    (0x2ac3, 0x2ac4),
+    (0x2ac4, 0x2ac3),
+    (0x2ac5, 0x2ac6),
+    (0x2ac6, 0x2ac5),
+    (0x2ac7, 0x2ac8),
+    (0x2ac8, 0x2ac7),
+    (0x2ac9, 0x2aca),
+    (0x2aca, 0x2ac9),
+    (0x2acb, 0x2acc),
+    (0x2acc, 0x2acb),
+    (0x2acd, 0x2ace),
+    (0x2ace, 0x2acd),
+    (0x2acf, 0x2ad0),
+    (0x2ad0, 0x2acf),
+    (0x2ad1, 0x2ad2),
+    (0x2ad2, 0x2ad1),
+    (0x2ad3, 0x2ad4),
+    (0x2ad4, 0x2ad3),
+    (0x2ad5, 0x2ad6),
+    (0x2ad6, 0x2ad5),
+    (0x2ade, 0x22a6),
+    (0x2ae3, 0x22a9),
+    (0x2ae4, 0x22a8),
+    (0x2ae5, 0x22ab),
+    (0x2aec, 0x2aed),
+    (0x2aed, 0x2aec),
+    (0x2aee, 0x2224),
+    (0x2af7, 0x2af8),
+    (0x2af8, 0x2af7),
+    (0x2af9, 0x2afa),
+    (0x2afa, 0x2af9),
+    (0x2bfe, 0x221f),
+    (0x2e02, 0x2e03),
+    (0x2e03, 0x2e02),
+    (0x2e04, 0x2e05),
+    (0x2e05, 0x2e04),
+    (0x2e09, 0x2e0a),
+    (0x2e0a, 0x2e09),
+    (0x2e0c, 0x2e0d),
+    (0x2e0d, 0x2e0c),
+    (0x2e1c, 0x2e1d),
+    (0x2e1d, 0x2e1c),
+    (0x2e20, 0x2e21),
+    (0x2e21, 0x2e20),
+    (0x2e22, 0x2e23),
+    (0x2e23, 0x2e22),
+    (0x2e24, 0x2e25),
+    (0x2e25, 0x2e24),
+    (0x2e26, 0x2e27),
+    (0x2e27, 0x2e26),
+    (0x2e28, 0x2e29),
+    (0x2e29, 0x2e28),
+    (0x2e55, 0x2e56),
+    (0x2e56, 0x2e55),
+    (0x2e57, 0x2e58),
+    (0x2e58, 0x2e57),
+    (0x2e59, 0x2e5a),
+    (0x2e5a, 0x2e59),
+    (0x2e5b, 0x2e5c),
+    (0x2e5c, 0x2e5b),
+    (0x3008, 0x3009),
+    (0x3009, 0x3008),
+    (0x300a, 0x300b),
+    (0x300b, 0x300a),
+    (0x300c, 0x300d),
+    (0x300d, 0x300c),
+    (0x300e, 0x300f),
+    (0x300f, 0x300e),
+    (0x3010, 0x3011),
+    (0x3011, 0x3010),
+    (0x3014, 0x3015),
+    (0x3015, 0x3014),
+    (0x3016, 0x3017),
+    (0x3017, 0x3016),
+    (0x3018, 0x3019),
+    (0x3019, 0x3018),
+    (0x301a, 0x301b),
+    (0x301b, 0x301a),
+    (0xfe59, 0xfe5a),
+    (0xfe5a, 0xfe59),
+    (0xfe5b, 0xfe5c),
+    (0xfe5c, 0xfe5b),
+    (0xfe5d, 0xfe5e),
+    (0xfe5e, 0xfe5d),
+    (0xfe64, 0xfe65),
+    (0xfe65, 0xfe64),
+    (0xff08, 0xff09),
+    (0xff09, 0xff08),
+    (0xff1c, 0xff1e),
+    (0xff1e, 0xff1c),
+    (0xff3b, 0xff3d),
+    (0xff3d, 0xff3b),
+    (0xff5b, 0xff5d),
+    (0xff5d, 0xff5b),
+    (0xff5f, 0xff60),
+    (0xff60, 0xff5f),
+    (0xff62, 0xff63),
+    (0xff63, 0xff62),
+];
+
+// (char, paired bracket, is opening).
+pub(crate) static BIDI_BRACKETS: &[(u32, u32, bool)] = &[
+    (0x0028, 0x0029, true),
+    (0x0029, 0x0028, false),
+    (0x005b, 0x005d, true),
+    (0x005d, 0x005b, false),
+    (0x007b, 0x007d, true),
+    (0x007d, 0x007b, false),
+    (0x0f3a, 0x0f3b, true),
+    (0x0f3b, 0x0f3a, false),
+    (0x0f3c, 0x0f3d, true),
+    (0x0f3d, 0x0f3c, false),
+    (0x169b, 0x169c, true),
+    (0x169c, 0x169b, false),
+    (0x2045, 0x2046, true),
+    (0x2046, 0x2045, false),
+    (0x207d, 0x207e, true),
+    (0x207e, 0x207d, false),
+    (0x208d, 0x208e, true),
+    (0x208e, 0x208d, false),
+    (0x2308, 0x2309, true),
+    (0x2309, 0x2308, false),
+    (0x230a, 0x230b, true),
+    (0x230b, 0x230a, false),
+    (0x2329, 0x232a, true),
+    (0x232a, 0x2329, false),
+    (0x2768, 0x2769, true),
+    (0x2769, 0x2768, false),
+    (0x276a, 0x276b, true),
+    (0x276b, 0x276a, false),
+    (0x276c, 0x276d, true),
+    (0x276d, 0x276c, false),
+    (0x276e, 0x276f, true),
+    (0x276f, 0x276e, false),
+    (0x2770, 0x2771, true),
+    (0x2771, 0x2770, false),
+    (0x2772, 0x2773, true),
+    (0x2773, 0x2772, false),
+    (0x2774, 0x2775, true),
+    (0x2775, 0x2774, false),
+    (0x27c5, 0x27c6, true),
+    (0x27c6, 0x27c5, false),
+    (0x27e6, 0x27e7, true),
+    (0x27e7, 0x27e6, false),
+    (0x27e8, 0x27e9, true),
+    (0x27e9, 0x27e8, false),
+    (0x27ea, 0x27eb, true),
+    (0x27eb, 0x27ea, false),
+    (0x27ec, 0x27ed, true),
+    (0x27ed, 0x27ec, false),
+    (0x27ee, 0x27ef, true),
+    (0x27ef, 0x27ee, false),
+    (0x2983, 0x2984, true),
+    (0x2984, 0x2983, false),
+    (0x2985, 0x2986, true),
+    (0x2986, 0x2985, false),
+    (0x2987, 0x2988, true),
+    (0x2988, 0x2987, false),
+    (0x2989, 0x298a, true),
+    (0x298a, 0x2989, false),
+    (0x298b, 0x298c, true),
+    (0x298c, 0x298b, false),
+    (0x298d, 0x2990, true),
+    (0x298e, 0x298f, false),
+    (0x298f, 0x298e, true),
+    (0x2990, 0x298d, false),
+    (0x2991, 0x2992, true),
+    (0x2992, 0x2991, false),
+    (0x2993, 0x2994, true),
+    (0x2994, 0x2993, false),
+    (0x2995, 0x2996, true),
+    (0x2996, 0x2995, false),
+    (0x2997, 0x2998, true),
+    (0x2998, 0x2997, false),
+    (0x29d8, 0x29d9, true),
+    (0x29d9, 0x29d8, false),
+    (0x29da, 0x29db, true),
+    (0x29db, 0x29da, false),
+    (0x29fc, 0x29fd, true),
+    (0x29fd, 0x29fc, false),
+    (0x2e22, 0x2e23, true),
+    (0x2e23, 0x2e22, false),
+    (0x2e24, 0x2e25, true),
+    (0x2e25, 0x2e24, false),
+    (0x2e26, 0x2e27, true),
+    (0x2e27, 0x2e26, false),
+    (0x2e28, 0x2e29, true),
+    (0x2e29, 0x2e28, false),
+    (0x2e55, 0x2e56, true),
+    (0x2e56, 0x2e55, false),
+    (0x2e57, 0x2e58, true),
+    (0x2e58, 0x2e57, false),
+    (0x2e59, 0x2e5a, true),
+    (0x2e5a, 0x2e59, false),
+    (0x2e5b, 0x2e5c, true),
+    (0x2e5c, 0x2e5b, false),
+    (0x3008, 0x3009, true),
+    (0x3009, 0x3008, false),
+    (0x300a, 0x300b, true),
+    (0x300b, 0x300a, false),
+    (0x300c, 0x300d, true),
+    (0x300d, 0x300c, false),
+    (0x300e, 0x300f, true),
+    (0x300f, 0x300e, false),
+    (0x3010, 0x3011, true),
+    (0x3011, 0x3010, false),
+    (0x3014, 0x3015, true),
+    (0x3015, 0x3014, false),
+    (0x3016, 0x3017, true),
+    (0x3017, 0x3016, false),
+    (0x3018, 0x3019, true),
+    (0x3019, 0x3018, false),
+    (0x301a, 0x301b, true),
+    (0x301b, 0x301a, false),
+    (0xfe59, 0xfe5a, true),
+    (0xfe5a, 0xfe59, false),
+    (0xfe5b, 0xfe5c, true),
+    (0xfe5c, 0xfe5b, false),
+    (0xfe5d, 0xfe5e, true),
+    (0xfe5e, 0xfe5d, false),
+    (0xff08, 0xff09, true),
+    (0xff09, 0xff08, false),
+    (0xff3b, 0xff3d, true),
+    (0xff3d, 0xff3b, false),
+    (0xff5b, 0xff5d, true),
+    (0xff5d, 0xff5b, false),
+    (0xff5f, 0xff60, true),
+    (0xff60, 0xff5f, false),
+    (0xff62, 0xff63, true),
+    (0xff63, 0xff62, false),
+];
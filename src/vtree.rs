@@ -0,0 +1,259 @@
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::common::Style;
+use crate::gui::*;
+
+
+/// a lightweight description of a node & its children.
+/// built every frame, `VTree::update` applies the differences to the `Gui`.
+pub struct Element {
+    pub kind:     NodeKind,
+    /// identifies the element among its siblings, across updates.
+    /// unkeyed elements are matched by position.
+    pub key:      Option<String>,
+    pub style:    Style,
+    /// only for `NodeKind::Text`.
+    pub text:     String,
+    /// only for `NodeKind::Button`.
+    pub on_click: Option<Rc<dyn EventHandler>>,
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    pub fn new(kind: NodeKind) -> Element {
+        Element {
+            kind,
+            key:      None,
+            style:    Style::new(),
+            text:     String::new(),
+            on_click: None,
+            children: vec![],
+        }
+    }
+
+    pub fn text(value: &str) -> Element {
+        let mut result = Element::new(NodeKind::Text);
+        result.text = value.into();
+        result
+    }
+
+    pub fn key<K: Into<String>>(mut self, key: K) -> Element {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// panics, if the element is a `NodeKind::Text`.
+    pub fn style(mut self, style: &[(&str, &str)]) -> Element {
+        assert!(self.kind != NodeKind::Text, "style on a Text element, text can't be styled");
+        for (k, v) in style {
+            self.style.insert(k.to_string(), v.to_string());
+        }
+        self
+    }
+
+    /// panics, if the element isn't a `NodeKind::Button`.
+    pub fn on_click<H: EventHandler>(mut self, handler: H) -> Element {
+        assert!(self.kind == NodeKind::Button, "on_click on a {:?} element, only buttons are clickable", self.kind);
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn child(mut self, child: Element) -> Element {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children<C: IntoIterator<Item=Element>>(mut self, children: C) -> Element {
+        self.children.extend(children);
+        self
+    }
+}
+
+
+/// keeps the children of a node in sync with a list of `Element`s.
+///
+/// matched elements keep their nodes, so hover, focus & scroll positions survive.
+/// the vtree owns the children of its parent, don't modify them directly.
+pub struct VTree {
+    parent:   Node,
+    children: Vec<Mounted>,
+}
+
+// the node's click handler reads this,
+// so handlers can be replaced & removed.
+type HandlerSlot = Rc<RefCell<Option<Rc<dyn EventHandler>>>>;
+
+struct Mounted {
+    node:     Node,
+    kind:     NodeKind,
+    key:      Option<String>,
+    style:    Style,
+    text:     String,
+    on_click: Option<HandlerSlot>,
+    children: Vec<Mounted>,
+}
+
+impl VTree {
+    pub fn new(parent: Node) -> VTree {
+        VTree { parent, children: vec![] }
+    }
+
+    #[inline]
+    pub fn parent(&self) -> Node {
+        self.parent
+    }
+
+    pub fn update(&mut self, gui: &mut Gui, elements: Vec<Element>) {
+        let old = core::mem::take(&mut self.children);
+        self.children = reconcile_children(gui, self.parent, old, elements);
+    }
+}
+
+
+fn reconcile_children(gui: &mut Gui, parent: Node, old: Vec<Mounted>, new: Vec<Element>) -> Vec<Mounted> {
+    // match keyed elements by key, unkeyed ones by position.
+    let mut keyed   = HashMap::new();
+    let mut unkeyed = vec![];
+    for (i, m) in old.iter().enumerate() {
+        match &m.key {
+            Some(key) => { keyed.insert(key.clone(), i); }
+            None      => unkeyed.push(i),
+        }
+    }
+
+    let mut old: Vec<Option<Mounted>> = old.into_iter().map(Some).collect();
+    let mut unkeyed = unkeyed.into_iter();
+    let sources: Vec<Option<usize>> = new.iter().map(|element| {
+        let i = match &element.key {
+            Some(key) => keyed.remove(key),
+            None      => unkeyed.next(),
+        }?;
+        let m = old[i].as_ref()?;
+        (m.kind == element.kind).then_some(i)
+    }).collect();
+
+    let mut matched: Vec<Option<Mounted>> = sources.iter().map(|i| i.and_then(|i| old[i].take())).collect();
+
+    // destroy the rest.
+    for m in old.into_iter().flatten() {
+        gui.remove_child(parent, m.node, false);
+    }
+
+    // the longest run of nodes, that are already in order, stays.
+    // the others are moved or inserted, back to front.
+    let stays = longest_increasing(&sources);
+    let mut result = Vec::with_capacity(new.len());
+    let mut next = None;
+    for (i, element) in new.into_iter().enumerate().rev() {
+        let m = match matched[i].take() {
+            Some(m) => patch(gui, m, element),
+            None    => create(gui, element),
+        };
+        if !stays[i] {
+            gui.insert_before_child(parent, next, m.node);
+        }
+        next = Some(m.node);
+        result.push(m);
+    }
+    result.reverse();
+    result
+}
+
+fn create(gui: &mut Gui, element: Element) -> Mounted {
+    let node =
+        if element.kind == NodeKind::Text {
+            gui.create_text(&element.text)
+        }
+        else {
+            let node = gui.create_node(element.kind);
+            if !element.style.is_empty() {
+                gui.set_style(node, element.style.clone());
+            }
+            node
+        };
+
+    let mut on_click = None;
+    set_handler(gui, node, element.kind, &mut on_click, element.on_click);
+
+    let children = reconcile_children(gui, node, vec![], element.children);
+
+    Mounted {
+        node,
+        kind:  element.kind,
+        key:   element.key,
+        style: element.style,
+        text:  element.text,
+        on_click,
+        children,
+    }
+}
+
+fn patch(gui: &mut Gui, mut m: Mounted, element: Element) -> Mounted {
+    if m.style != element.style {
+        gui.set_style(m.node, element.style.clone());
+        m.style = element.style;
+    }
+    if m.text != element.text {
+        gui.set_text(m.node, element.text.clone());
+        m.text = element.text;
+    }
+    set_handler(gui, m.node, m.kind, &mut m.on_click, element.on_click);
+
+    m.children = reconcile_children(gui, m.node, m.children, element.children);
+    m
+}
+
+fn set_handler(gui: &mut Gui, node: Node, kind: NodeKind, slot: &mut Option<HandlerSlot>, handler: Option<Rc<dyn EventHandler>>) {
+    // the field is public, `Element::on_click` checks this too.
+    if kind != NodeKind::Button {
+        debug_assert!(handler.is_none(), "on_click on a {:?} element, only buttons are clickable", kind);
+        return;
+    }
+
+    if let Some(slot) = slot {
+        *slot.borrow_mut() = handler;
+    }
+    else if handler.is_some() {
+        let new_slot: HandlerSlot = Rc::new(RefCell::new(handler));
+        let reader = new_slot.clone();
+        gui.set_on_click(node, move |gui: &mut Gui, event: &mut Event| {
+            // the handler may update the vtree.
+            let handler = reader.borrow().clone();
+            if let Some(handler) = handler {
+                handler(gui, event);
+            }
+        });
+        *slot = Some(new_slot);
+    }
+}
+
+/// marks the longest strictly increasing subsequence of the `Some` values.
+fn longest_increasing(values: &[Option<usize>]) -> Vec<bool> {
+    // `tails[k]`: index of the smallest tail of an increasing run of length `k + 1`.
+    let mut tails: Vec<usize> = vec![];
+    let mut prev = vec![usize::MAX; values.len()];
+    for (i, value) in values.iter().enumerate() {
+        let Some(value) = *value else { continue };
+
+        let k = tails.partition_point(|t| values[*t].unwrap() < value);
+        if k > 0 {
+            prev[i] = tails[k - 1];
+        }
+        if k == tails.len() {
+            tails.push(i);
+        }
+        else {
+            tails[k] = i;
+        }
+    }
+
+    let mut result = vec![false; values.len()];
+    let mut at = tails.last().copied().unwrap_or(usize::MAX);
+    while at != usize::MAX {
+        result[at] = true;
+        at = prev[at];
+    }
+    result
+}
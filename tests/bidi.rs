@@ -0,0 +1,74 @@
+use pogui::unicode::*;
+use pogui::unicode::bidi::*;
+
+
+/// runs the official conformance test, BidiCharacterTest.txt.
+#[test]
+fn bidi_character_test() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/unicode/BidiCharacterTest.txt");
+    let data = std::fs::read_to_string(path).unwrap();
+
+    let mut failures = vec![];
+    let mut count = 0;
+    for (line_index, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // eg: `05D0 0028 0061 0029;2;1;1 1 2 1;3 2 1 0`
+        let fields: Vec<&str> = line.split(';').collect();
+        let chars: Vec<char> = fields[0].split_whitespace()
+            .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+            .collect();
+        let expected_paragraph: Level = fields[2].parse().unwrap();
+        let expected_levels: Vec<Option<Level>> = fields[3].split_whitespace()
+            .map(|level| level.parse().ok())
+            .collect();
+        let expected_order: Vec<usize> = fields[4].split_whitespace()
+            .map(|index| index.parse().unwrap())
+            .collect();
+
+        let paragraph = match fields[1] {
+            "0" => 0,
+            "1" => 1,
+            _   => paragraph_level(&chars),
+        };
+        let mut levels = resolve_levels(&chars, paragraph);
+        reset_line_levels(&chars, &mut levels, paragraph);
+
+        // removed chars are `x` & don't appear in the order.
+        let levels: Vec<Option<Level>> = chars.iter().zip(&levels)
+            .map(|(c, level)| (!is_removed(bidi_class(*c))).then_some(*level))
+            .collect();
+        let kept: Vec<usize> = (0..chars.len()).filter(|i| levels[*i].is_some()).collect();
+        let kept_levels: Vec<Level> = kept.iter().map(|i| levels[*i].unwrap()).collect();
+        let order: Vec<usize> = visual_order(&kept_levels).iter().map(|k| kept[*k]).collect();
+
+        if paragraph != expected_paragraph || levels != expected_levels || order != expected_order {
+            failures.push(format!("line {}: {}\n  actual {}; {:?}; {:?}", line_index + 1, line, paragraph, levels, order));
+        }
+        count += 1;
+    }
+
+    assert!(count > 90000);
+    assert!(failures.is_empty(), "{} of {} failed:\n{}", failures.len(), count, failures[..failures.len().min(20)].join("\n"));
+}
+
+#[test]
+fn mirroring() {
+    assert_eq!(mirrored('(', 1), ')');
+    assert_eq!(mirrored('(', 0), '(');
+    assert_eq!(mirrored('\u{226e}', 1), '\u{226f}');
+    assert_eq!(mirrored('a', 1), 'a');
+}
+
+#[test]
+fn reordering() {
+    // latin, then hebrew with a number in brackets.
+    let chars: Vec<char> = "ab \u{5d0}\u{5d1}(1)".chars().collect();
+    assert_eq!(paragraph_level(&chars), 0);
+    let levels = resolve_levels(&chars, 0);
+    assert_eq!(levels, [0, 0, 0, 1, 1, 1, 2, 1]);
+    assert_eq!(visual_order(&levels), [0, 1, 2, 7, 6, 5, 4, 3]);
+}
@@ -0,0 +1,37 @@
+// helpers shared by the integration tests.
+// each test crate uses only some of them.
+#![allow(dead_code)]
+
+use pogui::gui::*;
+
+
+/// a `kind` node with `children` & `style`.
+pub fn node<C: IntoIterator<Item=Node>>(gui: &mut Gui, kind: NodeKind, children: C, style: &[(&str, &str)]) -> Node {
+    let node = gui.create_node(kind);
+    gui.set_children(node, children);
+    gui.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    node
+}
+
+/// a `kind` node with `children`, an `id` & `classes`.
+pub fn tagged<C: IntoIterator<Item=Node>>(g: &mut Gui, kind: NodeKind, id: Option<&str>, classes: &[&str], children: C) -> Node {
+    let node = g.create_node(kind);
+    g.set_children(node, children);
+    g.set_id(node, id.map(String::from));
+    for class in classes {
+        g.add_class(node, class);
+    }
+    node
+}
+
+/// the children of `parent`, by walking the sibling links.
+pub fn children(g: &Gui, parent: Node) -> Vec<Node> {
+    let mut result = vec![];
+    let mut at = g.get_first_child(parent);
+    while let Some(child) = at {
+        assert_eq!(g.get_parent(child), Some(parent));
+        result.push(child);
+        at = g.get_next_sibling(child);
+    }
+    result
+}
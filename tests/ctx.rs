@@ -0,0 +1,51 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::text::FontFallbackId;
+
+
+fn text_scene(g: &mut Gui) {
+    let text = g.create_text("shared fonts");
+    let span = g.create_node(NodeKind::Span);
+    g.set_children(span, [text]);
+    let root = g.root();
+    g.set_children(root, [span]);
+}
+
+
+#[test]
+fn shared_ctx() {
+    let mut a = HeadlessGui::new(200.0, 100.0);
+    let mut b = HeadlessGui::new_with_ctx(a.ctx().clone(), 200.0, 100.0);
+
+    // fonts state is shared.
+    let family = a.query_font("DejaVu Sans").unwrap_or_default();
+    assert_eq!(a.add_font_fallback(&[family]), FontFallbackId(0));
+    assert_eq!(b.add_font_fallback(&[family, family]), FontFallbackId(1));
+    assert_eq!(b.add_font_fallback(&[family]), FontFallbackId(0));
+
+    // `b` keeps the context alive.
+    drop(a);
+    text_scene(&mut b);
+    b.update();
+    b.record();
+}
+
+#[test]
+fn separate_ctx() {
+    let mut a = HeadlessGui::new(200.0, 100.0);
+    let mut b = HeadlessGui::new(200.0, 100.0);
+
+    let family = a.query_font("DejaVu Sans").unwrap_or_default();
+    assert_eq!(a.add_font_fallback(&[family]), FontFallbackId(0));
+    assert_eq!(b.add_font_fallback(&[family, family]), FontFallbackId(0));
+}
+
+#[test]
+fn many_guis() {
+    // contexts are freed with their last `Gui`.
+    for _ in 0..200 {
+        let mut g = HeadlessGui::new(200.0, 100.0);
+        text_scene(&mut g);
+        g.update();
+    }
+}
@@ -0,0 +1,104 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::paint::record::*;
+
+mod common;
+use common::node;
+
+
+/// compares against `tests/data/golden/<name>.txt`.
+/// run with `UPDATE_GOLDEN=1` to write the files instead.
+fn check_golden(name: &str, commands: &[DrawCommand]) {
+    let path = format!("{}/tests/data/golden/{}.txt", env!("CARGO_MANIFEST_DIR"), name);
+    let actual = display_list_to_string(commands);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    if actual != expected {
+        let line = actual.lines().zip(expected.lines())
+            .position(|(a, e)| a != e)
+            .unwrap_or(actual.lines().count().min(expected.lines().count()));
+        panic!("{} differs from the golden file at line {}:\n  actual:   {:?}\n  expected: {:?}\n(run with UPDATE_GOLDEN=1 to update)",
+            name, line + 1, actual.lines().nth(line), expected.lines().nth(line));
+    }
+}
+
+#[cfg(not(windows))]
+fn with_test_font(g: &mut HeadlessGui) {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/fonts/DejaVuSans.ttf");
+    let font = g.add_font_file(path).unwrap();
+    g.set_default_font(font);
+}
+
+
+#[test]
+fn buttons() {
+    let mut g = HeadlessGui::new(400.0, 200.0);
+
+    let block = [("display", "block"), ("width", "50"), ("height", "30"), ("background_color", "ff0000")];
+    let a = node(&mut g, NodeKind::Button, [], &block);
+    let b = node(&mut g, NodeKind::Button, [], &block);
+    let c = node(&mut g, NodeKind::Button, [], &block);
+    let root = g.root();
+    g.set_children(root, [a, b, c]);
+
+    // b is focused, c is hovered & pressed.
+    g.click(10.0, 40.0);
+    g.on_mouse_move(10.0, 70.0);
+    g.on_mouse_down(10.0, 70.0);
+    g.on_char('\t', true);
+
+    let commands = g.record();
+    assert!(commands.contains(&DrawCommand::StrokeRect {
+        rect: pogui::paint::Rect { min: [-1.0, 29.0], max: [51.0, 61.0] },
+        color: pogui::paint::Color::rgb(0.5, 0.8, 1.0),
+        width: 2.0,
+    }));
+    check_golden("buttons", &commands);
+}
+
+#[test]
+fn scrollbars() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let items: Vec<Node> = (0..10).map(|i| {
+        let color = if i % 2 == 0 { "eeeeee" } else { "dddddd" };
+        node(&mut g, NodeKind::Div, [], &[("height", "30"), ("width", "300"), ("background_color", color)])
+    }).collect();
+    let list = node(&mut g, NodeKind::Div, items.clone(), &[("height", "100")]);
+    let root = g.root();
+    g.set_children(root, [list]);
+
+    g.hover(items[0]);
+    g.on_mouse_wheel(-60.0, false);
+    g.on_mouse_wheel(-30.0, true);
+
+    let commands = g.record();
+    assert!(commands.iter().filter(|c| matches!(c, DrawCommand::PushClip { .. })).count() == 1);
+    check_golden("scrollbars", &commands);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn text() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    with_test_font(&mut g);
+
+    let hello = g.create_text("hello, ");
+    let world = g.create_text("world");
+    let red   = node(&mut g, NodeKind::Span, [world], &[("text_color", "ff0000")]);
+    let label = g.create_text("ok");
+    let button = node(&mut g, NodeKind::Button, [label], &[]);
+    let wrapped = g.create_text("this line is long enough to wrap at the root's width.");
+    let root = g.root();
+    g.set_children(root, [hello, red, button, wrapped]);
+
+    let commands = g.record();
+    let runs = commands.iter().filter(|c| matches!(c, DrawCommand::Glyphs { .. })).count();
+    assert!(runs >= 4);
+    check_golden("text", &commands);
+}
@@ -0,0 +1,111 @@
+// `Fonts::new` takes the DirectWrite factory on windows.
+#![cfg(not(windows))]
+
+use pogui::text::*;
+
+
+fn fonts_dir() -> std::path::PathBuf {
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/fonts").into()
+}
+
+
+#[test]
+fn add_file() {
+    let mut fonts = Fonts::new();
+    let id = fonts.add_file(&fonts_dir().join("DejaVuSans.ttf")).unwrap();
+    assert_eq!(fonts.font_data(id).name_utf8, "DejaVu Sans");
+
+    let face = fonts.face(id, 400, false).unwrap();
+    let file = face.file.as_ref().unwrap();
+    assert_eq!(file.family_name().as_deref(), Some("DejaVu Sans"));
+
+    // faces are loaded once.
+    let again = fonts.face(id, 700, true).unwrap();
+    assert!(core::ptr::eq(&*face, &*again));
+
+    // not a font.
+    assert_eq!(fonts.add_file(&fonts_dir().join("LICENSE")), None);
+    assert_eq!(fonts.add_file(&fonts_dir().join("missing.ttf")), None);
+}
+
+#[test]
+fn add_data() {
+    let data = std::fs::read(fonts_dir().join("DejaVuSans.ttf")).unwrap();
+
+    let mut fonts = Fonts::new();
+    let id = fonts.add_data(&data).unwrap();
+    assert!(fonts.face(id, 400, false).is_some());
+
+    // same family.
+    assert_eq!(fonts.add_data(&data), Some(id));
+    assert_eq!(fonts.font_data(id).faces.len(), 2);
+
+    assert_eq!(fonts.add_data(b"not a font"), None);
+    assert_eq!(fonts.add_data(&data[..100]), None);
+}
+
+#[test]
+fn add_dir() {
+    let mut fonts = Fonts::new();
+    assert_eq!(fonts.add_dir(&fonts_dir()), 1);
+    assert_eq!(fonts.add_dir(&fonts_dir().join("missing")), 0);
+}
+
+#[test]
+fn default_family() {
+    let mut fonts = Fonts::new();
+    assert!(fonts.face(FontFamilyId::DEFAULT, 400, false).is_none());
+
+    // the first family, until changed.
+    let id = fonts.add_file(&fonts_dir().join("DejaVuSans.ttf")).unwrap();
+    assert_eq!(fonts.default_family(), id);
+    assert!(fonts.face(FontFamilyId::DEFAULT, 400, false).is_some());
+
+    fonts.set_default_family(id);
+    assert_eq!(fonts.font_data(FontFamilyId::DEFAULT).name_utf8, "DejaVu Sans");
+}
+
+#[test]
+fn fallback_chains() {
+    let mut fonts = Fonts::new();
+    let id = fonts.add_file(&fonts_dir().join("DejaVuSans.ttf")).unwrap();
+
+    let a = fonts.add_fallback(&[id]);
+    assert_eq!(fonts.add_fallback(&[id]), a);
+    assert_ne!(fonts.add_fallback(&[id, id]), a);
+    assert_eq!(fonts.fallback(a), &[id]);
+    assert_eq!(fonts.fallback(FontFallbackId::NONE), &[]);
+
+    // an unknown family, without faces.
+    let format = TextFormat { font: FontFamilyId(7), ..Default::default() };
+    let runs = fonts.fallback_runs("ab", &format);
+    assert_eq!(runs.len(), 1);
+    assert!(runs[0].1.is_none());
+
+    // the format's chain, then the global chain.
+    let runs = fonts.fallback_runs("ab", &TextFormat { fallback: a, ..format });
+    assert!(runs[0].1.is_some());
+
+    fonts.set_global_fallback(&[id]);
+    let runs = fonts.fallback_runs("ab", &format);
+    assert!(runs[0].1.is_some());
+}
+
+#[test]
+fn fallback_runs() {
+    let mut fonts = Fonts::new();
+    fonts.add_file(&fonts_dir().join("DejaVuSans.ttf")).unwrap();
+    let format = TextFormat::default();
+
+    let ends = |runs: Vec<(usize, Option<FontFace>)>| -> Vec<(usize, bool)> {
+        runs.into_iter().map(|(end, face)| (end, face.is_some())).collect()
+    };
+
+    // DejaVu Sans has no CJK. spaces stay in the current run.
+    let runs = fonts.fallback_runs("ab \u{6f22}\u{5b57} cd", &format);
+    assert_eq!(ends(runs), [(3, true), (9, false), (12, true)]);
+
+    // diacritics stay with their base char.
+    let runs = fonts.fallback_runs("e\u{301}\u{6f22}\u{301}b", &format);
+    assert_eq!(ends(runs), [(3, true), (8, false), (9, true)]);
+}
@@ -0,0 +1,155 @@
+use core::cell::Cell;
+use std::rc::Rc;
+
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::paint::Rect;
+
+
+fn div<C: IntoIterator<Item=Node>>(gui: &mut Gui, children: C, style: &[(&str, &str)]) -> Node {
+    let node = gui.create_node(NodeKind::Div);
+    gui.set_children(node, children);
+    gui.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    node
+}
+
+fn button(gui: &mut Gui, style: &[(&str, &str)], clicks: &Rc<Cell<u32>>) -> Node {
+    let node = gui.create_node(NodeKind::Button);
+    gui.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    let clicks = clicks.clone();
+    gui.set_on_click(node, move |_, _| clicks.set(clicks.get() + 1));
+    node
+}
+
+const BLOCK: &[(&str, &str)] = &[("display", "block"), ("width", "50"), ("height", "30")];
+
+
+#[test]
+fn layout() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let a = div(&mut g, [], &[("height", "20")]);
+    let b = div(&mut g, [], &[("height", "30"), ("width", "40")]);
+    let parent = div(&mut g, [a, b], &[]);
+    let root = g.root();
+    g.set_children(root, [parent]);
+    g.update();
+
+    // the root is half as wide as the window.
+    assert_eq!(g.get_rect(root), Rect { min: [0.0, 0.0], max: [200.0, 300.0] });
+    assert_eq!(g.get_rect(parent), Rect { min: [0.0, 0.0], max: [200.0, 50.0] });
+    assert_eq!(g.get_rect(a), Rect { min: [0.0, 0.0], max: [200.0, 20.0] });
+    assert_eq!(g.get_rect(b), Rect { min: [0.0, 20.0], max: [40.0, 50.0] });
+}
+
+#[test]
+fn hover_and_click() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let clicks = Rc::new(Cell::new(0));
+
+    let spacer = div(&mut g, [], &[("height", "100")]);
+    let b = button(&mut g, BLOCK, &clicks);
+    let root = g.root();
+    g.set_children(root, [spacer, b]);
+    g.update();
+    assert_eq!(g.get_rect(b), Rect { min: [0.0, 100.0], max: [50.0, 130.0] });
+
+    g.on_mouse_move(10.0, 10.0);
+    assert_eq!(g.get_hover(), Some(spacer));
+    assert_eq!(g.get_cursor(), Cursor::Default);
+
+    g.on_mouse_move(10.0, 110.0);
+    assert_eq!(g.get_hover(), Some(b));
+    assert_eq!(g.get_cursor(), Cursor::Pointer);
+
+    g.on_mouse_down(10.0, 110.0);
+    assert_eq!(g.get_active(), Some(b));
+    assert_eq!(g.get_focus(), Some(b));
+    g.on_mouse_up();
+    assert_eq!(g.get_active(), None);
+    assert_eq!(clicks.get(), 1);
+
+    // released elsewhere.
+    g.on_mouse_down(10.0, 110.0);
+    g.on_mouse_move(10.0, 10.0);
+    g.on_mouse_up();
+    assert_eq!(clicks.get(), 1);
+
+    g.click(10.0, 120.0);
+    assert_eq!(clicks.get(), 2);
+    g.click(10.0, 50.0);
+    assert_eq!(clicks.get(), 2);
+    assert_eq!(g.get_focus(), None);
+}
+
+#[test]
+fn tab_focus() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let clicks = Rc::new(Cell::new(0));
+
+    let a = button(&mut g, BLOCK, &clicks);
+    let b = button(&mut g, BLOCK, &clicks);
+    let root = g.root();
+    g.set_children(root, [a, b]);
+
+    g.on_char('\t', false);
+    assert_eq!(g.get_focus(), Some(a));
+    g.on_char('\t', false);
+    assert_eq!(g.get_focus(), Some(b));
+    g.on_char('\t', true);
+    assert_eq!(g.get_focus(), Some(a));
+
+    g.on_char('\r', false);
+    assert_eq!(clicks.get(), 1);
+}
+
+#[test]
+fn scrolling() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let items: Vec<Node> = (0..10).map(|_| div(&mut g, [], &[("height", "30")])).collect();
+    let list = div(&mut g, items.clone(), &[("height", "100")]);
+    let root = g.root();
+    g.set_children(root, [list]);
+
+    g.hover(items[1]);
+    assert_eq!(g.get_hover(), Some(items[1]));
+
+    g.on_mouse_wheel(-30.0, false);
+    assert_eq!(g.get_scroll_pos(list), [0.0, 30.0]);
+    g.update();
+    assert_eq!(g.get_rect(items[1]), Rect { min: [0.0, 0.0], max: [180.0, 30.0] });
+
+    // clamped to the content.
+    for _ in 0..20 {
+        g.on_mouse_wheel(-30.0, false);
+    }
+    assert_eq!(g.get_scroll_pos(list), [0.0, 200.0]);
+
+    // horizontal scrolling doesn't apply.
+    g.on_mouse_wheel(30.0, true);
+    assert_eq!(g.get_scroll_pos(list), [0.0, 200.0]);
+}
+
+#[test]
+fn inline_layout() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let clicks = Rc::new(Cell::new(0));
+
+    let label = g.create_text("ok");
+    let b = button(&mut g, &[], &clicks);
+    g.set_children(b, [label]);
+    let before = g.create_text("abc ");
+    let root = g.root();
+    g.set_children(root, [before, b]);
+
+    g.update();
+    let rect = g.get_rect(b);
+    assert!(rect.min[0] > 10.0 && rect.width() > 10.0 && rect.height() > 10.0);
+
+    g.hover(b);
+    assert_eq!(g.get_hover(), Some(b));
+    g.on_mouse_down(rect.min[0] + 1.0, rect.min[1] + 1.0);
+    g.on_mouse_up();
+    assert_eq!(clicks.get(), 1);
+}
@@ -0,0 +1,80 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::iter::Edge;
+
+
+// root
+//   a
+//     a1
+//     a2
+//       a21
+//   b
+fn tree(g: &mut Gui) -> [Node; 6] {
+    let [a, a1, a2, a21, b] = [0; 5].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(a2, [a21]);
+    g.set_children(a, [a1, a2]);
+    let root = g.root();
+    g.set_children(root, [a, b]);
+    [root, a, a1, a2, a21, b]
+}
+
+
+#[test]
+fn children() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let [root, a, a1, a2, _, b] = tree(&mut g);
+
+    assert_eq!(g.children(root).collect::<Vec<_>>(), [a, b]);
+    assert_eq!(g.children(a).collect::<Vec<_>>(), [a1, a2]);
+    assert_eq!(g.children_rev(a).collect::<Vec<_>>(), [a2, a1]);
+    assert_eq!(g.children(b).count(), 0);
+    assert_eq!(g.children_rev(b).count(), 0);
+}
+
+#[test]
+fn descendants_and_ancestors() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let [root, a, a1, a2, a21, b] = tree(&mut g);
+
+    assert_eq!(g.descendants(root).collect::<Vec<_>>(), [a, a1, a2, a21, b]);
+    // bounded to the subtree.
+    assert_eq!(g.descendants(a).collect::<Vec<_>>(), [a1, a2, a21]);
+    assert_eq!(g.descendants(a21).count(), 0);
+
+    assert_eq!(g.ancestors(a21).collect::<Vec<_>>(), [a2, a, root]);
+    assert_eq!(g.ancestors(root).count(), 0);
+
+    // detached.
+    let x = g.create_node(NodeKind::Div);
+    let y = g.create_node(NodeKind::Div);
+    g.set_children(x, [y]);
+    assert_eq!(g.ancestors(y).collect::<Vec<_>>(), [x]);
+    assert_eq!(g.descendants(x).collect::<Vec<_>>(), [y]);
+
+    // composes, while reading the tree.
+    let leaf = g.descendants(root).filter(|n| g.get_first_child(*n).is_none()).find(|n| g.get_parent(*n) == Some(a2));
+    assert_eq!(leaf, Some(a21));
+}
+
+#[test]
+fn traverse() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let [_, a, a1, a2, a21, b] = tree(&mut g);
+    use Edge::*;
+
+    assert_eq!(g.traverse(a).collect::<Vec<_>>(), [
+        Enter(a), Enter(a1), Leave(a1), Enter(a2), Enter(a21), Leave(a21), Leave(a2), Leave(a),
+    ]);
+    assert_eq!(g.traverse(b).collect::<Vec<_>>(), [Enter(b), Leave(b)]);
+
+    // depth from the events.
+    let mut depth = 0;
+    let mut max_depth = 0;
+    for edge in g.traverse(g.root()) {
+        match edge {
+            Enter(_) => { depth += 1; max_depth = max_depth.max(depth); }
+            Leave(_) => depth -= 1,
+        }
+    }
+    assert_eq!((depth, max_depth), (0, 4));
+}
@@ -0,0 +1,52 @@
+use pogui::unicode::line_break::*;
+
+
+/// runs the official conformance test, LineBreakTest.txt.
+#[test]
+fn line_break_test() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/unicode/LineBreakTest.txt");
+    let data = std::fs::read_to_string(path).unwrap();
+
+    let mut failures = vec![];
+    let mut count = 0;
+    for (line_index, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // eg: `× 0023 × 0308 ÷ 0009 ÷`
+        let mut text = String::new();
+        let mut expected = vec![];
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => expected.push(text.len()),
+                "×" => (),
+                cp  => text.push(char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap()),
+            }
+        }
+        // the test includes the break at sot.
+        expected.retain(|at| *at != 0);
+
+        let actual: Vec<usize> = line_breaks(&text).iter().map(|b| b.offset).collect();
+        if actual != expected {
+            failures.push(format!("line {}: {}\n  expected {:?}\n  actual   {:?}", line_index + 1, line, expected, actual));
+        }
+        count += 1;
+    }
+
+    assert!(count > 10000);
+    assert!(failures.is_empty(), "{} of {} failed:\n{}", failures.len(), count, failures[..failures.len().min(20)].join("\n"));
+}
+
+#[test]
+fn mandatory_breaks() {
+    let breaks = line_breaks("ab\r\ncd\nef gh\u{2028}");
+    assert_eq!(breaks, [
+        Break { offset: 4,  mandatory: true },
+        Break { offset: 7,  mandatory: true },
+        Break { offset: 10, mandatory: false },
+        Break { offset: 15, mandatory: true },
+    ]);
+    assert!(line_breaks("").is_empty());
+}
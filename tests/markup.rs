@@ -0,0 +1,71 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::markup::{parse_markup, MarkupError};
+use pogui::paint::Rect;
+
+mod common;
+use common::children;
+
+
+#[test]
+fn build_tree() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let markup = parse_markup(&mut g, r#"
+        <!-- the toolbar. -->
+        <div id="toolbar" class="dark tall" style="height: 30; background_color: 202020;">
+            <button id="save" style='display: block; width: 50; height: 20'>save &amp; quit</button>
+            <span>a   b
+                c</span>
+            <div/>
+        </div>
+        <div id="content"></div>
+    "#).unwrap();
+
+    assert_eq!(markup.roots.len(), 2);
+    let toolbar = markup.get("toolbar").unwrap();
+    let save    = markup.get("save").unwrap();
+    assert_eq!(markup.roots, [toolbar, markup.get("content").unwrap()]);
+    assert_eq!(markup.ids.len(), 3);
+
+    let [button, span, div]: [Node; 3] = children(&g, toolbar).try_into().unwrap();
+    assert_eq!(button, save);
+    assert_eq!(g.get_id(save).as_deref(), Some("save"));
+    assert!(g.has_class(toolbar, "dark") && g.has_class(toolbar, "tall"));
+    assert_eq!(children(&g, span).len(), 1);
+    assert_eq!(children(&g, div), []);
+    assert_eq!(g.query_selector_all(toolbar, "button > *").unwrap().len(), 1);
+
+    let root = g.root();
+    g.set_children(root, markup.roots);
+    g.update();
+    assert_eq!(g.get_rect(toolbar).height(), 30.0);
+    assert_eq!(g.get_rect(save), Rect { min: [0.0, 0.0], max: [50.0, 20.0] });
+}
+
+#[test]
+fn errors() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let live = g.node_stats().live;
+
+    let mut error = |source: &str, line, column, message: &str| {
+        let result = parse_markup(&mut g, source);
+        assert_eq!(result.err(), Some(MarkupError { line, column, message: message.into() }));
+    };
+
+    error("<div>\n  <p></p>\n</div>", 2, 4, "unknown element `p`");
+    error("<div>\n  <span>\n</div>", 3, 3, "expected `</span>`");
+    error("<div>\n  <span/>", 1, 1, "unclosed `<div>`");
+    error("</div>", 1, 3, "unexpected `</div>`");
+    error("<div width=\"5\"/>", 1, 6, "unknown attribute `width`");
+    error("<div style=\"height: 5; width\"/>", 1, 24, "expected `name: value`");
+    error("<div style=\"a: &amp;; b\"/>", 1, 23, "expected `name: value`");
+    error("<div style=\"a: &amp; &x;\"/>", 1, 22, "invalid entity");
+    error("<div id=a/>", 1, 9, "expected a quoted value");
+    error("<div id='a'/><span id='a'/>", 1, 24, "duplicate id `a`");
+    error("<div>ä &nope; </div>", 1, 8, "invalid entity");
+    error("<div><!-- </div>", 1, 6, "unterminated comment");
+
+    // the partial trees are destroyed.
+    assert_eq!(g.node_stats().live, live);
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::message::MessageQueue;
+
+
+struct Progress (u32);
+
+#[test]
+fn cross_thread() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+    let label = gui.create_node(NodeKind::Div);
+    let root = gui.root();
+    gui.append_child(root, label);
+
+    let wakes = Arc::new(AtomicU32::new(0));
+    let mut queue = {
+        let wakes = wakes.clone();
+        MessageQueue::new(move || { wakes.fetch_add(1, Ordering::Relaxed); })
+    };
+    queue.on_message(move |gui, Progress(percent)| {
+        gui.set_id(label, Some(format!("{}%", percent)));
+    });
+
+    // nothing queued.
+    assert!(!gui.with_gui(|gui| queue.dispatch(gui)));
+
+    let sender = queue.sender();
+    let threads = (0..4).map(|i| {
+        let sender = sender.clone();
+        std::thread::spawn(move || {
+            assert!(sender.post(move |gui| {
+                let node = gui.create_node(NodeKind::Div);
+                gui.set_id(node, Some(format!("worker {}", i)));
+                let root = gui.root();
+                gui.append_child(root, node);
+            }));
+        })
+    }).collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(wakes.load(Ordering::Relaxed), 4);
+
+    // runs on this thread, only when dispatched.
+    assert_eq!(gui.children(root).count(), 1);
+    assert!(gui.with_gui(|gui| queue.dispatch(gui)));
+    let mut ids = gui.children(root).skip(1).map(|node| gui.get_id(node).unwrap()).collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, ["worker 0", "worker 1", "worker 2", "worker 3"]);
+
+    // typed messages run in order.
+    std::thread::spawn(move || {
+        for percent in [10, 50, 100] {
+            assert!(sender.send(Progress(percent)));
+        }
+        // no handler, dropped.
+        assert!(sender.send(42u8));
+    }).join().unwrap();
+    assert!(gui.with_gui(|gui| queue.dispatch(gui)));
+    assert_eq!(gui.get_id(label).as_deref(), Some("100%"));
+}
+
+#[test]
+fn closed() {
+    let queue = MessageQueue::new(|| ());
+    let sender = queue.sender();
+    assert!(sender.send(1u32));
+
+    drop(queue);
+    assert!(!sender.post(|_| ()));
+    assert!(!sender.send(2u32));
+}
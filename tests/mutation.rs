@@ -0,0 +1,201 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::mutation::MutationRecord;
+
+
+type Log   = Rc<RefCell<Vec<Vec<MutationRecord>>>>;
+type Style = std::collections::HashMap<String, String>;
+
+fn observe(g: &mut Gui, root: Node) -> Log {
+    let log = Log::default();
+    let l = log.clone();
+    g.observe_mutations(root, move |_, records| l.borrow_mut().push(records.to_vec()));
+    log
+}
+
+fn style(pairs: &[(&str, &str)]) -> Style {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+
+#[test]
+fn batched_records() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let log = observe(&mut g, root);
+
+    let a = g.create_node(NodeKind::Div);
+    let b = g.create_node(NodeKind::Div);
+    let text = g.create_text("old");
+    g.append_child(root, a);
+    g.append_child(root, b);
+    g.append_child(a, text);
+    g.set_style(a, style(&[("width", "10")]));
+    g.set_style(a, style(&[("width", "20")]));
+    g.set_text(text, "new".into());
+    assert!(log.borrow().is_empty());
+
+    g.update();
+    assert_eq!(*log.borrow(), [vec![
+        MutationRecord::ChildList { target: root, added: vec![a], removed: vec![], prev_sibling: None, next_sibling: None },
+        MutationRecord::ChildList { target: root, added: vec![b], removed: vec![], prev_sibling: Some(a), next_sibling: None },
+        MutationRecord::ChildList { target: a, added: vec![text], removed: vec![], prev_sibling: None, next_sibling: None },
+        MutationRecord::Style { target: a, old_value: style(&[]) },
+        MutationRecord::Style { target: a, old_value: style(&[("width", "10")]) },
+        MutationRecord::Text  { target: text, old_value: "old".into() },
+    ]]);
+    log.borrow_mut().clear();
+
+    // swaps are removes & inserts.
+    g.swap_nodes(a, b);
+    g.remove_child(root, a, false);
+    g.flush_mutations();
+    let records = log.borrow_mut().pop().unwrap();
+    assert!(records.iter().all(|record| record.target() == root));
+    assert_eq!(records.last(), Some(&MutationRecord::ChildList {
+        target: root, added: vec![], removed: vec![a], prev_sibling: Some(b), next_sibling: None,
+    }));
+    assert_eq!(g.get_first_child(root), Some(b));
+
+    // nothing changed.
+    g.update();
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn subtree_only() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [watched, other, child] = [0; 3].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(root, [watched, other]);
+    let log = observe(&mut g, watched);
+
+    g.set_children(other, [child]);
+    g.set_style(other, style(&[("width", "10")]));
+    g.flush_mutations();
+    assert!(log.borrow().is_empty());
+
+    // moved into the subtree.
+    g.append_child(watched, child);
+    g.set_style(child, style(&[("width", "10")]));
+    g.flush_mutations();
+    let records = log.borrow_mut().pop().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].target(), watched);
+    assert_eq!(records[1].target(), child);
+}
+
+#[test]
+fn set_children_record() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [a, b, c] = [0; 3].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(root, [a, b]);
+    let log = observe(&mut g, root);
+
+    g.set_children(root, [c, a]);
+    g.set_children(root, [c, a]);
+    g.flush_mutations();
+    assert_eq!(*log.borrow(), [vec![
+        MutationRecord::ChildList { target: root, added: vec![], removed: vec![b], prev_sibling: Some(a), next_sibling: None },
+        MutationRecord::ChildList { target: root, added: vec![c, a], removed: vec![a], prev_sibling: None, next_sibling: None },
+    ]]);
+}
+
+#[test]
+fn self_mutating_observer() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let div = g.create_node(NodeKind::Div);
+    g.append_child(root, div);
+    g.flush_mutations();
+
+    // answers every style change with another one.
+    let batches = Rc::new(RefCell::new(0));
+    let b = batches.clone();
+    g.observe_mutations(root, move |g, records| {
+        *b.borrow_mut() += 1;
+        let width = b.borrow().to_string();
+        for record in records {
+            if let MutationRecord::Style { target, .. } = record {
+                g.set_style(*target, style(&[("width", &width)]));
+            }
+        }
+    });
+
+    g.set_style(div, style(&[("width", "0")]));
+    g.flush_mutations();
+    let first = *batches.borrow();
+    assert!(first > 1);
+
+    // the rest is delivered in the next flush.
+    g.flush_mutations();
+    assert!(*batches.borrow() > first);
+}
+
+#[test]
+fn callbacks_mutate_and_disconnect() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let counter = g.create_text("0");
+    g.append_child(root, counter);
+
+    // counts added children in a text node, which is a mutation too.
+    let batches = Rc::new(RefCell::new(0));
+    let b = batches.clone();
+    let id = g.observe_mutations(root, move |g, records| {
+        *b.borrow_mut() += 1;
+        let added = records.iter().filter(|r| matches!(r, MutationRecord::ChildList { added, .. } if !added.is_empty())).count();
+        if added > 0 {
+            g.set_text(counter, added.to_string());
+        }
+    });
+
+    for _ in 0..3 {
+        let div = g.create_node(NodeKind::Div);
+        g.append_child(root, div);
+    }
+    g.flush_mutations();
+    assert_eq!(*batches.borrow(), 2);
+
+    g.disconnect_mutations(id);
+    let div = g.create_node(NodeKind::Div);
+    g.append_child(root, div);
+    g.flush_mutations();
+    assert_eq!(*batches.borrow(), 2);
+}
+
+#[test]
+fn unchanged_style() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let div = g.create_node(NodeKind::Div);
+    g.append_child(root, div);
+    g.set_style(div, style(&[("width", "10")]));
+    let log = observe(&mut g, root);
+
+    g.set_style(div, style(&[("width", "10")]));
+    g.flush_mutations();
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn destroyed_root_drops_observer() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let [watched, other] = [0; 2].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(root, [watched, other]);
+
+    let log = observe(&mut g, watched);
+    g.destroy_node(watched);
+    g.set_style(other, style(&[("width", "10")]));
+    g.flush_mutations();
+    assert!(log.borrow().is_empty());
+    // the observer released its callback.
+    assert_eq!(Rc::strong_count(&log), 1);
+}
@@ -0,0 +1,212 @@
+// needs an X server, eg: `xvfb-run cargo test --test native_gui_x11 -- --ignored`
+#![cfg(target_os = "linux")]
+
+use core::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{self, ConnectionExt as _};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use pogui::gui::*;
+use pogui::native_gui::NativeGui;
+
+
+fn style(gui: &mut Gui, node: Node, style: &[(&str, &str)]) {
+    gui.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+}
+
+/// sends synthetic events from a second client.
+struct Input {
+    conn:   RustConnection,
+    root:   xproto::Window,
+    window: xproto::Window,
+}
+
+impl Input {
+    fn new(ngui: &NativeGui) -> Input {
+        let (conn, screen_num) = x11rb::connect(None).unwrap();
+        let root = conn.setup().roots[screen_num].root;
+        Input { conn, root, window: ngui.window_id() }
+    }
+
+    fn send<E: Into<[u8; 32]>>(&self, event: E) {
+        self.conn.send_event(false, self.window, xproto::EventMask::NO_EVENT, event).unwrap();
+        self.conn.sync().unwrap();
+    }
+
+    fn motion(&self, x: i16, y: i16) {
+        self.send(xproto::MotionNotifyEvent {
+            response_type: xproto::MOTION_NOTIFY_EVENT,
+            detail: xproto::Motion::NORMAL,
+            sequence: 0, time: 0,
+            root: self.root, event: self.window, child: 0,
+            root_x: x, root_y: y, event_x: x, event_y: y,
+            state: Default::default(),
+            same_screen: true,
+        });
+    }
+
+    fn button(&self, response_type: u8, button: u8, x: i16, y: i16) {
+        self.send(xproto::ButtonPressEvent {
+            response_type,
+            detail: button,
+            sequence: 0, time: 0,
+            root: self.root, event: self.window, child: 0,
+            root_x: x, root_y: y, event_x: x, event_y: y,
+            state: Default::default(),
+            same_screen: true,
+        });
+    }
+
+    /// a press & release of the first key, that produces `keysym`.
+    fn key(&self, keysym: xproto::Keysym) {
+        let setup = self.conn.setup();
+        let count = setup.max_keycode - setup.min_keycode + 1;
+        let mapping = self.conn.get_keyboard_mapping(setup.min_keycode, count).unwrap().reply().unwrap();
+        let per_keycode = mapping.keysyms_per_keycode as usize;
+        let index = mapping.keysyms.iter().position(|k| *k == keysym).expect("keysym not mapped");
+        let keycode = setup.min_keycode + (index / per_keycode) as u8;
+
+        for response_type in [xproto::KEY_PRESS_EVENT, xproto::KEY_RELEASE_EVENT] {
+            self.send(xproto::KeyPressEvent {
+                response_type,
+                detail: keycode,
+                sequence: 0, time: 0,
+                root: self.root, event: self.window, child: 0,
+                root_x: 0, root_y: 0, event_x: 0, event_y: 0,
+                state: Default::default(),
+                same_screen: true,
+            });
+        }
+    }
+}
+
+/// dispatches events, until `done` or a timeout.
+fn pump<F: FnMut(&mut NativeGui) -> bool>(ngui: &mut NativeGui, mut done: F) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        assert!(ngui.dispatch_pending());
+        if done(ngui) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+
+#[test]
+#[ignore = "needs an X server"]
+fn click_through_x11() {
+    let mut ngui = NativeGui::new();
+
+    let clicked = Rc::new(Cell::new(false));
+    ngui.with_gui(|gui| {
+        let button = gui.create_node(NodeKind::Button);
+        style(gui, button, &[("display", "block"), ("width", "100"), ("height", "50")]);
+
+        let clicked = clicked.clone();
+        gui.set_on_click(button, move |_, _| clicked.set(true));
+
+        let root = gui.root();
+        gui.append_child(root, button);
+    });
+    assert!(ngui.dispatch_pending());
+
+    let input = Input::new(&ngui);
+    input.motion(20, 20);
+    input.button(xproto::BUTTON_PRESS_EVENT,   1, 20, 20);
+    input.button(xproto::BUTTON_RELEASE_EVENT, 1, 20, 20);
+
+    assert!(pump(&mut ngui, |_| clicked.get()));
+}
+
+#[test]
+#[ignore = "needs an X server"]
+fn wheel_through_x11() {
+    let mut ngui = NativeGui::new();
+
+    let list = ngui.with_gui(|gui| {
+        let items: Vec<Node> = (0..10).map(|_| {
+            let item = gui.create_node(NodeKind::Div);
+            style(gui, item, &[("height", "30")]);
+            item
+        }).collect();
+        let list = gui.create_node(NodeKind::Div);
+        style(gui, list, &[("height", "100")]);
+        gui.set_children(list, items);
+
+        let root = gui.root();
+        gui.append_child(root, list);
+        list
+    });
+    assert!(ngui.dispatch_pending());
+
+    // button 5 is a notch down.
+    let input = Input::new(&ngui);
+    input.motion(20, 40);
+    input.button(xproto::BUTTON_PRESS_EVENT,   5, 20, 40);
+    input.button(xproto::BUTTON_RELEASE_EVENT, 5, 20, 40);
+
+    assert!(pump(&mut ngui, |ngui| ngui.with_gui(|gui| gui.get_scroll_pos(list)) == [0.0, 30.0]));
+}
+
+#[test]
+#[ignore = "needs an X server"]
+fn keys_through_x11() {
+    let mut ngui = NativeGui::new();
+
+    let clicks = Rc::new(Cell::new(0));
+    let [a, b] = ngui.with_gui(|gui| {
+        let buttons = [0; 2].map(|_| {
+            let button = gui.create_node(NodeKind::Button);
+            style(gui, button, &[("display", "block"), ("width", "50"), ("height", "30")]);
+            let clicks = clicks.clone();
+            gui.set_on_click(button, move |_, _| clicks.set(clicks.get() + 1));
+            button
+        });
+        let root = gui.root();
+        gui.set_children(root, buttons);
+        buttons
+    });
+    assert!(ngui.dispatch_pending());
+
+    // tab & return become chars.
+    const TAB: xproto::Keysym = 0xff09;
+    const RETURN: xproto::Keysym = 0xff0d;
+    let input = Input::new(&ngui);
+    input.key(TAB);
+    assert!(pump(&mut ngui, |ngui| ngui.with_gui(|gui| gui.get_focus()) == Some(a)));
+    input.key(TAB);
+    assert!(pump(&mut ngui, |ngui| ngui.with_gui(|gui| gui.get_focus()) == Some(b)));
+
+    input.key(RETURN);
+    assert!(pump(&mut ngui, |_| clicks.get() == 1));
+}
+
+#[test]
+#[ignore = "needs an X server"]
+fn resize_through_x11() {
+    let mut ngui = NativeGui::new();
+    assert!(ngui.dispatch_pending());
+
+    let input = Input::new(&ngui);
+    input.send(xproto::ConfigureNotifyEvent {
+        response_type: xproto::CONFIGURE_NOTIFY_EVENT,
+        sequence: 0,
+        event: input.window, window: input.window, above_sibling: 0,
+        x: 0, y: 0, width: 321, height: 123,
+        border_width: 0,
+        override_redirect: false,
+    });
+
+    assert!(pump(&mut ngui, |ngui| {
+        let rect = ngui.with_gui(|gui| gui.get_rect(gui.root()));
+        rect.max == [321.0, 123.0]
+    }));
+    let image = ngui.screenshot();
+    assert_eq!((image.width(), image.height()), (321, 123));
+}
@@ -0,0 +1,254 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+
+mod common;
+use common::children;
+
+
+#[test]
+fn stats() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    // the root.
+    assert_eq!(g.node_stats(), NodeStats { live: 1, free: 0, peak: 1 });
+
+    let list = g.create_node(NodeKind::Div);
+    let items: Vec<Node> = (0..10).map(|_| g.create_node(NodeKind::Div)).collect();
+    g.set_children(list, items);
+    assert_eq!(g.node_stats(), NodeStats { live: 12, free: 0, peak: 12 });
+
+    // destroys the children too.
+    g.destroy_node(list);
+    assert_eq!(g.node_stats(), NodeStats { live: 1, free: 11, peak: 12 });
+
+    // reuses free slots.
+    for _ in 0..5 {
+        g.create_node(NodeKind::Span);
+    }
+    assert_eq!(g.node_stats(), NodeStats { live: 6, free: 6, peak: 12 });
+}
+
+#[test]
+fn churn() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    for _ in 0..20 {
+        let items: Vec<Node> = (0..200).map(|_| g.create_node(NodeKind::Div)).collect();
+        g.set_children(root, items);
+    }
+    assert_eq!(g.node_stats(), NodeStats { live: 201, free: 200, peak: 401 });
+}
+
+#[test]
+#[should_panic(expected = "stale node handle")]
+fn stale_handle() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let a = g.create_node(NodeKind::Div);
+    g.destroy_node(a);
+
+    // same slot, new generation.
+    let b = g.create_node(NodeKind::Div);
+    assert_ne!(a, b);
+    g.get_rect(a);
+}
+
+#[test]
+fn compact() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let keep = g.create_node(NodeKind::Div);
+    let items: Vec<Node> = (0..100).map(|_| g.create_node(NodeKind::Div)).collect();
+    g.set_children(root, items.iter().copied().chain([keep]));
+    g.remove_node(keep, true);
+    g.set_children(root, []);
+
+    g.compact_nodes();
+    assert_eq!(g.node_stats(), NodeStats { live: 2, free: 0, peak: 102 });
+
+    // handles stay valid.
+    g.set_children(root, [keep]);
+    g.update();
+
+    // new nodes don't alias old handles.
+    let fresh: Vec<Node> = (0..100).map(|_| g.create_node(NodeKind::Div)).collect();
+    for item in &items {
+        assert!(!fresh.contains(item));
+    }
+}
+
+
+#[test]
+fn set_children_diff() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [a, b, c, d] = [0; 4].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(root, [a, b, c]);
+
+    // keeps `a` & `c`, reorders them, destroys `b`.
+    g.set_children(root, [c, d, a]);
+    assert_eq!(children(&g, root), [c, d, a]);
+    assert_eq!(g.node_stats().live, 4);
+
+    // repeats are inserted once.
+    g.set_children(root, [a, a, c, a]);
+    assert_eq!(children(&g, root), [a, c]);
+    assert_eq!(g.node_stats().live, 3);
+
+    g.set_children(root, [a, c]);
+    assert_eq!(children(&g, root), [a, c]);
+    assert_eq!(g.node_stats().live, 3);
+}
+
+#[test]
+fn set_children_moves() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [a, b, child] = [0; 3].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(a, [child]);
+    g.set_children(root, [a, b]);
+
+    // from `a` to `b`.
+    g.set_children(b, [child]);
+    assert_eq!(children(&g, a), []);
+    assert_eq!(children(&g, b), [child]);
+    assert_eq!(g.node_stats().live, 4);
+}
+
+#[test]
+fn set_children_promotes_grandchild() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [a, x] = [0; 2].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(a, [x]);
+    g.set_children(root, [a]);
+
+    // `a` is destroyed, its child `x` survives.
+    g.set_children(root, [x]);
+    assert_eq!(children(&g, root), [x]);
+    assert!(!g.is_alive(a));
+    assert!(g.is_alive(x));
+    assert_eq!(g.node_stats().live, 2);
+}
+
+#[test]
+#[should_panic]
+fn set_children_cycle() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let [a, b] = [0; 2].map(|_| g.create_node(NodeKind::Div));
+    g.set_children(a, [b]);
+    g.set_children(root, [a]);
+    g.set_children(b, [a]);
+}
+
+#[test]
+fn set_children_keeps_state() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+
+    let style = |g: &mut Gui, node| {
+        g.set_style(node, [("display", "block"), ("width", "50"), ("height", "30")]
+            .iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    };
+    let [a, b] = [0; 2].map(|_| g.create_node(NodeKind::Button));
+    style(&mut g, a);
+    style(&mut g, b);
+    g.set_children(root, [a, b]);
+
+    g.hover(b);
+    g.on_mouse_down(25.0, 45.0);
+    assert_eq!(g.get_hover(), Some(b));
+    assert_eq!(g.get_active(), Some(b));
+
+    g.set_children(root, [b, a]);
+    assert_eq!(g.get_hover(), Some(b));
+    assert_eq!(g.get_active(), Some(b));
+}
+
+#[test]
+fn ids_classes_user_data() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let a = g.create_node(NodeKind::Div);
+    g.set_id(a, Some("list".into()));
+    assert_eq!(g.get_id(a).as_deref(), Some("list"));
+
+    g.add_class(a, "item");
+    g.add_class(a, "selected");
+    g.add_class(a, "item");
+    assert!(g.has_class(a, "item") && g.has_class(a, "selected"));
+    g.remove_class(a, "item");
+    assert!(!g.has_class(a, "item") && g.has_class(a, "selected"));
+
+    g.set_user_data(a, 42u32);
+    assert_eq!(g.user_data::<u32>(a).as_deref(), Some(&42));
+    assert!(g.user_data::<String>(a).is_none());
+    g.set_user_data(a, String::from("x"));
+    assert_eq!(g.user_data::<String>(a).as_deref().map(String::as_str), Some("x"));
+
+    // cleared when the slot is reused.
+    g.destroy_node(a);
+    let b = g.create_node(NodeKind::Div);
+    assert_eq!(g.get_id(b), None);
+    assert!(!g.has_class(b, "selected"));
+    assert!(g.user_data::<String>(b).is_none());
+}
+
+#[test]
+fn user_data_dropped() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let data = std::rc::Rc::new(());
+
+    let a = g.create_node(NodeKind::Div);
+    g.set_user_data(a, data.clone());
+    assert_eq!(std::rc::Rc::strong_count(&data), 2);
+
+    g.destroy_node(a);
+    assert_eq!(std::rc::Rc::strong_count(&data), 1);
+}
+
+#[test]
+fn clone_subtree() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let clicks = std::rc::Rc::new(core::cell::Cell::new(0));
+
+    let label  = g.create_text("row");
+    let button = g.create_node(NodeKind::Button);
+    g.set_style(button, [("display", "block"), ("width", "50"), ("height", "30")]
+        .iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    { let clicks = clicks.clone(); g.set_on_click(button, move |_, _| clicks.set(clicks.get() + 1)); }
+    g.set_children(button, [label]);
+    let row = g.create_node(NodeKind::Div);
+    g.add_class(row, "row");
+    g.set_user_data(row, 1u32);
+    g.set_children(row, [button]);
+
+    let copy = g.clone_subtree(row);
+    assert_eq!(g.get_parent(copy), None);
+    assert!(g.has_class(copy, "row"));
+    assert!(g.user_data::<u32>(copy).is_none());
+    assert_eq!(g.node_stats().live, 1 + 2*3);
+
+    let copy_button = g.get_first_child(copy).unwrap();
+    let copy_label  = g.get_first_child(copy_button).unwrap();
+    assert!(![row, button, label].contains(&copy_button));
+    assert_eq!(g.get_first_child(copy_label), None);
+
+    // same layout & shared handler.
+    g.set_children(root, [row, copy]);
+    g.update();
+    assert_eq!(g.get_rect(copy_button).height(), 30.0);
+    g.click(25.0, 45.0);
+    g.click(25.0, 15.0);
+    assert_eq!(clicks.get(), 2);
+
+    // independent.
+    g.destroy_node(row);
+    assert_eq!(g.get_first_child(copy), Some(copy_button));
+}
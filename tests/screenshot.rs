@@ -0,0 +1,184 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::paint::software::{render_frame, Pixmap};
+
+
+/// decodes the PNGs `encode_png` writes:
+/// 8 bit RGBA, a single IDAT, stored or fixed huffman deflate blocks.
+fn decode_png(png: &[u8]) -> (u32, u32, Vec<u8>) {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mut chunks = vec![];
+    let mut at = 8;
+    while at < png.len() {
+        let len = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+        let kind = &png[at + 4 .. at + 8];
+        chunks.push((kind, &png[at + 8 .. at + 8 + len]));
+        at += 12 + len;
+    }
+    assert_eq!(at, png.len());
+
+    let kinds: Vec<&[u8]> = chunks.iter().map(|c| c.0).collect();
+    assert_eq!(kinds, [&b"IHDR"[..], b"IDAT", b"IEND"]);
+
+    let ihdr = chunks[0].1;
+    let width  = u32::from_be_bytes(ihdr[0..4].try_into().unwrap());
+    let height = u32::from_be_bytes(ihdr[4..8].try_into().unwrap());
+    assert_eq!(&ihdr[8..], &[8, 6, 0, 0, 0]);
+
+    let zlib = chunks[1].1;
+    assert_eq!((zlib[0] as u32 * 256 + zlib[1] as u32) % 31, 0);
+    let filtered = inflate(&zlib[2 .. zlib.len() - 4]);
+
+    let stride = 4 * width as usize;
+    assert_eq!(filtered.len(), (stride + 1) * height as usize);
+
+    let mut data = vec![0u8; stride * height as usize];
+    for y in 0..height as usize {
+        let filter = filtered[y * (stride + 1)];
+        for i in 0..stride {
+            let v = filtered[y * (stride + 1) + 1 + i];
+            let a = if i >= 4 { data[y*stride + i - 4] } else { 0 };
+            let b = if y > 0  { data[(y - 1)*stride + i] } else { 0 };
+            let c = if i >= 4 && y > 0 { data[(y - 1)*stride + i - 4] } else { 0 };
+            let predictor = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => {
+                    let p = a as i16 + b as i16 - c as i16;
+                    let (pa, pb, pc) = ((p - a as i16).abs(), (p - b as i16).abs(), (p - c as i16).abs());
+                    if pa <= pb && pa <= pc { a } else if pb <= pc { b } else { c }
+                }
+                _ => panic!("invalid filter"),
+            };
+            data[y*stride + i] = v.wrapping_add(predictor);
+        }
+    }
+    (width, height, data)
+}
+
+fn inflate(data: &[u8]) -> Vec<u8> {
+    let mut bit = 0;
+    let mut read = |count: u32| -> u32 {
+        let mut v = 0;
+        for i in 0..count {
+            v |= ((data[bit / 8] >> (bit % 8)) as u32 & 1) << i;
+            bit += 1;
+        }
+        v
+    };
+
+    const LENGTH_BASE:  [u32; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+    const LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+    const DIST_BASE:  [u32; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+    const DIST_EXTRA: [u32; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+    let mut out = vec![];
+    loop {
+        let last = read(1);
+        match read(2) {
+            1 => loop {
+                // fixed codes, msb first.
+                let mut code = 0;
+                let mut len = 0;
+                let symbol = loop {
+                    code = code << 1 | read(1);
+                    len += 1;
+                    match (len, code) {
+                        (7, 0x00..=0x17)  => break code + 256,
+                        (8, 0x30..=0xbf)  => break code - 0x30,
+                        (8, 0xc0..=0xc7)  => break code - 0xc0 + 280,
+                        (9, 0x190..=0x1ff) => break code - 0x190 + 144,
+                        _ => assert!(len < 9),
+                    }
+                };
+
+                if symbol < 256 {
+                    out.push(symbol as u8);
+                    continue;
+                }
+                if symbol == 256 {
+                    break;
+                }
+
+                let i = (symbol - 257) as usize;
+                let length = LENGTH_BASE[i] + read(LENGTH_EXTRA[i]);
+                let d = (0..5).fold(0, |d, _| d << 1 | read(1)) as usize;
+                let distance = (DIST_BASE[d] + read(DIST_EXTRA[d])) as usize;
+                for _ in 0..length {
+                    out.push(out[out.len() - distance]);
+                }
+            },
+
+            kind => panic!("unsupported block type {}", kind),
+        }
+
+        if last == 1 {
+            return out;
+        }
+    }
+}
+
+fn scene(g: &mut Gui) {
+    let node = |g: &mut Gui, kind, style: &[(&str, &str)]| {
+        let node = g.create_node(kind);
+        g.set_style(node, style.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+        node
+    };
+
+    let items: Vec<Node> = (0..20).map(|i| {
+        let color = format!("{:02x}8040", i * 12);
+        node(g, NodeKind::Div, &[("height", "15"), ("background_color", &color)])
+    }).collect();
+    let list = node(g, NodeKind::Div, &[("height", "150")]);
+    g.set_children(list, items);
+    let button = node(g, NodeKind::Button, &[("display", "block"), ("width", "60"), ("height", "20")]);
+
+    let root = g.root();
+    g.set_children(root, [list, button]);
+}
+
+
+#[test]
+fn screenshot_png() {
+    let mut g = HeadlessGui::new(300.0, 200.0);
+    scene(&mut g);
+
+    let image = g.screenshot([160, 190]);
+    assert_eq!((image.width(), image.height()), (160, 190));
+
+    let expected: Pixmap = g.with_gui(|gui| render_frame(gui, 160, 190));
+    assert_eq!(image.pixmap, expected);
+
+    let png = image.encode_png();
+    let (width, height, data) = decode_png(&png);
+    assert_eq!((width, height), (160, 190));
+    assert!(data == expected.data());
+
+    // mostly flat colors.
+    assert!(png.len() < data.len() / 10);
+}
+
+#[test]
+fn screenshot_keeps_window_size() {
+    let mut g = HeadlessGui::new(300.0, 200.0);
+    scene(&mut g);
+
+    g.update();
+    let root = g.root();
+    let before = g.get_rect(root);
+
+    g.screenshot([100, 100]);
+    g.update();
+    assert_eq!(g.get_rect(root), before);
+}
+
+#[test]
+fn empty_png() {
+    let mut g = HeadlessGui::new(0.0, 0.0);
+    let png = g.screenshot([0, 0]).encode_png();
+    let (width, height, data) = decode_png(&png);
+    assert_eq!((width, height, data.len()), (0, 0, 0));
+}
@@ -0,0 +1,70 @@
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::selector::{Selector, SelectorError};
+
+mod common;
+use common::tagged;
+
+
+#[test]
+fn queries() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let save  = tagged(&mut g, NodeKind::Button, Some("save"), &["primary"], []);
+    let open  = tagged(&mut g, NodeKind::Button, Some("open"), &[], []);
+    let group = tagged(&mut g, NodeKind::Span, None, &[], [open]);
+    let toolbar = tagged(&mut g, NodeKind::Div, Some("top"), &["toolbar", "dark"], [save, group]);
+    let label = g.create_text("label");
+    let content = tagged(&mut g, NodeKind::Div, None, &["content"], [label]);
+    let root = g.root();
+    g.set_children(root, [toolbar, content]);
+
+    assert_eq!(g.query_selector(root, "div.toolbar > button#save").unwrap(), Some(save));
+    assert_eq!(g.query_selector(root, "div.toolbar > button#open").unwrap(), None);
+    assert_eq!(g.query_selector(root, "div.toolbar button#open").unwrap(), Some(open));
+    assert_eq!(g.query_selector(root, ".toolbar.dark  >  span > *").unwrap(), Some(open));
+    assert_eq!(g.query_selector(root, "#top").unwrap(), Some(toolbar));
+    assert_eq!(g.query_selector(root, ".missing").unwrap(), None);
+
+    assert_eq!(g.query_selector_all(root, "button").unwrap(), [save, open]);
+    assert_eq!(g.query_selector_all(root, "div").unwrap(), [toolbar, content]);
+    assert_eq!(g.query_selector_all(root, "text, #open, .primary").unwrap(), [save, open, label]);
+    // the root is a div too.
+    assert_eq!(g.query_selector_all(root, "div > *").unwrap(), [toolbar, save, group, content, label]);
+
+    // only descendants of the root, but ancestors can be outside.
+    assert_eq!(g.query_selector_all(toolbar, "div").unwrap(), []);
+    assert_eq!(g.query_selector_all(group, "div button").unwrap(), [open]);
+}
+
+#[test]
+fn detached() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let inner = tagged(&mut g, NodeKind::Span, None, &["x"], []);
+    let outer = tagged(&mut g, NodeKind::Div, None, &[], [inner]);
+    assert_eq!(g.query_selector(outer, ".x").unwrap(), Some(inner));
+    assert_eq!(g.query_selector(inner, ".x").unwrap(), None);
+}
+
+#[test]
+fn parse_errors() {
+    let error = |selector, offset, message| {
+        assert_eq!(Selector::parse(selector), Err(SelectorError { offset, message }));
+    };
+    error("",          0, "expected a selector");
+    error("div >",     5, "expected a selector");
+    error("p",         0, "unknown node kind");
+    error("div#a#b",   6, "multiple ids");
+    error("div.",      4, "expected a name");
+    error("div, ",     5, "expected a selector");
+    error("div+span",  3, "unexpected character");
+    assert!(Selector::parse("div > span.a#b, *").is_ok());
+}
+
+#[test]
+fn invalid_query() {
+    let g = HeadlessGui::new(400.0, 300.0);
+    let error = SelectorError { offset: 5, message: "expected a selector" };
+    assert_eq!(g.query_selector(g.root(), "div >"), Err(error.clone()));
+    assert_eq!(g.query_selector_all(g.root(), "div >"), Err(error));
+}
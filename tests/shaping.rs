@@ -0,0 +1,81 @@
+use pogui::text::*;
+use pogui::text::shaping::*;
+
+
+fn dejavu_sans() -> FontFile {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/fonts/DejaVuSans.ttf");
+    FontFile::parse(std::fs::read(path).unwrap(), 0).unwrap()
+}
+
+fn shape_str(font: &FontFile, text: &str, is_rtl: bool) -> Vec<ShapedGlyph> {
+    let chars: Vec<char> = text.chars().collect();
+    shape(font, &chars, is_rtl)
+}
+
+
+#[test]
+fn font_tables() {
+    let font = dejavu_sans();
+    assert_eq!(font.family_name().as_deref(), Some("DejaVu Sans"));
+    assert_eq!(font.weight(), 400);
+    assert!(!font.is_italic());
+
+    let metrics = font.metrics();
+    assert_eq!(metrics.units_per_em, 2048);
+    assert_eq!(metrics.ascent,  1901);
+    assert_eq!(metrics.descent, 483);
+
+    let a = font.glyph_index('A' as u32).unwrap();
+    assert_ne!(a, 0);
+    assert!(font.glyph_advance(a) > 0);
+    assert_eq!(font.glyph_index(0x10ffff), None);
+}
+
+#[test]
+fn unmapped_chars() {
+    let font = dejavu_sans();
+    let glyphs = shape_str(&font, "a\u{10ffff}b", false);
+    assert_eq!(glyphs.len(), 3);
+    assert_eq!(glyphs[1].glyph, 0);
+    assert_eq!(glyphs.iter().map(|g| g.cluster).collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn kerning() {
+    let font = dejavu_sans();
+    let a = font.glyph_index('A' as u32).unwrap();
+
+    let glyphs = shape_str(&font, "AV", false);
+    assert_eq!(glyphs[0].glyph, a);
+    assert_eq!(glyphs[0].advance, font.glyph_advance(a) as i32 - 131);
+}
+
+#[test]
+fn ligatures() {
+    let font = dejavu_sans();
+    let glyphs = shape_str(&font, "office", false);
+    assert_eq!(glyphs.len(), 4);
+    assert_eq!(glyphs.iter().map(|g| g.cluster).collect::<Vec<_>>(), [0, 1, 4, 5]);
+    assert_eq!(glyphs[1].glyph, font.glyph_index(0xfb03).unwrap());
+}
+
+#[test]
+fn mark_positioning() {
+    let font = dejavu_sans();
+    let glyphs = shape_str(&font, "e\u{301}x", false);
+    assert_eq!(glyphs.len(), 3);
+
+    // the mark joins the base's cluster & doesn't advance.
+    assert_eq!(glyphs.iter().map(|g| g.cluster).collect::<Vec<_>>(), [0, 0, 2]);
+    assert_eq!(glyphs[1].advance, 0);
+    assert_ne!(glyphs[1].offset, [0, 0]);
+}
+
+#[test]
+fn rtl_clusters() {
+    let font = dejavu_sans();
+    let glyphs = shape_str(&font, "\u{5e9}\u{5c1}\u{5dc}\u{5d5}\u{5dd}", true);
+    assert_eq!(glyphs.len(), 5);
+    assert_eq!(glyphs.iter().map(|g| g.cluster).collect::<Vec<_>>(), [0, 0, 2, 3, 4]);
+    assert!(glyphs.iter().all(|g| g.glyph != 0));
+}
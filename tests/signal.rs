@@ -0,0 +1,96 @@
+use core::cell::Cell;
+use std::rc::Rc;
+
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::paint::Rect;
+use pogui::paint::record::DrawCommand;
+use pogui::signal::Signal;
+
+
+#[test]
+fn derived() {
+    let count = Signal::new(1);
+    let double = count.map(|count| count * 2);
+    let label  = double.map(|double| format!("{} items", double));
+    assert_eq!(label.get(), "2 items");
+
+    count.set(5);
+    assert_eq!((double.get(), label.get()), (10, "10 items".to_string()));
+
+    count.update(|count| *count += 1);
+    assert_eq!(label.get(), "12 items");
+
+    // dropped derived signals stop updating.
+    let calls = Rc::new(Cell::new(0));
+    let tracked = { let calls = calls.clone(); count.map(move |_| calls.set(calls.get() + 1)) };
+    count.set(0);
+    drop(tracked);
+    count.set(1);
+    count.set(2);
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn subscribe() {
+    let signal = Signal::new(0);
+    let seen = Rc::new(Cell::new(0));
+    { let seen = seen.clone(); signal.subscribe(move |v| { seen.set(*v); *v < 3 }); }
+
+    for i in 1..6 {
+        signal.set(i);
+    }
+    assert_eq!(seen.get(), 3);
+}
+
+
+fn text_len(g: &mut HeadlessGui) -> u32 {
+    g.record().iter().map(|command| match command {
+        DrawCommand::Glyphs { text_begin, text_end, .. } => text_end - text_begin,
+        _ => 0,
+    }).sum()
+}
+
+#[test]
+fn bindings() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+
+    let width = Signal::new(50);
+    let count = Signal::new(7);
+
+    let div = g.create_node(NodeKind::Div);
+    g.set_style(div, [("height", "20")].iter().map(|(k, v)| (k.to_string(), v.to_string())).collect());
+    g.bind_style(div, "width", &width.map(|width| width.to_string()));
+
+    let text = g.create_text("");
+    g.bind_text(text, &count.map(|count| "x".repeat(*count)));
+
+    let root = g.root();
+    g.set_children(root, [div, text]);
+    g.update();
+    assert_eq!(g.get_rect(div), Rect { min: [0.0, 0.0], max: [50.0, 20.0] });
+    assert_eq!(text_len(&mut g), 7);
+
+    width.set(80);
+    count.set(3);
+    g.update();
+    assert_eq!(g.get_rect(div), Rect { min: [0.0, 0.0], max: [80.0, 20.0] });
+    assert_eq!(text_len(&mut g), 3);
+
+    // bindings end with their node.
+    g.destroy_node(text);
+    count.set(4);
+    g.update();
+    let new_text = g.create_text("new");
+    g.append_child(root, new_text);
+    count.set(5);
+    assert_eq!(text_len(&mut g), 3);
+}
+
+#[test]
+#[should_panic(expected = "style on a Text node")]
+fn bind_style_on_text() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let text = g.create_text("hi");
+    g.bind_style(text, "width", &Signal::new("50".to_string()));
+}
@@ -0,0 +1,53 @@
+use pogui::gui::*;
+use pogui::paint::svg::render_svg;
+
+mod common;
+use common::node;
+
+
+#[test]
+fn rects_and_clips() {
+    let mut g = pogui::headless_gui::HeadlessGui::new(400.0, 300.0);
+
+    let items: Vec<Node> = (0..10).map(|_| {
+        node(&mut g, NodeKind::Div, [], &[("height", "30"), ("background_color", "00ff00")])
+    }).collect();
+    let list = node(&mut g, NodeKind::Div, items.clone(), &[("height", "100")]);
+    let root = g.root();
+    g.set_children(root, [list]);
+
+    g.hover(items[0]);
+    g.on_mouse_wheel(-45.0, false);
+
+    let svg = g.with_gui(|gui| render_svg(gui, 400, 300));
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="400" height="300""#));
+    assert!(svg.ends_with("</svg>\n"));
+
+    // the list's content is clipped & scrolled.
+    assert!(svg.contains(r#"<clipPath id="clip0"><rect x="0" y="0" width="200" height="100"/></clipPath>"#));
+    assert!(svg.contains(r##"  <rect x="0" y="0" width="180" height="30" fill="#00ff00" transform="translate(0 -45)"/>"##));
+    assert_eq!(svg.matches("<g ").count(), svg.matches("</g>").count());
+
+    // scroll bar.
+    assert!(svg.contains(r##"<rect x="180" y="0" width="20" height="100" fill="#cccccc"/>"##));
+}
+
+#[cfg(not(windows))]
+#[test]
+fn outlined_text() {
+    let mut g = pogui::headless_gui::HeadlessGui::new(400.0, 300.0);
+    let font = g.add_font_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/fonts/DejaVuSans.ttf")).unwrap();
+    g.set_default_font(font);
+
+    let text = g.create_text("hi");
+    let span = node(&mut g, NodeKind::Span, [text], &[("text_color", "0000ff")]);
+    let root = g.root();
+    g.set_children(root, [span]);
+
+    let svg = g.with_gui(|gui| render_svg(gui, 400, 300));
+    let path = svg.lines().find(|line| line.starts_with("<path ")).unwrap();
+    assert!(path.starts_with(r#"<path d="M"#));
+    assert!(path.ends_with(r##" fill="#0000ff" data-text="0..2"/>"##));
+    // "h" & "i" & the dot of the "i".
+    assert_eq!(path.matches('Z').count(), 3);
+}
@@ -0,0 +1,138 @@
+use core::cell::RefCell;
+use std::rc::Rc;
+
+use pogui::headless_gui::HeadlessGui;
+
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn log(log: &Log, entry: String) {
+    log.borrow_mut().push(entry);
+}
+
+
+#[test]
+fn timeouts_and_intervals() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+    let entries = Log::default();
+
+    let l = entries.clone();
+    gui.set_timeout(0.5, move |gui| log(&l, format!("b {}", gui.time())));
+    let l = entries.clone();
+    gui.set_timeout(0.375, move |gui| log(&l, format!("a {}", gui.time())));
+    let l = entries.clone();
+    let cancelled = gui.set_timeout(0.3, move |_| log(&l, "cancelled".into()));
+    let l = entries.clone();
+    let tick = gui.set_interval(0.25, move |gui| log(&l, format!("tick {}", gui.time())));
+    gui.clear_timer(cancelled);
+    assert_eq!(gui.time_to_next_timer(), Some(0.25));
+
+    assert!(!gui.advance_time(0.125));
+    assert!(gui.advance_time(0.625));
+    // ties run in creation order.
+    assert_eq!(*entries.borrow(), ["tick 0.25", "a 0.375", "b 0.5", "tick 0.5", "tick 0.75"]);
+    assert_eq!(gui.time(), 0.75);
+    assert_eq!(gui.time_to_next_timer(), Some(0.25));
+    entries.borrow_mut().clear();
+
+    assert!(gui.advance_time(0.25));
+    assert_eq!(*entries.borrow(), ["tick 1"]);
+
+    gui.clear_timer(tick);
+    assert!(!gui.advance_time(10.0));
+    assert_eq!(gui.time_to_next_timer(), None);
+}
+
+#[test]
+fn every_tick() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+
+    let calls = Rc::new(RefCell::new(0));
+    let c = calls.clone();
+    gui.set_interval(0.25, move |_| *c.borrow_mut() += 1);
+
+    // the virtual clock isn't late, large steps run every tick.
+    gui.advance_time(10.0);
+    assert_eq!(*calls.borrow(), 40);
+    assert_eq!(gui.time_to_next_timer(), Some(0.25));
+}
+
+#[test]
+fn nested() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+    let entries = Log::default();
+
+    // timers scheduled by callbacks are relative to their due time.
+    let l = entries.clone();
+    gui.set_timeout(1.0, move |gui| {
+        log(&l, format!("outer {}", gui.time()));
+        let l = l.clone();
+        gui.set_timeout(2.5, move |gui| log(&l, format!("inner {}", gui.time())));
+    });
+
+    // intervals can clear themselves.
+    let l = entries.clone();
+    let own_id = Rc::new(RefCell::new(None));
+    let id = {
+        let own_id = own_id.clone();
+        gui.set_interval(0.75, move |gui| {
+            log(&l, format!("interval {}", gui.time()));
+            gui.clear_timer(own_id.borrow().unwrap());
+        })
+    };
+    *own_id.borrow_mut() = Some(id);
+
+    gui.advance_time(3.0);
+    assert_eq!(*entries.borrow(), ["interval 0.75", "outer 1"]);
+    assert_eq!(gui.time_to_next_timer(), Some(0.5));
+    gui.advance_time(0.5);
+    assert_eq!(*entries.borrow(), ["interval 0.75", "outer 1", "inner 3.5"]);
+}
+
+#[test]
+fn rearming_timeout() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+
+    fn rearm(gui: &mut pogui::gui::Gui, calls: Rc<RefCell<u32>>) {
+        gui.set_timeout(0.0, move |gui| {
+            *calls.borrow_mut() += 1;
+            rearm(gui, calls);
+        });
+    }
+    let calls = Rc::new(RefCell::new(0));
+    rearm(&mut gui, calls.clone());
+
+    // timers created by callbacks run in the next advance.
+    gui.advance_time(1.0);
+    assert_eq!(*calls.borrow(), 1);
+    gui.advance_time(0.0);
+    assert_eq!(*calls.borrow(), 2);
+}
+
+#[test]
+fn animation_frames() {
+    let mut gui = HeadlessGui::new(100.0, 100.0);
+    let entries = Log::default();
+
+    fn animate(gui: &mut pogui::gui::Gui, l: Log, frames: u32) {
+        gui.request_animation_frame(move |gui, time| {
+            log(&l, format!("frame {}", time));
+            if frames > 1 {
+                animate(gui, l, frames - 1);
+            }
+        });
+    }
+    animate(&mut gui, entries.clone(), 3);
+
+    let l = entries.clone();
+    let cancelled = gui.request_animation_frame(move |_, _| log(&l, "cancelled".into()));
+    gui.cancel_animation_frame(cancelled);
+    assert!(gui.has_animation_frames());
+
+    // one frame per advance.
+    for _ in 0..5 {
+        gui.advance_time(0.5);
+    }
+    assert_eq!(*entries.borrow(), ["frame 0.5", "frame 1", "frame 1.5"]);
+    assert!(!gui.has_animation_frames());
+}
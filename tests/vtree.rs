@@ -0,0 +1,136 @@
+use core::cell::Cell;
+use std::rc::Rc;
+
+use pogui::gui::*;
+use pogui::headless_gui::HeadlessGui;
+use pogui::vtree::{Element, VTree};
+
+mod common;
+use common::children;
+
+
+fn item(key: &str) -> Element {
+    Element::new(NodeKind::Button).key(key)
+        .style(&[("display", "block"), ("width", "50"), ("height", "30")])
+        .child(Element::text(key))
+}
+
+
+#[test]
+fn patch_in_place() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let mut tree = VTree::new(g.root());
+
+    let view = |text: &str, color: &str| vec![
+        Element::new(NodeKind::Div).style(&[("background_color", color)])
+            .child(Element::new(NodeKind::Span).child(Element::text(text))),
+    ];
+
+    tree.update(&mut g, view("1", "ff0000"));
+    let div = g.get_first_child(tree.parent()).unwrap();
+    let span = g.get_first_child(div).unwrap();
+    let text = g.get_first_child(span).unwrap();
+    let stats = g.node_stats();
+
+    tree.update(&mut g, view("12", "00ff00"));
+    assert_eq!(children(&g, tree.parent()), [div]);
+    assert_eq!(children(&g, div), [span]);
+    assert_eq!(children(&g, span), [text]);
+    assert_eq!(g.node_stats(), stats);
+    g.update();
+
+    // a different kind replaces the node.
+    tree.update(&mut g, vec![Element::new(NodeKind::Button)]);
+    let button = g.get_first_child(tree.parent()).unwrap();
+    assert_ne!(button, div);
+    assert_eq!(g.node_stats().live, 2);
+}
+
+#[test]
+fn keyed_reorder() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let mut tree = VTree::new(root);
+
+    tree.update(&mut g, ["a", "b", "c", "d"].map(item).into());
+    let [a, b, c, d]: [Node; 4] = children(&g, root).try_into().unwrap();
+
+    g.hover(c);
+    assert_eq!(g.get_hover(), Some(c));
+
+    tree.update(&mut g, ["d", "a", "c", "b"].map(item).into());
+    assert_eq!(children(&g, root), [d, a, c, b]);
+    assert_eq!(g.get_hover(), Some(c));
+
+    // removes & inserts.
+    tree.update(&mut g, ["e", "c", "a"].map(item).into());
+    let nodes = children(&g, root);
+    assert_eq!(nodes[1..], [c, a]);
+    assert!(![a, b, c, d].contains(&nodes[0]));
+    assert_eq!(g.get_hover(), Some(c));
+    assert_eq!(g.node_stats().live, 1 + 2*3);
+}
+
+#[test]
+fn keyed_scroll_pos() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let root = g.root();
+    let mut tree = VTree::new(root);
+
+    let list = |key: &str| {
+        Element::new(NodeKind::Div).key(key).style(&[("height", "100")])
+            .children((0..10).map(|_| Element::new(NodeKind::Div).style(&[("height", "30")])))
+    };
+
+    tree.update(&mut g, vec![list("a"), list("b")]);
+    let [a, b]: [Node; 2] = children(&g, root).try_into().unwrap();
+    g.hover(g.get_first_child(b).unwrap());
+    g.on_mouse_wheel(-45.0, false);
+    g.update();
+    assert_eq!(g.get_scroll_pos(b), [0.0, 45.0]);
+
+    tree.update(&mut g, vec![list("b"), list("a")]);
+    g.update();
+    assert_eq!(children(&g, root), [b, a]);
+    assert_eq!(g.get_scroll_pos(b), [0.0, 45.0]);
+}
+
+#[test]
+fn handlers() {
+    let mut g = HeadlessGui::new(400.0, 300.0);
+    let mut tree = VTree::new(g.root());
+    let clicks = Rc::new(Cell::new(0));
+
+    let view = |amount: Option<u32>| {
+        let mut button = item("button");
+        if let Some(amount) = amount {
+            let clicks = clicks.clone();
+            button = button.on_click(move |_, _| clicks.set(clicks.get() + amount));
+        }
+        vec![button]
+    };
+
+    tree.update(&mut g, view(Some(1)));
+    g.click(25.0, 15.0);
+    assert_eq!(clicks.get(), 1);
+
+    tree.update(&mut g, view(Some(10)));
+    g.click(25.0, 15.0);
+    assert_eq!(clicks.get(), 11);
+
+    tree.update(&mut g, view(None));
+    g.click(25.0, 15.0);
+    assert_eq!(clicks.get(), 11);
+}
+
+#[test]
+#[should_panic(expected = "on_click on a Div element")]
+fn on_click_only_on_buttons() {
+    let _ = Element::new(NodeKind::Div).on_click(|_, _| ());
+}
+
+#[test]
+#[should_panic(expected = "style on a Text element")]
+fn no_style_on_text() {
+    let _ = Element::text("hi").style(&[("width", "50")]);
+}
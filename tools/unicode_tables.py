@@ -0,0 +1,107 @@
+# generates src/unicode/tables.rs from the unicode character database.
+# usage: python3 tools/unicode_tables.py <ucd dir> > src/unicode/tables.rs
+#
+# reads LineBreak.txt, EastAsianWidth.txt, extracted/DerivedGeneralCategory.txt,
+# extracted/DerivedBidiClass.txt, BidiMirroring.txt, BidiBrackets.txt and
+# emoji/emoji-data.txt (the files may also be directly in <ucd dir>).
+
+import os
+import sys
+
+
+def find(ucd, name):
+    for sub in ["", "extracted", "emoji"]:
+        path = os.path.join(ucd, sub, name)
+        if os.path.exists(path):
+            return path
+    sys.exit(f"{name} not found in {ucd}")
+
+def parse(path):
+    """yields (first, last, fields) for each data line.
+    `@missing` defaults come first, so later lines override them."""
+    with open(path, encoding="utf-8") as f:
+        lines = f.read().splitlines()
+
+    missing = [l.split(":", 1)[1] for l in lines if l.startswith("# @missing:")]
+    data    = [l.split("#", 1)[0] for l in lines]
+
+    for line in missing + data:
+        line = line.strip()
+        if not line:
+            continue
+        fields = [field.strip() for field in line.split(";")]
+        if ".." in fields[0]:
+            first, last = fields[0].split("..")
+        else:
+            first = last = fields[0]
+        yield int(first, 16), int(last, 16), fields[1:]
+
+def merge(ranges):
+    """merges adjacent ranges with the same value. later ranges override."""
+    values = {}
+    for first, last, value in ranges:
+        for cp in range(first, last + 1):
+            values[cp] = value
+
+    result = []
+    for cp in sorted(values):
+        value = values[cp]
+        if result and result[-1][1] + 1 == cp and result[-1][2] == value:
+            result[-1] = (result[-1][0], cp, value)
+        else:
+            result.append((cp, cp, value))
+    return result
+
+
+def enum_table(name, ty, ranges, default):
+    ranges = [r for r in merge(ranges) if r[2] != default]
+    out  = f"pub(crate) static {name}: &[(u32, u32, {ty})] = &[\n"
+    for first, last, value in ranges:
+        out += f"    (0x{first:04x}, 0x{last:04x}, {ty}::{value}),\n"
+    out += "];\n"
+    return out
+
+def set_table(name, ranges):
+    ranges = merge([(first, last, True) for first, last in ranges])
+    out  = f"pub(crate) static {name}: &[(u32, u32)] = &[\n"
+    for first, last, _ in ranges:
+        out += f"    (0x{first:04x}, 0x{last:04x}),\n"
+    out += "];\n"
+    return out
+
+
+def main():
+    ucd = sys.argv[1]
+
+    line_break = [(a, b, f[0]) for a, b, f in parse(find(ucd, "LineBreak.txt"))]
+    general_category = [(a, b, f[0]) for a, b, f in parse(find(ucd, "DerivedGeneralCategory.txt"))]
+    east_asian_wide = [(a, b) for a, b, v in merge((a, b, f[0]) for a, b, f in parse(find(ucd, "EastAsianWidth.txt"))) if v in ("F", "W", "H")]
+    pictographic = [(a, b) for a, b, f in parse(find(ucd, "emoji-data.txt")) if f[0] == "Extended_Pictographic"]
+    bidi_class = [(a, b, f[0]) for a, b, f in parse(find(ucd, "DerivedBidiClass.txt"))]
+    mirroring = [(a, int(f[0], 16)) for a, b, f in parse(find(ucd, "BidiMirroring.txt"))]
+    brackets = [(a, int(f[0], 16), f[1]) for a, b, f in parse(find(ucd, "BidiBrackets.txt"))]
+
+    out  = "// generated by tools/unicode_tables.py, do not edit.\n"
+    out += "// ranges are sorted & inclusive.\n\n"
+    out += "use super::*;\n\n\n"
+    out += enum_table("LINE_BREAK", "LineBreak", line_break, "XX") + "\n"
+    out += enum_table("GENERAL_CATEGORY", "GeneralCategory", general_category, "Cn") + "\n"
+    out += "// East_Asian_Width F, W & H.\n"
+    out += set_table("EAST_ASIAN_WIDE", east_asian_wide) + "\n"
+    out += set_table("EXTENDED_PICTOGRAPHIC", pictographic) + "\n"
+    out += enum_table("BIDI_CLASS", "BidiClass", bidi_class, "L") + "\n"
+
+    out += "// (char, mirrored char).\n"
+    out += "pub(crate) static BIDI_MIRRORING: &[(u32, u32)] = &[\n"
+    for cp, mirror in sorted(mirroring):
+        out += f"    (0x{cp:04x}, 0x{mirror:04x}),\n"
+    out += "];\n\n"
+
+    out += "// (char, paired bracket, is opening).\n"
+    out += "pub(crate) static BIDI_BRACKETS: &[(u32, u32, bool)] = &[\n"
+    for cp, pair, kind in sorted(brackets):
+        out += f"    (0x{cp:04x}, 0x{pair:04x}, {'true' if kind == 'o' else 'false'}),\n"
+    out += "];\n"
+    sys.stdout.write(out)
+
+main()